@@ -0,0 +1,97 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Verifiable backup of the centralized party's secret key share under a backup key.
+
+pub mod centralized_party;
+pub mod decentralized_party;
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+pub(crate) mod tests {
+    use rand_core::OsRng;
+    use tiresias::test_exports::{N, SECRET_KEY};
+
+    use crate::{
+        dkg::tests::generates_distributed_key_internal,
+        secp256k1::paillier::bulletproofs::{
+            BackupEncryptionRoundParty, BackupVerificationRoundParty, ProtocolPublicParameters,
+        },
+        session::{tests::session_context, Protocol},
+        sign::tests::presign_signs_internal,
+    };
+
+    #[test]
+    fn backs_up_and_recovers_secret_key_share() {
+        // The test exports only provide a single Paillier key, which we use for the backup as well.
+        let backup_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 4);
+
+        let backup = BackupEncryptionRoundParty::new(
+            session_context(Protocol::Backup),
+            backup_public_parameters.clone(),
+            centralized_party_dkg_output.clone(),
+        )
+        .unwrap()
+        .encrypt_and_prove_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        BackupVerificationRoundParty::new(
+            session_context(Protocol::Backup),
+            backup_public_parameters.clone(),
+            decentralized_party_dkg_output.clone(),
+        )
+        .unwrap()
+        .verify_backup(backup.clone(), &mut OsRng)
+        .unwrap();
+
+        let backup_decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &backup_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let recovered_output = backup
+            .recover(backup_decryption_key, &backup_public_parameters)
+            .unwrap();
+
+        assert_eq!(recovered_output, centralized_party_dkg_output);
+
+        presign_signs_internal(2, 4, 1, recovered_output, decentralized_party_dkg_output);
+    }
+
+    #[test]
+    fn rejects_backup_of_another_key() {
+        let backup_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, _) = generates_distributed_key_internal(2, 2);
+        let (_, other_decentralized_party_dkg_output) = generates_distributed_key_internal(2, 2);
+
+        let backup = BackupEncryptionRoundParty::new(
+            session_context(Protocol::Backup),
+            backup_public_parameters.clone(),
+            centralized_party_dkg_output,
+        )
+        .unwrap()
+        .encrypt_and_prove_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        assert!(
+            BackupVerificationRoundParty::new(
+                session_context(Protocol::Backup),
+                backup_public_parameters,
+                other_decentralized_party_dkg_output,
+            )
+            .unwrap()
+            .verify_backup(backup, &mut OsRng)
+            .is_err(),
+            "a backup of another key should be rejected"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use crypto_bigint::{NonZero, Uint};
+use enhanced_maurer::{
+    encryption_of_discrete_log, encryption_of_discrete_log::StatementAccessors,
+    language::EnhancedLanguageStatementAccessors, EnhanceableLanguage,
+};
+use group::{GroupElement, KnownOrderGroupElement, PrimeGroupElement, Reduce, Samplable};
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKey, AdditivelyHomomorphicEncryptionKey,
+    GroupsPublicParametersAccessors,
+};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, AggregatableRangeProof};
+use serde::{Deserialize, Serialize};
+
+use crate::{dkg, session::RoundContext, Error, ProtocolPublicParameters};
+
+pub mod encryption_round;
+
+/// A backup of the centralized party's secret key share $x_A$: an encryption of it under a backup
+/// key, alongside a proof that it hides the discrete log of our public key share $X_A$, so that it
+/// can be verified without learning $x_A$.
+///
+/// It also holds the public parts of our DKG output, so that the output can be recovered from the
+/// backup and the backup decryption key alone.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SecretKeyShareBackup<
+    GroupElementValue,
+    RangeProofCommitmentValue,
+    CiphertextValue,
+    EncDLProof,
+> {
+    pub(in crate::backup) public_key_share: GroupElementValue,
+    pub(in crate::backup) public_key: GroupElementValue,
+    pub(in crate::backup) encrypted_decentralized_party_secret_key_share: CiphertextValue,
+    pub(in crate::backup) decentralized_party_public_key_share: GroupElementValue,
+    pub(in crate::backup) key_share_updates: u32,
+    pub(in crate::backup) encrypted_secret_key_share: CiphertextValue,
+    pub(in crate::backup) range_proof_commitment: RangeProofCommitmentValue,
+    pub(in crate::backup) encryption_of_secret_key_share_proof: EncDLProof,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    SecretKeyShareBackup<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    pub fn new(
+        dkg_output: dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+        >,
+        encryption_of_secret_key_share: enhanced_maurer::StatementSpaceGroupElement<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >,
+        encryption_of_secret_key_share_proof: encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    ) -> Self {
+        let encrypted_secret_key_share = encryption_of_secret_key_share
+            .language_statement()
+            .encrypted_discrete_log()
+            .value();
+
+        let range_proof_commitment = encryption_of_secret_key_share
+            .range_proof_commitment()
+            .value();
+
+        Self {
+            public_key_share: dkg_output.public_key_share,
+            public_key: dkg_output.public_key,
+            encrypted_decentralized_party_secret_key_share: dkg_output
+                .encrypted_decentralized_party_secret_key_share,
+            decentralized_party_public_key_share: dkg_output.decentralized_party_public_key_share,
+            key_share_updates: dkg_output.key_share_updates,
+            encrypted_secret_key_share,
+            range_proof_commitment,
+            encryption_of_secret_key_share_proof,
+        }
+    }
+
+    /// Recover our DKG output from this backup by decrypting our secret key share with the
+    /// backup decryption key, checking that it is the discrete log of our public key share.
+    pub fn recover<
+        const NUM_RANGE_CLAIMS: usize,
+        DecryptionKey: AdditivelyHomomorphicDecryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        self,
+        decryption_key: DecryptionKey,
+        backup_public_parameters: &ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+    ) -> crate::Result<
+        dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+        >,
+    > {
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            self.encrypted_secret_key_share,
+            backup_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )?;
+
+        let secret_key_share: Uint<PLAINTEXT_SPACE_SCALAR_LIMBS> =
+            Option::<_>::from(decryption_key.decrypt(
+                &encrypted_secret_key_share,
+                &backup_public_parameters.encryption_scheme_public_parameters,
+            ))
+            .ok_or(Error::InvalidParameters)?
+            .into();
+
+        let group_order = GroupElement::Scalar::order_from_public_parameters(
+            &backup_public_parameters.scalar_group_public_parameters,
+        );
+
+        let group_order =
+            Option::<_>::from(NonZero::new(group_order)).ok_or(Error::InternalError)?;
+
+        let secret_key_share = GroupElement::Scalar::new(
+            secret_key_share.reduce(&group_order).into(),
+            &backup_public_parameters.scalar_group_public_parameters,
+        )?;
+
+        let generator = GroupElement::new(
+            GroupElement::generator_value_from_public_parameters(
+                &backup_public_parameters.group_public_parameters,
+            ),
+            &backup_public_parameters.group_public_parameters,
+        )?;
+
+        let public_key_share = GroupElement::new(
+            self.public_key_share,
+            &backup_public_parameters.group_public_parameters,
+        )?;
+
+        if secret_key_share * generator != public_key_share {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(dkg::centralized_party::Output {
+            secret_key_share: secret_key_share.value(),
+            public_key_share: self.public_key_share,
+            public_key: self.public_key,
+            encrypted_decentralized_party_secret_key_share: self
+                .encrypted_decentralized_party_secret_key_share,
+            decentralized_party_public_key_share: self.decentralized_party_public_key_share,
+            key_share_updates: self.key_share_updates,
+        })
+    }
+}
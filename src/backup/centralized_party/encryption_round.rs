@@ -0,0 +1,283 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use enhanced_maurer::{
+    encryption_of_discrete_log, EnhanceableLanguage, EnhancedLanguage, EnhancedPublicParameters,
+};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, AggregatableRangeProof};
+
+use crate::{
+    backup::centralized_party::SecretKeyShareBackup,
+    dkg,
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) secret_key_share: GroupElement::Scalar,
+    pub(super) dkg_output: dkg::centralized_party::Output<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Encrypt our secret key share $x_A$ under the backup key and prove that it is the discrete
+    /// log of our public key share $X_A$.
+    ///
+    /// The backup is bound to the current key shares: it must be re-created after a refresh or a
+    /// transfer of the key, as those change our secret key share.
+    pub fn encrypt_and_prove_secret_key_share(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        SecretKeyShareBackup<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+    > {
+        let encryption_randomness = EncryptionKey::RandomnessSpaceGroupElement::sample(
+            &self
+                .encryption_scheme_public_parameters
+                .as_ref()
+                .randomness_space_public_parameters,
+            rng,
+        )?;
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters.clone(),
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters.clone(),
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = EnhancedPublicParameters::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        let secret_key_share_value: Uint<SCALAR_LIMBS> = self.secret_key_share.into();
+
+        let witness = EnhancedLanguage::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::generate_witness(
+            (
+                EncryptionKey::PlaintextSpaceGroupElement::new(
+                    Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(&secret_key_share_value).into(),
+                    self.encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )?,
+                encryption_randomness,
+            )
+                .into(),
+            &language_public_parameters,
+            rng,
+        )?;
+
+        let (encryption_of_secret_key_share_proof, statements) = encryption_of_discrete_log::Proof::<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >::prove(
+            &self.protocol_context.round(RoundId::BackupEncryption),
+            &language_public_parameters,
+            vec![witness],
+            rng,
+        )?;
+
+        let statement = statements.first().ok_or(Error::InternalError)?.clone();
+
+        Ok(SecretKeyShareBackup::new(
+            self.dkg_output,
+            statement,
+            encryption_of_secret_key_share_proof,
+        ))
+    }
+
+    /// Instantiate the backup round, where `backup_public_parameters` are the protocol public
+    /// parameters instantiated with the backup encryption key rather than the decentralized
+    /// party's one.
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        backup_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<Self> {
+        let scalar_group_public_parameters =
+            backup_public_parameters.scalar_group_public_parameters;
+
+        let secret_key_share = GroupElement::Scalar::new(
+            dkg_output.secret_key_share.clone(),
+            &scalar_group_public_parameters,
+        )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters: backup_public_parameters.group_public_parameters,
+            encryption_scheme_public_parameters: backup_public_parameters
+                .encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: backup_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: backup_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            secret_key_share,
+            dkg_output,
+        })
+    }
+}
@@ -0,0 +1,249 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use commitment::GroupsPublicParametersAccessors as _;
+use crypto_bigint::rand_core::CryptoRngCore;
+use enhanced_maurer::{encryption_of_discrete_log, EnhanceableLanguage};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, range::PublicParametersAccessors, AggregatableRangeProof};
+
+use crate::{
+    backup::centralized_party::SecretKeyShareBackup,
+    dkg,
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) centralized_party_public_key_share: GroupElement,
+    pub(super) dkg_output: dkg::decentralized_party::Output<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Verify that the centralized party's backup encrypts the discrete log of its public key
+    /// share under the backup key, and that it holds the public parts of our DKG output.
+    ///
+    /// This can be performed by any party holding the (public) output of the decentralized party,
+    /// e.g. a custodian of the backup, without learning the centralized party's secret key share.
+    pub fn verify_backup(
+        self,
+        backup: SecretKeyShareBackup<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<()> {
+        if backup.public_key_share != self.dkg_output.centralized_party_public_key_share
+            || backup.public_key != self.dkg_output.public_key
+            || backup.encrypted_decentralized_party_secret_key_share
+                != self.dkg_output.encrypted_secret_key_share
+            || backup.decentralized_party_public_key_share != self.dkg_output.public_key_share
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            backup.encrypted_secret_key_share,
+            self.encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )?;
+
+        let range_proof_commitment = range::CommitmentSchemeCommitmentSpaceGroupElement::<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >::new(
+            backup.range_proof_commitment,
+            self.range_proof_public_parameters
+                .commitment_scheme_public_parameters()
+                .commitment_space_public_parameters(),
+        )?;
+
+        let statement = (
+            range_proof_commitment,
+            (
+                encrypted_secret_key_share,
+                self.centralized_party_public_key_share,
+            )
+                .into(),
+        )
+            .into();
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters,
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters,
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = enhanced_maurer::PublicParameters::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        backup.encryption_of_secret_key_share_proof.verify(
+            &self.protocol_context.round(RoundId::BackupEncryption),
+            &language_public_parameters,
+            vec![statement],
+            rng,
+        )?;
+
+        Ok(())
+    }
+
+    /// Instantiate the backup verification round, where `backup_public_parameters` are the
+    /// protocol public parameters instantiated with the backup encryption key.
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        backup_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<Self> {
+        let group_public_parameters = backup_public_parameters.group_public_parameters;
+
+        let centralized_party_public_key_share = GroupElement::new(
+            dkg_output.centralized_party_public_key_share.clone(),
+            &group_public_parameters,
+        )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters: backup_public_parameters.scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters: backup_public_parameters
+                .encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: backup_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: backup_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            centralized_party_public_key_share,
+            dkg_output,
+        })
+    }
+}
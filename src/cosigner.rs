@@ -0,0 +1,747 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! A co-signer whose approval is required for every signature, alongside the two parties.
+
+#![allow(clippy::type_complexity)]
+
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
+use enhanced_maurer::{
+    encryption_of_discrete_log, encryption_of_tuple, language::composed_witness_upper_bound,
+    EnhanceableLanguage, EnhancedPublicParameters,
+};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, range::PublicParametersAccessors as _, AggregatableRangeProof};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cosigner::cosigner_party::{EncryptedMaskedKeyShareAndProof, PublicKeyShareEncryptionAndProof},
+    session::{RoundContext, RoundId},
+    ProtocolPublicParameters,
+};
+
+pub mod centralized_party;
+pub mod cosigner_party;
+pub mod decentralized_party;
+
+/// The protocol context of the co-signer's proof in the signing protocol, which binds its
+/// approval to the message being signed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ApprovalContext<ProtocolContext, ScalarValue> {
+    pub(in crate::cosigner) protocol_context: ProtocolContext,
+    pub(in crate::cosigner) message: ScalarValue,
+}
+
+/// Verify the co-signer's proof that its encrypted secret key share $\Enc(x_P)$ hides the discrete
+/// log of its public key share $X_P$, returning both.
+fn verify_public_key_share_encryption<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const NUM_RANGE_CLAIMS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+>(
+    protocol_context: &ProtocolContext,
+    protocol_public_parameters: &ProtocolPublicParameters<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        UnboundedDComEvalWitness,
+    >,
+    public_key_share_encryption_and_proof: PublicKeyShareEncryptionAndProof<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<(GroupElement, EncryptionKey::CiphertextSpaceGroupElement)>
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    let public_key_share = GroupElement::new(
+        public_key_share_encryption_and_proof.public_key_share,
+        &protocol_public_parameters.group_public_parameters,
+    )?;
+
+    let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+        public_key_share_encryption_and_proof.encrypted_secret_key_share,
+        protocol_public_parameters
+            .encryption_scheme_public_parameters
+            .ciphertext_space_public_parameters(),
+    )?;
+
+    let range_proof_commitment = range::CommitmentSchemeCommitmentSpaceGroupElement::<
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RangeProof,
+    >::new(
+        public_key_share_encryption_and_proof.range_proof_commitment,
+        protocol_public_parameters
+            .range_proof_enc_dl_public_parameters
+            .commitment_scheme_public_parameters()
+            .commitment_space_public_parameters(),
+    )?;
+
+    let language_public_parameters =
+        encryption_of_discrete_log::PublicParameters::<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+            protocol_public_parameters
+                .scalar_group_public_parameters
+                .clone(),
+            protocol_public_parameters.group_public_parameters.clone(),
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .clone(),
+            GroupElement::generator_value_from_public_parameters(
+                &protocol_public_parameters.group_public_parameters,
+            ),
+        );
+
+    let language_public_parameters = EnhancedPublicParameters::<
+        SOUND_PROOFS_REPETITIONS,
+        RANGE_CLAIMS_PER_SCALAR,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RangeProof,
+        UnboundedEncDLWitness,
+        encryption_of_discrete_log::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+    >::new::<
+        RangeProof,
+        UnboundedEncDLWitness,
+        encryption_of_discrete_log::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+    >(
+        protocol_public_parameters
+            .unbounded_encdl_witness_public_parameters
+            .clone(),
+        protocol_public_parameters
+            .range_proof_enc_dl_public_parameters
+            .clone(),
+        language_public_parameters,
+    )?;
+
+    let statement = (
+        range_proof_commitment,
+        (encrypted_secret_key_share.clone(), public_key_share.clone()).into(),
+    )
+        .into();
+
+    public_key_share_encryption_and_proof
+        .encryption_of_secret_key_share_proof
+        .verify(
+            &protocol_context.round(RoundId::CosignerPublicKeyShare),
+            &language_public_parameters,
+            vec![statement],
+            rng,
+        )?;
+
+    Ok((public_key_share, encrypted_secret_key_share))
+}
+
+/// Verify the co-signer's proof that $\ct_P$ evaluates its encrypted secret key share over the
+/// encrypted mask $\ct_1$ with its approval of `message`, and add it to the encrypted masked key
+/// share $\ct_2$.
+#[allow(clippy::too_many_arguments)]
+fn verify_encrypted_masked_key_share<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const NUM_RANGE_CLAIMS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+>(
+    protocol_context: ProtocolContext,
+    protocol_public_parameters: &ProtocolPublicParameters<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        UnboundedDComEvalWitness,
+    >,
+    message: GroupElement::Scalar,
+    cosigner_encrypted_secret_key_share: group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    encrypted_mask: group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    encrypted_masked_key_share: group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    encrypted_masked_key_share_and_proof: EncryptedMaskedKeyShareAndProof<
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_tuple::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ApprovalContext<ProtocolContext, group::Value<GroupElement::Scalar>>,
+        >,
+    >,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<group::Value<EncryptionKey::CiphertextSpaceGroupElement>>
+where
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    let protocol_context = ApprovalContext {
+        protocol_context: protocol_context.round(RoundId::CosignerEncryptedMaskedKeyShare),
+        message: message.value(),
+    };
+
+    let ciphertext_space_public_parameters = protocol_public_parameters
+        .encryption_scheme_public_parameters
+        .ciphertext_space_public_parameters();
+
+    let cosigner_encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+        cosigner_encrypted_secret_key_share,
+        ciphertext_space_public_parameters,
+    )?;
+
+    let encrypted_masked_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+        encrypted_masked_key_share,
+        ciphertext_space_public_parameters,
+    )?;
+
+    let cosigner_encrypted_masked_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+        encrypted_masked_key_share_and_proof.encrypted_masked_key_share,
+        ciphertext_space_public_parameters,
+    )?;
+
+    let range_proof_commitment = range::CommitmentSchemeCommitmentSpaceGroupElement::<
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RangeProof,
+    >::new(
+        encrypted_masked_key_share_and_proof.range_proof_commitment,
+        protocol_public_parameters
+            .range_proof_enc_dl_public_parameters
+            .commitment_scheme_public_parameters()
+            .commitment_space_public_parameters(),
+    )?;
+
+    let encrypted_mask_upper_bound = composed_witness_upper_bound::<
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RangeProof,
+    >()?;
+
+    let language_public_parameters = encryption_of_tuple::PublicParameters::<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >::new::<SCALAR_LIMBS, GroupElement, EncryptionKey>(
+        protocol_public_parameters
+            .scalar_group_public_parameters
+            .clone(),
+        protocol_public_parameters
+            .encryption_scheme_public_parameters
+            .clone(),
+        encrypted_mask,
+        encrypted_mask_upper_bound,
+    );
+
+    let language_public_parameters = EnhancedPublicParameters::<
+        SOUND_PROOFS_REPETITIONS,
+        RANGE_CLAIMS_PER_SCALAR,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RangeProof,
+        UnboundedEncDHWitness,
+        encryption_of_tuple::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+    >::new::<
+        RangeProof,
+        UnboundedEncDHWitness,
+        encryption_of_tuple::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+    >(
+        protocol_public_parameters
+            .unbounded_encdh_witness_public_parameters
+            .clone(),
+        protocol_public_parameters
+            .range_proof_enc_dl_public_parameters
+            .clone(),
+        language_public_parameters,
+    )?;
+
+    let statement = (
+        range_proof_commitment,
+        [
+            cosigner_encrypted_secret_key_share,
+            cosigner_encrypted_masked_key_share.clone(),
+        ]
+        .into(),
+    )
+        .into();
+
+    encrypted_masked_key_share_and_proof
+        .encrypted_masked_key_share_proof
+        .verify(
+            &protocol_context,
+            &language_public_parameters,
+            vec![statement],
+            rng,
+        )?;
+
+    Ok((encrypted_masked_key_share + cosigner_encrypted_masked_key_share).value())
+}
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+pub(crate) mod tests {
+    use core::ops::Neg;
+
+    use crypto_bigint::NonZero;
+    use group::{secp256k1, GroupElement as _, KnownOrderGroupElement, PartyID, Reduce as _};
+    use homomorphic_encryption::{
+        AdditivelyHomomorphicDecryptionKey, GroupsPublicParametersAccessors,
+    };
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::test_exports::{N, SECRET_KEY};
+
+    use super::*;
+    use crate::{
+        dkg::tests::generates_distributed_key_internal,
+        presign::tests::generates_presignatures_internal,
+        secp256k1::paillier::bulletproofs::{
+            CosignerEncryptedMaskedKeyShareRoundParty, CosignerPublicKeyShareRoundParty,
+            ProtocolPublicParameters,
+        },
+        session::{tests::session_context, Protocol},
+        sign::{tests::signs_internal, Hash},
+    };
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(2, 4)]
+    fn signs_with_cosigner(#[case] threshold: PartyID, #[case] number_of_parties: PartyID) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let (public_key_share_encryption_and_proof, cosigner_dkg_output) =
+            CosignerPublicKeyShareRoundParty::new(
+                session_context(Protocol::Cosigner),
+                protocol_public_parameters.clone(),
+                decentralized_party_dkg_output.public_key,
+            )
+            .unwrap()
+            .sample_encrypt_and_prove_secret_key_share(&mut OsRng)
+            .unwrap();
+
+        let centralized_party_dkg_output = centralized_party::verify_public_key_share_encryption(
+            &session_context(Protocol::Cosigner),
+            &protocol_public_parameters,
+            centralized_party_dkg_output,
+            public_key_share_encryption_and_proof.clone(),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let decentralized_party_dkg_output =
+            decentralized_party::verify_public_key_share_encryption(
+                &session_context(Protocol::Cosigner),
+                &protocol_public_parameters,
+                decentralized_party_dkg_output,
+                public_key_share_encryption_and_proof,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert_eq!(
+            centralized_party_dkg_output.dkg_output.public_key,
+            cosigner_dkg_output.public_key
+        );
+
+        assert_eq!(
+            decentralized_party_dkg_output.dkg_output.public_key,
+            cosigner_dkg_output.public_key
+        );
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output
+                .dkg_output
+                .encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presigns, encrypted_nonces, decentralized_party_presigns) =
+            generates_presignatures_internal(
+                threshold,
+                number_of_parties,
+                1,
+                encrypted_secret_key_share.clone(),
+                false,
+            )
+            .unwrap();
+
+        let message = "singing!".as_bytes();
+
+        let encrypted_masked_key_share_and_proof = CosignerEncryptedMaskedKeyShareRoundParty::new(
+            session_context(Protocol::Cosigner),
+            protocol_public_parameters.clone(),
+            cosigner_dkg_output.clone(),
+            decentralized_party_presigns.first().unwrap().clone(),
+        )
+        .unwrap()
+        .evaluate_encrypted_masked_key_share(message, Hash::Sha256, &mut OsRng)
+        .unwrap();
+
+        let centralized_party_presign = centralized_party::verify_encrypted_masked_key_share(
+            session_context(Protocol::Cosigner),
+            &protocol_public_parameters,
+            message,
+            Hash::Sha256,
+            &centralized_party_dkg_output,
+            centralized_party_presigns.first().unwrap().clone(),
+            encrypted_masked_key_share_and_proof.clone(),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let decentralized_party_presign = decentralized_party::verify_encrypted_masked_key_share(
+            session_context(Protocol::Cosigner),
+            &protocol_public_parameters,
+            message,
+            Hash::Sha256,
+            &decentralized_party_dkg_output,
+            decentralized_party_presigns.first().unwrap().clone(),
+            encrypted_masked_key_share_and_proof,
+            &mut OsRng,
+        )
+        .unwrap();
+
+        assert_eq!(
+            centralized_party_presign.encrypted_masked_key_share,
+            decentralized_party_presign.encrypted_masked_key_share
+        );
+
+        let paillier_decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let group_order = secp256k1::Scalar::order_from_public_parameters(
+            &protocol_public_parameters.scalar_group_public_parameters,
+        );
+
+        let group_order = Option::<_>::from(NonZero::new(group_order)).unwrap();
+
+        let decrypt = |ciphertext: &tiresias::CiphertextSpaceGroupElement| {
+            let plaintext = paillier_decryption_key
+                .decrypt(
+                    ciphertext,
+                    &protocol_public_parameters.encryption_scheme_public_parameters,
+                )
+                .unwrap();
+
+            secp256k1::Scalar::new(
+                plaintext.value().reduce(&group_order).into(),
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap()
+        };
+
+        let new_ciphertext = |value| {
+            tiresias::CiphertextSpaceGroupElement::new(
+                value,
+                protocol_public_parameters
+                    .encryption_scheme_public_parameters
+                    .ciphertext_space_public_parameters(),
+            )
+            .unwrap()
+        };
+
+        let new_group_element = |value| {
+            secp256k1::GroupElement::new(value, &protocol_public_parameters.group_public_parameters)
+                .unwrap()
+        };
+
+        // The co-signer's share is folded into the decentralized party's, whose encrypted masked
+        // key share now encrypts $\gamma \cdot (x_B + x_P)$.
+        let decentralized_party_secret_key_share = decrypt(&encrypted_secret_key_share)
+            + secp256k1::Scalar::new(
+                cosigner_dkg_output.secret_key_share,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap();
+
+        let decentralized_party_public_key_share =
+            new_group_element(decentralized_party_dkg_output.dkg_output.public_key_share)
+                + new_group_element(decentralized_party_dkg_output.cosigner_public_key_share);
+
+        signs_internal(
+            threshold,
+            number_of_parties,
+            secp256k1::Scalar::new(
+                centralized_party_dkg_output.dkg_output.secret_key_share,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap(),
+            new_group_element(centralized_party_dkg_output.dkg_output.public_key_share),
+            decentralized_party_secret_key_share,
+            decentralized_party_public_key_share,
+            centralized_party_presign.nonce_share,
+            new_group_element(decentralized_party_presign.centralized_party_nonce_share_commitment),
+            decrypt(encrypted_nonces.first().unwrap()),
+            new_group_element(decentralized_party_presign.nonce_public_share),
+            centralized_party_presign.commitment_randomness,
+            new_ciphertext(decentralized_party_presign.encrypted_mask),
+            new_ciphertext(decentralized_party_presign.encrypted_masked_key_share),
+            new_ciphertext(decentralized_party_presign.encrypted_masked_nonce_share),
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn rejects_approval_of_other_message() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (_, decentralized_party_dkg_output) = generates_distributed_key_internal(2, 2);
+
+        let (public_key_share_encryption_and_proof, cosigner_dkg_output) =
+            CosignerPublicKeyShareRoundParty::new(
+                session_context(Protocol::Cosigner),
+                protocol_public_parameters.clone(),
+                decentralized_party_dkg_output.public_key,
+            )
+            .unwrap()
+            .sample_encrypt_and_prove_secret_key_share(&mut OsRng)
+            .unwrap();
+
+        let decentralized_party_dkg_output =
+            decentralized_party::verify_public_key_share_encryption(
+                &session_context(Protocol::Cosigner),
+                &protocol_public_parameters,
+                decentralized_party_dkg_output,
+                public_key_share_encryption_and_proof,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output
+                .dkg_output
+                .encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (_, _, decentralized_party_presigns) =
+            generates_presignatures_internal(2, 2, 1, encrypted_secret_key_share, false).unwrap();
+
+        let decentralized_party_presign = decentralized_party_presigns.first().unwrap().clone();
+
+        let encrypted_masked_key_share_and_proof = CosignerEncryptedMaskedKeyShareRoundParty::new(
+            session_context(Protocol::Cosigner),
+            protocol_public_parameters.clone(),
+            cosigner_dkg_output,
+            decentralized_party_presign.clone(),
+        )
+        .unwrap()
+        .evaluate_encrypted_masked_key_share("singing!".as_bytes(), Hash::Sha256, &mut OsRng)
+        .unwrap();
+
+        assert!(
+            decentralized_party::verify_encrypted_masked_key_share(
+                session_context(Protocol::Cosigner),
+                &protocol_public_parameters,
+                "stealing!".as_bytes(),
+                Hash::Sha256,
+                &decentralized_party_dkg_output,
+                decentralized_party_presign,
+                encrypted_masked_key_share_and_proof,
+                &mut OsRng,
+            )
+            .is_err(),
+            "the co-signer's approval should not be usable to sign any other message"
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_public_key_share() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 2);
+
+        let (mut public_key_share_encryption_and_proof, _) = CosignerPublicKeyShareRoundParty::new(
+            session_context(Protocol::Cosigner),
+            protocol_public_parameters.clone(),
+            decentralized_party_dkg_output.public_key,
+        )
+        .unwrap()
+        .sample_encrypt_and_prove_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        // A rogue co-signer cannot cancel out the other parties' shares of the public key.
+        let public_key = secp256k1::GroupElement::new(
+            decentralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        public_key_share_encryption_and_proof.public_key_share =
+            (public_key.neg() + public_key.generator()).value();
+
+        assert!(
+            centralized_party::verify_public_key_share_encryption(
+                &session_context(Protocol::Cosigner),
+                &protocol_public_parameters,
+                centralized_party_dkg_output,
+                public_key_share_encryption_and_proof.clone(),
+                &mut OsRng,
+            )
+            .is_err(),
+            "the centralized party should reject a public key share that does not match the proof"
+        );
+
+        assert!(
+            decentralized_party::verify_public_key_share_encryption(
+                &session_context(Protocol::Cosigner),
+                &protocol_public_parameters,
+                decentralized_party_dkg_output,
+                public_key_share_encryption_and_proof,
+                &mut OsRng,
+            )
+            .is_err(),
+            "the decentralized party should reject a public key share that does not match the proof"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use serde::{Deserialize, Serialize};
+
+pub mod encrypted_masked_key_share_round;
+pub mod public_key_share_round;
+
+/// The co-signer's output of the DKG: its secret key share $x_P$ and the randomness with which it
+/// was encrypted, its public key share $X_P$ and the three-way public key $X = X_A + X_B + X_P$.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Output<GroupElementValue, ScalarValue, RandomnessSpaceValue, CiphertextSpaceValue> {
+    pub(crate) secret_key_share: ScalarValue,
+    pub(crate) encryption_randomness: RandomnessSpaceValue,
+    pub encrypted_secret_key_share: CiphertextSpaceValue,
+    pub public_key_share: GroupElementValue,
+    pub public_key: GroupElementValue,
+}
+
+/// The message sent by the co-signer to the centralized and decentralized parties in the DKG: its
+/// public key share $X_P$ and an encryption of $x_P$, alongside a proof that it hides the discrete
+/// log of $X_P$.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeyShareEncryptionAndProof<
+    GroupElementValue,
+    RangeProofCommitmentValue,
+    CiphertextValue,
+    EncDLProof,
+> {
+    pub(in crate::cosigner) public_key_share: GroupElementValue,
+    pub(in crate::cosigner) encrypted_secret_key_share: CiphertextValue,
+    pub(in crate::cosigner) range_proof_commitment: RangeProofCommitmentValue,
+    pub(in crate::cosigner) encryption_of_secret_key_share_proof: EncDLProof,
+}
+
+/// The message sent by the co-signer to the centralized and decentralized parties upon approving
+/// a message: the evaluation $\ct_P = x_P \cdot \ct_1$ of its secret key share over the encrypted
+/// mask of the presign, alongside a proof that it was computed using the secret key share
+/// encrypted in the DKG.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedMaskedKeyShareAndProof<RangeProofCommitmentValue, CiphertextValue, EncDHProof> {
+    pub(in crate::cosigner) encrypted_masked_key_share: CiphertextValue,
+    pub(in crate::cosigner) range_proof_commitment: RangeProofCommitmentValue,
+    pub(in crate::cosigner) encrypted_masked_key_share_proof: EncDHProof,
+}
@@ -0,0 +1,360 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
+use enhanced_maurer::{
+    encryption_of_tuple,
+    encryption_of_tuple::StatementAccessors as _,
+    language::{composed_witness_upper_bound, EnhancedLanguageStatementAccessors as _},
+    EnhanceableLanguage, EnhancedLanguage, EnhancedPublicParameters,
+};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, AggregatableRangeProof};
+
+use crate::{
+    cosigner::{
+        cosigner_party::{EncryptedMaskedKeyShareAndProof, Output},
+        ApprovalContext,
+    },
+    presign,
+    session::{RoundContext, RoundId},
+    sign::{message_digest, Hash},
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdh_witness_public_parameters: UnboundedEncDHWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) secret_key_share: GroupElement::Scalar,
+    pub(super) encryption_randomness: EncryptionKey::RandomnessSpaceGroupElement,
+    pub(super) encrypted_mask: EncryptionKey::CiphertextSpaceGroupElement,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    /// Approve signing `message`, which is hashed using `hash` and converted into a scalar via
+    /// [`message_digest`].
+    ///
+    /// See [`Self::evaluate_encrypted_masked_key_share_prehash`].
+    pub fn evaluate_encrypted_masked_key_share(
+        self,
+        message: &[u8],
+        hash: Hash,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        EncryptedMaskedKeyShareAndProof<
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_tuple::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDHWitness,
+                ApprovalContext<ProtocolContext, group::Value<GroupElement::Scalar>>,
+            >,
+        >,
+    > {
+        let message = message_digest::<SCALAR_LIMBS, GroupElement>(
+            message,
+            hash,
+            &self.scalar_group_public_parameters,
+        )?;
+
+        self.evaluate_encrypted_masked_key_share_prehash(message, rng)
+    }
+
+    /// Approve signing `message` by homomorphically evaluating $\ct_P = x_P \cdot \ct_1$ over the
+    /// encrypted mask of the presign, which both the centralized and decentralized parties add to
+    /// the encrypted masked key share $\ct_2 = \Enc(\gamma \cdot x_B)$ before signing.
+    ///
+    /// The proof is bound to `message`, so that the decentralized party, which verifies it before
+    /// partially decrypting, only signs messages we approved. As $\ct_P$ is never decrypted on its
+    /// own, it is not masked, and so respects the upper bound of $\ct_2$.
+    ///
+    /// Note: `message` is a `Scalar` which must be a hash on the message bytes translated into a
+    /// 32-byte number.
+    pub fn evaluate_encrypted_masked_key_share_prehash(
+        self,
+        message: GroupElement::Scalar,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        EncryptedMaskedKeyShareAndProof<
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_tuple::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDHWitness,
+                ApprovalContext<ProtocolContext, group::Value<GroupElement::Scalar>>,
+            >,
+        >,
+    > {
+        let protocol_context = ApprovalContext {
+            protocol_context: self
+                .protocol_context
+                .round(RoundId::CosignerEncryptedMaskedKeyShare),
+            message: message.value(),
+        };
+
+        let encrypted_mask_upper_bound = composed_witness_upper_bound::<
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+        >()?;
+
+        let language_public_parameters = encryption_of_tuple::PublicParameters::<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >::new::<SCALAR_LIMBS, GroupElement, EncryptionKey>(
+            self.scalar_group_public_parameters,
+            self.encryption_scheme_public_parameters.clone(),
+            self.encrypted_mask.value(),
+            encrypted_mask_upper_bound,
+        );
+
+        let language_public_parameters = EnhancedPublicParameters::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDHWitness,
+            encryption_of_tuple::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::new::<
+            RangeProof,
+            UnboundedEncDHWitness,
+            encryption_of_tuple::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdh_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        let masked_key_share_encryption_randomness =
+            EncryptionKey::RandomnessSpaceGroupElement::sample(
+                self.encryption_scheme_public_parameters
+                    .randomness_space_public_parameters(),
+                rng,
+            )?;
+
+        let secret_key_share_value: Uint<SCALAR_LIMBS> = self.secret_key_share.into();
+
+        // The encryption of our secret key share is recomputed using the randomness of the DKG, so
+        // that the proof is bound to the encryption the other parties verified.
+        let witness = EnhancedLanguage::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDHWitness,
+            encryption_of_tuple::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::generate_witness(
+            (
+                EncryptionKey::PlaintextSpaceGroupElement::new(
+                    Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(&secret_key_share_value).into(),
+                    self.encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )?,
+                self.encryption_randomness,
+                masked_key_share_encryption_randomness,
+            )
+                .into(),
+            &language_public_parameters,
+            rng,
+        )?;
+
+        let (encrypted_masked_key_share_proof, statements) = encryption_of_tuple::Proof::<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ApprovalContext<ProtocolContext, group::Value<GroupElement::Scalar>>,
+        >::prove(
+            &protocol_context,
+            &language_public_parameters,
+            vec![witness],
+            rng,
+        )?;
+
+        let statement = statements.first().ok_or(Error::InternalError)?;
+
+        Ok(EncryptedMaskedKeyShareAndProof {
+            encrypted_masked_key_share: statement.language_statement().encrypted_product().value(),
+            range_proof_commitment: statement.range_proof_commitment().value(),
+            encrypted_masked_key_share_proof,
+        })
+    }
+
+    /// Instantiate the co-signer's signing party for a `presign` generated by the centralized and
+    /// decentralized parties, of which only the public encrypted mask $\ct_1$ is used.
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::RandomnessSpaceGroupElement>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        presign: presign::decentralized_party::Presign<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<Self> {
+        let scalar_group_public_parameters =
+            protocol_public_parameters.scalar_group_public_parameters;
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let secret_key_share = GroupElement::Scalar::new(
+            dkg_output.secret_key_share,
+            &scalar_group_public_parameters,
+        )?;
+
+        let encryption_randomness = EncryptionKey::RandomnessSpaceGroupElement::new(
+            dkg_output.encryption_randomness,
+            encryption_scheme_public_parameters.randomness_space_public_parameters(),
+        )?;
+
+        let encrypted_mask = EncryptionKey::CiphertextSpaceGroupElement::new(
+            presign.encrypted_mask,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_encdh_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdh_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            secret_key_share,
+            encryption_randomness,
+            encrypted_mask,
+        })
+    }
+}
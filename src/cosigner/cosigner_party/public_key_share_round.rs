@@ -0,0 +1,298 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use enhanced_maurer::{
+    encryption_of_discrete_log, encryption_of_discrete_log::StatementAccessors as _,
+    language::EnhancedLanguageStatementAccessors as _, EnhanceableLanguage, EnhancedLanguage,
+    EnhancedPublicParameters,
+};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, AggregatableRangeProof};
+
+use crate::{
+    cosigner::cosigner_party::{Output, PublicKeyShareEncryptionAndProof},
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) two_party_public_key: GroupElement,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Sample our secret key share $x_P$, encrypt it and prove that it is the discrete log of our
+    /// public key share $X_P$, which is added to the two-party public key $X_A + X_B$.
+    ///
+    /// As the two-party public key is fixed prior to this round and $X_P$ comes with a proof of
+    /// knowledge of its discrete log, we cannot bias the three-way public key.
+    pub fn sample_encrypt_and_prove_secret_key_share(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        PublicKeyShareEncryptionAndProof<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+        Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::RandomnessSpaceGroupElement>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    )> {
+        let secret_key_share =
+            GroupElement::Scalar::sample(&self.scalar_group_public_parameters, rng)?;
+
+        let encryption_randomness = EncryptionKey::RandomnessSpaceGroupElement::sample(
+            self.encryption_scheme_public_parameters
+                .randomness_space_public_parameters(),
+            rng,
+        )?;
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters.clone(),
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters.clone(),
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = EnhancedPublicParameters::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        let secret_key_share_value: Uint<SCALAR_LIMBS> = secret_key_share.into();
+
+        let witness = EnhancedLanguage::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::generate_witness(
+            (
+                EncryptionKey::PlaintextSpaceGroupElement::new(
+                    Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(&secret_key_share_value).into(),
+                    self.encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )?,
+                encryption_randomness.clone(),
+            )
+                .into(),
+            &language_public_parameters,
+            rng,
+        )?;
+
+        let (encryption_of_secret_key_share_proof, statements) = encryption_of_discrete_log::Proof::<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >::prove(
+            &self.protocol_context.round(RoundId::CosignerPublicKeyShare),
+            &language_public_parameters,
+            vec![witness],
+            rng,
+        )?;
+
+        let statement = statements.first().ok_or(Error::InternalError)?;
+
+        let encrypted_secret_key_share = statement
+            .language_statement()
+            .encrypted_discrete_log()
+            .value();
+
+        let public_key_share = statement.language_statement().base_by_discrete_log();
+
+        let public_key = self.two_party_public_key + public_key_share;
+
+        let public_key_share_encryption_and_proof = PublicKeyShareEncryptionAndProof {
+            public_key_share: public_key_share.value(),
+            encrypted_secret_key_share: encrypted_secret_key_share.clone(),
+            range_proof_commitment: statement.range_proof_commitment().value(),
+            encryption_of_secret_key_share_proof,
+        };
+
+        let output = Output {
+            secret_key_share: secret_key_share.value(),
+            encryption_randomness: encryption_randomness.value(),
+            encrypted_secret_key_share,
+            public_key_share: public_key_share.value(),
+            public_key: public_key.value(),
+        };
+
+        Ok((public_key_share_encryption_and_proof, output))
+    }
+
+    /// Instantiate the co-signer's DKG party, given the `two_party_public_key` $X_A + X_B$ output
+    /// by the DKG between the centralized and decentralized parties.
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        two_party_public_key: GroupElement::Value,
+    ) -> crate::Result<Self> {
+        let group_public_parameters = protocol_public_parameters.group_public_parameters;
+
+        let two_party_public_key =
+            GroupElement::new(two_party_public_key, &group_public_parameters)?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters: protocol_public_parameters
+                .encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            two_party_public_key,
+        })
+    }
+}
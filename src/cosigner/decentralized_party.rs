@@ -0,0 +1,396 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
+use enhanced_maurer::{encryption_of_discrete_log, encryption_of_tuple, EnhanceableLanguage};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, AggregatableRangeProof};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cosigner::{
+        cosigner_party::{EncryptedMaskedKeyShareAndProof, PublicKeyShareEncryptionAndProof},
+        ApprovalContext,
+    },
+    dkg, presign,
+    session::RoundContext,
+    sign::{message_digest, Hash},
+    Error, ProtocolPublicParameters,
+};
+
+/// The decentralized party's output of the DKG with a co-signer: its two-party output, whose
+/// public key is extended to $X = X_A + X_B + X_P$, alongside the co-signer's public key share and
+/// encrypted secret key share against which its approvals are verified.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Output<GroupElementValue, CiphertextSpaceValue> {
+    pub dkg_output: dkg::decentralized_party::Output<GroupElementValue, CiphertextSpaceValue>,
+    pub cosigner_public_key_share: GroupElementValue,
+    pub cosigner_encrypted_secret_key_share: CiphertextSpaceValue,
+}
+
+/// Verify the co-signer's public key share and encrypted secret key share sent in the DKG, and
+/// extend the decentralized party's `dkg_output` into a three-way key.
+pub fn verify_public_key_share_encryption<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const NUM_RANGE_CLAIMS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+>(
+    protocol_context: &ProtocolContext,
+    protocol_public_parameters: &ProtocolPublicParameters<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        UnboundedDComEvalWitness,
+    >,
+    dkg_output: dkg::decentralized_party::Output<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    public_key_share_encryption_and_proof: PublicKeyShareEncryptionAndProof<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<
+    Output<GroupElement::Value, group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+>
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    let (cosigner_public_key_share, cosigner_encrypted_secret_key_share) =
+        super::verify_public_key_share_encryption(
+            protocol_context,
+            protocol_public_parameters,
+            public_key_share_encryption_and_proof,
+            rng,
+        )?;
+
+    let public_key = GroupElement::new(
+        dkg_output.public_key,
+        &protocol_public_parameters.group_public_parameters,
+    )?;
+
+    let public_key = public_key + &cosigner_public_key_share;
+
+    Ok(Output {
+        dkg_output: dkg::decentralized_party::Output {
+            public_key: public_key.value(),
+            ..dkg_output
+        },
+        cosigner_public_key_share: cosigner_public_key_share.value(),
+        cosigner_encrypted_secret_key_share: cosigner_encrypted_secret_key_share.value(),
+    })
+}
+
+/// Verify the co-signer's approval of signing `message`, which is hashed using `hash` and
+/// converted into a scalar via [`message_digest`].
+///
+/// See [`verify_encrypted_masked_key_share_prehash`].
+#[allow(clippy::too_many_arguments)]
+pub fn verify_encrypted_masked_key_share<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const NUM_RANGE_CLAIMS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+>(
+    protocol_context: ProtocolContext,
+    protocol_public_parameters: &ProtocolPublicParameters<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        UnboundedDComEvalWitness,
+    >,
+    message: &[u8],
+    hash: Hash,
+    dkg_output: &Output<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    presign: presign::decentralized_party::Presign<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    encrypted_masked_key_share_and_proof: EncryptedMaskedKeyShareAndProof<
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_tuple::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ApprovalContext<ProtocolContext, group::Value<GroupElement::Scalar>>,
+        >,
+    >,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<
+    presign::decentralized_party::Presign<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+>
+where
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    let message = message_digest::<SCALAR_LIMBS, GroupElement>(
+        message,
+        hash,
+        &protocol_public_parameters.scalar_group_public_parameters,
+    )?;
+
+    verify_encrypted_masked_key_share_prehash(
+        protocol_context,
+        protocol_public_parameters,
+        message,
+        dkg_output,
+        presign,
+        encrypted_masked_key_share_and_proof,
+        rng,
+    )
+}
+
+/// Verify the co-signer's approval of signing `message`, and add its evaluation $\ct_P = x_P
+/// \cdot \ct_1$ to the encrypted masked key share of `presign`.
+///
+/// This must be called before partially decrypting, so that the decentralized party only signs
+/// messages the co-signer approved. The returned presign can then be used with the extended DKG
+/// output of `dkg_output` by the decentralized party's signing protocol, which verifies the
+/// centralized party's proofs against the combined encrypted masked key share.
+///
+/// Note: `message` is a `Scalar` which must be a hash on the message bytes translated into a
+/// 32-byte number.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_encrypted_masked_key_share_prehash<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const NUM_RANGE_CLAIMS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+>(
+    protocol_context: ProtocolContext,
+    protocol_public_parameters: &ProtocolPublicParameters<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        UnboundedDComEvalWitness,
+    >,
+    message: GroupElement::Scalar,
+    dkg_output: &Output<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    presign: presign::decentralized_party::Presign<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    encrypted_masked_key_share_and_proof: EncryptedMaskedKeyShareAndProof<
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_tuple::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ApprovalContext<ProtocolContext, group::Value<GroupElement::Scalar>>,
+        >,
+    >,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<
+    presign::decentralized_party::Presign<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+>
+where
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    if presign
+        .encrypted_secret_key_share
+        .as_ref()
+        .is_some_and(|encrypted_secret_key_share| {
+            *encrypted_secret_key_share != dkg_output.dkg_output.encrypted_secret_key_share
+        })
+    {
+        return Err(Error::StalePresign);
+    }
+
+    let encrypted_masked_key_share = super::verify_encrypted_masked_key_share(
+        protocol_context,
+        protocol_public_parameters,
+        message,
+        dkg_output.cosigner_encrypted_secret_key_share.clone(),
+        presign.encrypted_mask.clone(),
+        presign.encrypted_masked_key_share,
+        encrypted_masked_key_share_and_proof,
+        rng,
+    )?;
+
+    Ok(presign::decentralized_party::Presign {
+        encrypted_masked_key_share,
+        ..presign
+    })
+}
@@ -0,0 +1,860 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Custody of the centralized party's secrets split among its devices.
+//!
+//! The centralized party's secret key share and presigns are Shamir-shared at rest, so that no
+//! single device holds them, and signing requires the approval of `threshold` devices. This is
+//! not threshold signing among the devices: the approving devices send their shares to the signing
+//! device, which reconstructs the secrets and runs the centralized party's rounds alone. That
+//! device learns the centralized party's secret key share, so it must be trusted for the duration
+//! of the signing session.
+
+use std::{collections::HashMap, ops::Neg};
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use group::{GroupElement as _, Invert, PartyID, PrimeGroupElement};
+use serde::{Deserialize, Serialize};
+
+use crate::{dkg, presign, Error};
+
+/// A Shamir share of a secret of the centralized party held by one of its devices, alongside
+/// Feldman commitments to the coefficients of the sharing polynomial, against which it is
+/// verified.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SecretShare<ScalarValue, GroupElementValue> {
+    pub(in crate::custody) share: ScalarValue,
+    pub(in crate::custody) polynomial_commitments: Vec<GroupElementValue>,
+}
+
+/// A device's share of the centralized party's output of the [`dkg`], in which the secret key
+/// share is replaced by a Shamir share of it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DKGOutputShare<GroupElementValue, ScalarValue, CiphertextSpaceValue> {
+    pub(in crate::custody) secret_key_share: SecretShare<ScalarValue, GroupElementValue>,
+    pub(in crate::custody) public_key_share: GroupElementValue,
+    pub public_key: GroupElementValue,
+    pub encrypted_decentralized_party_secret_key_share: CiphertextSpaceValue,
+    pub(in crate::custody) decentralized_party_public_key_share: GroupElementValue,
+    pub key_share_updates: u32,
+}
+
+/// A device's share of a centralized party [`presign::centralized_party::Presign`], in which the
+/// nonce share and its commitment randomness are replaced by Shamir shares of them.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PresignShare<GroupElementValue, ScalarValue, CiphertextValue> {
+    pub(in crate::custody) nonce_share: SecretShare<ScalarValue, GroupElementValue>,
+    pub(in crate::custody) commitment_randomness: SecretShare<ScalarValue, GroupElementValue>,
+    pub(in crate::custody) decentralized_party_nonce_public_share: GroupElementValue,
+    pub(in crate::custody) encrypted_mask: CiphertextValue,
+    pub(in crate::custody) encrypted_masked_key_share: CiphertextValue,
+    pub(in crate::custody) encrypted_nonce_share: CiphertextValue,
+    pub(in crate::custody) encrypted_secret_key_share: CiphertextValue,
+}
+
+/// Share the centralized party's output of the [`dkg`] among `number_of_devices` devices, any
+/// `threshold` of which are required to reconstruct it for signing.
+///
+/// The dealing device must erase `dkg_output` once the shares are delivered. Each device should
+/// check its share with [`verify_dkg_output_share`] upon receiving it.
+pub fn share_dkg_output<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    CiphertextSpaceValue: Clone,
+>(
+    dkg_output: dkg::centralized_party::Output<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        CiphertextSpaceValue,
+    >,
+    threshold: PartyID,
+    number_of_devices: PartyID,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<
+    HashMap<
+        PartyID,
+        DKGOutputShare<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            CiphertextSpaceValue,
+        >,
+    >,
+> {
+    let secret_key_share =
+        GroupElement::Scalar::new(dkg_output.secret_key_share, scalar_group_public_parameters)?;
+
+    let secret_key_shares = share_secret::<SCALAR_LIMBS, GroupElement>(
+        secret_key_share,
+        threshold,
+        number_of_devices,
+        scalar_group_public_parameters,
+        group_public_parameters,
+        rng,
+    )?;
+
+    Ok(secret_key_shares
+        .into_iter()
+        .map(|(device_id, secret_key_share)| {
+            (
+                device_id,
+                DKGOutputShare {
+                    secret_key_share,
+                    public_key_share: dkg_output.public_key_share.clone(),
+                    public_key: dkg_output.public_key.clone(),
+                    encrypted_decentralized_party_secret_key_share: dkg_output
+                        .encrypted_decentralized_party_secret_key_share
+                        .clone(),
+                    decentralized_party_public_key_share: dkg_output
+                        .decentralized_party_public_key_share
+                        .clone(),
+                    key_share_updates: dkg_output.key_share_updates,
+                },
+            )
+        })
+        .collect())
+}
+
+/// Verify the share of the [`dkg`] output received by `device_id`, and that it shares the
+/// secret key share behind its public key share.
+pub fn verify_dkg_output_share<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    CiphertextSpaceValue,
+>(
+    device_id: PartyID,
+    threshold: PartyID,
+    dkg_output_share: &DKGOutputShare<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        CiphertextSpaceValue,
+    >,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<()> {
+    let public_key_share = GroupElement::new(
+        dkg_output_share.public_key_share.clone(),
+        group_public_parameters,
+    )?;
+
+    verify_secret_share::<SCALAR_LIMBS, GroupElement>(
+        device_id,
+        threshold,
+        &dkg_output_share.secret_key_share,
+        Some(public_key_share),
+        scalar_group_public_parameters,
+        group_public_parameters,
+    )
+}
+
+/// Reconstruct the centralized party's output of the [`dkg`] on the signing device `device_id`
+/// from the shares of (at least) `threshold` approving devices.
+///
+/// Devices whose shares do not match those of `device_id` are identified. The reconstructed
+/// output holds the centralized party's whole secret key share: it is used to instantiate the
+/// centralized party's `signature_homomorphic_evaluation_round` party, which is why the
+/// decentralized party sees the exact same messages as with a single device, and must be erased
+/// once the round completes.
+pub fn reconstruct_dkg_output<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    CiphertextSpaceValue: PartialEq,
+>(
+    device_id: PartyID,
+    threshold: PartyID,
+    mut dkg_output_shares: HashMap<
+        PartyID,
+        DKGOutputShare<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            CiphertextSpaceValue,
+        >,
+    >,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<
+    dkg::centralized_party::Output<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        CiphertextSpaceValue,
+    >,
+> {
+    let dkg_output_share = dkg_output_shares
+        .remove(&device_id)
+        .ok_or(Error::InvalidParameters)?;
+
+    let mut malicious_devices: Vec<_> = dkg_output_shares
+        .iter()
+        .filter(|(_, other_dkg_output_share)| {
+            other_dkg_output_share.public_key_share != dkg_output_share.public_key_share
+                || other_dkg_output_share.public_key != dkg_output_share.public_key
+                || other_dkg_output_share.encrypted_decentralized_party_secret_key_share
+                    != dkg_output_share.encrypted_decentralized_party_secret_key_share
+                || other_dkg_output_share.decentralized_party_public_key_share
+                    != dkg_output_share.decentralized_party_public_key_share
+                || other_dkg_output_share.key_share_updates != dkg_output_share.key_share_updates
+        })
+        .map(|(&other_device_id, _)| other_device_id)
+        .collect();
+
+    if !malicious_devices.is_empty() {
+        malicious_devices.sort();
+
+        return Err(Error::MaliciousDevices(malicious_devices));
+    }
+
+    let mut secret_key_shares: HashMap<_, _> = dkg_output_shares
+        .into_iter()
+        .map(|(other_device_id, other_dkg_output_share)| {
+            (other_device_id, other_dkg_output_share.secret_key_share)
+        })
+        .collect();
+    secret_key_shares.insert(device_id, dkg_output_share.secret_key_share);
+
+    let public_key_share = GroupElement::new(
+        dkg_output_share.public_key_share.clone(),
+        group_public_parameters,
+    )?;
+
+    let secret_key_share = reconstruct_secret::<SCALAR_LIMBS, GroupElement>(
+        threshold,
+        secret_key_shares,
+        Some(public_key_share),
+        scalar_group_public_parameters,
+        group_public_parameters,
+    )?;
+
+    Ok(dkg::centralized_party::Output {
+        secret_key_share: secret_key_share.value(),
+        public_key_share: dkg_output_share.public_key_share,
+        public_key: dkg_output_share.public_key,
+        encrypted_decentralized_party_secret_key_share: dkg_output_share
+            .encrypted_decentralized_party_secret_key_share,
+        decentralized_party_public_key_share: dkg_output_share.decentralized_party_public_key_share,
+        key_share_updates: dkg_output_share.key_share_updates,
+    })
+}
+
+/// Share a centralized party [`presign::centralized_party::Presign`] among `number_of_devices`
+/// devices, any `threshold` of which are required to reconstruct it for signing.
+///
+/// The nonce share is sampled by the device which runs the presign protocol, which must erase
+/// `presign` once the shares are delivered. Each device should check its share with
+/// [`verify_presign_share`] upon receiving it.
+pub fn share_presign<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    CiphertextValue: Clone,
+>(
+    presign: presign::centralized_party::Presign<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        CiphertextValue,
+    >,
+    threshold: PartyID,
+    number_of_devices: PartyID,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<
+    HashMap<
+        PartyID,
+        PresignShare<GroupElement::Value, group::Value<GroupElement::Scalar>, CiphertextValue>,
+    >,
+> {
+    let nonce_share =
+        GroupElement::Scalar::new(presign.nonce_share, scalar_group_public_parameters)?;
+
+    let commitment_randomness = GroupElement::Scalar::new(
+        presign.commitment_randomness,
+        scalar_group_public_parameters,
+    )?;
+
+    let mut nonce_shares = share_secret::<SCALAR_LIMBS, GroupElement>(
+        nonce_share,
+        threshold,
+        number_of_devices,
+        scalar_group_public_parameters,
+        group_public_parameters,
+        rng,
+    )?;
+
+    let mut commitment_randomness_shares = share_secret::<SCALAR_LIMBS, GroupElement>(
+        commitment_randomness,
+        threshold,
+        number_of_devices,
+        scalar_group_public_parameters,
+        group_public_parameters,
+        rng,
+    )?;
+
+    (1..=number_of_devices)
+        .map(|device_id| {
+            let nonce_share = nonce_shares
+                .remove(&device_id)
+                .ok_or(Error::InternalError)?;
+
+            let commitment_randomness = commitment_randomness_shares
+                .remove(&device_id)
+                .ok_or(Error::InternalError)?;
+
+            Ok((
+                device_id,
+                PresignShare {
+                    nonce_share,
+                    commitment_randomness,
+                    decentralized_party_nonce_public_share: presign
+                        .decentralized_party_nonce_public_share
+                        .clone(),
+                    encrypted_mask: presign.encrypted_mask.clone(),
+                    encrypted_masked_key_share: presign.encrypted_masked_key_share.clone(),
+                    encrypted_nonce_share: presign.encrypted_nonce_share.clone(),
+                    encrypted_secret_key_share: presign.encrypted_secret_key_share.clone(),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Verify the share of a presign received by `device_id`.
+pub fn verify_presign_share<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    CiphertextValue,
+>(
+    device_id: PartyID,
+    threshold: PartyID,
+    presign_share: &PresignShare<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        CiphertextValue,
+    >,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<()> {
+    verify_secret_share::<SCALAR_LIMBS, GroupElement>(
+        device_id,
+        threshold,
+        &presign_share.nonce_share,
+        None,
+        scalar_group_public_parameters,
+        group_public_parameters,
+    )?;
+
+    verify_secret_share::<SCALAR_LIMBS, GroupElement>(
+        device_id,
+        threshold,
+        &presign_share.commitment_randomness,
+        None,
+        scalar_group_public_parameters,
+        group_public_parameters,
+    )
+}
+
+/// Reconstruct a centralized party [`presign::centralized_party::Presign`] on the signing device
+/// `device_id` from the shares of (at least) `threshold` approving devices.
+///
+/// Devices whose shares do not match those of `device_id` are identified. Like the reconstructed
+/// [`dkg`] output, the presign must be erased once the signing round completes.
+pub fn reconstruct_presign<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    CiphertextValue: PartialEq,
+>(
+    device_id: PartyID,
+    threshold: PartyID,
+    mut presign_shares: HashMap<
+        PartyID,
+        PresignShare<GroupElement::Value, group::Value<GroupElement::Scalar>, CiphertextValue>,
+    >,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<
+    presign::centralized_party::Presign<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        CiphertextValue,
+    >,
+> {
+    let presign_share = presign_shares
+        .remove(&device_id)
+        .ok_or(Error::InvalidParameters)?;
+
+    let mut malicious_devices: Vec<_> = presign_shares
+        .iter()
+        .filter(|(_, other_presign_share)| {
+            other_presign_share.nonce_share.polynomial_commitments
+                != presign_share.nonce_share.polynomial_commitments
+                || other_presign_share
+                    .commitment_randomness
+                    .polynomial_commitments
+                    != presign_share.commitment_randomness.polynomial_commitments
+                || other_presign_share.decentralized_party_nonce_public_share
+                    != presign_share.decentralized_party_nonce_public_share
+                || other_presign_share.encrypted_mask != presign_share.encrypted_mask
+                || other_presign_share.encrypted_masked_key_share
+                    != presign_share.encrypted_masked_key_share
+                || other_presign_share.encrypted_nonce_share != presign_share.encrypted_nonce_share
+                || other_presign_share.encrypted_secret_key_share
+                    != presign_share.encrypted_secret_key_share
+        })
+        .map(|(&other_device_id, _)| other_device_id)
+        .collect();
+
+    if !malicious_devices.is_empty() {
+        malicious_devices.sort();
+
+        return Err(Error::MaliciousDevices(malicious_devices));
+    }
+
+    let (mut nonce_shares, mut commitment_randomness_shares): (HashMap<_, _>, HashMap<_, _>) =
+        presign_shares
+            .into_iter()
+            .map(|(other_device_id, other_presign_share)| {
+                (
+                    (other_device_id, other_presign_share.nonce_share),
+                    (other_device_id, other_presign_share.commitment_randomness),
+                )
+            })
+            .unzip();
+    nonce_shares.insert(device_id, presign_share.nonce_share);
+    commitment_randomness_shares.insert(device_id, presign_share.commitment_randomness);
+
+    let nonce_share = reconstruct_secret::<SCALAR_LIMBS, GroupElement>(
+        threshold,
+        nonce_shares,
+        None,
+        scalar_group_public_parameters,
+        group_public_parameters,
+    )?;
+
+    let commitment_randomness = reconstruct_secret::<SCALAR_LIMBS, GroupElement>(
+        threshold,
+        commitment_randomness_shares,
+        None,
+        scalar_group_public_parameters,
+        group_public_parameters,
+    )?;
+
+    Ok(presign::centralized_party::Presign {
+        nonce_share: nonce_share.value(),
+        decentralized_party_nonce_public_share: presign_share
+            .decentralized_party_nonce_public_share,
+        encrypted_mask: presign_share.encrypted_mask,
+        encrypted_masked_key_share: presign_share.encrypted_masked_key_share,
+        encrypted_nonce_share: presign_share.encrypted_nonce_share,
+        commitment_randomness: commitment_randomness.value(),
+        encrypted_secret_key_share: presign_share.encrypted_secret_key_share,
+    })
+}
+
+/// Shamir-share `secret` among the devices `1..=number_of_devices` with a random polynomial of
+/// degree `threshold - 1`, committing to its coefficients in the exponent.
+fn share_secret<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>(
+    secret: GroupElement::Scalar,
+    threshold: PartyID,
+    number_of_devices: PartyID,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<
+    HashMap<PartyID, SecretShare<group::Value<GroupElement::Scalar>, GroupElement::Value>>,
+> {
+    if threshold == 0 || threshold > number_of_devices {
+        return Err(Error::InvalidParameters);
+    }
+
+    let generator = GroupElement::new(
+        GroupElement::generator_value_from_public_parameters(group_public_parameters),
+        group_public_parameters,
+    )?;
+
+    let coefficients = std::iter::once(Ok(secret))
+        .chain(
+            (1..threshold)
+                .map(|_| GroupElement::Scalar::sample(scalar_group_public_parameters, rng)),
+        )
+        .collect::<group::Result<Vec<_>>>()?;
+
+    let polynomial_commitments: Vec<_> = coefficients
+        .iter()
+        .map(|&coefficient| (coefficient * generator).value())
+        .collect();
+
+    (1..=number_of_devices)
+        .map(|device_id| {
+            let share = evaluate_polynomial::<SCALAR_LIMBS, GroupElement>(
+                &coefficients,
+                device_id,
+                scalar_group_public_parameters,
+            )?;
+
+            Ok((
+                device_id,
+                SecretShare {
+                    share: share.value(),
+                    polynomial_commitments: polynomial_commitments.clone(),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Verify `secret_share` of `device_id` against its polynomial commitments, and, if given, that
+/// it shares the discrete log of `public_share`.
+fn verify_secret_share<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>(
+    device_id: PartyID,
+    threshold: PartyID,
+    secret_share: &SecretShare<group::Value<GroupElement::Scalar>, GroupElement::Value>,
+    public_share: Option<GroupElement>,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<()> {
+    if device_id == 0 || secret_share.polynomial_commitments.len() != usize::from(threshold) {
+        return Err(Error::InvalidParameters);
+    }
+
+    let polynomial_commitments = secret_share
+        .polynomial_commitments
+        .iter()
+        .map(|polynomial_commitment| {
+            GroupElement::new(polynomial_commitment.clone(), group_public_parameters)
+        })
+        .collect::<group::Result<Vec<_>>>()?;
+
+    if let Some(public_share) = public_share {
+        if polynomial_commitments.first() != Some(&public_share) {
+            return Err(Error::InvalidParameters);
+        }
+    }
+
+    let generator = GroupElement::new(
+        GroupElement::generator_value_from_public_parameters(group_public_parameters),
+        group_public_parameters,
+    )?;
+
+    let device_id_scalar = GroupElement::Scalar::new(
+        Uint::<SCALAR_LIMBS>::from(u64::from(device_id)).into(),
+        scalar_group_public_parameters,
+    )?;
+
+    // Evaluate the committed polynomial at our device ID in the exponent, using Horner's rule.
+    let evaluated_polynomial_commitment = polynomial_commitments
+        .into_iter()
+        .rev()
+        .reduce(|accumulator, polynomial_commitment| {
+            (device_id_scalar * accumulator) + polynomial_commitment
+        })
+        .ok_or(Error::InvalidParameters)?;
+
+    let share =
+        GroupElement::Scalar::new(secret_share.share.clone(), scalar_group_public_parameters)?;
+
+    if share * generator != evaluated_polynomial_commitment {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(())
+}
+
+/// Reconstruct a secret from the `secret_shares` of (at least) `threshold` devices, identifying
+/// devices whose shares do not verify.
+fn reconstruct_secret<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>(
+    threshold: PartyID,
+    secret_shares: HashMap<
+        PartyID,
+        SecretShare<group::Value<GroupElement::Scalar>, GroupElement::Value>,
+    >,
+    public_share: Option<GroupElement>,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<GroupElement::Scalar> {
+    if secret_shares.len() < usize::from(threshold) {
+        return Err(Error::ThresholdNotReached);
+    }
+
+    let mut malicious_devices: Vec<_> = secret_shares
+        .iter()
+        .filter(|(&device_id, secret_share)| {
+            verify_secret_share::<SCALAR_LIMBS, GroupElement>(
+                device_id,
+                threshold,
+                secret_share,
+                public_share,
+                scalar_group_public_parameters,
+                group_public_parameters,
+            )
+            .is_err()
+        })
+        .map(|(&device_id, _)| device_id)
+        .collect();
+
+    if !malicious_devices.is_empty() {
+        malicious_devices.sort();
+
+        return Err(Error::MaliciousDevices(malicious_devices));
+    }
+
+    let mut devices: Vec<_> = secret_shares.keys().copied().collect();
+    devices.sort();
+    devices.truncate(threshold.into());
+
+    let to_scalar = |device_id: PartyID| {
+        GroupElement::Scalar::new(
+            Uint::<SCALAR_LIMBS>::from(u64::from(device_id)).into(),
+            scalar_group_public_parameters,
+        )
+    };
+
+    let mut secret = GroupElement::Scalar::new(
+        Uint::<SCALAR_LIMBS>::ZERO.into(),
+        scalar_group_public_parameters,
+    )?;
+
+    for &device_id in &devices {
+        // Compute the Lagrange coefficient $ \prod_{j \neq i} \frac{j}{j - i} $ at zero.
+        let mut numerator = to_scalar(1)?;
+        let mut denominator = to_scalar(1)?;
+        for &other_device_id in devices.iter().filter(|&&j| j != device_id) {
+            numerator = numerator * to_scalar(other_device_id)?;
+            denominator = denominator * (to_scalar(other_device_id)? + to_scalar(device_id)?.neg());
+        }
+
+        let inverted_denominator =
+            Option::<_>::from(denominator.invert()).ok_or(Error::InternalError)?;
+
+        let share = GroupElement::Scalar::new(
+            secret_shares
+                .get(&device_id)
+                .ok_or(Error::InternalError)?
+                .share
+                .clone(),
+            scalar_group_public_parameters,
+        )?;
+
+        secret = secret + (numerator * inverted_denominator * share);
+    }
+
+    Ok(secret)
+}
+
+fn evaluate_polynomial<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>(
+    coefficients: &[GroupElement::Scalar],
+    device_id: PartyID,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<GroupElement::Scalar> {
+    let device_id = GroupElement::Scalar::new(
+        Uint::<SCALAR_LIMBS>::from(u64::from(device_id)).into(),
+        scalar_group_public_parameters,
+    )?;
+
+    coefficients
+        .iter()
+        .rev()
+        .copied()
+        .reduce(|accumulator, coefficient| (accumulator * device_id) + coefficient)
+        .ok_or(Error::InvalidParameters)
+}
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+pub(crate) mod tests {
+    use group::secp256k1;
+    use homomorphic_encryption::GroupsPublicParametersAccessors;
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::test_exports::N;
+
+    use super::*;
+    use crate::{
+        dkg::tests::generates_distributed_key_internal,
+        presign::tests::generates_presignatures_internal,
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+        sign::tests::presign_signs_internal,
+    };
+
+    #[rstest]
+    #[case(2, 3)]
+    #[case(3, 5)]
+    fn shares_and_reconstructs_centralized_party(
+        #[case] threshold: PartyID,
+        #[case] number_of_devices: PartyID,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 4);
+
+        let dkg_output_shares =
+            share_dkg_output::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                centralized_party_dkg_output.clone(),
+                threshold,
+                number_of_devices,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert!(dkg_output_shares
+            .iter()
+            .all(|(&device_id, dkg_output_share)| {
+                verify_dkg_output_share::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                    device_id,
+                    threshold,
+                    dkg_output_share,
+                    &protocol_public_parameters.scalar_group_public_parameters,
+                    &protocol_public_parameters.group_public_parameters,
+                )
+                .is_ok()
+            }));
+
+        // The last `threshold` devices approve the signing session on the last device.
+        let approving_dkg_output_shares: HashMap<_, _> = dkg_output_shares
+            .clone()
+            .into_iter()
+            .filter(|(device_id, _)| *device_id > number_of_devices - threshold)
+            .collect();
+
+        let reconstructed_dkg_output =
+            reconstruct_dkg_output::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                number_of_devices,
+                threshold,
+                approving_dkg_output_shares,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .unwrap();
+
+        assert_eq!(reconstructed_dkg_output, centralized_party_dkg_output);
+
+        let insufficient_dkg_output_shares: HashMap<_, _> = dkg_output_shares
+            .into_iter()
+            .filter(|(device_id, _)| *device_id < threshold)
+            .collect();
+
+        assert!(matches!(
+            reconstruct_dkg_output::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                1,
+                threshold,
+                insufficient_dkg_output_shares,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .err()
+            .unwrap(),
+            Error::ThresholdNotReached
+        ));
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presigns, ..) =
+            generates_presignatures_internal(2, 4, 1, encrypted_secret_key_share, false).unwrap();
+
+        let centralized_party_presign = centralized_party_presigns.first().unwrap().clone();
+
+        let presign_shares =
+            share_presign::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                centralized_party_presign.clone(),
+                threshold,
+                number_of_devices,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert!(presign_shares.iter().all(|(&device_id, presign_share)| {
+            verify_presign_share::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                device_id,
+                threshold,
+                presign_share,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .is_ok()
+        }));
+
+        let approving_presign_shares: HashMap<_, _> = presign_shares
+            .into_iter()
+            .filter(|(device_id, _)| *device_id > number_of_devices - threshold)
+            .collect();
+
+        let reconstructed_presign =
+            reconstruct_presign::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                number_of_devices,
+                threshold,
+                approving_presign_shares,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .unwrap();
+
+        assert_eq!(reconstructed_presign, centralized_party_presign);
+
+        presign_signs_internal(
+            2,
+            4,
+            1,
+            reconstructed_dkg_output,
+            decentralized_party_dkg_output,
+        );
+    }
+
+    #[test]
+    fn identifies_malicious_devices() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, _) = generates_distributed_key_internal(2, 2);
+
+        let mut dkg_output_shares =
+            share_dkg_output::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                centralized_party_dkg_output,
+                2,
+                3,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        // Device 2 sends the share of device 3 as its own.
+        let secret_key_share = dkg_output_shares.get(&3).unwrap().secret_key_share.clone();
+        dkg_output_shares.get_mut(&2).unwrap().secret_key_share = secret_key_share;
+
+        assert!(
+            verify_dkg_output_share::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                2,
+                2,
+                dkg_output_shares.get(&2).unwrap(),
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .is_err()
+        );
+
+        assert!(matches!(
+            reconstruct_dkg_output::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement, _>(
+                1,
+                2,
+                dkg_output_shares,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .err()
+            .unwrap(),
+            Error::MaliciousDevices(devices) if devices == vec![2]
+        ));
+    }
+}
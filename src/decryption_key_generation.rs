@@ -0,0 +1,356 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Integration of an external distributed generation of the decentralized party's threshold
+//! decryption key.
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::PartyID;
+
+use crate::Error;
+
+/// A party of the decentralized party in a distributed generation of its threshold Paillier
+/// decryption key, which replaces a trusted dealer.
+///
+/// This crate does not provide a distributed key generation protocol: generating the Paillier
+/// bi-prime $N$ and sharing its decryption key must be done by an external protocol, which
+/// implementors of this trait wrap, returning its output for our party. That output is then
+/// validated by [`generate_decryption_key_share`].
+pub trait Party {
+    /// Run the distributed key generation protocol such that any `threshold` out of
+    /// `number_of_parties` parties can jointly decrypt, returning the public parameters of the
+    /// resulting decryption key and our share of it.
+    fn generate_decryption_key_share(
+        self,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        tiresias::decryption_key_share::PublicParameters,
+        tiresias::DecryptionKeyShare,
+    )>;
+}
+
+/// Generate our share of the decentralized party's decryption key using `party`, and validate
+/// its output: the resulting Paillier public parameters must be well-formed and for the requested
+/// `threshold` and `number_of_parties`, and the decryption key share must be that of `party_id`,
+/// matching its public verification key.
+///
+/// The protocol public parameters are then derived from the output via
+/// `ProtocolPublicParameters::from_decryption_key_share_public_parameters`, and their
+/// fingerprint should be bound into the [`crate::session::SessionContext`] so that parties that
+/// ended up with mismatching keys fail early.
+pub fn generate_decryption_key_share(
+    party: impl Party,
+    party_id: PartyID,
+    threshold: PartyID,
+    number_of_parties: PartyID,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<(
+    tiresias::decryption_key_share::PublicParameters,
+    tiresias::DecryptionKeyShare,
+)> {
+    if threshold == 0
+        || threshold > number_of_parties
+        || !(1..=number_of_parties).contains(&party_id)
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    let (decryption_key_share_public_parameters, decryption_key_share) =
+        party.generate_decryption_key_share(threshold, number_of_parties, rng)?;
+
+    let encryption_scheme_public_parameters = tiresias::encryption_key::PublicParameters::new(
+        decryption_key_share_public_parameters
+            .encryption_scheme_public_parameters
+            .associated_bi_prime,
+    )
+    .map_err(|_| Error::InvalidPublicParameters)?;
+
+    if encryption_scheme_public_parameters
+        != decryption_key_share_public_parameters.encryption_scheme_public_parameters
+        || decryption_key_share_public_parameters.threshold != threshold
+        || decryption_key_share_public_parameters.number_of_parties != number_of_parties
+        || decryption_key_share_public_parameters
+            .public_verification_keys
+            .len()
+            != usize::from(number_of_parties)
+        || !(1..=number_of_parties).all(|party_id| {
+            decryption_key_share_public_parameters
+                .public_verification_keys
+                .contains_key(&party_id)
+        })
+    {
+        return Err(Error::InvalidPublicParameters);
+    }
+
+    if decryption_key_share.party_id != party_id
+        || decryption_key_share_public_parameters
+            .public_verification_keys
+            .get(&party_id)
+            != Some(&decryption_key_share.public_verification_key)
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok((decryption_key_share_public_parameters, decryption_key_share))
+}
+
+#[cfg(all(test, feature = "secp256k1", feature = "bulletproofs"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use crypto_bigint::Uint;
+    use group::PartyID;
+    use homomorphic_encryption::{
+        AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors,
+    };
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::{
+        test_exports::{deal_trusted_shares, BASE, N, SECRET_KEY},
+        DecryptionKeyShare,
+    };
+
+    use super::*;
+    use crate::{
+        dkg::tests::generates_distributed_key_with_public_parameters_internal,
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+        sign::tests::{presign_signs_with_decryption_key_shares_internal, threshold_decrypt},
+    };
+
+    /// A stand-in for a distributed key generation protocol, which hands out shares dealt by a
+    /// trusted dealer. It must only be used for testing the integration of such protocols.
+    struct TrustedDealerParty {
+        decryption_key_share_public_parameters: tiresias::decryption_key_share::PublicParameters,
+        decryption_key_share: DecryptionKeyShare,
+    }
+
+    impl Party for TrustedDealerParty {
+        fn generate_decryption_key_share(
+            self,
+            _threshold: PartyID,
+            _number_of_parties: PartyID,
+            _rng: &mut impl CryptoRngCore,
+        ) -> crate::Result<(
+            tiresias::decryption_key_share::PublicParameters,
+            DecryptionKeyShare,
+        )> {
+            Ok((
+                self.decryption_key_share_public_parameters,
+                self.decryption_key_share,
+            ))
+        }
+    }
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(2, 4)]
+    #[case(6, 9)]
+    fn generates_decryption_key_shares(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+    ) {
+        let (decryption_key_share_public_parameters, secret_key_shares) =
+            deal_trusted_shares(threshold, number_of_parties, N, SECRET_KEY, BASE);
+
+        let outputs: HashMap<_, _> = secret_key_shares
+            .into_iter()
+            .map(|(party_id, secret_key_share)| {
+                let party = TrustedDealerParty {
+                    decryption_key_share: DecryptionKeyShare::new(
+                        party_id,
+                        secret_key_share,
+                        &decryption_key_share_public_parameters,
+                    )
+                    .unwrap(),
+                    decryption_key_share_public_parameters: decryption_key_share_public_parameters
+                        .clone(),
+                };
+
+                (
+                    party_id,
+                    generate_decryption_key_share(
+                        party,
+                        party_id,
+                        threshold,
+                        number_of_parties,
+                        &mut OsRng,
+                    )
+                    .unwrap(),
+                )
+            })
+            .collect();
+
+        let protocol_public_parameters: Vec<_> = outputs
+            .values()
+            .map(|(decryption_key_share_public_parameters, _)| {
+                ProtocolPublicParameters::from_decryption_key_share_public_parameters(
+                    decryption_key_share_public_parameters,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        assert!(
+            protocol_public_parameters
+                .iter()
+                .all(|public_parameters| *public_parameters == ProtocolPublicParameters::new(N)),
+            "all parties should derive the same protocol public parameters"
+        );
+
+        let protocol_public_parameters = protocol_public_parameters.first().unwrap().clone();
+
+        // From here on, only use the generated outputs rather than the dealt ones.
+        let decryption_key_share_public_parameters = outputs.values().next().unwrap().0.clone();
+
+        let decryption_key_shares: HashMap<_, _> = outputs
+            .into_iter()
+            .map(|(party_id, (_, decryption_key_share))| (party_id, decryption_key_share))
+            .collect();
+
+        let encryption_key = tiresias::EncryptionKey::new(
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let plaintext = Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::from_u64(42);
+
+        let (_, ciphertext) = encryption_key
+            .encrypt(
+                &tiresias::PlaintextSpaceGroupElement::new(
+                    plaintext,
+                    protocol_public_parameters
+                        .encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )
+                .unwrap(),
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert_eq!(
+            threshold_decrypt(
+                threshold,
+                number_of_parties,
+                &ciphertext,
+                &decryption_key_share_public_parameters,
+                &decryption_key_shares,
+            ),
+            plaintext
+        );
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_with_public_parameters_internal(
+                threshold,
+                number_of_parties,
+                protocol_public_parameters.clone(),
+                |ciphertext| {
+                    threshold_decrypt(
+                        threshold,
+                        number_of_parties,
+                        ciphertext,
+                        &decryption_key_share_public_parameters,
+                        &decryption_key_shares,
+                    )
+                },
+            );
+
+        presign_signs_with_decryption_key_shares_internal(
+            threshold,
+            number_of_parties,
+            1,
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+            protocol_public_parameters,
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+        );
+    }
+
+    /// A party whose output is our share of the decryption key dealt by a trusted dealer.
+    fn trusted_dealer_party(
+        party_id: PartyID,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+    ) -> TrustedDealerParty {
+        let (decryption_key_share_public_parameters, secret_key_shares) =
+            deal_trusted_shares(threshold, number_of_parties, N, SECRET_KEY, BASE);
+
+        TrustedDealerParty {
+            decryption_key_share: DecryptionKeyShare::new(
+                party_id,
+                *secret_key_shares.get(&party_id).unwrap(),
+                &decryption_key_share_public_parameters,
+            )
+            .unwrap(),
+            decryption_key_share_public_parameters,
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_threshold() {
+        assert!(matches!(
+            generate_decryption_key_share(trusted_dealer_party(1, 2, 2), 1, 3, 2, &mut OsRng)
+                .err()
+                .unwrap(),
+            Error::InvalidParameters
+        ));
+    }
+
+    #[rstest]
+    #[case::threshold(2, 3, 3, 3)]
+    #[case::number_of_parties(2, 3, 2, 4)]
+    fn rejects_mismatching_public_parameters(
+        #[case] dealt_threshold: PartyID,
+        #[case] dealt_number_of_parties: PartyID,
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+    ) {
+        assert!(matches!(
+            generate_decryption_key_share(
+                trusted_dealer_party(1, dealt_threshold, dealt_number_of_parties),
+                1,
+                threshold,
+                number_of_parties,
+                &mut OsRng
+            )
+            .err()
+            .unwrap(),
+            Error::InvalidPublicParameters
+        ));
+    }
+
+    #[test]
+    fn rejects_decryption_key_share_of_another_party() {
+        assert!(matches!(
+            generate_decryption_key_share(trusted_dealer_party(2, 2, 3), 1, 2, 3, &mut OsRng)
+                .err()
+                .unwrap(),
+            Error::InvalidParameters
+        ));
+    }
+
+    #[test]
+    fn rejects_mismatching_public_verification_key() {
+        let mut party = trusted_dealer_party(1, 2, 3);
+
+        let other_public_verification_key = *party
+            .decryption_key_share_public_parameters
+            .public_verification_keys
+            .get(&2)
+            .unwrap();
+        party
+            .decryption_key_share_public_parameters
+            .public_verification_keys
+            .insert(1, other_public_verification_key);
+
+        assert!(matches!(
+            generate_decryption_key_share(party, 1, 2, 3, &mut OsRng)
+                .err()
+                .unwrap(),
+            Error::InvalidParameters
+        ));
+    }
+}
@@ -1,8 +1,30 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use crate::Error;
+
 pub mod centralized_party;
 pub mod decentralized_party;
+pub mod derivation;
+
+/// The maximal number of homomorphic updates of the decentralized party's encrypted secret key
+/// share $\textsf{ct}_{\textsf{key}}$, as performed by refresh, transfer and derivation.
+///
+/// Each update adds a plaintext smaller than $q$ over the integers, so that after $k$ updates the
+/// plaintext of $\textsf{ct}_{\textsf{key}}$ may exceed the upper bound proven for it by a factor
+/// of $ k+1 $. The masks presign and sign sample from this bound have statistical slack over it,
+/// of which this costs up to $ \log_2(k+1) $ bits, which we cap at $8$.
+pub const MAXIMUM_KEY_SHARE_UPDATES: u32 = (1 << 8) - 1;
+
+/// Count a homomorphic update of the decentralized party's encrypted secret key share, returning
+/// the updated count, or `Error::ExhaustedKeyShareUpdates` past [`MAXIMUM_KEY_SHARE_UPDATES`].
+pub(crate) fn count_key_share_update(key_share_updates: u32) -> crate::Result<u32> {
+    if key_share_updates >= MAXIMUM_KEY_SHARE_UPDATES {
+        return Err(Error::ExhaustedKeyShareUpdates);
+    }
+
+    Ok(key_share_updates + 1)
+}
 
 #[cfg(all(
     any(test, feature = "benchmarking"),
@@ -11,7 +33,6 @@ pub mod decentralized_party;
     feature = "bulletproofs",
 ))]
 pub(crate) mod tests {
-    use core::marker::PhantomData;
     use std::{
         collections::{HashMap, HashSet},
         time::Duration,
@@ -33,7 +54,10 @@ pub(crate) mod tests {
 
     use super::*;
     use crate::{
-        dkg::decentralized_party::SecretKeyShareEncryptionAndProof, ProtocolPublicParameters,
+        dkg::decentralized_party::SecretKeyShareEncryptionAndProof,
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+        session::{tests::session_context, Protocol},
+        Error,
     };
 
     #[rstest]
@@ -59,10 +83,6 @@ pub(crate) mod tests {
             tiresias::CiphertextSpaceValue,
         >,
     ) {
-        let measurement = WallTime;
-        let mut centralized_party_total_time = Duration::ZERO;
-        let mut decentralized_party_total_time = Duration::ZERO;
-
         let protocol_public_parameters = ProtocolPublicParameters::new(N);
 
         let paillier_decryption_key = tiresias::DecryptionKey::new(
@@ -71,10 +91,50 @@ pub(crate) mod tests {
         )
         .unwrap();
 
+        generates_distributed_key_with_public_parameters_internal(
+            threshold,
+            number_of_parties,
+            protocol_public_parameters.clone(),
+            |encrypted_secret_key_share| {
+                paillier_decryption_key
+                    .decrypt(
+                        encrypted_secret_key_share,
+                        &protocol_public_parameters.encryption_scheme_public_parameters,
+                    )
+                    .unwrap()
+                    .into()
+            },
+        )
+    }
+
+    /// Generate a distributed key under `protocol_public_parameters`, checking the decentralized
+    /// party's encrypted secret key share with `decrypt`, which stands in for the holders of the
+    /// Paillier decryption key.
+    #[allow(dead_code)]
+    pub fn generates_distributed_key_with_public_parameters_internal(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        protocol_public_parameters: ProtocolPublicParameters,
+        decrypt: impl Fn(&tiresias::CiphertextSpaceGroupElement) -> LargeBiPrimeSizedNumber,
+    ) -> (
+        centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+    ) {
+        let measurement = WallTime;
+        let mut centralized_party_total_time = Duration::ZERO;
+        let mut decentralized_party_total_time = Duration::ZERO;
+
         let centralized_party_commitment_round_party =
             centralized_party::commitment_round::Party::new(
                 protocol_public_parameters.clone(),
-                PhantomData,
+                session_context(Protocol::Dkg),
             );
 
         let now = measurement.start();
@@ -107,7 +167,7 @@ pub(crate) mod tests {
                         party_id,
                         threshold,
                         parties.clone(),
-                        PhantomData::<()>,
+                        session_context(Protocol::Dkg),
                     ),
                 )
             })
@@ -252,14 +312,8 @@ pub(crate) mod tests {
                 )
                 .unwrap();
 
-                let decentralized_party_secret_key_share_decryption: LargeBiPrimeSizedNumber =
-                    paillier_decryption_key
-                        .decrypt(
-                            &encrypted_secret_key_share,
-                            &protocol_public_parameters.encryption_scheme_public_parameters,
-                        )
-                        .unwrap()
-                        .into();
+                let decentralized_party_secret_key_share_decryption =
+                    decrypt(&encrypted_secret_key_share);
 
                 let decentralized_party_secret_key_share: secp256k1::Scalar =
                     decentralized_party_secret_key_share_decryption.into();
@@ -303,4 +357,96 @@ pub(crate) mod tests {
 
         (centralized_party_dkg_output, decentralized_party_dkg_output)
     }
+
+    #[test]
+    fn deserializes_and_validates_protocol_public_parameters() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let serialized_protocol_public_parameters =
+            serde_json::to_vec(&protocol_public_parameters).unwrap();
+        let deserialized_protocol_public_parameters: ProtocolPublicParameters =
+            serde_json::from_slice(&serialized_protocol_public_parameters).unwrap();
+
+        assert!(deserialized_protocol_public_parameters == protocol_public_parameters);
+        assert!(deserialized_protocol_public_parameters.validate().is_ok());
+        assert_eq!(
+            deserialized_protocol_public_parameters
+                .fingerprint()
+                .unwrap(),
+            protocol_public_parameters.fingerprint().unwrap()
+        );
+
+        // Swap in the encryption scheme of another modulus, so that the witness parameters no
+        // longer match the Paillier randomness space.
+        let mut inconsistent_protocol_public_parameters = protocol_public_parameters.clone();
+        inconsistent_protocol_public_parameters.encryption_scheme_public_parameters =
+            tiresias::encryption_key::PublicParameters::new(
+                N.wrapping_sub(&LargeBiPrimeSizedNumber::from_u8(2)),
+            )
+            .unwrap();
+
+        assert!(matches!(
+            inconsistent_protocol_public_parameters.validate(),
+            Err(Error::InvalidPublicParameters)
+        ));
+        assert_ne!(
+            inconsistent_protocol_public_parameters
+                .fingerprint()
+                .unwrap(),
+            protocol_public_parameters.fingerprint().unwrap()
+        );
+    }
+
+    #[test]
+    fn restores_centralized_party_from_state() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let sample_decommitment_round_party = || {
+            let (_, centralized_party_decommitment_round_party) =
+                centralized_party::commitment_round::Party::new(
+                    protocol_public_parameters.clone(),
+                    session_context(Protocol::Dkg),
+                )
+                .sample_commit_and_prove_secret_key_share(&mut OsRng)
+                .unwrap();
+
+            centralized_party_decommitment_round_party
+        };
+
+        // Simulate the centralized party going down after the commitment round, by serializing
+        // its state and restoring it from the serialized bytes.
+        let state = sample_decommitment_round_party().to_state();
+        let serialized_state = serde_json::to_vec(&state).unwrap();
+
+        let restored_centralized_party_decommitment_round_party =
+            centralized_party::decommitment_round::Party::from_state(
+                protocol_public_parameters.clone(),
+                serde_json::from_slice(&serialized_state).unwrap(),
+                session_context(Protocol::Dkg),
+            )
+            .unwrap();
+
+        assert_eq!(
+            restored_centralized_party_decommitment_round_party.to_state(),
+            state
+        );
+
+        // A state whose public key share does not match its secret key share must be rejected.
+        let mut mismatching_state = serde_json::to_value(&state).unwrap();
+        mismatching_state["public_key_share"] =
+            serde_json::to_value(sample_decommitment_round_party().to_state()).unwrap()
+                ["public_key_share"]
+                .clone();
+
+        assert!(matches!(
+            centralized_party::decommitment_round::Party::from_state(
+                protocol_public_parameters.clone(),
+                serde_json::from_value(mismatching_state).unwrap(),
+                session_context(Protocol::Dkg),
+            )
+            .err()
+            .unwrap(),
+            Error::InvalidParameters
+        ));
+    }
 }
@@ -10,10 +10,12 @@ use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use maurer::knowledge_of_discrete_log;
 use merlin::Transcript;
 use proof::{AggregatableRangeProof, TranscriptProtocol};
-use serde::Serialize;
 
 use crate::{
-    dkg::centralized_party::decommitment_round, ProtocolPublicParameters, CENTRALIZED_PARTY_ID,
+    dkg::centralized_party::decommitment_round,
+    round::{Round, Transition},
+    session::{RoundContext, RoundId},
+    ProtocolPublicParameters, CENTRALIZED_PARTY_ID,
 };
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
@@ -26,7 +28,7 @@ pub struct Party<
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDLWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     protocol_context: ProtocolContext,
     scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
@@ -64,7 +66,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -110,7 +112,9 @@ impl<
             GroupElement,
             ProtocolContext,
         >::prove(
-            &self.protocol_context,
+            &self
+                .protocol_context
+                .round(RoundId::DkgCentralizedPartyCommitment),
             &language_public_parameters,
             vec![secret_key_share],
             rng,
@@ -180,3 +184,58 @@ impl<
         }
     }
 }
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+{
+    /// The centralized party starts the DKG without any incoming message.
+    type Incoming = ();
+    type Message = Commitment;
+    type NextRound = decommitment_round::Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >;
+    type Output = decommitment_round::Output<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >;
+    type Error = crate::Error;
+
+    fn process(
+        self,
+        _incoming: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (commitment, party) = self.sample_commit_and_prove_secret_key_share(rng)?;
+
+        Ok(Transition::Advance(commitment, party))
+    }
+}
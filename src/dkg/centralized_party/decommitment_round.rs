@@ -12,7 +12,12 @@ use maurer::{knowledge_of_discrete_log, SOUND_PROOFS_REPETITIONS};
 use proof::{range, range::PublicParametersAccessors, AggregatableRangeProof};
 use serde::{Deserialize, Serialize};
 
-use crate::{dkg::decentralized_party, ProtocolPublicParameters};
+use crate::{
+    dkg::decentralized_party,
+    round::{Round, Transition},
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Output<GroupElementValue, ScalarValue, CiphertextSpaceValue> {
@@ -20,7 +25,10 @@ pub struct Output<GroupElementValue, ScalarValue, CiphertextSpaceValue> {
     pub(crate) public_key_share: GroupElementValue,
     pub public_key: GroupElementValue,
     pub encrypted_decentralized_party_secret_key_share: CiphertextSpaceValue,
-    pub(in crate::dkg) decentralized_party_public_key_share: GroupElementValue,
+    pub(crate) decentralized_party_public_key_share: GroupElementValue,
+    /// The number of homomorphic updates applied to the decentralized party's encrypted secret
+    /// key share since it was generated, up to [`crate::dkg::MAXIMUM_KEY_SHARE_UPDATES`].
+    pub key_share_updates: u32,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -30,8 +38,8 @@ pub struct PublicKeyShareDecommitmentAndProof<GroupElementValue, DLProof> {
     pub(in crate::dkg) commitment_randomness: ComputationalSecuritySizedNumber,
 }
 
-/// This structs is a serializable state to use in case the `Party` struct cannot be saved in
-/// memory.
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct State<GroupElementValue, ScalarValue, DLProof> {
     proof: DLProof,
@@ -50,7 +58,7 @@ pub struct Party<
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDLWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub(super) group_public_parameters: GroupElement::PublicParameters,
     pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
@@ -74,7 +82,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -219,7 +227,9 @@ where
         decentralized_party_secret_key_share_encryption_and_proof
             .encryption_of_secret_key_share_proof
             .verify(
-                &self.protocol_context,
+                &self
+                    .protocol_context
+                    .round(RoundId::DkgEncryptionOfSecretKeyShare),
                 &encryption_of_discrete_log_enhanced_language_public_parameters,
                 vec![statement],
                 rng,
@@ -245,11 +255,14 @@ where
                 decentralized_party_secret_key_share_encryption_and_proof.encrypted_secret_key_share,
             decentralized_party_public_key_share:
                 decentralized_party_secret_key_share_encryption_and_proof.public_key_share,
+            key_share_updates: 0,
         };
 
         Ok((public_key_share_decommitment_proof, output))
     }
 
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
     pub fn to_state(
         self,
     ) -> State<
@@ -265,6 +278,7 @@ where
         }
     }
 
+    #[deprecated(note = "use `Party::from_state` instead")]
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
         UnboundedEncDHWitness: group::GroupElement + Samplable,
@@ -289,6 +303,38 @@ where
             knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
         >,
         protocol_context: ProtocolContext,
+    ) -> crate::Result<Self> {
+        Self::from_state(protocol_public_parameters, state, protocol_context)
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The public parameters and protocol context are not a part of the state, and must be the
+    /// same ones used to instantiate the party prior to it being saved.
+    pub fn from_state<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        state: State<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
+        >,
+        protocol_context: ProtocolContext,
     ) -> crate::Result<Self> {
         let scalar_group_public_parameters =
             protocol_public_parameters.scalar_group_public_parameters;
@@ -299,6 +345,10 @@ where
             GroupElement::Scalar::new(state.secret_key_share, &scalar_group_public_parameters)?;
         let public_key_share = GroupElement::new(state.public_key_share, &group_public_parameters)?;
 
+        if secret_key_share * public_key_share.generator() != public_key_share {
+            return Err(Error::InvalidParameters);
+        }
+
         Ok(Party {
             protocol_context,
             scalar_group_public_parameters,
@@ -316,3 +366,105 @@ where
         })
     }
 }
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    type Incoming = decentralized_party::SecretKeyShareEncryptionAndProof<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >;
+    type Message = PublicKeyShareDecommitmentAndProof<
+        GroupElement::Value,
+        knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
+    >;
+    type NextRound = core::convert::Infallible;
+    type Output = Output<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >;
+    type Error = Error;
+
+    fn process(
+        self,
+        decentralized_party_secret_key_share_encryption_and_proof: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (public_key_share_decommitment_proof, output) = self.decommit_proof_public_key_share(
+            decentralized_party_secret_key_share_encryption_and_proof,
+            rng,
+        )?;
+
+        Ok(Transition::Finalize(
+            public_key_share_decommitment_proof,
+            output,
+        ))
+    }
+}
@@ -11,6 +11,9 @@ use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use maurer::SOUND_PROOFS_REPETITIONS;
 use proof::{range, AggregatableRangeProof};
 use serde::{Deserialize, Serialize};
+
+use crate::session::RoundContext;
+
 pub mod decommitment_proof_verification_round;
 pub mod encryption_of_secret_key_share_round;
 
@@ -36,7 +39,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     SecretKeyShareEncryptionAndProof<
         GroupElement::Value,
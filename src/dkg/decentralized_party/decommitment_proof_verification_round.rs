@@ -3,11 +3,12 @@
 
 #![allow(clippy::type_complexity)]
 
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData};
 
 use commitment::Commitment;
+use crypto_bigint::rand_core::CryptoRngCore;
 use enhanced_maurer::{encryption_of_discrete_log, EnhanceableLanguage};
-use group::{PrimeGroupElement, Samplable};
+use group::{PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use maurer::knowledge_of_discrete_log;
 use proof::{range, AggregatableRangeProof};
@@ -17,7 +18,10 @@ use crate::{
     dkg::{
         centralized_party, centralized_party::commitment_round::commit_public_key_share,
         decentralized_party,
+        decentralized_party::encryption_of_secret_key_share_round::EncryptionOfSecretKeyShareAggregation,
     },
+    round::{aggregation, Round, Transition},
+    session::{RoundContext, RoundId},
     ProtocolPublicParameters, CENTRALIZED_PARTY_ID,
 };
 
@@ -27,6 +31,9 @@ pub struct Output<GroupElementValue, CiphertextSpaceValue> {
     pub public_key: GroupElementValue,
     pub encrypted_secret_key_share: CiphertextSpaceValue,
     pub centralized_party_public_key_share: GroupElementValue,
+    /// The number of homomorphic updates applied to `encrypted_secret_key_share` since it was
+    /// generated, up to [`crate::dkg::MAXIMUM_KEY_SHARE_UPDATES`].
+    pub key_share_updates: u32,
 }
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
@@ -39,7 +46,7 @@ pub struct Party<
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDLWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub protocol_context: ProtocolContext,
     pub group_public_parameters: GroupElement::PublicParameters,
@@ -60,7 +67,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -161,7 +168,9 @@ where
             );
 
         decommitment_and_proof.proof.verify(
-            &self.protocol_context,
+            &self
+                .protocol_context
+                .round(RoundId::DkgCentralizedPartyCommitment),
             &language_public_parameters,
             vec![centralized_party_public_key_share.clone()],
         )?;
@@ -174,6 +183,7 @@ where
             encrypted_secret_key_share: secret_key_share_encryption_and_proof
                 .encrypted_secret_key_share,
             centralized_party_public_key_share: decommitment_and_proof.public_key_share,
+            key_share_updates: 0,
         })
     }
 
@@ -211,3 +221,309 @@ where
         }
     }
 }
+
+/// The round in which the decentralized party, having aggregated the proof of the encryption of
+/// its secret key share, sends it to the centralized party.
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct SecretKeyShareEncryptionAndProofRound<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) party: Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >,
+}
+
+/// The round in which the decentralized party verifies the decommitment and proof of the
+/// centralized party's public key share, and outputs its share of the key.
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct DecommitmentAndProofVerificationRound<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    party: Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >,
+    secret_key_share_encryption_and_proof: decentralized_party::SecretKeyShareEncryptionAndProof<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for SecretKeyShareEncryptionAndProofRound<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            { maurer::SOUND_PROOFS_REPETITIONS },
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            { maurer::SOUND_PROOFS_REPETITIONS },
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// The decommitments of all parties, and the aggregated proof of the encryption of the
+    /// secret key share.
+    type Incoming = (
+        HashMap<
+            PartyID,
+            aggregation::Decommitment<
+                EncryptionOfSecretKeyShareAggregation<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >,
+            >,
+        >,
+        aggregation::AggregatedOutput<
+            EncryptionOfSecretKeyShareAggregation<
+                SCALAR_LIMBS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+    );
+    type Message = decentralized_party::SecretKeyShareEncryptionAndProof<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >;
+    type NextRound = DecommitmentAndProofVerificationRound<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >;
+    type Output =
+        Output<GroupElement::Value, group::Value<EncryptionKey::CiphertextSpaceGroupElement>>;
+    type Error = crate::Error;
+
+    fn process(
+        self,
+        (_, encryption_of_secret_share_aggregation_outputs): Self::Incoming,
+        _rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (encryption_of_secret_share_proof, encryption_of_secret_share) =
+            encryption_of_secret_share_aggregation_outputs
+                .into_iter()
+                .next()
+                .ok_or(crate::Error::InternalError)?;
+
+        let encryption_of_secret_share = *encryption_of_secret_share
+            .first()
+            .ok_or(crate::Error::InternalError)?;
+
+        let secret_key_share_encryption_and_proof =
+            decentralized_party::SecretKeyShareEncryptionAndProof::new(
+                encryption_of_secret_share,
+                encryption_of_secret_share_proof,
+            );
+
+        Ok(Transition::Advance(
+            secret_key_share_encryption_and_proof.clone(),
+            DecommitmentAndProofVerificationRound {
+                party: self.party,
+                secret_key_share_encryption_and_proof,
+            },
+        ))
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for DecommitmentAndProofVerificationRound<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            { maurer::SOUND_PROOFS_REPETITIONS },
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            { maurer::SOUND_PROOFS_REPETITIONS },
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    type Incoming = centralized_party::PublicKeyShareDecommitmentAndProof<
+        GroupElement::Value,
+        knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
+    >;
+    /// The decentralized party sends no message once the DKG is over.
+    type Message = ();
+    type NextRound = core::convert::Infallible;
+    type Output =
+        Output<GroupElement::Value, group::Value<EncryptionKey::CiphertextSpaceGroupElement>>;
+    type Error = crate::Error;
+
+    fn process(
+        self,
+        decommitment_and_proof: Self::Incoming,
+        _rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let output = self
+            .party
+            .verify_decommitment_and_proof_of_centralized_party_public_key_share(
+                decommitment_and_proof,
+                self.secret_key_share_encryption_and_proof,
+            )?;
+
+        Ok(Transition::Finalize((), output))
+    }
+}
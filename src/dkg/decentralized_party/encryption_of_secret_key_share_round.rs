@@ -16,13 +16,58 @@ use group::{GroupElement as _, PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::SOUND_PROOFS_REPETITIONS;
 use proof::AggregatableRangeProof;
-use serde::Serialize;
 
 use crate::{
-    dkg::decentralized_party::decommitment_proof_verification_round, Error,
+    dkg::decentralized_party::decommitment_proof_verification_round,
+    round::{aggregation, aggregation::Batch, Round, Transition},
+    session::{RoundContext, RoundId},
+    weighted_threshold::WeightedThresholdAccessStructure,
     ProtocolPublicParameters,
 };
 
+/// The proof aggregation of the encryption of the decentralized party's secret key share, started
+/// by [`Party`] and driven through [`aggregation`] rounds.
+pub type EncryptionOfSecretKeyShareAggregation<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement,
+    EncryptionKey,
+    RangeProof,
+    UnboundedEncDLWitness,
+    ProtocolContext,
+> = Batch<
+    enhanced_maurer::aggregation::Output<
+        SOUND_PROOFS_REPETITIONS,
+        RANGE_CLAIMS_PER_SCALAR,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RangeProof,
+        UnboundedEncDLWitness,
+        encryption_of_discrete_log::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+        ProtocolContext,
+    >,
+    enhanced_maurer::aggregation::commitment_round::Party<
+        SOUND_PROOFS_REPETITIONS,
+        RANGE_CLAIMS_PER_SCALAR,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RangeProof,
+        UnboundedEncDLWitness,
+        encryption_of_discrete_log::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+        ProtocolContext,
+    >,
+>;
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
@@ -33,10 +78,10 @@ pub struct Party<
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDLWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     party_id: PartyID,
-    threshold: PartyID,
+    access_structure: WeightedThresholdAccessStructure,
     parties: HashSet<PartyID>,
     protocol_context: ProtocolContext,
     group_public_parameters: GroupElement::PublicParameters,
@@ -55,7 +100,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -130,9 +175,7 @@ where
             ProtocolContext,
         >,
     )> {
-        if self.parties.len() < self.threshold.into() {
-            return Err(Error::ThresholdNotReached);
-        }
+        self.access_structure.is_authorized_subset(&self.parties)?;
 
         let encryption_randomness = EncryptionKey::RandomnessSpaceGroupElement::sample(
             &self
@@ -235,7 +278,8 @@ where
                 self.party_id,
                 self.parties.clone(),
                 language_public_parameters,
-                self.protocol_context.clone(),
+                self.protocol_context
+                    .round(RoundId::DkgEncryptionOfSecretKeyShare),
                 vec![share_of_decentralized_party_secret_key_share_witness],
                 rng,
             )?;
@@ -288,10 +332,46 @@ where
         threshold: PartyID,
         parties: HashSet<PartyID>,
         protocol_context: ProtocolContext,
+    ) -> Self {
+        let access_structure = WeightedThresholdAccessStructure::unweighted(threshold, &parties);
+
+        Self::new_weighted(
+            protocol_public_parameters,
+            party_id,
+            access_structure,
+            parties,
+            protocol_context,
+        )
+    }
+
+    /// Instantiate a party under a weighted threshold `access_structure`, in which the
+    /// participating `parties` must be of total weight reaching the threshold.
+    pub fn new_weighted<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        party_id: PartyID,
+        access_structure: WeightedThresholdAccessStructure,
+        parties: HashSet<PartyID>,
+        protocol_context: ProtocolContext,
     ) -> Self {
         Party {
             party_id,
-            threshold,
+            access_structure,
             parties,
             protocol_context,
             scalar_group_public_parameters: protocol_public_parameters
@@ -306,3 +386,124 @@ where
         }
     }
 }
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// The commitment of the centralized party to its public key share.
+    type Incoming = Commitment;
+    type Message = aggregation::Commitment<
+        EncryptionOfSecretKeyShareAggregation<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >;
+    type NextRound = aggregation::DecommitmentRound<
+        <EncryptionOfSecretKeyShareAggregation<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        > as aggregation::CommitmentStage>::DecommitmentStage,
+        decommitment_proof_verification_round::SecretKeyShareEncryptionAndProofRound<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >;
+    type Output = decommitment_proof_verification_round::Output<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >;
+    type Error = crate::Error;
+
+    fn process(
+        self,
+        commitment_to_centralized_party_secret_key_share: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (encryption_of_secret_share_commitment_round_party, decommitment_round_party) = self
+            .sample_secret_key_share_and_initialize_proof_aggregation(
+                commitment_to_centralized_party_secret_key_share,
+                rng,
+            )?;
+
+        let (commitment, decommitment_round) = aggregation::commit(
+            Batch::new(vec![encryption_of_secret_share_commitment_round_party]),
+            decommitment_proof_verification_round::SecretKeyShareEncryptionAndProofRound {
+                party: decommitment_round_party,
+            },
+            rng,
+        )?;
+
+        Ok(Transition::Advance(commitment, decommitment_round))
+    }
+}
@@ -0,0 +1,533 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! BIP-32 non-hardened (public) child key derivation for dWallets.
+//!
+//! A child public key is derived additively from its parent as $ X_i = X + t_i \cdot G $, where
+//! $t_i$ is computed from the parent public key and chain code alone, so that both parties derive
+//! the same child key without interaction.
+//!
+//! The tweak is absorbed by the decentralized party's key share: both parties set
+//! $ X_B' = X_B + t \cdot G $ and adjust its encryption homomorphically to
+//! $ \textsf{ct}_{\textsf{key}}' = \textsf{ct}_{\textsf{key}} \oplus \textsf{Enc}(t; 1) $, an
+//! encryption of $ x_B + t $. The centralized party's key share is left untouched. Presignatures
+//! depend on $\textsf{ct}_{\textsf{key}}$ and so must be generated for the derived
+//! [`centralized_party::Output`] and [`decentralized_party::Output`], against which the presign
+//! and sign protocols then run unchanged.
+//!
+//! The plaintext of $\textsf{ct}_{\textsf{key}}'$ is $ x_B + t $ over the integers, the tweak being
+//! reduced modulo $q$ along the whole path, so a derivation counts as a single update towards
+//! [`crate::dkg::MAXIMUM_KEY_SHARE_UPDATES`].
+
+use crypto_bigint::Uint;
+use group::{GroupElement, KnownOrderGroupElement, PrimeGroupElement};
+use hmac::{Hmac, Mac};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use sha2::Sha512;
+
+use crate::{
+    dkg::{centralized_party, count_key_share_update, decentralized_party},
+    Error,
+};
+
+/// The size in bytes of a BIP-32 chain code.
+pub const CHAIN_CODE_SIZE: usize = 32;
+
+/// The size in bytes of a SEC1 compressed point encoding, as used by BIP-32 for $ ser_P(K) $.
+pub const COMPRESSED_GROUP_ELEMENT_SIZE: usize = 33;
+
+/// Child indices from this one onwards denote hardened derivation, which requires the (shared)
+/// secret key and is therefore not supported.
+pub const HARDENED_CHILD_INDEX_OFFSET: u32 = 1 << 31;
+
+/// A group element which can be encoded as a SEC1 compressed point, as required by BIP-32.
+pub trait CompressedGroupElement<const SCALAR_LIMBS: usize>:
+    PrimeGroupElement<SCALAR_LIMBS>
+{
+    /// The SEC1 compressed encoding of this point.
+    fn compressed_bytes(&self) -> [u8; COMPRESSED_GROUP_ELEMENT_SIZE];
+}
+
+#[cfg(feature = "secp256k1")]
+impl CompressedGroupElement<{ group::secp256k1::SCALAR_LIMBS }> for group::secp256k1::GroupElement {
+    fn compressed_bytes(&self) -> [u8; COMPRESSED_GROUP_ELEMENT_SIZE] {
+        use k256::elliptic_curve::group::GroupEncoding;
+
+        let point: k256::AffinePoint = self.value().into();
+
+        point.to_bytes().into()
+    }
+}
+
+#[cfg(feature = "secp256r1")]
+impl CompressedGroupElement<{ group::secp256r1::SCALAR_LIMBS }> for group::secp256r1::GroupElement {
+    fn compressed_bytes(&self) -> [u8; COMPRESSED_GROUP_ELEMENT_SIZE] {
+        use p256::elliptic_curve::group::GroupEncoding;
+
+        let point: p256::AffinePoint = self.value().into();
+
+        point.to_bytes().into()
+    }
+}
+
+/// Derive the child of `public_key` along the non-hardened `derivation_path`, returning the
+/// accumulated tweak $t$, the child public key $ X + t \cdot G $ and the child chain code.
+///
+/// Fails with `Error::InvalidParameters` on hardened indices, and in the (negligible probability)
+/// cases BIP-32 deems a child key invalid, in which case the caller should proceed with the next
+/// index.
+pub fn derive_public_key<
+    const SCALAR_LIMBS: usize,
+    GroupElement: CompressedGroupElement<SCALAR_LIMBS>,
+>(
+    public_key: GroupElement,
+    chain_code: &[u8; CHAIN_CODE_SIZE],
+    derivation_path: &[u32],
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<(GroupElement::Scalar, GroupElement, [u8; CHAIN_CODE_SIZE])> {
+    let generator = public_key.generator();
+
+    let group_order =
+        GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters);
+
+    let mut tweak = GroupElement::Scalar::new(
+        Uint::<SCALAR_LIMBS>::ZERO.into(),
+        scalar_group_public_parameters,
+    )?;
+    let mut public_key = public_key;
+    let mut chain_code = *chain_code;
+
+    for &child_index in derivation_path {
+        if child_index >= HARDENED_CHILD_INDEX_OFFSET {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut mac =
+            Hmac::<Sha512>::new_from_slice(&chain_code).map_err(|_| Error::InternalError)?;
+        mac.update(&public_key.compressed_bytes());
+        mac.update(&child_index.to_be_bytes());
+        let mac = mac.finalize().into_bytes();
+
+        let (child_tweak, child_chain_code) = mac.split_at(CHAIN_CODE_SIZE);
+
+        if child_tweak.len() > Uint::<SCALAR_LIMBS>::BYTES {
+            return Err(Error::InvalidParameters);
+        }
+        let mut child_tweak_bytes = vec![0u8; Uint::<SCALAR_LIMBS>::BYTES - child_tweak.len()];
+        child_tweak_bytes.extend_from_slice(child_tweak);
+        let child_tweak = Uint::<SCALAR_LIMBS>::from_be_slice(&child_tweak_bytes); // $I_L$
+
+        if child_tweak >= group_order {
+            return Err(Error::InvalidParameters);
+        }
+
+        let child_tweak =
+            GroupElement::Scalar::new(child_tweak.into(), scalar_group_public_parameters)?;
+
+        public_key = public_key + (child_tweak * generator);
+        if public_key == public_key.neutral() {
+            return Err(Error::InvalidParameters);
+        }
+
+        tweak = tweak + child_tweak;
+        chain_code.copy_from_slice(child_chain_code);
+    }
+
+    Ok((tweak, public_key, chain_code))
+}
+
+/// Add `tweak` to the decentralized party's encrypted secret key share, as
+/// $ \textsf{ct}_{\textsf{key}} \oplus \textsf{Enc}(t; 1) $.
+///
+/// $t$ is public, so it is encrypted with the neutral randomness in order for both parties to
+/// compute the same ciphertext without interaction.
+fn tweak_encrypted_secret_key_share<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+>(
+    encrypted_secret_key_share: group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    tweak: GroupElement::Scalar,
+    encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+) -> crate::Result<group::Value<EncryptionKey::CiphertextSpaceGroupElement>> {
+    let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+        encrypted_secret_key_share,
+        encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+    )?;
+
+    let tweak: Uint<SCALAR_LIMBS> = tweak.into();
+    let tweak = EncryptionKey::PlaintextSpaceGroupElement::new(
+        Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(&tweak).into(),
+        encryption_scheme_public_parameters.plaintext_space_public_parameters(),
+    )?;
+
+    let neutral_randomness =
+        EncryptionKey::RandomnessSpaceGroupElement::neutral_from_public_parameters(
+            encryption_scheme_public_parameters.randomness_space_public_parameters(),
+        )?;
+
+    let encryption_key = EncryptionKey::new(encryption_scheme_public_parameters)?;
+
+    let encrypted_tweak = encryption_key.encrypt_with_randomness(
+        &tweak,
+        &neutral_randomness,
+        encryption_scheme_public_parameters,
+    );
+
+    Ok((encrypted_secret_key_share + encrypted_tweak).value())
+}
+
+/// Derive the centralized party's DKG output for the child of its public key along
+/// `derivation_path`, returning it alongside the child chain code.
+pub fn derive_centralized_party_output<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: CompressedGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+>(
+    dkg_output: centralized_party::Output<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    chain_code: &[u8; CHAIN_CODE_SIZE],
+    derivation_path: &[u32],
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+    encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+) -> crate::Result<(
+    centralized_party::Output<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    [u8; CHAIN_CODE_SIZE],
+)> {
+    let public_key = GroupElement::new(dkg_output.public_key, group_public_parameters)?;
+
+    let (tweak, public_key, chain_code) = derive_public_key::<SCALAR_LIMBS, GroupElement>(
+        public_key,
+        chain_code,
+        derivation_path,
+        scalar_group_public_parameters,
+    )?;
+
+    let decentralized_party_public_key_share = GroupElement::new(
+        dkg_output.decentralized_party_public_key_share,
+        group_public_parameters,
+    )? + (tweak * public_key.generator()); // $X_B'$
+
+    let encrypted_decentralized_party_secret_key_share = tweak_encrypted_secret_key_share::<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >(
+        dkg_output.encrypted_decentralized_party_secret_key_share,
+        tweak,
+        encryption_scheme_public_parameters,
+    )?; // $\textsf{ct}_{\textsf{key}}'$
+
+    Ok((
+        centralized_party::Output {
+            secret_key_share: dkg_output.secret_key_share,
+            public_key_share: dkg_output.public_key_share,
+            public_key: public_key.value(),
+            encrypted_decentralized_party_secret_key_share,
+            decentralized_party_public_key_share: decentralized_party_public_key_share.value(),
+            key_share_updates: count_key_share_update(dkg_output.key_share_updates)?,
+        },
+        chain_code,
+    ))
+}
+
+/// Derive the decentralized party's DKG output for the child of its public key along
+/// `derivation_path`, returning it alongside the child chain code.
+pub fn derive_decentralized_party_output<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: CompressedGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+>(
+    dkg_output: decentralized_party::Output<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    chain_code: &[u8; CHAIN_CODE_SIZE],
+    derivation_path: &[u32],
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+    encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+) -> crate::Result<(
+    decentralized_party::Output<
+        GroupElement::Value,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    >,
+    [u8; CHAIN_CODE_SIZE],
+)> {
+    let public_key = GroupElement::new(dkg_output.public_key, group_public_parameters)?;
+
+    let (tweak, public_key, chain_code) = derive_public_key::<SCALAR_LIMBS, GroupElement>(
+        public_key,
+        chain_code,
+        derivation_path,
+        scalar_group_public_parameters,
+    )?;
+
+    let public_key_share = GroupElement::new(dkg_output.public_key_share, group_public_parameters)?
+        + (tweak * public_key.generator()); // $X_B'$
+
+    let encrypted_secret_key_share = tweak_encrypted_secret_key_share::<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >(
+        dkg_output.encrypted_secret_key_share,
+        tweak,
+        encryption_scheme_public_parameters,
+    )?; // $\textsf{ct}_{\textsf{key}}'$
+
+    Ok((
+        decentralized_party::Output {
+            public_key_share: public_key_share.value(),
+            public_key: public_key.value(),
+            encrypted_secret_key_share,
+            centralized_party_public_key_share: dkg_output.centralized_party_public_key_share,
+            key_share_updates: count_key_share_update(dkg_output.key_share_updates)?,
+        },
+        chain_code,
+    ))
+}
+
+#[cfg(all(
+    test,
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+mod tests {
+    use crypto_bigint::{Encoding, NonZero, U256};
+    use group::{secp256k1, Reduce};
+    use homomorphic_encryption::AdditivelyHomomorphicDecryptionKey;
+    use rstest::rstest;
+    use tiresias::test_exports::{N, SECRET_KEY};
+
+    use super::*;
+    use crate::{
+        dkg::tests::generates_distributed_key_internal,
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+    };
+
+    // BIP-32 test vectors 1 (from m/0H/1/2H) and 2 (from m), restricted to public derivation.
+    #[rstest]
+    #[case(
+        "CBCE0D719ECF7431D88E6A89FA1483E02E35092AF60C042B1DF2FF59FA424DCA",
+        "04466B9CC8E161E966409CA52986C584F07E9DC81F735DB683C3FF6EC7B1503F",
+        &[2, 1000000000],
+        "022A471424DA5E657499D1FF51CB43C47481A03B1E77F951FE64CEC9F5A48F7011",
+        "C783E67B921D2BEB8F6B389CC646D7263B4145701DADD2161548A8B078E65E9E"
+    )]
+    #[case(
+        "4B03D6FC340455B363F51020AD3ECCA4F0850280CF436C70C727923F6DB46C3E",
+        "60499F801B896D83179A4374AEB7822AAEACEAA0DB1F85EE3E904C4DEFBD9689",
+        &[0],
+        "02FC9E5AF0AC8D9B3CECFE2A888E2117BA3D089D8585886C9C826B6B22A98D12EA",
+        "F0909AFFAA7EE7ABE5DD4E100598D4DC53CD709D5A5C2CAC40E7412F232F7C9C"
+    )]
+    fn derives_public_keys_like_bip32(
+        #[case] secret_key: &str,
+        #[case] chain_code: &str,
+        #[case] derivation_path: &[u32],
+        #[case] child_public_key: &str,
+        #[case] child_chain_code: &str,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let generator = secp256k1::GroupElement::new(
+            protocol_public_parameters.group_public_parameters.generator,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let secret_key: secp256k1::Scalar = U256::from_be_hex(secret_key).into();
+        let chain_code = U256::from_be_hex(chain_code).to_be_bytes();
+
+        let (_, public_key, chain_code) =
+            derive_public_key::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+                secret_key * generator,
+                &chain_code,
+                derivation_path,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap();
+
+        let expected_public_key_prefix = u8::from_str_radix(&child_public_key[..2], 16).unwrap();
+        let expected_public_key_x = U256::from_be_hex(&child_public_key[2..]).to_be_bytes();
+
+        let public_key = public_key.compressed_bytes();
+        assert_eq!(public_key[0], expected_public_key_prefix);
+        assert_eq!(public_key[1..], expected_public_key_x);
+        assert_eq!(
+            chain_code,
+            U256::from_be_hex(child_chain_code).to_be_bytes()
+        );
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[0])]
+    #[case(&[44, 60, 0, 0, 7])]
+    fn derives_consistent_dkg_outputs(#[case] derivation_path: &[u32]) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 4);
+
+        let chain_code = [7u8; CHAIN_CODE_SIZE];
+
+        let (centralized_party_dkg_output, centralized_party_chain_code) =
+            derive_centralized_party_output::<
+                { secp256k1::SCALAR_LIMBS },
+                { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                secp256k1::GroupElement,
+                tiresias::EncryptionKey,
+            >(
+                centralized_party_dkg_output,
+                &chain_code,
+                derivation_path,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+            )
+            .unwrap();
+
+        let (decentralized_party_dkg_output, decentralized_party_chain_code) =
+            derive_decentralized_party_output::<
+                { secp256k1::SCALAR_LIMBS },
+                { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                secp256k1::GroupElement,
+                tiresias::EncryptionKey,
+            >(
+                decentralized_party_dkg_output,
+                &chain_code,
+                derivation_path,
+                &protocol_public_parameters.scalar_group_public_parameters,
+                &protocol_public_parameters.group_public_parameters,
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+            )
+            .unwrap();
+
+        assert_eq!(centralized_party_chain_code, decentralized_party_chain_code);
+        assert_eq!(
+            centralized_party_dkg_output.public_key,
+            decentralized_party_dkg_output.public_key
+        );
+        assert_eq!(
+            centralized_party_dkg_output.public_key_share,
+            decentralized_party_dkg_output.centralized_party_public_key_share
+        );
+        assert_eq!(
+            centralized_party_dkg_output.decentralized_party_public_key_share,
+            decentralized_party_dkg_output.public_key_share
+        );
+        assert_eq!(
+            centralized_party_dkg_output.encrypted_decentralized_party_secret_key_share,
+            decentralized_party_dkg_output.encrypted_secret_key_share
+        );
+
+        let generator = secp256k1::GroupElement::new(
+            protocol_public_parameters.group_public_parameters.generator,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let public_key_share = secp256k1::GroupElement::new(
+            centralized_party_dkg_output.public_key_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let decentralized_party_public_key_share = secp256k1::GroupElement::new(
+            decentralized_party_dkg_output.public_key_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        assert_eq!(
+            centralized_party_dkg_output.secret_key_share * generator,
+            public_key_share
+        );
+        let paillier_decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let group_order = secp256k1::Scalar::order_from_public_parameters(
+            &protocol_public_parameters.scalar_group_public_parameters,
+        );
+
+        let group_order = Option::<_>::from(NonZero::new(group_order)).unwrap();
+
+        let decentralized_party_secret_key_share = paillier_decryption_key
+            .decrypt(
+                &tiresias::CiphertextSpaceGroupElement::new(
+                    decentralized_party_dkg_output.encrypted_secret_key_share,
+                    protocol_public_parameters
+                        .encryption_scheme_public_parameters
+                        .ciphertext_space_public_parameters(),
+                )
+                .unwrap(),
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+            )
+            .unwrap();
+
+        let decentralized_party_secret_key_share = secp256k1::Scalar::new(
+            decentralized_party_secret_key_share
+                .value()
+                .reduce(&group_order)
+                .into(),
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decentralized_party_secret_key_share * generator,
+            decentralized_party_public_key_share,
+            "the tweaked encrypted secret key share should match the tweaked public key share"
+        );
+        assert_eq!(
+            public_key_share + decentralized_party_public_key_share,
+            secp256k1::GroupElement::new(
+                centralized_party_dkg_output.public_key,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_hardened_derivation() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let generator = secp256k1::GroupElement::new(
+            protocol_public_parameters.group_public_parameters.generator,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            derive_public_key::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+                generator,
+                &[0u8; CHAIN_CODE_SIZE],
+                &[HARDENED_CHILD_INDEX_OFFSET],
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .err()
+            .unwrap(),
+            Error::InvalidParameters
+        ));
+    }
+}
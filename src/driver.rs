@@ -0,0 +1,955 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! An async driver running a decentralized party's rounds over a broadcast transport.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use group::PartyID;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::{
+    sync::mpsc,
+    time::{timeout_at, Instant},
+};
+
+use crate::{
+    round::{Round, Transition},
+    Error, Result,
+};
+
+/// A serialized message broadcast by a decentralized party in a round of a session.
+///
+/// The [`Driver`] attributes the message to `sender`, and blames it for messages that fail to
+/// deserialize or are missing. The transport must therefore authenticate `sender`, e.g. over
+/// authenticated channels or by verifying a signature on the envelope, as otherwise a party could
+/// frame another by sending envelopes in its name.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Envelope {
+    /// The party which sent this message, which must be authenticated by the transport.
+    pub sender: PartyID,
+    pub round: u32,
+    pub message: Vec<u8>,
+}
+
+/// A [`Round`] which can be processed on tokio's blocking thread pool.
+pub trait BlockingRound: Send + 'static {
+    type Incoming: Send + 'static;
+    type Message: Send + 'static;
+    type NextRound: Send + 'static;
+    type Output: Send + 'static;
+
+    fn process(
+        self,
+        incoming: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>>;
+}
+
+impl<R> BlockingRound for R
+where
+    R: Round<Error = Error> + Send + 'static,
+    R::Incoming: Send + 'static,
+    R::Message: Send + 'static,
+    R::NextRound: Send + 'static,
+    R::Output: Send + 'static,
+{
+    type Incoming = R::Incoming;
+    type Message = R::Message;
+    type NextRound = R::NextRound;
+    type Output = R::Output;
+
+    fn process(
+        self,
+        incoming: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        Round::process(self, incoming, rng)
+    }
+}
+
+/// An async driver of a single decentralized party through the rounds of a protocol session,
+/// which broadcasts its messages and receives those of the other parties of the session over
+/// `Transport`.
+///
+/// Each round is processed on tokio's blocking thread pool, as generating and verifying its proofs
+/// is CPU-heavy. A round in which not all parties' messages were received before `round_timeout`
+/// elapsed is aborted with [`Error::UnresponsiveParties`], identifying the parties whose messages
+/// are missing.
+///
+/// `Transport` must be a reliable broadcast channel which authenticates the
+/// [`sender`](Envelope::sender) of every envelope it yields: the driver trusts it to attribute
+/// messages to parties, and to identify malicious and unresponsive parties.
+///
+/// A driver, and its transport, must only be used for a single session.
+pub struct Driver<Transport, Rng> {
+    party_id: PartyID,
+    session: HashSet<PartyID>,
+    transport: Transport,
+    rng: Option<Rng>,
+    round_timeout: Duration,
+    round: u32,
+    pending: HashMap<u32, HashMap<PartyID, Vec<u8>>>,
+}
+
+impl<Transport, Rng> Driver<Transport, Rng>
+where
+    Transport: Stream<Item = Envelope> + Sink<Envelope, Error = Error> + Unpin,
+    Rng: CryptoRngCore + Send + 'static,
+{
+    pub fn new(
+        party_id: PartyID,
+        session: HashSet<PartyID>,
+        transport: Transport,
+        rng: Rng,
+        round_timeout: Duration,
+    ) -> Result<Self> {
+        if !session.contains(&party_id) {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(Self {
+            party_id,
+            session,
+            transport,
+            rng: Some(rng),
+            round_timeout,
+            round: 0,
+            pending: HashMap::new(),
+        })
+    }
+
+    /// Run the [`crate::dkg`] rounds of `party`, in its encryption of secret key share round,
+    /// over the `commitment` of the centralized party.
+    ///
+    /// Returns the secret key share encryption and proof to send to the centralized party, and
+    /// the party of the decommitment proof verification round, which should be finalized with
+    /// [`Driver::finalize`] over the centralized party's decommitment.
+    pub async fn dkg<
+        EncryptionOfSecretKeyShareRound,
+        EncryptionOfSecretKeyShareDecommitmentRound,
+        EncryptionOfSecretKeyShareProofShareRound,
+        EncryptionOfSecretKeyShareProofAggregationRound,
+    >(
+        &mut self,
+        party: EncryptionOfSecretKeyShareRound,
+        commitment: EncryptionOfSecretKeyShareRound::Incoming,
+    ) -> Result<(
+        EncryptionOfSecretKeyShareProofAggregationRound::Message,
+        EncryptionOfSecretKeyShareProofAggregationRound::NextRound,
+    )>
+    where
+        EncryptionOfSecretKeyShareRound:
+            BlockingRound<NextRound = EncryptionOfSecretKeyShareDecommitmentRound>,
+        EncryptionOfSecretKeyShareRound::Message: Serialize + DeserializeOwned,
+        EncryptionOfSecretKeyShareDecommitmentRound: BlockingRound<
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareRound::Message>,
+            NextRound = EncryptionOfSecretKeyShareProofShareRound,
+        >,
+        EncryptionOfSecretKeyShareDecommitmentRound::Message: Serialize + DeserializeOwned,
+        EncryptionOfSecretKeyShareProofShareRound: BlockingRound<
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareDecommitmentRound::Message>,
+            NextRound = EncryptionOfSecretKeyShareProofAggregationRound,
+        >,
+        EncryptionOfSecretKeyShareProofShareRound::Message: Serialize + DeserializeOwned,
+        EncryptionOfSecretKeyShareProofAggregationRound: BlockingRound<
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareProofShareRound::Message>,
+        >,
+    {
+        let (commitment, party) = self.advance(party, commitment).await?;
+
+        self.aggregate(commitment, party).await
+    }
+
+    /// Run the [`crate::presign`] rounds of `party`, in its encrypted masked key share and public
+    /// nonce shares round, over the `nonce_shares_commitments_and_batched_proof` of the
+    /// centralized party.
+    ///
+    /// Returns the output to send to the centralized party, and the presigns of the decentralized
+    /// party.
+    pub async fn presign<
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound,
+        MaskedNoncesDecommitmentRound,
+        MaskedNoncesProofShareRound,
+        MaskedNoncesProofAggregationRound,
+    >(
+        &mut self,
+        party: EncryptedMaskedKeyShareAndPublicNonceSharesRound,
+        nonce_shares_commitments_and_batched_proof: EncryptedMaskedKeyShareAndPublicNonceSharesRound::Incoming,
+    ) -> Result<(
+        MaskedNoncesProofAggregationRound::Message,
+        MaskedNoncesProofAggregationRound::Output,
+    )>
+    where
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound:
+            BlockingRound<NextRound = MaskedKeyShareAndPublicNonceSharesDecommitmentRound>,
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound::Message: Serialize + DeserializeOwned,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound: BlockingRound<
+            Incoming = HashMap<PartyID, EncryptedMaskedKeyShareAndPublicNonceSharesRound::Message>,
+            NextRound = MaskedKeyShareAndPublicNonceSharesProofShareRound,
+        >,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound::Message: Serialize + DeserializeOwned,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound: BlockingRound<
+            Incoming = HashMap<
+                PartyID,
+                MaskedKeyShareAndPublicNonceSharesDecommitmentRound::Message,
+            >,
+            NextRound = MaskedKeyShareAndPublicNonceSharesProofAggregationRound,
+        >,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound::Message: Serialize + DeserializeOwned,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound: BlockingRound<
+            Incoming = HashMap<PartyID, MaskedKeyShareAndPublicNonceSharesProofShareRound::Message>,
+            NextRound = MaskedNoncesDecommitmentRound,
+        >,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound::Message:
+            Serialize + DeserializeOwned,
+        MaskedNoncesDecommitmentRound: BlockingRound<
+            Incoming = HashMap<
+                PartyID,
+                MaskedKeyShareAndPublicNonceSharesProofAggregationRound::Message,
+            >,
+            NextRound = MaskedNoncesProofShareRound,
+        >,
+        MaskedNoncesDecommitmentRound::Message: Serialize + DeserializeOwned,
+        MaskedNoncesProofShareRound: BlockingRound<
+            Incoming = HashMap<PartyID, MaskedNoncesDecommitmentRound::Message>,
+            NextRound = MaskedNoncesProofAggregationRound,
+        >,
+        MaskedNoncesProofShareRound::Message: Serialize + DeserializeOwned,
+        MaskedNoncesProofAggregationRound:
+            BlockingRound<Incoming = HashMap<PartyID, MaskedNoncesProofShareRound::Message>>,
+    {
+        let (commitment, party) = self
+            .advance(party, nonce_shares_commitments_and_batched_proof)
+            .await?;
+        let (commitment, party) = self.aggregate(commitment, party).await?;
+
+        let commitments = self.exchange(commitment).await?;
+        let (decommitment, party) = self.advance(party, commitments).await?;
+
+        let decommitments = self.exchange(decommitment).await?;
+        let (proof_share, party) = self.advance(party, decommitments).await?;
+
+        let proof_shares = self.exchange(proof_share).await?;
+        self.finalize(party, proof_shares).await
+    }
+
+    /// Run the [`crate::sign`] rounds of `party`, in its signature partial decryption round, over
+    /// the message to sign and the public nonce encrypted partial signature and proof of the
+    /// centralized party, threshold decrypting the signature using `lagrange_coefficients`.
+    pub async fn sign<
+        LagrangeCoefficients,
+        SignaturePartialDecryptionRound,
+        SignatureThresholdDecryptionRound,
+    >(
+        &mut self,
+        party: SignaturePartialDecryptionRound,
+        message_and_public_nonce_encrypted_partial_signature_and_proof: SignaturePartialDecryptionRound::Incoming,
+        lagrange_coefficients: LagrangeCoefficients,
+    ) -> Result<SignatureThresholdDecryptionRound::Output>
+    where
+        SignaturePartialDecryptionRound:
+            BlockingRound<NextRound = SignatureThresholdDecryptionRound>,
+        SignaturePartialDecryptionRound::Message: Serialize + DeserializeOwned,
+        SignatureThresholdDecryptionRound: BlockingRound<
+            Incoming = (
+                LagrangeCoefficients,
+                HashMap<PartyID, SignaturePartialDecryptionRound::Message>,
+            ),
+        >,
+    {
+        let (decryption_shares, party) = self
+            .advance(
+                party,
+                message_and_public_nonce_encrypted_partial_signature_and_proof,
+            )
+            .await?;
+
+        let decryption_shares = self.exchange(decryption_shares).await?;
+        let (_, signature) = self
+            .finalize(party, (lagrange_coefficients, decryption_shares))
+            .await?;
+
+        Ok(signature)
+    }
+
+    /// Process `incoming` by `party` on the blocking thread pool, and proceed to its next round.
+    pub async fn advance<R: BlockingRound>(
+        &mut self,
+        party: R,
+        incoming: R::Incoming,
+    ) -> Result<(R::Message, R::NextRound)> {
+        self.process(party, incoming).await?.advance()
+    }
+
+    /// Process `incoming` by `party` on the blocking thread pool in the last round of its
+    /// protocol.
+    pub async fn finalize<R: BlockingRound>(
+        &mut self,
+        party: R,
+        incoming: R::Incoming,
+    ) -> Result<(R::Message, R::Output)> {
+        self.process(party, incoming).await?.finalize()
+    }
+
+    /// Broadcast `message` to the other parties of the session, and receive theirs for this round.
+    ///
+    /// Parties whose messages fail to deserialize are identified as malicious.
+    pub async fn exchange<Message: Serialize + DeserializeOwned>(
+        &mut self,
+        message: Message,
+    ) -> Result<HashMap<PartyID, Message>> {
+        let round = self.round;
+        self.round += 1;
+
+        self.transport
+            .send(Envelope {
+                sender: self.party_id,
+                round,
+                message: serde_json::to_vec(&message)?,
+            })
+            .await?;
+
+        let messages = self.receive(round).await?;
+
+        let mut malicious_parties = vec![];
+        let mut messages: HashMap<_, _> = messages
+            .into_iter()
+            .filter_map(
+                |(party_id, message)| match serde_json::from_slice(&message) {
+                    Ok(message) => Some((party_id, message)),
+                    Err(_) => {
+                        malicious_parties.push(party_id);
+
+                        None
+                    }
+                },
+            )
+            .collect();
+
+        if !malicious_parties.is_empty() {
+            malicious_parties.sort();
+
+            return Err(Error::MaliciousParties(malicious_parties));
+        }
+
+        messages.insert(self.party_id, message);
+
+        Ok(messages)
+    }
+
+    /// Run the decommitment, proof share and proof aggregation rounds of a proof aggregation,
+    /// starting from this party's `commitment`.
+    async fn aggregate<Commitment, DecommitmentRound, ProofShareRound, ProofAggregationRound>(
+        &mut self,
+        commitment: Commitment,
+        party: DecommitmentRound,
+    ) -> Result<(
+        ProofAggregationRound::Message,
+        ProofAggregationRound::NextRound,
+    )>
+    where
+        Commitment: Serialize + DeserializeOwned,
+        DecommitmentRound:
+            BlockingRound<Incoming = HashMap<PartyID, Commitment>, NextRound = ProofShareRound>,
+        DecommitmentRound::Message: Serialize + DeserializeOwned,
+        ProofShareRound: BlockingRound<
+            Incoming = HashMap<PartyID, DecommitmentRound::Message>,
+            NextRound = ProofAggregationRound,
+        >,
+        ProofShareRound::Message: Serialize + DeserializeOwned,
+        ProofAggregationRound: BlockingRound<Incoming = HashMap<PartyID, ProofShareRound::Message>>,
+    {
+        let commitments = self.exchange(commitment).await?;
+        let (decommitment, party) = self.advance(party, commitments).await?;
+
+        let decommitments = self.exchange(decommitment).await?;
+        let (proof_share, party) = self.advance(party, decommitments).await?;
+
+        let proof_shares = self.exchange(proof_share).await?;
+        self.advance(party, proof_shares).await
+    }
+
+    async fn process<R: BlockingRound>(
+        &mut self,
+        party: R,
+        incoming: R::Incoming,
+    ) -> Result<Transition<R::Message, R::NextRound, R::Output>> {
+        let mut rng = self.rng.take().ok_or(Error::InternalError)?;
+
+        let (transition, rng) = tokio::task::spawn_blocking(move || {
+            let transition = party.process(incoming, &mut rng);
+
+            (transition, rng)
+        })
+        .await
+        .map_err(|_| Error::InternalError)?;
+
+        self.rng = Some(rng);
+
+        transition
+    }
+
+    /// Receive the serialized messages of the other parties of the session for `round`, buffering
+    /// those of later rounds sent by parties which are ahead of this one.
+    async fn receive(&mut self, round: u32) -> Result<HashMap<PartyID, Vec<u8>>> {
+        let deadline = Instant::now() + self.round_timeout;
+        let mut messages = self.pending.remove(&round).unwrap_or_default();
+
+        while messages.len() + 1 < self.session.len() {
+            let envelope = match timeout_at(deadline, self.transport.next()).await {
+                Ok(Some(envelope)) => envelope,
+                // The round timed out, or the transport was closed, before all parties' messages
+                // were received.
+                Ok(None) | Err(_) => {
+                    let mut unresponsive_parties: Vec<PartyID> = self
+                        .session
+                        .iter()
+                        .filter(|&&party_id| {
+                            party_id != self.party_id && !messages.contains_key(&party_id)
+                        })
+                        .copied()
+                        .collect();
+                    unresponsive_parties.sort();
+
+                    return Err(Error::UnresponsiveParties(unresponsive_parties));
+                }
+            };
+
+            if envelope.sender == self.party_id || !self.session.contains(&envelope.sender) {
+                continue;
+            }
+
+            // Under a reliable broadcast all parties receive the same message from a sender, so
+            // any later copies of it are ignored.
+            match envelope.round.cmp(&round) {
+                Ordering::Less => {}
+                Ordering::Equal => {
+                    messages.entry(envelope.sender).or_insert(envelope.message);
+                }
+                Ordering::Greater => {
+                    self.pending
+                        .entry(envelope.round)
+                        .or_default()
+                        .entry(envelope.sender)
+                        .or_insert(envelope.message);
+                }
+            }
+        }
+
+        Ok(messages)
+    }
+}
+
+/// An in-memory transport, which broadcasts every [`Envelope`] to all other parties of the
+/// session.
+///
+/// It does not authenticate the sender of an envelope, and is only meant for parties running in
+/// the same process, e.g. in tests.
+pub struct ChannelTransport {
+    peers: Vec<mpsc::UnboundedSender<Envelope>>,
+    receiver: mpsc::UnboundedReceiver<Envelope>,
+}
+
+impl ChannelTransport {
+    /// Connect the parties of `session` to one another.
+    pub fn connect(session: &HashSet<PartyID>) -> HashMap<PartyID, Self> {
+        let (senders, receivers): (HashMap<_, _>, HashMap<_, _>) = session
+            .iter()
+            .map(|&party_id| {
+                let (sender, receiver) = mpsc::unbounded_channel();
+
+                ((party_id, sender), (party_id, receiver))
+            })
+            .unzip();
+
+        receivers
+            .into_iter()
+            .map(|(party_id, receiver)| {
+                let peers = senders
+                    .iter()
+                    .filter(|(&peer_id, _)| peer_id != party_id)
+                    .map(|(_, sender)| sender.clone())
+                    .collect();
+
+                (party_id, Self { peers, receiver })
+            })
+            .collect()
+    }
+}
+
+impl Stream for ChannelTransport {
+    type Item = Envelope;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().receiver.poll_recv(cx)
+    }
+}
+
+impl Sink<Envelope> for ChannelTransport {
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, envelope: Envelope) -> Result<()> {
+        for peer in &self.peers {
+            // A peer which left the session is identified as unresponsive by the other parties.
+            let _ = peer.send(envelope.clone());
+        }
+
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs"
+))]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    };
+
+    use futures::SinkExt;
+    use group::{secp256k1, PartyID};
+    use rand::seq::IteratorRandom;
+    use rand_core::OsRng;
+    use tiresias::test_exports::N;
+
+    use super::{ChannelTransport, Driver, Envelope};
+    use crate::{
+        dkg,
+        dkg::tests::generates_distributed_key_internal,
+        round::Round,
+        secp256k1::paillier::bulletproofs::{
+            CentralizedPartyPresign, DecentralizedPartyPresign, EncryptedMaskedKeyShareRoundParty,
+            PresignCommitmentRoundParty, ProtocolPublicParameters,
+            SignatureHomomorphicEvaluationParty, SignaturePartialDecryptionParty,
+        },
+        session::{tests::session_context, Protocol},
+        sign::{message_digest, tests::setup_decryption_key_shares, Hash},
+        Error,
+    };
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn generates_distributed_key() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+        let session: HashSet<PartyID> = (1..=3).collect();
+
+        let (commitment, centralized_party_decommitment_round_party) = Round::process(
+            dkg::centralized_party::commitment_round::Party::new(
+                protocol_public_parameters.clone(),
+                session_context(Protocol::Dkg),
+            ),
+            (),
+            &mut OsRng,
+        )
+        .unwrap()
+        .advance()
+        .unwrap();
+
+        let mut transports = ChannelTransport::connect(&session);
+        let handles: Vec<_> = session
+            .iter()
+            .map(|&party_id| {
+                let party =
+                    dkg::decentralized_party::encryption_of_secret_key_share_round::Party::new(
+                        protocol_public_parameters.clone(),
+                        party_id,
+                        3,
+                        session.clone(),
+                        session_context(Protocol::Dkg),
+                    );
+                let mut driver = Driver::new(
+                    party_id,
+                    session.clone(),
+                    transports.remove(&party_id).unwrap(),
+                    OsRng,
+                    Duration::from_secs(600),
+                )
+                .unwrap();
+                let commitment = commitment.clone();
+
+                tokio::spawn(async move {
+                    let (secret_key_share_encryption_and_proof, party) =
+                        driver.dkg(party, commitment).await.unwrap();
+
+                    (secret_key_share_encryption_and_proof, party, driver)
+                })
+            })
+            .collect();
+
+        let mut decentralized_parties = vec![];
+        for handle in handles {
+            decentralized_parties.push(handle.await.unwrap());
+        }
+
+        let (public_key_share_decommitment_and_proof, centralized_party_output) = Round::process(
+            centralized_party_decommitment_round_party,
+            decentralized_parties[0].0.clone(),
+            &mut OsRng,
+        )
+        .unwrap()
+        .finalize()
+        .unwrap();
+
+        for (_, party, mut driver) in decentralized_parties {
+            let (_, decentralized_party_output) = driver
+                .finalize(party, public_key_share_decommitment_and_proof.clone())
+                .await
+                .unwrap();
+
+            assert_eq!(
+                decentralized_party_output.public_key,
+                centralized_party_output.public_key
+            );
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn generates_presignatures() {
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 3);
+
+        let (centralized_party_presigns, decentralized_party_presigns) = presign(
+            2,
+            3,
+            4,
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+        )
+        .await;
+
+        assert_eq!(centralized_party_presigns.len(), 4);
+
+        let decentralized_party_presign = decentralized_party_presigns.values().next().unwrap();
+        assert!(decentralized_party_presigns
+            .values()
+            .all(|presigns| presigns == decentralized_party_presign));
+        assert_eq!(decentralized_party_presign.len(), 4);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn signs() {
+        let threshold = 2;
+        let number_of_parties = 3;
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let (centralized_party_presigns, decentralized_party_presigns) = presign(
+            threshold,
+            number_of_parties,
+            1,
+            centralized_party_dkg_output.clone(),
+            decentralized_party_dkg_output.clone(),
+        )
+        .await;
+        let decentralized_party_presign = decentralized_party_presigns
+            .into_values()
+            .next()
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let message = message_digest::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+            "signing through the driver!".as_bytes(),
+            Hash::Sha256,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let (
+            public_nonce_encrypted_partial_signature_and_proof,
+            signature_verification_round_party,
+        ) = Round::process(
+            SignatureHomomorphicEvaluationParty::new(
+                session_context(Protocol::Sign),
+                protocol_public_parameters.clone(),
+                centralized_party_dkg_output,
+                centralized_party_presigns.first().unwrap().clone(),
+            )
+            .unwrap(),
+            message,
+            &mut OsRng,
+        )
+        .unwrap()
+        .advance()
+        .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+        let session: HashSet<PartyID> = decryption_key_shares.keys().copied().collect();
+
+        let mut transports = ChannelTransport::connect(&session);
+        let handles: Vec<_> = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                let party = SignaturePartialDecryptionParty::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters.clone(),
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output.clone(),
+                    decentralized_party_presign.clone(),
+                )
+                .unwrap();
+                let mut driver = Driver::new(
+                    party_id,
+                    session.clone(),
+                    transports.remove(&party_id).unwrap(),
+                    OsRng,
+                    Duration::from_secs(600),
+                )
+                .unwrap();
+                let public_nonce_encrypted_partial_signature_and_proof =
+                    public_nonce_encrypted_partial_signature_and_proof.clone();
+                let lagrange_coefficients = lagrange_coefficients.clone();
+
+                tokio::spawn(async move {
+                    driver
+                        .sign(
+                            party,
+                            (message, public_nonce_encrypted_partial_signature_and_proof),
+                            lagrange_coefficients,
+                        )
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        let mut signatures = vec![];
+        for handle in handles {
+            signatures.push(handle.await.unwrap());
+        }
+
+        let signature = *signatures.first().unwrap();
+        assert!(signatures
+            .iter()
+            .all(|&other_signature| other_signature == signature));
+
+        Round::process(signature_verification_round_party, signature, &mut OsRng)
+            .unwrap()
+            .finalize()
+            .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn identifies_unresponsive_parties_on_timeout() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+        let session: HashSet<PartyID> = (1..=3).collect();
+
+        let (commitment, _) = Round::process(
+            dkg::centralized_party::commitment_round::Party::new(
+                protocol_public_parameters.clone(),
+                session_context(Protocol::Dkg),
+            ),
+            (),
+            &mut OsRng,
+        )
+        .unwrap()
+        .advance()
+        .unwrap();
+
+        // Party 3 never joins the session.
+        let mut transports = ChannelTransport::connect(&session);
+        let handles: Vec<_> = [1, 2]
+            .into_iter()
+            .map(|party_id| {
+                let party =
+                    dkg::decentralized_party::encryption_of_secret_key_share_round::Party::new(
+                        protocol_public_parameters.clone(),
+                        party_id,
+                        3,
+                        session.clone(),
+                        session_context(Protocol::Dkg),
+                    );
+                let mut driver = Driver::new(
+                    party_id,
+                    session.clone(),
+                    transports.remove(&party_id).unwrap(),
+                    OsRng,
+                    Duration::from_secs(1),
+                )
+                .unwrap();
+                let commitment = commitment.clone();
+
+                tokio::spawn(async move { driver.dkg(party, commitment).await.err().unwrap() })
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(matches!(
+                handle.await.unwrap(),
+                Error::UnresponsiveParties(parties) if parties == vec![3]
+            ));
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn identifies_parties_sending_undecodable_messages() {
+        let session: HashSet<PartyID> = (1..=4).collect();
+        let mut transports = ChannelTransport::connect(&session);
+
+        // Parties 3 and 4 broadcast messages which do not deserialize.
+        for party_id in [3, 4] {
+            transports
+                .remove(&party_id)
+                .unwrap()
+                .send(Envelope {
+                    sender: party_id,
+                    round: 0,
+                    message: b"not a message".to_vec(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let handles: Vec<_> = [1, 2]
+            .into_iter()
+            .map(|party_id| {
+                let mut driver = Driver::new(
+                    party_id,
+                    session.clone(),
+                    transports.remove(&party_id).unwrap(),
+                    OsRng,
+                    Duration::from_secs(1),
+                )
+                .unwrap();
+
+                tokio::spawn(async move { driver.exchange(u64::from(party_id)).await })
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(matches!(
+                handle.await.unwrap().err().unwrap(),
+                Error::MaliciousParties(parties) if parties == vec![3, 4]
+            ));
+        }
+    }
+
+    /// Generate `batch_size` presigns by `threshold` out of `number_of_parties` parties, each
+    /// driven over a [`ChannelTransport`], returning the presigns of the centralized party and
+    /// those of each decentralized party.
+    async fn presign(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        batch_size: usize,
+        centralized_party_dkg_output: dkg::centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        decentralized_party_dkg_output: dkg::decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+    ) -> (
+        Vec<CentralizedPartyPresign>,
+        HashMap<PartyID, Vec<DecentralizedPartyPresign>>,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (nonce_shares_commitments_and_batched_proof, proof_verification_round_party) =
+            Round::process(
+                PresignCommitmentRoundParty::new(
+                    session_context(Protocol::Presign),
+                    protocol_public_parameters.clone(),
+                    centralized_party_dkg_output,
+                )
+                .unwrap(),
+                batch_size,
+                &mut OsRng,
+            )
+            .unwrap()
+            .advance()
+            .unwrap();
+
+        let session: HashSet<PartyID> = (1..=number_of_parties)
+            .choose_multiple(&mut OsRng, threshold.into())
+            .into_iter()
+            .collect();
+
+        let mut transports = ChannelTransport::connect(&session);
+        let handles: Vec<_> = session
+            .iter()
+            .map(|&party_id| {
+                let party = EncryptedMaskedKeyShareRoundParty::new(
+                    party_id,
+                    threshold,
+                    session.clone(),
+                    session_context(Protocol::Presign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output.clone(),
+                )
+                .unwrap();
+                let mut driver = Driver::new(
+                    party_id,
+                    session.clone(),
+                    transports.remove(&party_id).unwrap(),
+                    OsRng,
+                    Duration::from_secs(600),
+                )
+                .unwrap();
+                let nonce_shares_commitments_and_batched_proof =
+                    nonce_shares_commitments_and_batched_proof.clone();
+
+                tokio::spawn(async move {
+                    let (output, presigns) = driver
+                        .presign(party, nonce_shares_commitments_and_batched_proof)
+                        .await
+                        .unwrap();
+
+                    (party_id, output, presigns)
+                })
+            })
+            .collect();
+
+        let mut outputs = vec![];
+        let mut decentralized_party_presigns = HashMap::new();
+        for handle in handles {
+            let (party_id, output, presigns) = handle.await.unwrap();
+
+            outputs.push(output);
+            decentralized_party_presigns.insert(party_id, presigns);
+        }
+
+        let (_, centralized_party_presigns) = Round::process(
+            proof_verification_round_party,
+            outputs.pop().unwrap(),
+            &mut OsRng,
+        )
+        .unwrap()
+        .finalize()
+        .unwrap();
+
+        (centralized_party_presigns, decentralized_party_presigns)
+    }
+}
@@ -0,0 +1,777 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Ed25519 signing over edwards25519, whose presign nonces must never be reused.
+
+use std::ops::Neg;
+
+use crypto_bigint::{NonZero, Uint, U512};
+use group::{GroupElement, KnownOrderGroupElement, PrimeGroupElement, Reduce};
+use sha2::{Digest, Sha512};
+
+use crate::Error;
+
+pub mod centralized_party;
+pub mod decentralized_party;
+
+/// The size in bytes of an Ed25519 signature: the encoding of the public nonce $R$, followed by
+/// the little-endian encoding of $s$.
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// The size in bytes of an Ed25519 public key.
+pub const PUBLIC_KEY_SIZE: usize = 32;
+
+/// A group element which can be encoded as a compressed Edwards point, as defined in RFC 8032.
+pub trait EdwardsGroupElement<const SCALAR_LIMBS: usize>: PrimeGroupElement<SCALAR_LIMBS> {
+    /// The little-endian encoding of the y-coordinate of this point, with the sign of the
+    /// x-coordinate in its most significant bit.
+    fn compressed_bytes(&self) -> [u8; PUBLIC_KEY_SIZE];
+}
+
+impl EdwardsGroupElement<{ group::curve25519::SCALAR_LIMBS }> for group::curve25519::GroupElement {
+    fn compressed_bytes(&self) -> [u8; PUBLIC_KEY_SIZE] {
+        let point: curve25519_dalek::EdwardsPoint = self.value().into();
+
+        point.compress().to_bytes()
+    }
+}
+
+/// The RFC 8032 encoding of `public_key`.
+///
+/// Note that the secret key behind it is a scalar shared between the parties, and not an RFC 8032
+/// seed: it can therefore not be exported to, or imported from, standard Ed25519 wallets.
+pub fn public_key<const SCALAR_LIMBS: usize, GroupElement: EdwardsGroupElement<SCALAR_LIMBS>>(
+    public_key: GroupElement::Value,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<[u8; PUBLIC_KEY_SIZE]> {
+    let public_key = GroupElement::new(public_key, group_public_parameters)?;
+
+    Ok(public_key.compressed_bytes())
+}
+
+/// Compute the Ed25519 challenge $ k = \textsf{int}(SHA512(R || A || M)) \mod q $.
+pub fn challenge<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>(
+    nonce: &[u8; PUBLIC_KEY_SIZE],
+    public_key: &[u8; PUBLIC_KEY_SIZE],
+    message: &[u8],
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<GroupElement::Scalar> {
+    if Uint::<SCALAR_LIMBS>::BYTES != 32 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let challenge = Sha512::new()
+        .chain_update(nonce)
+        .chain_update(public_key)
+        .chain_update(message)
+        .finalize();
+    let challenge = U512::from_le_slice(&challenge);
+
+    let group_order =
+        GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters);
+    let group_order = Option::<_>::from(NonZero::new(group_order)).ok_or(Error::InternalError)?;
+
+    Ok(GroupElement::Scalar::new(
+        challenge.reduce(&group_order).into(),
+        scalar_group_public_parameters,
+    )?)
+}
+
+/// Encode a signature $(R, s)$ in the 64-byte RFC 8032 format. Assumes a 256-bit `signature_s`,
+/// which [`challenge`] and [`verify_signature`] enforce.
+pub(crate) fn encode_signature<const SCALAR_LIMBS: usize>(
+    nonce: [u8; PUBLIC_KEY_SIZE],
+    signature_s: Uint<SCALAR_LIMBS>,
+) -> [u8; SIGNATURE_SIZE] {
+    let mut signature = [0u8; SIGNATURE_SIZE];
+
+    signature[..PUBLIC_KEY_SIZE].copy_from_slice(&nonce);
+
+    let signature_s: Vec<u8> = signature_s
+        .as_words()
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect();
+    signature[PUBLIC_KEY_SIZE..].copy_from_slice(&signature_s);
+
+    signature
+}
+
+/// Verify an Ed25519 `signature` on `message` under `public_key`, checking the cofactorless
+/// equation $ s \cdot B = R + k \cdot A $ over the encoding of $R$.
+pub fn verify_signature<
+    const SCALAR_LIMBS: usize,
+    GroupElement: EdwardsGroupElement<SCALAR_LIMBS>,
+>(
+    signature: &[u8; SIGNATURE_SIZE],
+    message: &[u8],
+    public_key: GroupElement,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<()> {
+    if Uint::<SCALAR_LIMBS>::BYTES != 32 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let mut nonce = [0u8; PUBLIC_KEY_SIZE];
+    nonce.copy_from_slice(&signature[..PUBLIC_KEY_SIZE]);
+
+    let signature_s = Uint::<SCALAR_LIMBS>::from_le_slice(&signature[PUBLIC_KEY_SIZE..]);
+    if signature_s
+        >= GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters)
+    {
+        return Err(Error::SignatureVerification);
+    }
+    let signature_s =
+        GroupElement::Scalar::new(signature_s.into(), scalar_group_public_parameters)?;
+
+    let challenge = challenge::<SCALAR_LIMBS, GroupElement>(
+        &nonce,
+        &public_key.compressed_bytes(),
+        message,
+        scalar_group_public_parameters,
+    )?;
+
+    // $ R = s \cdot B - k \cdot A $
+    let expected_nonce = (signature_s * public_key.generator()) + (challenge * public_key).neg();
+
+    if expected_nonce.compressed_bytes() != nonce {
+        return Err(Error::SignatureVerification);
+    }
+
+    Ok(())
+}
+
+#[cfg(all(
+    test,
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs"
+))]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use crypto_bigint::{Encoding, U256};
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+    use enhanced_maurer::language::EnhancedLanguageStatementAccessors;
+    use group::{curve25519, PartyID};
+    use homomorphic_encryption::{
+        AdditivelyHomomorphicDecryptionKey, AdditivelyHomomorphicDecryptionKeyShare,
+        GroupsPublicParametersAccessors,
+    };
+    use proof::aggregation::test_helpers::{
+        aggregates, aggregates_multiple_with_decommitments, aggregates_with_decommitments,
+    };
+    use rand::prelude::IteratorRandom;
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::{
+        test_exports::{N, SECRET_KEY},
+        DecryptionKeyShare,
+    };
+
+    use super::*;
+    use crate::{
+        curve25519::paillier::bulletproofs::{
+            CentralizedPartyPresign, DKGCentralizedPartyOutput, DKGCommitmentRoundParty,
+            DKGDecentralizedPartyOutput, DecentralizedPartyPresign,
+            EdDSASignaturePartialDecryptionParty, EdDSASignatureShareParty,
+            EncryptedMaskedKeyShareRoundParty, EncryptionOfSecretKeyShareRoundParty,
+            PresignCommitmentRoundParty, ProtocolPublicParameters,
+        },
+        dkg::decentralized_party::SecretKeyShareEncryptionAndProof,
+        presign,
+        session::{tests::session_context, Protocol, SessionContext},
+        sign::tests::setup_decryption_key_shares,
+    };
+
+    // Test vectors 1-3 of RFC 8032, section 7.1.
+    #[rstest]
+    #[case(
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        "",
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+    )]
+    #[case(
+        "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        "72",
+        "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+    )]
+    #[case(
+        "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+        "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        "af82",
+        "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a"
+    )]
+    fn verifies_rfc8032_test_vectors(
+        #[case] seed: &str,
+        #[case] public_key: &str,
+        #[case] message: &str,
+        #[case] signature: &str,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let generator = curve25519::GroupElement::new(
+            protocol_public_parameters.group_public_parameters.generator,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        // Derive the secret scalar from the seed as in RFC 8032, section 5.1.5.
+        let mut secret_key: [u8; 32] = Sha512::digest(U256::from_be_hex(seed).to_be_bytes())[..32]
+            .try_into()
+            .unwrap();
+        secret_key[0] &= 248;
+        secret_key[31] &= 127;
+        secret_key[31] |= 64;
+
+        let group_order = curve25519::Scalar::order_from_public_parameters(
+            &protocol_public_parameters.scalar_group_public_parameters,
+        );
+        let group_order = Option::<_>::from(NonZero::new(group_order)).unwrap();
+        let secret_key = curve25519::Scalar::new(
+            U256::from_le_slice(&secret_key).reduce(&group_order).into(),
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let public_key_point = secret_key * generator;
+
+        assert_eq!(
+            public_key_point.compressed_bytes(),
+            U256::from_be_hex(public_key).to_be_bytes()
+        );
+
+        let message: Vec<u8> = (0..message.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&message[i..i + 2], 16).unwrap())
+            .collect();
+
+        let mut signature_bytes = [0u8; SIGNATURE_SIZE];
+        signature_bytes[..32].copy_from_slice(&U256::from_be_hex(&signature[..64]).to_be_bytes());
+        signature_bytes[32..].copy_from_slice(&U256::from_be_hex(&signature[64..]).to_be_bytes());
+
+        assert!(verify_signature::<{ curve25519::SCALAR_LIMBS }, _>(
+            &signature_bytes,
+            &message,
+            public_key_point,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .is_ok());
+
+        signature_bytes[0] ^= 1;
+
+        assert!(matches!(
+            verify_signature::<{ curve25519::SCALAR_LIMBS }, _>(
+                &signature_bytes,
+                &message,
+                public_key_point,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            ),
+            Err(Error::SignatureVerification)
+        ));
+    }
+
+    /// Run the DKG and presign protocols over curve25519, returning the outputs of both parties.
+    fn generates_distributed_key_and_presignatures(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        batch_size: usize,
+    ) -> (
+        DKGCentralizedPartyOutput,
+        DKGDecentralizedPartyOutput,
+        Vec<CentralizedPartyPresign>,
+        Vec<DecentralizedPartyPresign>,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let mut parties = HashSet::new();
+        (1..=number_of_parties)
+            .choose_multiple(&mut OsRng, threshold.into())
+            .into_iter()
+            .for_each(|party_id| {
+                parties.insert(party_id);
+            });
+
+        // DKG
+        let (
+            commitment_to_centralized_party_secret_key_share,
+            centralized_party_decommitment_round_party,
+        ) = DKGCommitmentRoundParty::new(
+            protocol_public_parameters.clone(),
+            session_context(Protocol::Dkg),
+        )
+        .sample_commit_and_prove_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let (
+            encryption_of_secret_key_share_commitment_round_parties,
+            decommitment_proof_verification_round_parties,
+        ): (HashMap<_, _>, HashMap<_, _>) = parties
+            .clone()
+            .into_iter()
+            .map(|party_id| {
+                let (commitment_round_party, decommitment_proof_verification_round_party) =
+                    EncryptionOfSecretKeyShareRoundParty::new(
+                        protocol_public_parameters.clone(),
+                        party_id,
+                        threshold,
+                        parties.clone(),
+                        session_context(Protocol::Dkg),
+                    )
+                    .sample_secret_key_share_and_initialize_proof_aggregation(
+                        commitment_to_centralized_party_secret_key_share,
+                        &mut OsRng,
+                    )
+                    .unwrap();
+
+                (
+                    (party_id, commitment_round_party),
+                    (party_id, decommitment_proof_verification_round_party),
+                )
+            })
+            .unzip();
+
+        let (.., (encryption_of_secret_key_share_proof, encryption_of_secret_key_share)) =
+            aggregates(encryption_of_secret_key_share_commitment_round_parties);
+
+        let secret_key_share_encryption_and_proof = SecretKeyShareEncryptionAndProof::new(
+            *encryption_of_secret_key_share.first().unwrap(),
+            encryption_of_secret_key_share_proof,
+        );
+
+        let (public_key_share_decommitment_and_proof, centralized_party_dkg_output) =
+            centralized_party_decommitment_round_party
+                .decommit_proof_public_key_share(
+                    secret_key_share_encryption_and_proof.clone(),
+                    &mut OsRng,
+                )
+                .unwrap();
+
+        let decentralized_party_dkg_output = decommitment_proof_verification_round_parties
+            .into_values()
+            .map(|party| {
+                party
+                    .verify_decommitment_and_proof_of_centralized_party_public_key_share(
+                        public_key_share_decommitment_and_proof.clone(),
+                        secret_key_share_encryption_and_proof.clone(),
+                    )
+                    .unwrap()
+            })
+            .next()
+            .unwrap();
+
+        // Presign
+        let (nonce_shares_commitments_and_batched_proof, proof_verification_round_party) =
+            PresignCommitmentRoundParty::new(
+                session_context(Protocol::Presign),
+                protocol_public_parameters.clone(),
+                centralized_party_dkg_output.clone(),
+            )
+            .unwrap()
+            .sample_commit_and_prove_signature_nonce_share(batch_size, &mut OsRng)
+            .unwrap();
+
+        let (aggregation_parties, encrypted_masked_nonces_round_parties): (
+            HashMap<_, _>,
+            HashMap<_, _>,
+        ) = parties
+            .clone()
+            .into_iter()
+            .map(|party_id| {
+                let (aggregation_parties, encrypted_masked_nonces_round_party) =
+                    EncryptedMaskedKeyShareRoundParty::new(
+                        party_id,
+                        threshold,
+                        parties.clone(),
+                        session_context(Protocol::Presign),
+                        protocol_public_parameters.clone(),
+                        decentralized_party_dkg_output.clone(),
+                    )
+                    .unwrap()
+                    .sample_mask_and_nonce_shares_and_initialize_proof_aggregation(
+                        nonce_shares_commitments_and_batched_proof.clone(),
+                        &mut OsRng,
+                    )
+                    .unwrap();
+
+                (
+                    (party_id, aggregation_parties),
+                    (party_id, encrypted_masked_nonces_round_party),
+                )
+            })
+            .unzip();
+
+        let (
+            encrypted_masked_key_share_commitment_round_parties,
+            public_nonce_shares_commitment_round_parties,
+        ): (HashMap<_, _>, HashMap<_, _>) = aggregation_parties
+            .into_iter()
+            .map(|(party_id, (encdh_party, encdl_party))| {
+                ((party_id, encdh_party), (party_id, encdl_party))
+            })
+            .unzip();
+
+        let (
+            ..,
+            (masks_and_encrypted_masked_key_share_proof, masks_and_encrypted_masked_key_share),
+        ) = aggregates(encrypted_masked_key_share_commitment_round_parties);
+
+        let (
+            encrypted_nonce_shares_and_public_shares_decommitments,
+            ..,
+            (
+                encrypted_nonce_shares_and_public_shares_proof,
+                encrypted_nonce_shares_and_public_shares,
+            ),
+        ) = aggregates_with_decommitments(public_nonce_shares_commitment_round_parties);
+
+        let output = presign::decentralized_party::Output::new(
+            masks_and_encrypted_masked_key_share.clone(),
+            masks_and_encrypted_masked_key_share_proof,
+            encrypted_nonce_shares_and_public_shares.clone(),
+            encrypted_nonce_shares_and_public_shares_proof,
+        )
+        .unwrap();
+
+        let centralized_party_presigns = proof_verification_round_party
+            .verify_presign_output(output, &mut OsRng)
+            .unwrap();
+
+        let masks_and_encrypted_masked_key_share: Vec<_> = masks_and_encrypted_masked_key_share
+            .into_iter()
+            .map(|statement| *statement.language_statement())
+            .collect();
+
+        let encrypted_nonce_shares_and_public_shares: Vec<_> =
+            encrypted_nonce_shares_and_public_shares
+                .into_iter()
+                .map(|statement| *statement.language_statement())
+                .collect();
+
+        let encrypted_masked_nonce_shares_commitment_round_parties: HashMap<_, Vec<_>> =
+            encrypted_masked_nonces_round_parties
+                .into_iter()
+                .map(|(party_id, party)| {
+                    (
+                        party_id,
+                        party
+                            .initialize_proof_aggregation(
+                                masks_and_encrypted_masked_key_share.clone(),
+                                encrypted_nonce_shares_and_public_shares.clone(),
+                                &mut OsRng,
+                            )
+                            .unwrap(),
+                    )
+                })
+                .collect();
+
+        let (encrypted_masked_nonce_shares_decommitments, .., res) =
+            aggregates_multiple_with_decommitments(
+                encrypted_masked_nonce_shares_commitment_round_parties,
+            );
+
+        let encrypted_masked_nonce_shares: Vec<_> = res
+            .into_iter()
+            .flat_map(|(_, statements)| statements)
+            .map(|statement| *statement.language_statement())
+            .collect();
+
+        let individual_encrypted_nonce_shares_and_public_shares =
+            encrypted_nonce_shares_and_public_shares_decommitments
+                .into_iter()
+                .map(|(party_id, decommitments)| {
+                    (
+                        party_id,
+                        decommitments
+                            .into_iter()
+                            .flat_map(|(maurer_decommitment, _)| {
+                                maurer_decommitment.statements.into_iter().map(|statement| {
+                                    let (_, language_statement) = statement.into();
+
+                                    language_statement
+                                })
+                            })
+                            .collect(),
+                    )
+                })
+                .collect();
+
+        let individual_encrypted_masked_nonce_shares = encrypted_masked_nonce_shares_decommitments
+            .into_iter()
+            .map(|(party_id, decommitments)| {
+                (
+                    party_id,
+                    decommitments
+                        .into_iter()
+                        .flat_map(|(maurer_decommitment, _)| {
+                            maurer_decommitment.statements.into_iter().map(|statement| {
+                                let (_, language_statement) = statement.into();
+
+                                language_statement
+                            })
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let decentralized_party_presigns = presign::decentralized_party::Presign::new_batch::<
+            { curve25519::SCALAR_LIMBS },
+            { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+            curve25519::GroupElement,
+            tiresias::EncryptionKey,
+            SessionContext,
+        >(
+            parties,
+            nonce_shares_commitments_and_batched_proof,
+            masks_and_encrypted_masked_key_share,
+            individual_encrypted_nonce_shares_and_public_shares,
+            encrypted_nonce_shares_and_public_shares,
+            individual_encrypted_masked_nonce_shares,
+            encrypted_masked_nonce_shares,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        (
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+            centralized_party_presigns,
+            decentralized_party_presigns,
+        )
+    }
+
+    #[rstest]
+    #[case(2, 2, 1)]
+    #[case(2, 4, 2)]
+    fn dkg_presign_signs(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] batch_size: usize,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+            centralized_party_presigns,
+            decentralized_party_presigns,
+        ) = generates_distributed_key_and_presignatures(threshold, number_of_parties, batch_size);
+
+        let centralized_party_presign = centralized_party_presigns.first().unwrap().clone();
+        let decentralized_party_presign = decentralized_party_presigns.first().unwrap().clone();
+
+        let public_key = public_key::<{ curve25519::SCALAR_LIMBS }, curve25519::GroupElement>(
+            centralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let message = "singing!";
+
+        let (public_nonce_share_signature_share_and_proof, signature_verification_round_party) =
+            EdDSASignatureShareParty::new(
+                session_context(Protocol::EdDSA),
+                protocol_public_parameters.clone(),
+                centralized_party_dkg_output,
+                centralized_party_presign,
+            )
+            .unwrap()
+            .sign_signature_share(message.as_bytes(), &mut OsRng)
+            .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let (decryption_shares, signature_threshold_decryption_round_parties): (
+            HashMap<_, _>,
+            Vec<_>,
+        ) = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                let (decryption_share, signature_threshold_decryption_round_party) =
+                    EdDSASignaturePartialDecryptionParty::new(
+                        threshold,
+                        decryption_key_share,
+                        decryption_key_share_public_parameters.clone(),
+                        session_context(Protocol::EdDSA),
+                        protocol_public_parameters.clone(),
+                        decentralized_party_dkg_output.clone(),
+                        decentralized_party_presign.clone(),
+                    )
+                    .unwrap()
+                    .partially_decrypt_signature_share(
+                        message.as_bytes(),
+                        public_nonce_share_signature_share_and_proof.clone(),
+                    )
+                    .unwrap();
+
+                (
+                    (party_id, decryption_share),
+                    signature_threshold_decryption_round_party,
+                )
+            })
+            .unzip();
+
+        let signature = signature_threshold_decryption_round_parties
+            .into_iter()
+            .next()
+            .unwrap()
+            .decrypt_signature(lagrange_coefficients, decryption_shares)
+            .unwrap();
+
+        signature_verification_round_party
+            .verify_signature(signature)
+            .unwrap();
+
+        let verifying_key = VerifyingKey::from_bytes(&public_key).unwrap();
+
+        assert!(
+            verifying_key
+                .verify(message.as_bytes(), &Signature::from_bytes(&signature))
+                .is_ok(),
+            "generated Ed25519 signatures should be valid"
+        );
+    }
+
+    #[test]
+    fn masks_decrypted_signature_share() {
+        let threshold = 2;
+        let number_of_parties = 4;
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+            centralized_party_presigns,
+            decentralized_party_presigns,
+        ) = generates_distributed_key_and_presignatures(threshold, number_of_parties, 1);
+
+        let centralized_party_presign = centralized_party_presigns.first().unwrap().clone();
+        let decentralized_party_presign = decentralized_party_presigns.first().unwrap().clone();
+
+        let message = "signing with ed25519!";
+
+        let (public_nonce_share_signature_share_and_proof, _) = EdDSASignatureShareParty::new(
+            session_context(Protocol::EdDSA),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output.clone(),
+            centralized_party_presign,
+        )
+        .unwrap()
+        .sign_signature_share(message.as_bytes(), &mut OsRng)
+        .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let decryption_shares: HashMap<_, _> = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                let (decryption_share, _) = EdDSASignaturePartialDecryptionParty::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters.clone(),
+                    session_context(Protocol::EdDSA),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output.clone(),
+                    decentralized_party_presign.clone(),
+                )
+                .unwrap()
+                .partially_decrypt_signature_share(
+                    message.as_bytes(),
+                    public_nonce_share_signature_share_and_proof.clone(),
+                )
+                .unwrap();
+
+                (party_id, decryption_share)
+            })
+            .collect();
+
+        let decrypted_signature_share = DecryptionKeyShare::combine_decryption_shares_semi_honest(
+            decryption_shares,
+            lagrange_coefficients,
+            &decryption_key_share_public_parameters,
+        )
+        .unwrap();
+
+        // Compute the unmasked $ k_B + e \cdot x_B $ the network would otherwise have decrypted.
+        let public_nonce_share = curve25519::GroupElement::new(
+            public_nonce_share_signature_share_and_proof.public_nonce_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let nonce_public_share = curve25519::GroupElement::new(
+            decentralized_party_presign.nonce_public_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let public_key = curve25519::GroupElement::new(
+            centralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let challenge: Uint<{ curve25519::SCALAR_LIMBS }> =
+            challenge::<{ curve25519::SCALAR_LIMBS }, curve25519::GroupElement>(
+                &(public_nonce_share + nonce_public_share).compressed_bytes(),
+                &public_key.compressed_bytes(),
+                message.as_bytes(),
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap()
+            .into();
+
+        let paillier_decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let [nonce_share, secret_key_share] = [
+            decentralized_party_presign.encrypted_nonce_share,
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+        ]
+        .map(|ciphertext| {
+            paillier_decryption_key
+                .decrypt(
+                    &tiresias::CiphertextSpaceGroupElement::new(
+                        ciphertext,
+                        protocol_public_parameters
+                            .encryption_scheme_public_parameters
+                            .ciphertext_space_public_parameters(),
+                    )
+                    .unwrap(),
+                    &protocol_public_parameters.encryption_scheme_public_parameters,
+                )
+                .unwrap()
+        });
+
+        let unmasked_signature_share = nonce_share + secret_key_share.scalar_mul(&challenge);
+
+        assert_ne!(
+            decrypted_signature_share, unmasked_signature_share,
+            "the decentralized party's signature share should be decrypted masked"
+        );
+
+        let group_order = Option::<_>::from(NonZero::new(
+            curve25519::Scalar::order_from_public_parameters(
+                &protocol_public_parameters.scalar_group_public_parameters,
+            ),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            (decrypted_signature_share - unmasked_signature_share)
+                .value()
+                .reduce(&group_order),
+            Uint::<{ curve25519::SCALAR_LIMBS }>::ZERO,
+            "the mask should be a multiple of the group order"
+        );
+    }
+}
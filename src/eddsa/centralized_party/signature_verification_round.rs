@@ -0,0 +1,41 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use crate::{
+    eddsa::{verify_signature, EdwardsGroupElement, SIGNATURE_SIZE},
+    Result,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<const SCALAR_LIMBS: usize, GroupElement: EdwardsGroupElement<SCALAR_LIMBS>> {
+    pub(super) message: Vec<u8>,
+    pub(super) public_key: GroupElement,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+}
+
+impl<const SCALAR_LIMBS: usize, GroupElement: EdwardsGroupElement<SCALAR_LIMBS>>
+    Party<SCALAR_LIMBS, GroupElement>
+{
+    pub fn verify_signature(self, signature: [u8; SIGNATURE_SIZE]) -> crate::Result<()> {
+        verify_signature::<SCALAR_LIMBS, GroupElement>(
+            &signature,
+            &self.message,
+            self.public_key,
+            &self.scalar_group_public_parameters,
+        )
+    }
+
+    pub fn new(
+        message: Vec<u8>,
+        public_key: GroupElement::Value,
+        scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: &group::PublicParameters<GroupElement>,
+    ) -> Result<Self> {
+        let public_key = GroupElement::new(public_key, group_public_parameters)?;
+        Ok(Self {
+            message,
+            public_key,
+            scalar_group_public_parameters,
+        })
+    }
+}
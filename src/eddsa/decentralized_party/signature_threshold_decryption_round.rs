@@ -0,0 +1,111 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use std::collections::HashMap;
+
+use group::PartyID;
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+};
+
+use crate::{
+    eddsa::{encode_signature, verify_signature, EdwardsGroupElement, SIGNATURE_SIZE},
+    schnorr_like::decrypt_signature_share,
+    Error,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: EdwardsGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+> {
+    pub(super) threshold: PartyID,
+    pub(super) decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) message: Vec<u8>,
+    pub(super) public_key: GroupElement,
+    pub(super) public_nonce: GroupElement,
+    pub(super) centralized_party_signature_share: GroupElement::Scalar,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: EdwardsGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+    >
+    Party<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >
+where
+    Error: From<DecryptionKeyShare::Error>,
+{
+    /// The designated threshold decryption party logic, which decrypts the decentralized party's
+    /// signature share $s_B$ and outputs the Ed25519 signature $ (R, s_A + s_B) $.
+    ///
+    /// As in ECDSA, this function never returns an invalid signature, so that parties that
+    /// receive an invalid signature can blame the decrypter.
+    pub fn decrypt_signature(
+        self,
+        lagrange_coefficients: HashMap<PartyID, DecryptionKeyShare::LagrangeCoefficient>,
+        signature_share_decryption_shares: HashMap<PartyID, DecryptionKeyShare::DecryptionShare>,
+    ) -> crate::Result<[u8; SIGNATURE_SIZE]> {
+        // The centralized party's mask $ \eta \cdot q $ vanishes modulo $q$.
+        let signature_share = decrypt_signature_share::<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            DecryptionKeyShare,
+        >(
+            self.threshold,
+            lagrange_coefficients,
+            signature_share_decryption_shares,
+            &self.decryption_key_share_public_parameters,
+            &self.scalar_group_public_parameters,
+        )?; // $s_B$
+
+        let signature_s = self.centralized_party_signature_share + signature_share;
+
+        let signature = encode_signature::<SCALAR_LIMBS>(
+            self.public_nonce.compressed_bytes(),
+            signature_s.into(),
+        );
+
+        verify_signature::<SCALAR_LIMBS, GroupElement>(
+            &signature,
+            &self.message,
+            self.public_key,
+            &self.scalar_group_public_parameters,
+        )?;
+
+        Ok(signature)
+    }
+
+    /// The lightweight $$ O(1) $$ threshold decryption logic, which simply verifies the output of
+    /// the decryption sent by the designated decrypting party. Blames it in case of an invalid
+    /// signature, and accepts otherwise.
+    pub fn verify_decrypted_signature(
+        self,
+        signature: [u8; SIGNATURE_SIZE],
+        designated_decrypting_party_id: PartyID,
+    ) -> crate::Result<[u8; SIGNATURE_SIZE]> {
+        verify_signature::<SCALAR_LIMBS, GroupElement>(
+            &signature,
+            &self.message,
+            self.public_key,
+            &self.scalar_group_public_parameters,
+        )
+        .map_err(|_| Error::MaliciousDesignatedDecryptingParty(designated_decrypting_party_id))?;
+
+        Ok(signature)
+    }
+}
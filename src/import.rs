@@ -0,0 +1,179 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Import of an existing secret key, yielding the same outputs as the DKG.
+
+pub mod centralized_party;
+pub mod decentralized_party;
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+pub(crate) mod tests {
+    use crypto_bigint::{Encoding, U256};
+    use group::{secp256k1, GroupElement as _, PartyID};
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::test_exports::N;
+
+    use crate::{
+        dkg::derivation::CompressedGroupElement,
+        secp256k1::paillier::bulletproofs::{
+            ImportEncryptionOfSecretKeyShareRoundParty, ImportProofVerificationRoundParty,
+            ProtocolPublicParameters,
+        },
+        session::{tests::session_context, Protocol},
+        sign::tests::presign_signs_internal,
+        Error,
+    };
+
+    // The master key of BIP-32 test vector 1.
+    const SECRET_KEY: &str = "E8F32E723DECF4051AEFAC8E2C93C9C5B214313817CDB01A1494B917C8436B35";
+    const PUBLIC_KEY: &str = "0339A36013301597DAEF41FBE593A02CC513D0B55527EC2DF1050E2E8FF49C85C2";
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(2, 4)]
+    #[case(6, 9)]
+    fn imports_secret_key(#[case] threshold: PartyID, #[case] number_of_parties: PartyID) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let secret_key: secp256k1::Scalar = U256::from_be_hex(SECRET_KEY).into();
+
+        let (secret_key_share_encryption_and_proof, centralized_party_dkg_output) =
+            ImportEncryptionOfSecretKeyShareRoundParty::new(
+                session_context(Protocol::Import),
+                protocol_public_parameters.clone(),
+                secret_key,
+            )
+            .unwrap()
+            .split_encrypt_and_prove_secret_key_share(&mut OsRng)
+            .unwrap();
+
+        let decentralized_party_dkg_output = ImportProofVerificationRoundParty::new(
+            session_context(Protocol::Import),
+            protocol_public_parameters.clone(),
+        )
+        .verify_proof(secret_key_share_encryption_and_proof, &mut OsRng)
+        .unwrap();
+
+        assert_eq!(
+            centralized_party_dkg_output.public_key,
+            decentralized_party_dkg_output.public_key
+        );
+
+        assert_eq!(
+            centralized_party_dkg_output.encrypted_decentralized_party_secret_key_share,
+            decentralized_party_dkg_output.encrypted_secret_key_share
+        );
+
+        let public_key = secp256k1::GroupElement::new(
+            decentralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let expected_public_key_prefix = u8::from_str_radix(&PUBLIC_KEY[..2], 16).unwrap();
+        let expected_public_key_x = U256::from_be_hex(&PUBLIC_KEY[2..]).to_be_bytes();
+
+        let public_key = public_key.compressed_bytes();
+        assert_eq!(
+            public_key[0], expected_public_key_prefix,
+            "the imported key should keep its public key"
+        );
+        assert_eq!(
+            public_key[1..],
+            expected_public_key_x,
+            "the imported key should keep its public key"
+        );
+
+        presign_signs_internal(
+            threshold,
+            number_of_parties,
+            1,
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_imports() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        assert!(matches!(
+            ImportEncryptionOfSecretKeyShareRoundParty::new(
+                session_context(Protocol::Import),
+                protocol_public_parameters.clone(),
+                U256::ZERO.into(),
+            )
+            .err()
+            .unwrap(),
+            Error::InvalidParameters
+        ));
+
+        let secret_key: secp256k1::Scalar = U256::from_be_hex(SECRET_KEY).into();
+
+        let (mut secret_key_share_encryption_and_proof, _) =
+            ImportEncryptionOfSecretKeyShareRoundParty::new(
+                session_context(Protocol::Import),
+                protocol_public_parameters.clone(),
+                secret_key,
+            )
+            .unwrap()
+            .split_encrypt_and_prove_secret_key_share(&mut OsRng)
+            .unwrap();
+
+        secret_key_share_encryption_and_proof.decentralized_party_public_key_share =
+            secret_key_share_encryption_and_proof.public_key_share;
+
+        assert!(
+            ImportProofVerificationRoundParty::new(session_context(Protocol::Import), protocol_public_parameters)
+                .verify_proof(secret_key_share_encryption_and_proof, &mut OsRng)
+                .is_err(),
+            "an encrypted secret key share that does not match its public key share should be rejected"
+        );
+    }
+
+    #[test]
+    fn rejects_mismatching_public_key_share() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let secret_key: secp256k1::Scalar = U256::from_be_hex(SECRET_KEY).into();
+
+        let (mut secret_key_share_encryption_and_proof, _) =
+            ImportEncryptionOfSecretKeyShareRoundParty::new(
+                session_context(Protocol::Import),
+                protocol_public_parameters.clone(),
+                secret_key,
+            )
+            .unwrap()
+            .split_encrypt_and_prove_secret_key_share(&mut OsRng)
+            .unwrap();
+
+        let (other_secret_key_share_encryption_and_proof, _) =
+            ImportEncryptionOfSecretKeyShareRoundParty::new(
+                session_context(Protocol::Import),
+                protocol_public_parameters.clone(),
+                secret_key,
+            )
+            .unwrap()
+            .split_encrypt_and_prove_secret_key_share(&mut OsRng)
+            .unwrap();
+
+        secret_key_share_encryption_and_proof.public_key_share =
+            other_secret_key_share_encryption_and_proof.public_key_share;
+
+        assert!(
+            ImportProofVerificationRoundParty::new(
+                session_context(Protocol::Import),
+                protocol_public_parameters
+            )
+            .verify_proof(secret_key_share_encryption_and_proof, &mut OsRng)
+            .is_err(),
+            "a public key share whose discrete log was not proven should be rejected"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use enhanced_maurer::{
+    encryption_of_discrete_log, encryption_of_discrete_log::StatementAccessors,
+    language::EnhancedLanguageStatementAccessors, EnhanceableLanguage,
+};
+use group::{GroupElement, PrimeGroupElement, Samplable};
+use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
+use maurer::{knowledge_of_discrete_log, SOUND_PROOFS_REPETITIONS};
+use proof::{range, AggregatableRangeProof};
+use serde::{Deserialize, Serialize};
+
+use crate::session::RoundContext;
+
+pub mod encryption_of_secret_key_share_round;
+
+/// The message sent by the centralized party to the decentralized party in the key import
+/// protocol: our public key share with a proof of knowledge of its discrete log, and an encryption
+/// of the decentralized party's secret key share alongside its public key share, with a proof that
+/// both hide the same value.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SecretKeyShareEncryptionAndProof<
+    GroupElementValue,
+    RangeProofCommitmentValue,
+    CiphertextValue,
+    DLProof,
+    EncDLProof,
+> {
+    pub(in crate::import) public_key_share: GroupElementValue,
+    pub(in crate::import) knowledge_of_discrete_log_proof: DLProof,
+    pub(in crate::import) decentralized_party_public_key_share: GroupElementValue,
+    pub(in crate::import) encrypted_decentralized_party_secret_key_share: CiphertextValue,
+    pub(in crate::import) range_proof_commitment: RangeProofCommitmentValue,
+    pub(in crate::import) encryption_of_secret_key_share_proof: EncDLProof,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    SecretKeyShareEncryptionAndProof<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    pub fn new(
+        public_key_share: GroupElement,
+        knowledge_of_discrete_log_proof: knowledge_of_discrete_log::Proof<
+            GroupElement::Scalar,
+            GroupElement,
+            ProtocolContext,
+        >,
+        encryption_of_decentralized_party_secret_key_share: enhanced_maurer::StatementSpaceGroupElement<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >,
+        encryption_of_secret_key_share_proof: encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    ) -> Self {
+        let encrypted_decentralized_party_secret_key_share =
+            encryption_of_decentralized_party_secret_key_share
+                .language_statement()
+                .encrypted_discrete_log()
+                .value();
+
+        let decentralized_party_public_key_share =
+            encryption_of_decentralized_party_secret_key_share
+                .language_statement()
+                .base_by_discrete_log()
+                .value();
+
+        let range_proof_commitment = encryption_of_decentralized_party_secret_key_share
+            .range_proof_commitment()
+            .value();
+
+        Self {
+            public_key_share: public_key_share.value(),
+            knowledge_of_discrete_log_proof,
+            decentralized_party_public_key_share,
+            encrypted_decentralized_party_secret_key_share,
+            range_proof_commitment,
+            encryption_of_secret_key_share_proof,
+        }
+    }
+}
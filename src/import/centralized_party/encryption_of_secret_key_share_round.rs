@@ -0,0 +1,344 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use std::ops::Neg;
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use enhanced_maurer::{
+    encryption_of_discrete_log, encryption_of_discrete_log::StatementAccessors as _,
+    language::EnhancedLanguageStatementAccessors as _, EnhanceableLanguage, EnhancedLanguage,
+    EnhancedPublicParameters,
+};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::{knowledge_of_discrete_log, SOUND_PROOFS_REPETITIONS};
+use proof::{range, AggregatableRangeProof};
+
+use crate::{
+    dkg,
+    import::centralized_party::SecretKeyShareEncryptionAndProof,
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) secret_key: GroupElement::Scalar,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Split the imported secret key $x$ into a random secret key share $x_A$ for us and
+    /// $x_B = x - x_A$ for the decentralized party, prove knowledge of the former and the
+    /// encryption of the latter.
+    ///
+    /// The output is the same as that of the DKG, so that presign and sign are unaffected.
+    /// Note, however, that as we knew the entire secret key, we could sign on our own with it:
+    /// importing a key does not give the same guarantees as generating one distributively, and
+    /// the original secret key should be erased once the import completes.
+    pub fn split_encrypt_and_prove_secret_key_share(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        SecretKeyShareEncryptionAndProof<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+        dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    )> {
+        let secret_key_share =
+            GroupElement::Scalar::sample(&self.scalar_group_public_parameters, rng)?;
+        let decentralized_party_secret_key_share = self.secret_key + secret_key_share.neg();
+
+        let language_public_parameters =
+            knowledge_of_discrete_log::PublicParameters::new::<GroupElement::Scalar, GroupElement>(
+                self.scalar_group_public_parameters.clone(),
+                self.group_public_parameters.clone(),
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let (knowledge_of_discrete_log_proof, public_key_share) = knowledge_of_discrete_log::Proof::<
+            GroupElement::Scalar,
+            GroupElement,
+            ProtocolContext,
+        >::prove(
+            &self
+                .protocol_context
+                .round(RoundId::ImportEncryptionOfSecretKeyShare),
+            &language_public_parameters,
+            vec![secret_key_share],
+            rng,
+        )?;
+
+        let public_key_share: GroupElement = public_key_share
+            .first()
+            .ok_or(Error::InternalError)?
+            .clone();
+
+        let encryption_randomness = EncryptionKey::RandomnessSpaceGroupElement::sample(
+            &self
+                .encryption_scheme_public_parameters
+                .as_ref()
+                .randomness_space_public_parameters,
+            rng,
+        )?;
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters.clone(),
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters.clone(),
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = EnhancedPublicParameters::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        let decentralized_party_secret_key_share_value: Uint<SCALAR_LIMBS> =
+            decentralized_party_secret_key_share.into();
+
+        let witness = EnhancedLanguage::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::generate_witness(
+            (
+                EncryptionKey::PlaintextSpaceGroupElement::new(
+                    Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(
+                        &decentralized_party_secret_key_share_value,
+                    )
+                    .into(),
+                    self.encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )?,
+                encryption_randomness,
+            )
+                .into(),
+            &language_public_parameters,
+            rng,
+        )?;
+
+        let (encryption_of_secret_key_share_proof, statements) = encryption_of_discrete_log::Proof::<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >::prove(
+            &self
+                .protocol_context
+                .round(RoundId::ImportEncryptionOfSecretKeyShare),
+            &language_public_parameters,
+            vec![witness],
+            rng,
+        )?;
+
+        let statement = statements.first().ok_or(Error::InternalError)?.clone();
+
+        let encrypted_decentralized_party_secret_key_share = statement
+            .language_statement()
+            .encrypted_discrete_log()
+            .clone();
+
+        let decentralized_party_public_key_share = statement
+            .language_statement()
+            .base_by_discrete_log()
+            .clone();
+
+        let public_key = public_key_share.clone() + &decentralized_party_public_key_share;
+
+        let secret_key_share_encryption_and_proof = SecretKeyShareEncryptionAndProof::new(
+            public_key_share.clone(),
+            knowledge_of_discrete_log_proof,
+            statement,
+            encryption_of_secret_key_share_proof,
+        );
+
+        let output = dkg::centralized_party::Output {
+            secret_key_share: secret_key_share.value(),
+            public_key_share: public_key_share.value(),
+            public_key: public_key.value(),
+            encrypted_decentralized_party_secret_key_share:
+                encrypted_decentralized_party_secret_key_share.value(),
+            decentralized_party_public_key_share: decentralized_party_public_key_share.value(),
+            key_share_updates: 0,
+        };
+
+        Ok((secret_key_share_encryption_and_proof, output))
+    }
+
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        secret_key: group::Value<GroupElement::Scalar>,
+    ) -> crate::Result<Self> {
+        let scalar_group_public_parameters =
+            protocol_public_parameters.scalar_group_public_parameters;
+
+        let secret_key = GroupElement::Scalar::new(secret_key, &scalar_group_public_parameters)?;
+
+        if secret_key == secret_key.neutral() {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters: protocol_public_parameters.group_public_parameters,
+            encryption_scheme_public_parameters: protocol_public_parameters
+                .encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            secret_key,
+        })
+    }
+}
@@ -0,0 +1,4 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+pub mod proof_verification_round;
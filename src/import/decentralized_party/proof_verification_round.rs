@@ -0,0 +1,266 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use commitment::GroupsPublicParametersAccessors as _;
+use crypto_bigint::rand_core::CryptoRngCore;
+use enhanced_maurer::{encryption_of_discrete_log, EnhanceableLanguage};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::{knowledge_of_discrete_log, SOUND_PROOFS_REPETITIONS};
+use proof::{range, range::PublicParametersAccessors, AggregatableRangeProof};
+
+use crate::{
+    dkg,
+    import::centralized_party::SecretKeyShareEncryptionAndProof,
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Verify the centralized party's proof of knowledge of the discrete log of its public key
+    /// share, and its proof that our encrypted secret key share matches our public key share, and
+    /// output the imported key in the same form as the DKG does.
+    ///
+    /// It is up to the caller to check that the resulting public key is the one being imported.
+    pub fn verify_proof(
+        self,
+        secret_key_share_encryption_and_proof: SecretKeyShareEncryptionAndProof<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    > {
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            secret_key_share_encryption_and_proof.encrypted_decentralized_party_secret_key_share,
+            self.encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )?;
+
+        let public_key_share = GroupElement::new(
+            secret_key_share_encryption_and_proof.decentralized_party_public_key_share,
+            &self.group_public_parameters,
+        )?;
+
+        let centralized_party_public_key_share = GroupElement::new(
+            secret_key_share_encryption_and_proof.public_key_share,
+            &self.group_public_parameters,
+        )?;
+
+        let knowledge_of_discrete_log_language_public_parameters =
+            knowledge_of_discrete_log::PublicParameters::new::<GroupElement::Scalar, GroupElement>(
+                self.scalar_group_public_parameters.clone(),
+                self.group_public_parameters.clone(),
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        secret_key_share_encryption_and_proof
+            .knowledge_of_discrete_log_proof
+            .verify(
+                &self
+                    .protocol_context
+                    .round(RoundId::ImportEncryptionOfSecretKeyShare),
+                &knowledge_of_discrete_log_language_public_parameters,
+                vec![centralized_party_public_key_share.clone()],
+            )?;
+
+        let range_proof_commitment = range::CommitmentSchemeCommitmentSpaceGroupElement::<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >::new(
+            secret_key_share_encryption_and_proof.range_proof_commitment,
+            self.range_proof_public_parameters
+                .commitment_scheme_public_parameters()
+                .commitment_space_public_parameters(),
+        )?;
+
+        let statement = (
+            range_proof_commitment,
+            (encrypted_secret_key_share.clone(), public_key_share.clone()).into(),
+        )
+            .into();
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters,
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters,
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = enhanced_maurer::PublicParameters::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        secret_key_share_encryption_and_proof
+            .encryption_of_secret_key_share_proof
+            .verify(
+                &self
+                    .protocol_context
+                    .round(RoundId::ImportEncryptionOfSecretKeyShare),
+                &language_public_parameters,
+                vec![statement],
+                rng,
+            )?;
+
+        let public_key = centralized_party_public_key_share.clone() + &public_key_share;
+
+        if public_key == public_key.neutral() {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(dkg::decentralized_party::Output {
+            public_key_share: public_key_share.value(),
+            public_key: public_key.value(),
+            encrypted_secret_key_share: encrypted_secret_key_share.value(),
+            centralized_party_public_key_share: centralized_party_public_key_share.value(),
+            key_share_updates: 0,
+        })
+    }
+
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+    ) -> Self {
+        Self {
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters: protocol_public_parameters.group_public_parameters,
+            encryption_scheme_public_parameters: protocol_public_parameters
+                .encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+        }
+    }
+}
@@ -3,12 +3,36 @@
 
 use group::{PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
-use proof::{range, AggregatableRangeProof};
-use serde::Serialize;
+use merlin::Transcript;
+use proof::{range, AggregatableRangeProof, TranscriptProtocol};
+use serde::{Deserialize, Serialize};
 
+pub mod backup;
+pub mod cosigner;
+pub mod custody;
+#[cfg(feature = "paillier")]
+pub mod decryption_key_generation;
 pub mod dkg;
+#[cfg(feature = "tokio")]
+pub mod driver;
+#[cfg(feature = "eddsa")]
+pub mod eddsa;
+pub mod import;
 pub mod presign;
+pub mod refresh;
+#[cfg(feature = "paillier")]
+pub mod resharing;
+pub mod round;
+#[cfg(feature = "schnorr")]
+pub mod schnorr;
+#[cfg(any(feature = "schnorr", feature = "eddsa"))]
+mod schnorr_like;
+pub mod session;
 pub mod sign;
+#[cfg(feature = "simulator")]
+pub mod simulator;
+pub mod transfer;
+pub mod weighted_threshold;
 
 /// 2PC-MPC error.
 #[derive(thiserror::Error, Debug)]
@@ -29,18 +53,42 @@ pub enum Error {
     Tiresias(#[from] tiresias::Error),
     #[error("serialization/deserialization error")]
     Serialization(#[from] serde_json::Error),
+    #[error("i/o error")]
+    Io(#[from] std::io::Error),
     #[error("parties {:?} sent mismatching encrypted masks in the first and second proof aggregation protocols in the presign protocol", .0)]
     MismatchingEncrypedMasks(Vec<PartyID>),
     #[error("parties {:?} did not send partial decryption proofs in the signing identifiable abort protocol", .0)]
     UnresponsiveParties(Vec<PartyID>),
+    #[error("parties {:?} sent malformed messages", .0)]
+    MaliciousParties(Vec<PartyID>),
+    #[error("the centralized party did not send its message")]
+    UnresponsiveCentralizedParty,
+    #[error("parties {:?} sent invalid dealings in the decryption key share resharing protocol", .0)]
+    MaliciousDealers(Vec<PartyID>),
+    #[error("devices {:?} sent invalid shares of the centralized party's secrets", .0)]
+    MaliciousDevices(Vec<PartyID>),
     #[error("not enough parties to initiate the session")]
     ThresholdNotReached,
     #[error("the other party maliciously attempted to bypass the commitment round by sending decommitment which does not match its commitment")]
     WrongDecommitment,
     #[error("the designated decrypting party behaved maliciously by not sending the honest decrypted values")]
     MaliciousDesignatedDecryptingParty(PartyID),
+    #[error("the same presign was used to sign more than one message")]
+    ReusedPresign,
+    #[error("the presign is not in the presign pool")]
+    UnknownPresign,
+    #[error("the presign pool is already open, e.g. by another process")]
+    PresignPoolInUse,
+    #[error("the presign was generated for a different encryption of the decentralized party's secret key share, e.g. prior to a key refresh")]
+    StalePresign,
+    #[error(
+        "the encrypted secret key share was homomorphically updated the maximal number of times"
+    )]
+    ExhaustedKeyShareUpdates,
     #[error("signature failed to verify")]
     SignatureVerification,
+    #[error("invalid signature encoding")]
+    InvalidSignatureEncoding,
     #[error("invalid public parameters")]
     InvalidPublicParameters,
     #[error("invalid parameters")]
@@ -55,7 +103,15 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub const CENTRALIZED_PARTY_ID: PartyID = 1;
 pub const DECENTRALIZED_PARTY_ID: PartyID = 2;
 
-#[derive(Serialize, Clone, PartialEq)]
+/// The public parameters of the 2PC-MPC protocol, shared by all parties.
+///
+/// Parameters that are deserialized from an untrusted source (e.g. a configuration file) should
+/// be validated before use, and their [`ProtocolPublicParameters::fingerprint`] is bound into the
+/// [`session::SessionContext`] so that parties holding mismatching parameters fail early.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+// All public parameters types are required to be (de)serializable by their respective traits,
+// so we drop the bounds `serde` would otherwise infer on the group elements themselves.
+#[serde(bound = "")]
 pub struct ProtocolPublicParameters<
     const SCALAR_LIMBS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
@@ -89,6 +145,79 @@ pub struct ProtocolPublicParameters<
     >,
 }
 
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >
+    ProtocolPublicParameters<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        UnboundedDComEvalWitness,
+    >
+{
+    /// A stable fingerprint of these public parameters, computed by absorbing each of them into a
+    /// transcript in a fixed order. Two parties hold the same parameters if and only if (up to
+    /// collisions) they compute the same fingerprint.
+    pub fn fingerprint(&self) -> Result<[u8; 32]> {
+        let mut transcript = Transcript::new(b"2PC-MPC protocol public parameters");
+
+        transcript.serialize_to_transcript_as_json(
+            b"scalar group public parameters",
+            &self.scalar_group_public_parameters,
+        )?;
+        transcript.serialize_to_transcript_as_json(
+            b"group public parameters",
+            &self.group_public_parameters,
+        )?;
+        transcript.serialize_to_transcript_as_json(
+            b"encryption scheme public parameters",
+            &self.encryption_scheme_public_parameters,
+        )?;
+        transcript.serialize_to_transcript_as_json(
+            b"unbounded encdl witness public parameters",
+            &self.unbounded_encdl_witness_public_parameters,
+        )?;
+        transcript.serialize_to_transcript_as_json(
+            b"unbounded encdh witness public parameters",
+            &self.unbounded_encdh_witness_public_parameters,
+        )?;
+        transcript.serialize_to_transcript_as_json(
+            b"unbounded dcom eval witness public parameters",
+            &self.unbounded_dcom_eval_witness_public_parameters,
+        )?;
+        transcript.serialize_to_transcript_as_json(
+            b"range proof enc dl public parameters",
+            &self.range_proof_enc_dl_public_parameters,
+        )?;
+        transcript.serialize_to_transcript_as_json(
+            b"range proof dcom eval public parameters",
+            &self.range_proof_dcom_eval_public_parameters,
+        )?;
+
+        let mut fingerprint = [0u8; 32];
+        transcript.challenge_bytes(b"fingerprint", &mut fingerprint);
+
+        Ok(fingerprint)
+    }
+}
+
 #[cfg(feature = "paillier")]
 pub mod paillier {
     use group::self_product;
@@ -249,6 +378,54 @@ pub mod secp256k1 {
                         unbounded_dcom_eval_witness_public_parameters,
                     }
                 }
+
+                /// Derive the public parameters from those of the decentralized party's threshold
+                /// decryption key, as output by a distributed key generation (see
+                /// [`crate::decryption_key_generation`]).
+                pub fn from_decryption_key_share_public_parameters(
+                    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+                ) -> crate::Result<Self> {
+                    let encryption_scheme_public_parameters =
+                        &decryption_key_share_public_parameters.encryption_scheme_public_parameters;
+
+                    tiresias::encryption_key::PublicParameters::new(
+                        encryption_scheme_public_parameters.associated_bi_prime,
+                    )
+                    .map_err(|_| crate::Error::InvalidPublicParameters)?;
+
+                    let protocol_public_parameters =
+                        Self::new(encryption_scheme_public_parameters.associated_bi_prime);
+
+                    if protocol_public_parameters.encryption_scheme_public_parameters
+                        != *encryption_scheme_public_parameters
+                    {
+                        return Err(crate::Error::InvalidPublicParameters);
+                    }
+
+                    Ok(protocol_public_parameters)
+                }
+
+                /// Validate that these public parameters are mutually consistent, i.e. that they
+                /// are exactly the ones [`Self::new`] derives from their Paillier modulus: the
+                /// group and range proof parameters are the canonical ones, and the unbounded
+                /// witness parameters match the Paillier randomness space.
+                ///
+                /// This should be called on any parameters that were not constructed locally.
+                pub fn validate(&self) -> crate::Result<()> {
+                    let paillier_associated_bi_prime =
+                        self.encryption_scheme_public_parameters.associated_bi_prime;
+
+                    // Make sure the modulus is valid before deriving the rest of the parameters
+                    // from it.
+                    tiresias::encryption_key::PublicParameters::new(paillier_associated_bi_prime)
+                        .map_err(|_| crate::Error::InvalidPublicParameters)?;
+
+                    if *self != Self::new(paillier_associated_bi_prime) {
+                        return Err(crate::Error::InvalidPublicParameters);
+                    }
+
+                    Ok(())
+                }
             }
 
             // TODO: Implement a similar macro like https://github.com/rozbb/rust-hpke/blob/main/src/kem/dhkem.rs (only use::*).
@@ -610,6 +787,250 @@ pub mod secp256k1 {
                     EncDLProof<ProtocolContext>,
                 >;
 
+            pub type BackupEncryptionRoundParty<ProtocolContext> =
+                crate::backup::centralized_party::encryption_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type SecretKeyShareBackup<ProtocolContext> =
+                crate::backup::centralized_party::SecretKeyShareBackup<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type BackupVerificationRoundParty<ProtocolContext> =
+                crate::backup::decentralized_party::verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type ImportEncryptionOfSecretKeyShareRoundParty<ProtocolContext> =
+                crate::import::centralized_party::encryption_of_secret_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type ImportSecretKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::import::centralized_party::SecretKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    SchnorrProof<ProtocolContext>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type ImportProofVerificationRoundParty<ProtocolContext> =
+                crate::import::decentralized_party::proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshCommitmentRoundParty<ProtocolContext> =
+                crate::refresh::centralized_party::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshDecommitmentRoundParty<ProtocolContext> =
+                crate::refresh::centralized_party::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshDeltaDecommitmentAndProof<ProtocolContext> =
+                crate::refresh::centralized_party::DeltaDecommitmentAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type RefreshKeyShareRoundParty<ProtocolContext> =
+                crate::refresh::decentralized_party::key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshKeyShare = crate::refresh::decentralized_party::KeyShare<
+                group::Value<GroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type RefreshDecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::refresh::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferSecretKeyShareEncryptionRoundParty =
+                crate::transfer::previous_owner::secret_key_share_encryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >;
+
+            pub type TransferSecretKeyShareEncryption =
+                crate::transfer::previous_owner::SecretKeyShareEncryption<
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type TransferCommitmentRoundParty<ProtocolContext> =
+                crate::transfer::new_owner::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferDecommitmentRoundParty<ProtocolContext> =
+                crate::transfer::new_owner::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferPublicKeyShareDecommitmentAndProof<ProtocolContext> =
+                crate::transfer::new_owner::PublicKeyShareDecommitmentAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type TransferDecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::transfer::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerPublicKeyShareRoundParty<ProtocolContext> =
+                crate::cosigner::cosigner_party::public_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerPublicKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::cosigner::cosigner_party::PublicKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type CosignerOutput = crate::cosigner::cosigner_party::Output<
+                group::Value<GroupElement>,
+                group::Value<Scalar>,
+                group::Value<crate::paillier::RandomnessSpaceGroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type CosignerEncryptedMaskedKeyShareRoundParty<ProtocolContext> =
+                crate::cosigner::cosigner_party::encrypted_masked_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerEncryptedMaskedKeyShareAndProof<ProtocolContext> =
+                crate::cosigner::cosigner_party::EncryptedMaskedKeyShareAndProof<
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDHProof<
+                        crate::cosigner::ApprovalContext<ProtocolContext, group::Value<Scalar>>,
+                    >,
+                >;
+
             pub type PresignCommitmentRoundParty<ProtocolContext> =
                 crate::presign::centralized_party::commitment_round::Party<
                     SCALAR_LIMBS,
@@ -638,6 +1059,12 @@ pub mod secp256k1 {
                     ProtocolContext,
                 >;
 
+            pub type PresignProofVerificationRoundState =
+                crate::presign::centralized_party::proof_verification_round::State<
+                    group::Value<Scalar>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
             pub type SignatureNonceSharesCommitmentsAndBatchedProof<ProtocolContext> =
             crate::presign::centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof<
                 SCALAR_LIMBS,
@@ -702,6 +1129,12 @@ pub mod secp256k1 {
                     ProtocolContext,
                 >;
 
+            pub type EncryptedMaskedNoncesRoundState =
+                crate::presign::decentralized_party::encrypted_masked_nonces_round::State<
+                    group::Value<crate::paillier::PlaintextSpaceGroupElement>,
+                    group::Value<crate::paillier::RandomnessSpaceGroupElement>,
+                >;
+
             pub type DecentralizedPartyPresign = crate::presign::decentralized_party::Presign<
                 group::Value<GroupElement>,
                 group::Value<CiphertextSpaceGroupElement>,
@@ -754,6 +1187,12 @@ pub mod secp256k1 {
                     ProtocolContext,
                 >;
 
+            pub type SignaturePartialDecryptionState =
+                crate::sign::decentralized_party::signature_partial_decryption_round::State<
+                    group::Value<GroupElement>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
             pub type SignatureThresholdDecryptionParty =
                 crate::sign::decentralized_party::signature_threshold_decryption_round::Party<
                     SCALAR_LIMBS,
@@ -763,19 +1202,2066 @@ pub mod secp256k1 {
                     DecryptionKeyShare,
                 >;
 
-            pub type SignaturePartialDecryptionProofParty =
-            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_proof_round::Party<
-                PLAINTEXT_SPACE_SCALAR_LIMBS,
-                EncryptionKey,
-                DecryptionKeyShare,
-            >;
+            pub type SignatureThresholdDecryptionState =
+                crate::sign::decentralized_party::signature_threshold_decryption_round::State<
+                    group::Value<Scalar>,
+                    group::Value<GroupElement>,
+                >;
 
-            pub type SignaturePartialDecryptionProofVerificationParty =
-            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_verification_round::Party<
-                PLAINTEXT_SPACE_SCALAR_LIMBS,
-                EncryptionKey,
-                DecryptionKeyShare,
-            >;
+            pub type BatchedPublicNonceEncryptedPartialSignatureAndProof<ProtocolContext> =
+                crate::sign::centralized_party::BatchedPublicNonceEncryptedPartialSignatureAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<NUM_RANGE_CLAIMS>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    ComDLProof<ProtocolContext>,
+                    ComRatioProof<ProtocolContext>,
+                    DComEvalProof<ProtocolContext>,
+                >;
+
+            pub type BatchedSignatureHomomorphicEvaluationParty<ProtocolContext> =
+                crate::sign::centralized_party::batched_signature_homomorphic_evaluation_round::Party<
+                    SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    NUM_RANGE_CLAIMS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedDComEvalWitness,
+                    ProtocolContext,
+                >;
+
+            pub type BatchedSignaturePartialDecryptionParty<ProtocolContext> =
+                crate::sign::decentralized_party::batched_signature_partial_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    NUM_RANGE_CLAIMS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    DecryptionKeyShare,
+                    RangeProof,
+                    UnboundedDComEvalWitness,
+                    ProtocolContext,
+                >;
+
+            pub type BatchedSignatureThresholdDecryptionParty =
+                crate::sign::decentralized_party::batched_signature_threshold_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    DecryptionKeyShare,
+                >;
+
+            pub type SignaturePartialDecryptionProofParty =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_proof_round::Party<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+                DecryptionKeyShare,
+            >;
+
+            pub type SignaturePartialDecryptionProofState =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_proof_round::State<
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type SignaturePartialDecryptionProofVerificationParty =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_verification_round::Party<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+                DecryptionKeyShare,
+            >;
+
+            pub type SignaturePartialDecryptionProofVerificationState =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_verification_round::State<
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+        }
+    }
+
+    #[cfg(feature = "bulletproofs")]
+    pub mod bulletproofs {
+        use crypto_bigint::{Uint, U64};
+        use group::StatisticalSecuritySizedNumber;
+        use proof::range::bulletproofs::RANGE_CLAIM_BITS;
+
+        use super::SCALAR_LIMBS;
+        use crate::sign::DIMENSION;
+
+        pub const RANGE_CLAIMS_PER_SCALAR: usize = Uint::<SCALAR_LIMBS>::BITS / RANGE_CLAIM_BITS;
+        pub const MASK_LIMBS: usize =
+            SCALAR_LIMBS + StatisticalSecuritySizedNumber::LIMBS + U64::LIMBS;
+
+        pub const RANGE_CLAIMS_PER_MASK: usize = Uint::<MASK_LIMBS>::BITS / RANGE_CLAIM_BITS;
+
+        pub const NUM_RANGE_CLAIMS: usize =
+            DIMENSION * RANGE_CLAIMS_PER_SCALAR + RANGE_CLAIMS_PER_MASK;
+    }
+}
+
+#[cfg(feature = "secp256r1")]
+pub mod secp256r1 {
+    use group::secp256r1;
+
+    pub const SCALAR_LIMBS: usize = secp256r1::SCALAR_LIMBS;
+    pub type GroupElement = secp256r1::GroupElement;
+    pub type Scalar = secp256r1::Scalar;
+
+    #[cfg(feature = "paillier")]
+    pub mod paillier {
+        use group::{direct_product, self_product};
+
+        use super::Scalar;
+        use crate::sign::DIMENSION;
+
+        type UnboundedDComEvalWitness = direct_product::GroupElement<
+            self_product::GroupElement<DIMENSION, Scalar>,
+            tiresias::RandomnessSpaceGroupElement,
+        >;
+
+        #[cfg(feature = "bulletproofs")]
+        pub mod bulletproofs {
+            use bulletproofs::*;
+            use commitment::Pedersen;
+            use enhanced_maurer::{
+                committed_linear_evaluation, encryption_of_discrete_log, encryption_of_tuple,
+            };
+            use group::{direct_product, secp256r1::GroupElement, self_product};
+            use homomorphic_encryption::GroupsPublicParametersAccessors;
+            use maurer::{
+                committment_of_discrete_log, discrete_log_ratio_of_committed_values,
+                knowledge_of_decommitment, knowledge_of_discrete_log,
+            };
+            use tiresias::LargeBiPrimeSizedNumber;
+
+            use super::super::*;
+            use crate::{
+                bulletproofs::*,
+                paillier::{
+                    CiphertextSpaceGroupElement, DecryptionKeyShare, EncryptionKey,
+                    UnboundedEncDHWitness, UnboundedEncDLWitness, PLAINTEXT_SPACE_SCALAR_LIMBS,
+                },
+                secp256r1::paillier::UnboundedDComEvalWitness,
+                sign::DIMENSION,
+            };
+
+            pub type ProtocolPublicParameters = crate::ProtocolPublicParameters<
+                SCALAR_LIMBS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                NUM_RANGE_CLAIMS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                UnboundedEncDHWitness,
+                UnboundedDComEvalWitness,
+            >;
+
+            impl ProtocolPublicParameters {
+                pub fn new(paillier_associated_bi_prime: LargeBiPrimeSizedNumber) -> Self {
+                    let scalar_group_public_parameters =
+                        secp256r1::scalar::PublicParameters::default();
+
+                    let group_public_parameters =
+                        secp256r1::group_element::PublicParameters::default();
+
+                    let range_proof_enc_dl_public_parameters =
+                        proof::range::bulletproofs::PublicParameters::<RANGE_CLAIMS_PER_SCALAR>::default();
+
+                    let range_proof_dcom_eval_public_parameters =
+                        proof::range::bulletproofs::PublicParameters::<NUM_RANGE_CLAIMS>::default();
+
+                    let encryption_scheme_public_parameters =
+                        tiresias::encryption_key::PublicParameters::new(
+                            paillier_associated_bi_prime,
+                        )
+                        .unwrap();
+
+                    let unbounded_encdl_witness_public_parameters =
+                        encryption_scheme_public_parameters
+                            .randomness_space_public_parameters()
+                            .clone();
+
+                    let unbounded_encdh_witness_public_parameters =
+                        self_product::PublicParameters::new(
+                            encryption_scheme_public_parameters
+                                .randomness_space_public_parameters()
+                                .clone(),
+                        );
+
+                    let unbounded_dcom_eval_witness_public_parameters =
+                        direct_product::PublicParameters(
+                            self_product::PublicParameters::new(
+                                scalar_group_public_parameters.clone(),
+                            ),
+                            encryption_scheme_public_parameters
+                                .randomness_space_public_parameters()
+                                .clone(),
+                        );
+
+                    Self {
+                        scalar_group_public_parameters,
+                        group_public_parameters,
+                        encryption_scheme_public_parameters,
+                        range_proof_enc_dl_public_parameters,
+                        range_proof_dcom_eval_public_parameters,
+                        unbounded_encdl_witness_public_parameters,
+                        unbounded_encdh_witness_public_parameters,
+                        unbounded_dcom_eval_witness_public_parameters,
+                    }
+                }
+
+                /// Derive the public parameters from those of the decentralized party's threshold
+                /// decryption key, as output by a distributed key generation (see
+                /// [`crate::decryption_key_generation`]).
+                pub fn from_decryption_key_share_public_parameters(
+                    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+                ) -> crate::Result<Self> {
+                    let encryption_scheme_public_parameters =
+                        &decryption_key_share_public_parameters.encryption_scheme_public_parameters;
+
+                    tiresias::encryption_key::PublicParameters::new(
+                        encryption_scheme_public_parameters.associated_bi_prime,
+                    )
+                    .map_err(|_| crate::Error::InvalidPublicParameters)?;
+
+                    let protocol_public_parameters =
+                        Self::new(encryption_scheme_public_parameters.associated_bi_prime);
+
+                    if protocol_public_parameters.encryption_scheme_public_parameters
+                        != *encryption_scheme_public_parameters
+                    {
+                        return Err(crate::Error::InvalidPublicParameters);
+                    }
+
+                    Ok(protocol_public_parameters)
+                }
+
+                /// Validate that these public parameters are mutually consistent, i.e. that they
+                /// are exactly the ones [`Self::new`] derives from their Paillier modulus: the
+                /// group and range proof parameters are the canonical ones, and the unbounded
+                /// witness parameters match the Paillier randomness space.
+                ///
+                /// This should be called on any parameters that were not constructed locally.
+                pub fn validate(&self) -> crate::Result<()> {
+                    let paillier_associated_bi_prime =
+                        self.encryption_scheme_public_parameters.associated_bi_prime;
+
+                    // Make sure the modulus is valid before deriving the rest of the parameters
+                    // from it.
+                    tiresias::encryption_key::PublicParameters::new(paillier_associated_bi_prime)
+                        .map_err(|_| crate::Error::InvalidPublicParameters)?;
+
+                    if *self != Self::new(paillier_associated_bi_prime) {
+                        return Err(crate::Error::InvalidPublicParameters);
+                    }
+
+                    Ok(())
+                }
+            }
+
+            pub type EncDLCommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::commitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLDecommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::decommitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLProofShareRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_share_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLProofAggregationRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_aggregation_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLProofAggregationOutput<ProtocolContext> =
+                enhanced_maurer::aggregation::Output<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+            pub type EncDLProof<ProtocolContext> = enhanced_maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedEncDLWitness,
+                encryption_of_discrete_log::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type EncDLCommitment<ProtocolContext> =
+                <EncDLCommitmentRoundParty<ProtocolContext> as proof::aggregation::CommitmentRoundParty<
+                    EncDLProofAggregationOutput<ProtocolContext>,
+                >>::Commitment;
+
+            pub type EncDLDecommitment<ProtocolContext> = <EncDLDecommitmentRoundParty<
+                ProtocolContext,
+            > as proof::aggregation::DecommitmentRoundParty<
+                EncDLProofAggregationOutput<ProtocolContext>,
+            >>::Decommitment;
+
+            pub type EncDLProofShare<ProtocolContext> =
+            <EncDLProofShareRoundParty<ProtocolContext> as proof::aggregation::ProofShareRoundParty<
+                EncDLProofAggregationOutput<ProtocolContext>,
+            >>::ProofShare;
+
+            pub type EncDHCommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::commitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHDecommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::decommitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProofShareRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_share_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProofAggregationRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_aggregation_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProofAggregationOutput<ProtocolContext> =
+                enhanced_maurer::aggregation::Output<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProof<ProtocolContext> = enhanced_maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedEncDHWitness,
+                encryption_of_tuple::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type DComEvalProof<ProtocolContext> = enhanced_maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                NUM_RANGE_CLAIMS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                committed_linear_evaluation::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    DIMENSION,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type EncDHCommitment<ProtocolContext> =
+            <EncDHCommitmentRoundParty<ProtocolContext> as proof::aggregation::CommitmentRoundParty<
+                EncDHProofAggregationOutput<ProtocolContext>,
+            >>::Commitment;
+
+            pub type EncDHDecommitment<ProtocolContext> = <EncDHDecommitmentRoundParty<
+                ProtocolContext,
+            > as proof::aggregation::DecommitmentRoundParty<
+                EncDHProofAggregationOutput<ProtocolContext>,
+            >>::Decommitment;
+
+            pub type EncDHProofShare<ProtocolContext> =
+            <EncDHProofShareRoundParty<ProtocolContext> as proof::aggregation::ProofShareRoundParty<
+                EncDHProofAggregationOutput<ProtocolContext>,
+            >>::ProofShare;
+
+            pub type SchnorrProof<ProtocolContext> =
+                knowledge_of_discrete_log::Proof<Scalar, GroupElement, ProtocolContext>;
+
+            pub type ComDLProof<ProtocolContext> = maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type ComRatioProof<ProtocolContext> = maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type DComProof<ProtocolContext> = maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                knowledge_of_decommitment::Language<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    SCALAR_LIMBS,
+                    Pedersen<1, SCALAR_LIMBS, Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type DKGCommitmentRoundParty<ProtocolContext> =
+                crate::dkg::centralized_party::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DKGDecommitmentRoundParty<ProtocolContext> =
+                crate::dkg::centralized_party::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DKGDecommitmentRoundState<ProtocolContext> =
+                crate::dkg::centralized_party::decommitment_round::State<
+                    group::Value<GroupElement>,
+                    group::Value<Scalar>,
+                    SchnorrProof<ProtocolContext>,
+                >;
+
+            pub type PublicKeyShareDecommitmentAndProof<ProtocolContext> =
+            crate::dkg::centralized_party::decommitment_round::PublicKeyShareDecommitmentAndProof<
+                group::Value<GroupElement>,
+                SchnorrProof<ProtocolContext>
+            >;
+
+            pub type DKGCentralizedPartyOutput = crate::dkg::centralized_party::Output<
+                group::Value<GroupElement>,
+                group::Value<Scalar>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type EncryptionOfSecretKeyShareRoundParty<ProtocolContext> =
+                crate::dkg::decentralized_party::encryption_of_secret_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::dkg::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DKGDecentralizedPartyOutput =
+                crate::dkg::decentralized_party::decommitment_proof_verification_round::Output<
+                    group::Value<GroupElement>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type SecretKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::dkg::decentralized_party::SecretKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type BackupEncryptionRoundParty<ProtocolContext> =
+                crate::backup::centralized_party::encryption_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type SecretKeyShareBackup<ProtocolContext> =
+                crate::backup::centralized_party::SecretKeyShareBackup<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type BackupVerificationRoundParty<ProtocolContext> =
+                crate::backup::decentralized_party::verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type ImportEncryptionOfSecretKeyShareRoundParty<ProtocolContext> =
+                crate::import::centralized_party::encryption_of_secret_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type ImportSecretKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::import::centralized_party::SecretKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    SchnorrProof<ProtocolContext>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type ImportProofVerificationRoundParty<ProtocolContext> =
+                crate::import::decentralized_party::proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshCommitmentRoundParty<ProtocolContext> =
+                crate::refresh::centralized_party::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshDecommitmentRoundParty<ProtocolContext> =
+                crate::refresh::centralized_party::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshDeltaDecommitmentAndProof<ProtocolContext> =
+                crate::refresh::centralized_party::DeltaDecommitmentAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type RefreshKeyShareRoundParty<ProtocolContext> =
+                crate::refresh::decentralized_party::key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshKeyShare = crate::refresh::decentralized_party::KeyShare<
+                group::Value<GroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type RefreshDecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::refresh::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferSecretKeyShareEncryptionRoundParty =
+                crate::transfer::previous_owner::secret_key_share_encryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >;
+
+            pub type TransferSecretKeyShareEncryption =
+                crate::transfer::previous_owner::SecretKeyShareEncryption<
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type TransferCommitmentRoundParty<ProtocolContext> =
+                crate::transfer::new_owner::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferDecommitmentRoundParty<ProtocolContext> =
+                crate::transfer::new_owner::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferPublicKeyShareDecommitmentAndProof<ProtocolContext> =
+                crate::transfer::new_owner::PublicKeyShareDecommitmentAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type TransferDecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::transfer::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerPublicKeyShareRoundParty<ProtocolContext> =
+                crate::cosigner::cosigner_party::public_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerPublicKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::cosigner::cosigner_party::PublicKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type CosignerOutput = crate::cosigner::cosigner_party::Output<
+                group::Value<GroupElement>,
+                group::Value<Scalar>,
+                group::Value<crate::paillier::RandomnessSpaceGroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type CosignerEncryptedMaskedKeyShareRoundParty<ProtocolContext> =
+                crate::cosigner::cosigner_party::encrypted_masked_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerEncryptedMaskedKeyShareAndProof<ProtocolContext> =
+                crate::cosigner::cosigner_party::EncryptedMaskedKeyShareAndProof<
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDHProof<
+                        crate::cosigner::ApprovalContext<ProtocolContext, group::Value<Scalar>>,
+                    >,
+                >;
+
+            pub type PresignCommitmentRoundParty<ProtocolContext> =
+                crate::presign::centralized_party::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type PresignProofVerificationRoundParty<ProtocolContext> =
+                crate::presign::centralized_party::proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type PresignProofVerificationRoundState =
+                crate::presign::centralized_party::proof_verification_round::State<
+                    group::Value<Scalar>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type SignatureNonceSharesCommitmentsAndBatchedProof<ProtocolContext> =
+            crate::presign::centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof<
+                SCALAR_LIMBS,
+                group::Value<GroupElement>,
+                DComProof<ProtocolContext>,
+            >;
+
+            pub type CentralizedPartyPresign = crate::presign::centralized_party::Presign<
+                group::Value<GroupElement>,
+                group::Value<Scalar>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type PresignDecentralizedPartyOutput<ProtocolContext> =
+                crate::presign::decentralized_party::Output<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDHProof<ProtocolContext>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type EncryptedMaskAndMaskedNonceShare =
+                encryption_of_tuple::StatementSpaceGroupElement<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    EncryptionKey,
+                >;
+
+            pub type EncryptedNonceShareAndPublicShare =
+                encryption_of_discrete_log::StatementSpaceGroupElement<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >;
+
+            pub type EncryptedMaskedKeyShareRoundParty<ProtocolContext> =
+                crate::presign::decentralized_party::encrypted_masked_key_share_and_public_nonce_shares_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type EncryptedMaskedNoncesRoundParty<ProtocolContext> =
+                crate::presign::decentralized_party::encrypted_masked_nonces_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type EncryptedMaskedNoncesRoundState =
+                crate::presign::decentralized_party::encrypted_masked_nonces_round::State<
+                    group::Value<crate::paillier::PlaintextSpaceGroupElement>,
+                    group::Value<crate::paillier::RandomnessSpaceGroupElement>,
+                >;
+
+            pub type DecentralizedPartyPresign = crate::presign::decentralized_party::Presign<
+                group::Value<GroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type PublicNonceEncryptedPartialSignatureAndProof<ProtocolContext> =
+                crate::sign::centralized_party::PublicNonceEncryptedPartialSignatureAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<NUM_RANGE_CLAIMS>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    ComDLProof<ProtocolContext>,
+                    ComRatioProof<ProtocolContext>,
+                    DComEvalProof<ProtocolContext>,
+                >;
+
+            pub type SignatureHomomorphicEvaluationParty<ProtocolContext> =
+                crate::sign::centralized_party::signature_homomorphic_evaluation_round::Party<
+                    SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    NUM_RANGE_CLAIMS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedDComEvalWitness,
+                    ProtocolContext,
+                >;
+
+            pub type SignatureVerificationParty =
+                crate::sign::centralized_party::signature_verification_round::Party<
+                    SCALAR_LIMBS,
+                    GroupElement,
+                >;
+
+            pub type SignaturePartialDecryptionParty<ProtocolContext> =
+                crate::sign::decentralized_party::signature_partial_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    NUM_RANGE_CLAIMS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    DecryptionKeyShare,
+                    RangeProof,
+                    UnboundedDComEvalWitness,
+                    ProtocolContext,
+                >;
+
+            pub type SignaturePartialDecryptionState =
+                crate::sign::decentralized_party::signature_partial_decryption_round::State<
+                    group::Value<GroupElement>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type SignatureThresholdDecryptionParty =
+                crate::sign::decentralized_party::signature_threshold_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    DecryptionKeyShare,
+                >;
+
+            pub type SignatureThresholdDecryptionState =
+                crate::sign::decentralized_party::signature_threshold_decryption_round::State<
+                    group::Value<Scalar>,
+                    group::Value<GroupElement>,
+                >;
+
+            pub type BatchedPublicNonceEncryptedPartialSignatureAndProof<ProtocolContext> =
+                crate::sign::centralized_party::BatchedPublicNonceEncryptedPartialSignatureAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<NUM_RANGE_CLAIMS>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    ComDLProof<ProtocolContext>,
+                    ComRatioProof<ProtocolContext>,
+                    DComEvalProof<ProtocolContext>,
+                >;
+
+            pub type BatchedSignatureHomomorphicEvaluationParty<ProtocolContext> =
+                crate::sign::centralized_party::batched_signature_homomorphic_evaluation_round::Party<
+                    SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    NUM_RANGE_CLAIMS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedDComEvalWitness,
+                    ProtocolContext,
+                >;
+
+            pub type BatchedSignaturePartialDecryptionParty<ProtocolContext> =
+                crate::sign::decentralized_party::batched_signature_partial_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    NUM_RANGE_CLAIMS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    DecryptionKeyShare,
+                    RangeProof,
+                    UnboundedDComEvalWitness,
+                    ProtocolContext,
+                >;
+
+            pub type BatchedSignatureThresholdDecryptionParty =
+                crate::sign::decentralized_party::batched_signature_threshold_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    DecryptionKeyShare,
+                >;
+
+            pub type SignaturePartialDecryptionProofParty =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_proof_round::Party<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+                DecryptionKeyShare,
+            >;
+
+            pub type SignaturePartialDecryptionProofState =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_proof_round::State<
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type SignaturePartialDecryptionProofVerificationParty =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_verification_round::Party<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+                DecryptionKeyShare,
+            >;
+
+            pub type SignaturePartialDecryptionProofVerificationState =
+            crate::sign::decentralized_party::identifiable_abort::signature_partial_decryption_verification_round::State<
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+        }
+    }
+
+    #[cfg(feature = "bulletproofs")]
+    pub mod bulletproofs {
+        use crypto_bigint::{Uint, U64};
+        use group::StatisticalSecuritySizedNumber;
+        use proof::range::bulletproofs::RANGE_CLAIM_BITS;
+
+        use super::SCALAR_LIMBS;
+        use crate::sign::DIMENSION;
+
+        pub const RANGE_CLAIMS_PER_SCALAR: usize = Uint::<SCALAR_LIMBS>::BITS / RANGE_CLAIM_BITS;
+        pub const MASK_LIMBS: usize =
+            SCALAR_LIMBS + StatisticalSecuritySizedNumber::LIMBS + U64::LIMBS;
+
+        pub const RANGE_CLAIMS_PER_MASK: usize = Uint::<MASK_LIMBS>::BITS / RANGE_CLAIM_BITS;
+
+        pub const NUM_RANGE_CLAIMS: usize =
+            DIMENSION * RANGE_CLAIMS_PER_SCALAR + RANGE_CLAIMS_PER_MASK;
+    }
+}
+
+#[cfg(feature = "curve25519")]
+pub mod curve25519 {
+    use group::curve25519;
+
+    pub const SCALAR_LIMBS: usize = curve25519::SCALAR_LIMBS;
+    pub type GroupElement = curve25519::GroupElement;
+    pub type Scalar = curve25519::Scalar;
+
+    #[cfg(feature = "paillier")]
+    pub mod paillier {
+        use group::{direct_product, self_product};
+
+        use super::Scalar;
+        use crate::sign::DIMENSION;
+
+        type UnboundedDComEvalWitness = direct_product::GroupElement<
+            self_product::GroupElement<DIMENSION, Scalar>,
+            tiresias::RandomnessSpaceGroupElement,
+        >;
+
+        #[cfg(feature = "bulletproofs")]
+        pub mod bulletproofs {
+            use bulletproofs::*;
+            use commitment::Pedersen;
+            use enhanced_maurer::{
+                committed_linear_evaluation, encryption_of_discrete_log, encryption_of_tuple,
+            };
+            use group::{curve25519::GroupElement, direct_product, self_product};
+            use homomorphic_encryption::GroupsPublicParametersAccessors;
+            use maurer::{
+                committment_of_discrete_log, discrete_log_ratio_of_committed_values,
+                knowledge_of_decommitment, knowledge_of_discrete_log,
+            };
+            use tiresias::LargeBiPrimeSizedNumber;
+
+            use super::super::*;
+            use crate::{
+                bulletproofs::*,
+                curve25519::paillier::UnboundedDComEvalWitness,
+                paillier::{
+                    CiphertextSpaceGroupElement, EncryptionKey, UnboundedEncDHWitness,
+                    UnboundedEncDLWitness, PLAINTEXT_SPACE_SCALAR_LIMBS,
+                },
+                sign::DIMENSION,
+            };
+
+            pub type ProtocolPublicParameters = crate::ProtocolPublicParameters<
+                SCALAR_LIMBS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                NUM_RANGE_CLAIMS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                UnboundedEncDHWitness,
+                UnboundedDComEvalWitness,
+            >;
+
+            impl ProtocolPublicParameters {
+                pub fn new(paillier_associated_bi_prime: LargeBiPrimeSizedNumber) -> Self {
+                    let scalar_group_public_parameters =
+                        curve25519::scalar::PublicParameters::default();
+
+                    let group_public_parameters =
+                        curve25519::group_element::PublicParameters::default();
+
+                    let range_proof_enc_dl_public_parameters =
+                        proof::range::bulletproofs::PublicParameters::<RANGE_CLAIMS_PER_SCALAR>::default();
+
+                    let range_proof_dcom_eval_public_parameters =
+                        proof::range::bulletproofs::PublicParameters::<NUM_RANGE_CLAIMS>::default();
+
+                    let encryption_scheme_public_parameters =
+                        tiresias::encryption_key::PublicParameters::new(
+                            paillier_associated_bi_prime,
+                        )
+                        .unwrap();
+
+                    let unbounded_encdl_witness_public_parameters =
+                        encryption_scheme_public_parameters
+                            .randomness_space_public_parameters()
+                            .clone();
+
+                    let unbounded_encdh_witness_public_parameters =
+                        self_product::PublicParameters::new(
+                            encryption_scheme_public_parameters
+                                .randomness_space_public_parameters()
+                                .clone(),
+                        );
+
+                    let unbounded_dcom_eval_witness_public_parameters =
+                        direct_product::PublicParameters(
+                            self_product::PublicParameters::new(
+                                scalar_group_public_parameters.clone(),
+                            ),
+                            encryption_scheme_public_parameters
+                                .randomness_space_public_parameters()
+                                .clone(),
+                        );
+
+                    Self {
+                        scalar_group_public_parameters,
+                        group_public_parameters,
+                        encryption_scheme_public_parameters,
+                        range_proof_enc_dl_public_parameters,
+                        range_proof_dcom_eval_public_parameters,
+                        unbounded_encdl_witness_public_parameters,
+                        unbounded_encdh_witness_public_parameters,
+                        unbounded_dcom_eval_witness_public_parameters,
+                    }
+                }
+
+                /// Derive the public parameters from those of the decentralized party's threshold
+                /// decryption key, as output by a distributed key generation (see
+                /// [`crate::decryption_key_generation`]).
+                pub fn from_decryption_key_share_public_parameters(
+                    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+                ) -> crate::Result<Self> {
+                    let encryption_scheme_public_parameters =
+                        &decryption_key_share_public_parameters.encryption_scheme_public_parameters;
+
+                    tiresias::encryption_key::PublicParameters::new(
+                        encryption_scheme_public_parameters.associated_bi_prime,
+                    )
+                    .map_err(|_| crate::Error::InvalidPublicParameters)?;
+
+                    let protocol_public_parameters =
+                        Self::new(encryption_scheme_public_parameters.associated_bi_prime);
+
+                    if protocol_public_parameters.encryption_scheme_public_parameters
+                        != *encryption_scheme_public_parameters
+                    {
+                        return Err(crate::Error::InvalidPublicParameters);
+                    }
+
+                    Ok(protocol_public_parameters)
+                }
+
+                /// Validate that these public parameters are mutually consistent, i.e. that they
+                /// are exactly the ones [`Self::new`] derives from their Paillier modulus: the
+                /// group and range proof parameters are the canonical ones, and the unbounded
+                /// witness parameters match the Paillier randomness space.
+                ///
+                /// This should be called on any parameters that were not constructed locally.
+                pub fn validate(&self) -> crate::Result<()> {
+                    let paillier_associated_bi_prime =
+                        self.encryption_scheme_public_parameters.associated_bi_prime;
+
+                    // Make sure the modulus is valid before deriving the rest of the parameters
+                    // from it.
+                    tiresias::encryption_key::PublicParameters::new(paillier_associated_bi_prime)
+                        .map_err(|_| crate::Error::InvalidPublicParameters)?;
+
+                    if *self != Self::new(paillier_associated_bi_prime) {
+                        return Err(crate::Error::InvalidPublicParameters);
+                    }
+
+                    Ok(())
+                }
+            }
+
+            pub type EncDLCommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::commitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLDecommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::decommitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLProofShareRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_share_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLProofAggregationRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_aggregation_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDLProofAggregationOutput<ProtocolContext> =
+                enhanced_maurer::aggregation::Output<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    encryption_of_discrete_log::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+            pub type EncDLProof<ProtocolContext> = enhanced_maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedEncDLWitness,
+                encryption_of_discrete_log::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type EncDLCommitment<ProtocolContext> =
+                <EncDLCommitmentRoundParty<ProtocolContext> as proof::aggregation::CommitmentRoundParty<
+                    EncDLProofAggregationOutput<ProtocolContext>,
+                >>::Commitment;
+
+            pub type EncDLDecommitment<ProtocolContext> = <EncDLDecommitmentRoundParty<
+                ProtocolContext,
+            > as proof::aggregation::DecommitmentRoundParty<
+                EncDLProofAggregationOutput<ProtocolContext>,
+            >>::Decommitment;
+
+            pub type EncDLProofShare<ProtocolContext> =
+            <EncDLProofShareRoundParty<ProtocolContext> as proof::aggregation::ProofShareRoundParty<
+                EncDLProofAggregationOutput<ProtocolContext>,
+            >>::ProofShare;
+
+            pub type EncDHCommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::commitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHDecommitmentRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::decommitment_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProofShareRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_share_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProofAggregationRoundParty<ProtocolContext> =
+                enhanced_maurer::aggregation::proof_aggregation_round::Party<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProofAggregationOutput<ProtocolContext> =
+                enhanced_maurer::aggregation::Output<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    RANGE_CLAIMS_PER_SCALAR,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    encryption_of_tuple::Language<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                    >,
+                    ProtocolContext,
+                >;
+
+            pub type EncDHProof<ProtocolContext> = enhanced_maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedEncDHWitness,
+                encryption_of_tuple::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type DComEvalProof<ProtocolContext> = enhanced_maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                NUM_RANGE_CLAIMS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                committed_linear_evaluation::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    DIMENSION,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type EncDHCommitment<ProtocolContext> =
+            <EncDHCommitmentRoundParty<ProtocolContext> as proof::aggregation::CommitmentRoundParty<
+                EncDHProofAggregationOutput<ProtocolContext>,
+            >>::Commitment;
+
+            pub type EncDHDecommitment<ProtocolContext> = <EncDHDecommitmentRoundParty<
+                ProtocolContext,
+            > as proof::aggregation::DecommitmentRoundParty<
+                EncDHProofAggregationOutput<ProtocolContext>,
+            >>::Decommitment;
+
+            pub type EncDHProofShare<ProtocolContext> =
+            <EncDHProofShareRoundParty<ProtocolContext> as proof::aggregation::ProofShareRoundParty<
+                EncDHProofAggregationOutput<ProtocolContext>,
+            >>::ProofShare;
+
+            pub type SchnorrProof<ProtocolContext> =
+                knowledge_of_discrete_log::Proof<Scalar, GroupElement, ProtocolContext>;
+
+            pub type ComDLProof<ProtocolContext> = maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type ComRatioProof<ProtocolContext> = maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type DComProof<ProtocolContext> = maurer::Proof<
+                { maurer::SOUND_PROOFS_REPETITIONS },
+                knowledge_of_decommitment::Language<
+                    { maurer::SOUND_PROOFS_REPETITIONS },
+                    SCALAR_LIMBS,
+                    Pedersen<1, SCALAR_LIMBS, Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >;
+
+            pub type DKGCommitmentRoundParty<ProtocolContext> =
+                crate::dkg::centralized_party::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DKGDecommitmentRoundParty<ProtocolContext> =
+                crate::dkg::centralized_party::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DKGDecommitmentRoundState<ProtocolContext> =
+                crate::dkg::centralized_party::decommitment_round::State<
+                    group::Value<GroupElement>,
+                    group::Value<Scalar>,
+                    SchnorrProof<ProtocolContext>,
+                >;
+
+            pub type PublicKeyShareDecommitmentAndProof<ProtocolContext> =
+            crate::dkg::centralized_party::decommitment_round::PublicKeyShareDecommitmentAndProof<
+                group::Value<GroupElement>,
+                SchnorrProof<ProtocolContext>
+            >;
+
+            pub type DKGCentralizedPartyOutput = crate::dkg::centralized_party::Output<
+                group::Value<GroupElement>,
+                group::Value<Scalar>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type EncryptionOfSecretKeyShareRoundParty<ProtocolContext> =
+                crate::dkg::decentralized_party::encryption_of_secret_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::dkg::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type DKGDecentralizedPartyOutput =
+                crate::dkg::decentralized_party::decommitment_proof_verification_round::Output<
+                    group::Value<GroupElement>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type SecretKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::dkg::decentralized_party::SecretKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type BackupEncryptionRoundParty<ProtocolContext> =
+                crate::backup::centralized_party::encryption_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type SecretKeyShareBackup<ProtocolContext> =
+                crate::backup::centralized_party::SecretKeyShareBackup<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type BackupVerificationRoundParty<ProtocolContext> =
+                crate::backup::decentralized_party::verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type ImportEncryptionOfSecretKeyShareRoundParty<ProtocolContext> =
+                crate::import::centralized_party::encryption_of_secret_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type ImportSecretKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::import::centralized_party::SecretKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    SchnorrProof<ProtocolContext>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type ImportProofVerificationRoundParty<ProtocolContext> =
+                crate::import::decentralized_party::proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshCommitmentRoundParty<ProtocolContext> =
+                crate::refresh::centralized_party::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshDecommitmentRoundParty<ProtocolContext> =
+                crate::refresh::centralized_party::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshDeltaDecommitmentAndProof<ProtocolContext> =
+                crate::refresh::centralized_party::DeltaDecommitmentAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type RefreshKeyShareRoundParty<ProtocolContext> =
+                crate::refresh::decentralized_party::key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type RefreshKeyShare = crate::refresh::decentralized_party::KeyShare<
+                group::Value<GroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type RefreshDecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::refresh::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferSecretKeyShareEncryptionRoundParty =
+                crate::transfer::previous_owner::secret_key_share_encryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >;
+
+            pub type TransferSecretKeyShareEncryption =
+                crate::transfer::previous_owner::SecretKeyShareEncryption<
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type TransferCommitmentRoundParty<ProtocolContext> =
+                crate::transfer::new_owner::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferDecommitmentRoundParty<ProtocolContext> =
+                crate::transfer::new_owner::decommitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type TransferPublicKeyShareDecommitmentAndProof<ProtocolContext> =
+                crate::transfer::new_owner::PublicKeyShareDecommitmentAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type TransferDecommitmentProofVerificationRoundParty<ProtocolContext> =
+                crate::transfer::decentralized_party::decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerPublicKeyShareRoundParty<ProtocolContext> =
+                crate::cosigner::cosigner_party::public_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerPublicKeyShareEncryptionAndProof<ProtocolContext> =
+                crate::cosigner::cosigner_party::PublicKeyShareEncryptionAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type CosignerOutput = crate::cosigner::cosigner_party::Output<
+                group::Value<GroupElement>,
+                group::Value<Scalar>,
+                group::Value<crate::paillier::RandomnessSpaceGroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type CosignerEncryptedMaskedKeyShareRoundParty<ProtocolContext> =
+                crate::cosigner::cosigner_party::encrypted_masked_key_share_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type CosignerEncryptedMaskedKeyShareAndProof<ProtocolContext> =
+                crate::cosigner::cosigner_party::EncryptedMaskedKeyShareAndProof<
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDHProof<
+                        crate::cosigner::ApprovalContext<ProtocolContext, group::Value<Scalar>>,
+                    >,
+                >;
+
+            pub type PresignCommitmentRoundParty<ProtocolContext> =
+                crate::presign::centralized_party::commitment_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type PresignProofVerificationRoundParty<ProtocolContext> =
+                crate::presign::centralized_party::proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type PresignProofVerificationRoundState =
+                crate::presign::centralized_party::proof_verification_round::State<
+                    group::Value<Scalar>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                >;
+
+            pub type SignatureNonceSharesCommitmentsAndBatchedProof<ProtocolContext> =
+            crate::presign::centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof<
+                SCALAR_LIMBS,
+                group::Value<GroupElement>,
+                DComProof<ProtocolContext>,
+            >;
+
+            pub type CentralizedPartyPresign = crate::presign::centralized_party::Presign<
+                group::Value<GroupElement>,
+                group::Value<Scalar>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            pub type PresignDecentralizedPartyOutput<ProtocolContext> =
+                crate::presign::decentralized_party::Output<
+                    group::Value<GroupElement>,
+                    group::Value<CommitmentSpaceGroupElement<RANGE_CLAIMS_PER_SCALAR>>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    EncDHProof<ProtocolContext>,
+                    EncDLProof<ProtocolContext>,
+                >;
+
+            pub type EncryptedMaskAndMaskedNonceShare =
+                encryption_of_tuple::StatementSpaceGroupElement<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    EncryptionKey,
+                >;
+
+            pub type EncryptedNonceShareAndPublicShare =
+                encryption_of_discrete_log::StatementSpaceGroupElement<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >;
+
+            pub type EncryptedMaskedKeyShareRoundParty<ProtocolContext> =
+                crate::presign::decentralized_party::encrypted_masked_key_share_and_public_nonce_shares_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type EncryptedMaskedNoncesRoundParty<ProtocolContext> =
+                crate::presign::decentralized_party::encrypted_masked_nonces_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >;
+
+            pub type EncryptedMaskedNoncesRoundState =
+                crate::presign::decentralized_party::encrypted_masked_nonces_round::State<
+                    group::Value<crate::paillier::PlaintextSpaceGroupElement>,
+                    group::Value<crate::paillier::RandomnessSpaceGroupElement>,
+                >;
+
+            pub type DecentralizedPartyPresign = crate::presign::decentralized_party::Presign<
+                group::Value<GroupElement>,
+                group::Value<CiphertextSpaceGroupElement>,
+            >;
+
+            #[cfg(feature = "eddsa")]
+            pub type EdDSAPublicNonceShareSignatureShareAndProof<ProtocolContext> =
+                crate::eddsa::centralized_party::PublicNonceShareSignatureShareAndProof<
+                    group::Value<GroupElement>,
+                    group::Value<Scalar>,
+                    group::Value<CiphertextSpaceGroupElement>,
+                    ComDLProof<ProtocolContext>,
+                >;
+
+            #[cfg(feature = "eddsa")]
+            pub type EdDSASignatureShareParty<ProtocolContext> =
+                crate::eddsa::centralized_party::signature_share_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    ProtocolContext,
+                >;
+
+            #[cfg(feature = "eddsa")]
+            pub type EdDSASignatureVerificationParty =
+                crate::eddsa::centralized_party::signature_verification_round::Party<
+                    SCALAR_LIMBS,
+                    GroupElement,
+                >;
+
+            #[cfg(feature = "eddsa")]
+            pub type EdDSASignaturePartialDecryptionParty<ProtocolContext> =
+                crate::eddsa::decentralized_party::signature_partial_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    crate::paillier::DecryptionKeyShare,
+                    ProtocolContext,
+                >;
+
+            #[cfg(feature = "eddsa")]
+            pub type EdDSASignatureThresholdDecryptionParty =
+                crate::eddsa::decentralized_party::signature_threshold_decryption_round::Party<
+                    SCALAR_LIMBS,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    crate::paillier::DecryptionKeyShare,
+                >;
         }
     }
 
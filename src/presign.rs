@@ -5,6 +5,7 @@
 
 pub mod centralized_party;
 pub mod decentralized_party;
+pub mod pool;
 
 #[cfg(all(
     any(test, feature = "benchmarking"),
@@ -14,7 +15,6 @@ pub mod decentralized_party;
 ))]
 #[allow(unused_imports)]
 pub(crate) mod tests {
-    use core::marker::PhantomData;
     use std::{
         collections::{HashMap, HashSet},
         iter,
@@ -50,7 +50,12 @@ pub(crate) mod tests {
     use super::*;
     use crate::{
         dkg::decentralized_party::SecretKeyShareEncryptionAndProof,
-        secp256k1::bulletproofs::RANGE_CLAIMS_PER_SCALAR, Error, ProtocolPublicParameters,
+        secp256k1::{
+            bulletproofs::RANGE_CLAIMS_PER_SCALAR, paillier::bulletproofs::ProtocolPublicParameters,
+        },
+        session::{tests::session_context, Protocol, SessionContext},
+        weighted_threshold::WeightedThresholdAccessStructure,
+        Error,
     };
 
     #[rstest]
@@ -126,13 +131,46 @@ pub(crate) mod tests {
                 tiresias::CiphertextSpaceValue,
             >,
         >,
+    )> {
+        generates_presignatures_with_public_parameters_internal(
+            threshold,
+            number_of_parties,
+            batch_size,
+            encrypted_decentralized_party_secret_key_share,
+            mismatch_encrypted_masks,
+            ProtocolPublicParameters::new(N),
+        )
+    }
+
+    /// Generate presignatures under `protocol_public_parameters`.
+    #[allow(dead_code)]
+    pub fn generates_presignatures_with_public_parameters_internal(
+        threshold: u16,
+        number_of_parties: u16,
+        batch_size: usize,
+        encrypted_decentralized_party_secret_key_share: tiresias::CiphertextSpaceGroupElement,
+        mismatch_encrypted_masks: bool,
+        protocol_public_parameters: ProtocolPublicParameters,
+    ) -> Option<(
+        Vec<
+            centralized_party::Presign<
+                secp256k1::group_element::Value,
+                secp256k1::Scalar,
+                tiresias::CiphertextSpaceValue,
+            >,
+        >,
+        Vec<tiresias::CiphertextSpaceGroupElement>,
+        Vec<
+            decentralized_party::Presign<
+                secp256k1::group_element::Value,
+                tiresias::CiphertextSpaceValue,
+            >,
+        >,
     )> {
         let measurement = WallTime;
         let mut centralized_party_total_time = Duration::ZERO;
         let mut decentralized_party_total_time = Duration::ZERO;
 
-        let protocol_public_parameters = ProtocolPublicParameters::new(N);
-
         let centralized_party_commitment_round_party = centralized_party::commitment_round::Party::<
             { secp256k1::SCALAR_LIMBS },
             { ristretto::SCALAR_LIMBS },
@@ -143,9 +181,9 @@ pub(crate) mod tests {
             bulletproofs::RangeProof,
             tiresias::RandomnessSpaceGroupElement,
             self_product::GroupElement<2, tiresias::RandomnessSpaceGroupElement>,
-            PhantomData<()>,
+            SessionContext,
         > {
-            protocol_context: PhantomData::<()>,
+            protocol_context: session_context(Protocol::Presign),
             scalar_group_public_parameters: protocol_public_parameters
                 .scalar_group_public_parameters
                 .clone(),
@@ -198,12 +236,12 @@ pub(crate) mod tests {
                         bulletproofs::RangeProof,
                         tiresias::RandomnessSpaceGroupElement,
                         self_product::GroupElement<2, tiresias::RandomnessSpaceGroupElement>,
-                        PhantomData<()>,
+                        SessionContext,
                     > {
                         party_id,
-                        threshold,
+                        access_structure: WeightedThresholdAccessStructure::unweighted(threshold, &parties),
                         parties: parties.clone(),
-                        protocol_context: PhantomData::<()>,
+                        protocol_context: session_context(Protocol::Presign),
                         scalar_group_public_parameters: protocol_public_parameters.scalar_group_public_parameters.clone(),
                         group_public_parameters: protocol_public_parameters.group_public_parameters.clone(),
                         encryption_scheme_public_parameters: protocol_public_parameters.encryption_scheme_public_parameters.clone(),
@@ -454,7 +492,7 @@ pub(crate) mod tests {
             { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
             secp256k1::GroupElement,
             tiresias::EncryptionKey,
-            PhantomData<()>,
+            SessionContext,
         >(
             parties,
             centralized_party_nonce_shares_commitments_and_batched_proof,
@@ -478,7 +516,15 @@ pub(crate) mod tests {
             return None;
         }
 
-        let decentralized_party_presigns = res.unwrap();
+        let decentralized_party_presigns: Vec<_> = res
+            .unwrap()
+            .into_iter()
+            .map(|presign| {
+                presign.bind_to_encrypted_secret_key_share(
+                    encrypted_decentralized_party_secret_key_share.value(),
+                )
+            })
+            .collect();
 
         assert!(centralized_party_presigns
             .clone()
@@ -520,4 +566,192 @@ pub(crate) mod tests {
             decentralized_party_presigns,
         ))
     }
+
+    #[test]
+    fn restores_round_parties_from_state() {
+        let threshold = 2;
+        let number_of_parties = 4;
+        let batch_size = 2;
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let paillier_encryption_key = tiresias::EncryptionKey::new(
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let decentralized_party_secret_key_share = secp256k1::Scalar::sample(
+            &protocol_public_parameters.scalar_group_public_parameters,
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let (_, encrypted_decentralized_party_secret_key_share) = paillier_encryption_key
+            .encrypt(
+                &tiresias::PlaintextSpaceGroupElement::new(
+                    LargeBiPrimeSizedNumber::from(&U256::from(
+                        decentralized_party_secret_key_share,
+                    )),
+                    protocol_public_parameters
+                        .encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )
+                .unwrap(),
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        let centralized_party_commitment_round_party = centralized_party::commitment_round::Party::<
+            { secp256k1::SCALAR_LIMBS },
+            { ristretto::SCALAR_LIMBS },
+            { RANGE_CLAIMS_PER_SCALAR },
+            { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+            secp256k1::GroupElement,
+            tiresias::EncryptionKey,
+            bulletproofs::RangeProof,
+            tiresias::RandomnessSpaceGroupElement,
+            self_product::GroupElement<2, tiresias::RandomnessSpaceGroupElement>,
+            SessionContext,
+        > {
+            protocol_context: session_context(Protocol::Presign),
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters
+                .clone(),
+            group_public_parameters: protocol_public_parameters.group_public_parameters.clone(),
+            encryption_scheme_public_parameters: protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .clone(),
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters
+                .clone(),
+            unbounded_encdh_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdh_witness_public_parameters
+                .clone(),
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters
+                .clone(),
+            encrypted_decentralized_party_secret_key_share,
+        };
+
+        let state =
+            serde_json::to_vec(&centralized_party_commitment_round_party.to_state()).unwrap();
+        let centralized_party_commitment_round_party =
+            centralized_party::commitment_round::Party::from_state(
+                protocol_public_parameters.clone(),
+                serde_json::from_slice(&state).unwrap(),
+                session_context(Protocol::Presign),
+            )
+            .unwrap();
+
+        let (
+            centralized_party_nonce_shares_commitments_and_batched_proof,
+            centralized_party_proof_verification_round_party,
+        ) = centralized_party_commitment_round_party
+            .sample_commit_and_prove_signature_nonce_share(batch_size, &mut OsRng)
+            .unwrap();
+
+        let state = centralized_party_proof_verification_round_party.to_state();
+        let restored_party = centralized_party::proof_verification_round::Party::from_state(
+            protocol_public_parameters.clone(),
+            serde_json::from_slice(&serde_json::to_vec(&state).unwrap()).unwrap(),
+            session_context(Protocol::Presign),
+        )
+        .unwrap();
+
+        assert_eq!(
+            restored_party.to_state(),
+            state,
+            "restoring a party from its serialized state should not change it"
+        );
+
+        let parties: HashSet<_> = (1..=number_of_parties)
+            .choose_multiple(&mut OsRng, threshold.into())
+            .into_iter()
+            .collect();
+        let party_id = *parties.iter().next().unwrap();
+
+        let decentralized_party_encrypted_masked_key_share_and_public_nonce_shares_party =
+            decentralized_party::encrypted_masked_key_share_and_public_nonce_shares_round::Party::<
+                { secp256k1::SCALAR_LIMBS },
+                { ristretto::SCALAR_LIMBS },
+                { RANGE_CLAIMS_PER_SCALAR },
+                { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                secp256k1::GroupElement,
+                tiresias::EncryptionKey,
+                bulletproofs::RangeProof,
+                tiresias::RandomnessSpaceGroupElement,
+                self_product::GroupElement<2, tiresias::RandomnessSpaceGroupElement>,
+                SessionContext,
+            > {
+                party_id,
+                access_structure: WeightedThresholdAccessStructure::unweighted(threshold, &parties),
+                parties: parties.clone(),
+                protocol_context: session_context(Protocol::Presign),
+                scalar_group_public_parameters: protocol_public_parameters
+                    .scalar_group_public_parameters
+                    .clone(),
+                group_public_parameters: protocol_public_parameters.group_public_parameters.clone(),
+                encryption_scheme_public_parameters: protocol_public_parameters
+                    .encryption_scheme_public_parameters
+                    .clone(),
+                unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                    .unbounded_encdl_witness_public_parameters
+                    .clone(),
+                unbounded_encdh_witness_public_parameters: protocol_public_parameters
+                    .unbounded_encdh_witness_public_parameters
+                    .clone(),
+                range_proof_public_parameters: protocol_public_parameters
+                    .range_proof_enc_dl_public_parameters
+                    .clone(),
+                encrypted_secret_key_share: encrypted_decentralized_party_secret_key_share,
+            };
+
+        let state = serde_json::to_value(
+            decentralized_party_encrypted_masked_key_share_and_public_nonce_shares_party.to_state(),
+        )
+        .unwrap();
+
+        // A party that is not one of the participating parties cannot be restored.
+        let mut foreign_party_state = state.clone();
+        foreign_party_state["party_id"] = serde_json::json!(number_of_parties + 1);
+        assert!(matches!(
+            decentralized_party::encrypted_masked_key_share_and_public_nonce_shares_round::Party::from_state(
+                protocol_public_parameters.clone(),
+                serde_json::from_value(foreign_party_state).unwrap(),
+                session_context(Protocol::Presign),
+            ),
+            Err(Error::InvalidParameters)
+        ));
+
+        let decentralized_party_encrypted_masked_key_share_and_public_nonce_shares_party =
+            decentralized_party::encrypted_masked_key_share_and_public_nonce_shares_round::Party::from_state(
+                protocol_public_parameters.clone(),
+                serde_json::from_value(state).unwrap(),
+                session_context(Protocol::Presign),
+            )
+            .unwrap();
+
+        let (_, decentralized_party_encrypted_masked_nonce_shares_round_party) =
+            decentralized_party_encrypted_masked_key_share_and_public_nonce_shares_party
+                .sample_mask_and_nonce_shares_and_initialize_proof_aggregation(
+                    centralized_party_nonce_shares_commitments_and_batched_proof,
+                    &mut OsRng,
+                )
+                .unwrap();
+
+        let state = decentralized_party_encrypted_masked_nonce_shares_round_party.to_state();
+        let restored_party = decentralized_party::encrypted_masked_nonces_round::Party::from_state(
+            protocol_public_parameters.clone(),
+            serde_json::from_slice(&serde_json::to_vec(&state).unwrap()).unwrap(),
+            session_context(Protocol::Presign),
+        )
+        .unwrap();
+
+        assert_eq!(
+            restored_party.to_state(),
+            state,
+            "restoring a party from its serialized state should not change it"
+        );
+    }
 }
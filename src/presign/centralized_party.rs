@@ -12,5 +12,7 @@ pub struct Presign<GroupElementValue, ScalarValue, CiphertextValue> {
     pub(crate) decentralized_party_nonce_public_share: GroupElementValue, // $K_A$
     pub(crate) encrypted_mask: CiphertextValue, // $\ct_1$
     pub(crate) encrypted_masked_key_share: CiphertextValue, // $\ct_2$
+    pub(crate) encrypted_nonce_share: CiphertextValue, // $\ct_3$
     pub(crate) commitment_randomness: ScalarValue, // $\rho$
+    pub(crate) encrypted_secret_key_share: CiphertextValue, // $\ct_{key}$
 }
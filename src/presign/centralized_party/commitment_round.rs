@@ -11,7 +11,20 @@ use maurer::{knowledge_of_decommitment, SOUND_PROOFS_REPETITIONS};
 use proof::AggregatableRangeProof;
 use serde::{Deserialize, Serialize};
 
-use crate::{dkg, presign::centralized_party::proof_verification_round, ProtocolPublicParameters};
+use crate::{
+    dkg,
+    presign::centralized_party::{proof_verification_round, Presign},
+    round::{Round, Transition},
+    session::{RoundContext, RoundId},
+    ProtocolPublicParameters,
+};
+
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<CiphertextSpaceValue> {
+    encrypted_decentralized_party_secret_key_share: CiphertextSpaceValue,
+}
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
@@ -24,7 +37,7 @@ pub struct Party<
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDLWitness: group::GroupElement + Samplable,
     UnboundedEncDHWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub(in crate::presign) protocol_context: ProtocolContext,
     pub(in crate::presign) scalar_group_public_parameters:
@@ -61,7 +74,7 @@ impl<
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
         UnboundedEncDHWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -146,7 +159,9 @@ impl<
             >,
             ProtocolContext,
         >::prove(
-            &self.protocol_context,
+            &self
+                .protocol_context
+                .round(RoundId::PresignCentralizedPartyCommitment),
             &language_public_parameters,
             signature_nonce_shares_and_commitment_randomnesses
                 .clone()
@@ -229,4 +244,142 @@ impl<
             encrypted_decentralized_party_secret_key_share,
         })
     }
+
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(self) -> State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>> {
+        State {
+            encrypted_decentralized_party_secret_key_share: self
+                .encrypted_decentralized_party_secret_key_share
+                .value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The public parameters and protocol context are not a part of the state, and must be the
+    /// same ones used to instantiate the party prior to it being saved.
+    pub fn from_state<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        state: State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+        protocol_context: ProtocolContext,
+    ) -> crate::Result<Self> {
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let encrypted_decentralized_party_secret_key_share =
+            EncryptionKey::CiphertextSpaceGroupElement::new(
+                state.encrypted_decentralized_party_secret_key_share,
+                encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+            )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters: protocol_public_parameters.group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            unbounded_encdh_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdh_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            encrypted_decentralized_party_secret_key_share,
+        })
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >
+{
+    /// The number of presigns to generate.
+    type Incoming = usize;
+    type Message = SignatureNonceSharesCommitmentsAndBatchedProof<
+        SCALAR_LIMBS,
+        GroupElement::Value,
+        maurer::Proof<
+            SOUND_PROOFS_REPETITIONS,
+            knowledge_of_decommitment::Language<
+                SOUND_PROOFS_REPETITIONS,
+                SCALAR_LIMBS,
+                Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+            >,
+            ProtocolContext,
+        >,
+    >;
+    type NextRound = proof_verification_round::Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >;
+    type Output = Vec<
+        Presign<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+        >,
+    >;
+    type Error = crate::Error;
+
+    fn process(
+        self,
+        batch_size: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (signature_nonce_shares_commitments_and_batched_proof, party) =
+            self.sample_commit_and_prove_signature_nonce_share(batch_size, rng)?;
+
+        Ok(Transition::Advance(
+            signature_nonce_shares_commitments_and_batched_proof,
+            party,
+        ))
+    }
 }
@@ -15,13 +15,24 @@ use homomorphic_encryption::{
 };
 use maurer::SOUND_PROOFS_REPETITIONS;
 use proof::{range::PublicParametersAccessors, AggregatableRangeProof};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     dkg,
     presign::{centralized_party::Presign, decentralized_party},
+    round::{Round, Transition},
+    session::{RoundContext, RoundId},
     Error, ProtocolPublicParameters,
 };
+
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<ScalarValue, CiphertextSpaceValue> {
+    signature_nonce_shares_and_commitment_randomnesses: Vec<(ScalarValue, ScalarValue)>,
+    encrypted_decentralized_party_secret_key_share: CiphertextSpaceValue,
+}
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
@@ -33,7 +44,7 @@ pub struct Party<
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDLWitness: group::GroupElement + Samplable,
     UnboundedEncDHWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub(super) protocol_context: ProtocolContext,
     pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
@@ -58,7 +69,7 @@ impl<
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
         UnboundedEncDHWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -300,7 +311,9 @@ where
         )?;
 
         output.masks_and_encrypted_masked_key_share_proof.verify(
-            &self.protocol_context,
+            &self
+                .protocol_context
+                .round(RoundId::PresignEncryptedMaskedKeyShareAndPublicNonceShares),
             &language_public_parameters,
             statements,
             rng,
@@ -403,15 +416,21 @@ where
         output
             .encrypted_nonce_shares_and_public_shares_proof
             .verify(
-                &self.protocol_context,
+                &self
+                    .protocol_context
+                    .round(RoundId::PresignEncryptedMaskedKeyShareAndPublicNonceShares),
                 &language_public_parameters,
                 statements,
                 rng,
             )?;
 
+        let encrypted_secret_key_share =
+            self.encrypted_decentralized_party_secret_key_share.value();
+
         Ok(output
             .nonce_public_shares
             .into_iter()
+            .zip(output.encrypted_nonces)
             .zip(
                 output.encrypted_masks.into_iter().zip(
                     output
@@ -422,7 +441,7 @@ where
             )
             .map(
                 |(
-                    decentralized_party_nonce_public_share,
+                    (decentralized_party_nonce_public_share, encrypted_nonce_share),
                     (
                         encrypted_mask,
                         (encrypted_masked_key_share, (nonce_share, commitment_randomness)),
@@ -433,13 +452,107 @@ where
                         decentralized_party_nonce_public_share,
                         encrypted_mask,
                         encrypted_masked_key_share,
+                        encrypted_nonce_share,
                         commitment_randomness: commitment_randomness.value(),
+                        encrypted_secret_key_share,
                     }
                 },
             )
             .collect())
     }
 
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(
+        self,
+    ) -> State<
+        group::Value<GroupElement::Scalar>,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    > {
+        State {
+            signature_nonce_shares_and_commitment_randomnesses: self
+                .signature_nonce_shares_and_commitment_randomnesses
+                .into_iter()
+                .map(|(nonce_share, commitment_randomness)| {
+                    (nonce_share.value(), commitment_randomness.value())
+                })
+                .collect(),
+            encrypted_decentralized_party_secret_key_share: self
+                .encrypted_decentralized_party_secret_key_share
+                .value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The public parameters and protocol context are not a part of the state, and must be the
+    /// same ones used to instantiate the party prior to it being saved.
+    pub fn from_state<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        state: State<
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        protocol_context: ProtocolContext,
+    ) -> crate::Result<Self> {
+        let signature_nonce_shares_and_commitment_randomnesses = state
+            .signature_nonce_shares_and_commitment_randomnesses
+            .into_iter()
+            .map(|(nonce_share, commitment_randomness)| {
+                Ok((
+                    GroupElement::Scalar::new(
+                        nonce_share,
+                        &protocol_public_parameters.scalar_group_public_parameters,
+                    )?,
+                    GroupElement::Scalar::new(
+                        commitment_randomness,
+                        &protocol_public_parameters.scalar_group_public_parameters,
+                    )?,
+                ))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let encrypted_decentralized_party_secret_key_share =
+            EncryptionKey::CiphertextSpaceGroupElement::new(
+                state.encrypted_decentralized_party_secret_key_share,
+                protocol_public_parameters
+                    .encryption_scheme_public_parameters
+                    .ciphertext_space_public_parameters(),
+            )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters: protocol_public_parameters.group_public_parameters,
+            encryption_scheme_public_parameters: protocol_public_parameters
+                .encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            unbounded_encdh_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdh_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            signature_nonce_shares_and_commitment_randomnesses,
+            encrypted_decentralized_party_secret_key_share,
+        })
+    }
+
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
         UnboundedDComEvalWitness: group::GroupElement + Samplable,
@@ -494,3 +607,145 @@ where
         })
     }
 }
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    /// The output of the decentralized party, from which the presigns are computed.
+    type Incoming = decentralized_party::Output<
+        GroupElement::Value,
+        proof::range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        encryption_of_tuple::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        >,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >;
+    /// The centralized party sends no message once the presigns are verified.
+    type Message = ();
+    type NextRound = core::convert::Infallible;
+    type Output = Vec<
+        Presign<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+        >,
+    >;
+    type Error = Error;
+
+    fn process(
+        self,
+        output: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let presigns = self.verify_presign_output(output, rng)?;
+
+        Ok(Transition::Finalize((), presigns))
+    }
+}
@@ -18,7 +18,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     presign::centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof,
-    Error, Result,
+    session::RoundContext, Error, Result,
 };
 
 pub mod encrypted_masked_key_share_and_public_nonce_shares_round;
@@ -52,7 +52,7 @@ impl<
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDHWitness: group::GroupElement + Samplable,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Output<
         GroupElement::Value,
@@ -284,7 +284,10 @@ pub struct Presign<GroupElementValue, CiphertextValue> {
     pub(crate) nonce_public_share: GroupElementValue,                       // R_B
     pub(crate) encrypted_mask: CiphertextValue,                             // \ct_1
     pub(crate) encrypted_masked_key_share: CiphertextValue,                 // \ct_2
+    pub(crate) encrypted_nonce_share: CiphertextValue,                      // \ct_3
     pub(crate) encrypted_masked_nonce_share: CiphertextValue,               // \ct_4
+    #[serde(default)]
+    pub(crate) encrypted_secret_key_share: Option<CiphertextValue>, // \ct_{key}
 }
 
 impl<
@@ -355,6 +358,10 @@ impl<
             .base_by_discrete_log()
             .value();
 
+        let encrypted_nonce_share = encrypted_nonce_share_and_public_share
+            .encrypted_discrete_log()
+            .value();
+
         if encrypted_nonce_share_and_public_share.encrypted_discrete_log()
             != encrypted_masked_nonce_share.encrypted_multiplicand()
         {
@@ -420,17 +427,35 @@ impl<
             nonce_public_share,
             encrypted_mask,
             encrypted_masked_key_share,
+            encrypted_nonce_share,
             encrypted_masked_nonce_share,
+            encrypted_secret_key_share: None,
         })
     }
 
+    /// Bind this presign to the encrypted secret key share it was generated for, so that the
+    /// decentralized party rejects it with [`Error::StalePresign`] once that key share was
+    /// refreshed or transferred.
+    ///
+    /// Presigns output by the presign protocol are already bound; this is only needed for those
+    /// constructed directly using [`Self::new`] or [`Self::new_batch`].
+    pub fn bind_to_encrypted_secret_key_share(
+        self,
+        encrypted_secret_key_share: CiphertextValue,
+    ) -> Self {
+        Self {
+            encrypted_secret_key_share: Some(encrypted_secret_key_share),
+            ..self
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn new_batch<
         const SCALAR_LIMBS: usize,
         const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
         GroupElement,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >(
         parties: HashSet<PartyID>,
         centralized_party_nonce_shares_commitments_and_batched_proof:
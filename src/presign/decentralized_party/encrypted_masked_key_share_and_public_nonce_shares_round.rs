@@ -3,7 +3,7 @@
 
 #![allow(clippy::type_complexity)]
 
-use std::collections::HashSet;
+use std::{collections::HashSet, marker::PhantomData};
 
 use commitment::{pedersen, Pedersen};
 use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
@@ -15,17 +15,107 @@ use group::{GroupElement, PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::{knowledge_of_decommitment, SOUND_PROOFS_REPETITIONS};
 use proof::AggregatableRangeProof;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     dkg,
     presign::{
         centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof,
-        decentralized_party::encrypted_masked_nonces_round,
+        decentralized_party::{encrypted_masked_nonces_round, Presign},
     },
+    round::{aggregation, aggregation::Batch, Round, Transition},
+    session::{RoundContext, RoundId},
+    weighted_threshold::WeightedThresholdAccessStructure,
     Error, ProtocolPublicParameters,
 };
 
+/// The proof aggregations of the encryptions of the masks and masked key shares, and of the
+/// encryptions of the nonce shares and their public shares, started by [`Party`] and run in
+/// parallel through [`aggregation`] rounds.
+pub type MaskedKeyShareAndPublicNonceSharesAggregation<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement,
+    EncryptionKey,
+    RangeProof,
+    UnboundedEncDLWitness,
+    UnboundedEncDHWitness,
+    ProtocolContext,
+> = (
+    Batch<
+        enhanced_maurer::aggregation::Output<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDHWitness,
+            encryption_of_tuple::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            ProtocolContext,
+        >,
+        enhanced_maurer::aggregation::commitment_round::Party<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDHWitness,
+            encryption_of_tuple::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            ProtocolContext,
+        >,
+    >,
+    Batch<
+        enhanced_maurer::aggregation::Output<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            ProtocolContext,
+        >,
+        enhanced_maurer::aggregation::commitment_round::Party<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            ProtocolContext,
+        >,
+    >,
+);
+
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<CiphertextSpaceValue> {
+    party_id: PartyID,
+    access_structure: WeightedThresholdAccessStructure,
+    parties: HashSet<PartyID>,
+    encrypted_secret_key_share: CiphertextSpaceValue,
+}
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
@@ -37,10 +127,10 @@ pub struct Party<
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDLWitness: group::GroupElement + Samplable,
     UnboundedEncDHWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub(in crate::presign) party_id: PartyID,
-    pub(in crate::presign) threshold: PartyID,
+    pub(in crate::presign) access_structure: WeightedThresholdAccessStructure,
     pub(in crate::presign) parties: HashSet<PartyID>,
     pub(in crate::presign) protocol_context: ProtocolContext,
     pub(in crate::presign) scalar_group_public_parameters:
@@ -66,7 +156,7 @@ impl<
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDLWitness: group::GroupElement + Samplable,
         UnboundedEncDHWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -196,9 +286,7 @@ where
             ProtocolContext,
         >,
     )> {
-        if self.parties.len() < self.threshold.into() {
-            return Err(Error::ThresholdNotReached);
-        }
+        self.access_structure.is_authorized_subset(&self.parties)?;
 
         let batch_size = centralized_party_nonce_shares_commitments_and_batched_proof
             .commitments
@@ -226,7 +314,9 @@ where
         centralized_party_nonce_shares_commitments_and_batched_proof
             .proof
             .verify(
-                &self.protocol_context,
+                &self
+                    .protocol_context
+                    .round(RoundId::PresignCentralizedPartyCommitment),
                 &language_public_parameters,
                 centralized_party_nonce_shares_commitments.clone(),
             )?;
@@ -375,7 +465,8 @@ where
                 self.party_id,
                 self.parties.clone(),
                 language_public_parameters,
-                self.protocol_context.clone(),
+                self.protocol_context
+                    .round(RoundId::PresignEncryptedMaskedKeyShareAndPublicNonceShares),
                 witnesses,
                 rng,
             )?;
@@ -487,7 +578,8 @@ where
                 self.party_id,
                 self.parties.clone(),
                 language_public_parameters,
-                self.protocol_context.clone(),
+                self.protocol_context
+                    .round(RoundId::PresignEncryptedMaskedKeyShareAndPublicNonceShares),
                 witnesses,
                 rng,
             )?;
@@ -539,6 +631,46 @@ where
             GroupElement::Value,
             group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
         >,
+    ) -> crate::Result<Self> {
+        let access_structure = WeightedThresholdAccessStructure::unweighted(threshold, &parties);
+
+        Self::new_weighted(
+            party_id,
+            access_structure,
+            parties,
+            protocol_context,
+            protocol_public_parameters,
+            dkg_output,
+        )
+    }
+
+    /// Instantiate a party under a weighted threshold `access_structure`, in which the
+    /// participating `parties` must be of total weight reaching the threshold.
+    pub fn new_weighted<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        party_id: PartyID,
+        access_structure: WeightedThresholdAccessStructure,
+        parties: HashSet<PartyID>,
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
     ) -> crate::Result<Self> {
         let encryption_scheme_public_parameters =
             protocol_public_parameters.encryption_scheme_public_parameters;
@@ -550,7 +682,7 @@ where
 
         Ok(Self {
             party_id,
-            threshold,
+            access_structure,
             parties,
             protocol_context,
             scalar_group_public_parameters: protocol_public_parameters
@@ -566,4 +698,254 @@ where
             encrypted_secret_key_share,
         })
     }
+
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(self) -> State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>> {
+        State {
+            party_id: self.party_id,
+            access_structure: self.access_structure,
+            parties: self.parties,
+            encrypted_secret_key_share: self.encrypted_secret_key_share.value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The public parameters and protocol context are not a part of the state, and must be the
+    /// same ones used to instantiate the party prior to it being saved.
+    pub fn from_state<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        state: State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+        protocol_context: ProtocolContext,
+    ) -> crate::Result<Self> {
+        if !state.parties.contains(&state.party_id) {
+            return Err(Error::InvalidParameters);
+        }
+
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_secret_key_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            party_id: state.party_id,
+            access_structure: state.access_structure,
+            parties: state.parties,
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters: protocol_public_parameters.group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            unbounded_encdh_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdh_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            encrypted_secret_key_share,
+        })
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    /// The commitments of the centralized party to its nonce shares, and their proof.
+    type Incoming = SignatureNonceSharesCommitmentsAndBatchedProof<
+        SCALAR_LIMBS,
+        GroupElement::Value,
+        maurer::Proof<
+            SOUND_PROOFS_REPETITIONS,
+            knowledge_of_decommitment::Language<
+                SOUND_PROOFS_REPETITIONS,
+                SCALAR_LIMBS,
+                Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+            >,
+            ProtocolContext,
+        >,
+    >;
+    type Message = aggregation::Commitment<
+        MaskedKeyShareAndPublicNonceSharesAggregation<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        >,
+    >;
+    type NextRound = aggregation::DecommitmentRound<
+        <MaskedKeyShareAndPublicNonceSharesAggregation<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        > as aggregation::CommitmentStage>::DecommitmentStage,
+        encrypted_masked_nonces_round::EncryptedMaskedNoncesAggregationRound<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        >,
+    >;
+    type Output = Vec<
+        Presign<
+            GroupElement::Value,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+        >,
+    >;
+    type Error = crate::Error;
+
+    fn process(
+        self,
+        centralized_party_nonce_shares_commitments_and_batched_proof: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let encrypted_secret_key_share = self.encrypted_secret_key_share.clone();
+        let group_public_parameters = self.group_public_parameters.clone();
+
+        let (
+            (key_share_masking_commitment_round_party, nonce_sharing_commitment_round_party),
+            encrypted_masked_nonces_round_party,
+        ) = self.sample_mask_and_nonce_shares_and_initialize_proof_aggregation(
+            centralized_party_nonce_shares_commitments_and_batched_proof.clone(),
+            rng,
+        )?;
+
+        let (commitment, decommitment_round) = aggregation::commit(
+            (
+                Batch::new(vec![key_share_masking_commitment_round_party]),
+                Batch::new(vec![nonce_sharing_commitment_round_party]),
+            ),
+            encrypted_masked_nonces_round::EncryptedMaskedNoncesAggregationRound {
+                party: encrypted_masked_nonces_round_party,
+                encrypted_secret_key_share,
+                centralized_party_nonce_shares_commitments_and_batched_proof,
+                group_public_parameters,
+                _unbounded_encdl_witness_choice: PhantomData,
+            },
+            rng,
+        )?;
+
+        Ok(Transition::Advance(commitment, decommitment_round))
+    }
 }
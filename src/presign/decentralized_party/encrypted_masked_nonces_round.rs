@@ -3,21 +3,91 @@
 
 #![allow(clippy::type_complexity)]
 
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+};
 
+use commitment::Pedersen;
 use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
 use enhanced_maurer::{
-    encryption_of_discrete_log, encryption_of_tuple, encryption_of_tuple::StatementAccessors,
-    language::composed_witness_upper_bound, EnhanceableLanguage, EnhancedLanguage,
-    EnhancedPublicParameters,
+    encryption_of_discrete_log, encryption_of_tuple,
+    encryption_of_tuple::StatementAccessors,
+    language::{composed_witness_upper_bound, EnhancedLanguageStatementAccessors},
+    EnhanceableLanguage, EnhancedLanguage, EnhancedPublicParameters,
 };
 use group::{GroupElement as _, PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
-use maurer::SOUND_PROOFS_REPETITIONS;
-use proof::AggregatableRangeProof;
-use serde::Serialize;
+use maurer::{knowledge_of_decommitment, SOUND_PROOFS_REPETITIONS};
+use proof::{range, AggregatableRangeProof};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    presign::{
+        centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof,
+        decentralized_party,
+        decentralized_party::{
+            encrypted_masked_key_share_and_public_nonce_shares_round::MaskedKeyShareAndPublicNonceSharesAggregation,
+            Presign,
+        },
+    },
+    round::{aggregation, aggregation::Batch, Round, Transition},
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters, Result,
+};
 
-use crate::{Error, Result};
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<PlaintextSpaceValue, RandomnessSpaceValue> {
+    party_id: PartyID,
+    parties: HashSet<PartyID>,
+    shares_of_signature_nonce_shares_witnesses: Vec<PlaintextSpaceValue>,
+    shares_of_signature_nonce_shares_encryption_randomness: Vec<RandomnessSpaceValue>,
+}
+
+/// The proof aggregations of the encryptions of the masked nonces, one per presign, started by
+/// [`Party`] and run in lockstep through [`aggregation`] rounds.
+pub type MaskedNoncesAggregation<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement,
+    EncryptionKey,
+    RangeProof,
+    UnboundedEncDHWitness,
+    ProtocolContext,
+> = Batch<
+    enhanced_maurer::aggregation::Output<
+        SOUND_PROOFS_REPETITIONS,
+        RANGE_CLAIMS_PER_SCALAR,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RangeProof,
+        UnboundedEncDHWitness,
+        encryption_of_tuple::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+        ProtocolContext,
+    >,
+    enhanced_maurer::aggregation::commitment_round::Party<
+        SOUND_PROOFS_REPETITIONS,
+        RANGE_CLAIMS_PER_SCALAR,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RangeProof,
+        UnboundedEncDHWitness,
+        encryption_of_tuple::Language<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+        ProtocolContext,
+    >,
+>;
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
@@ -29,7 +99,7 @@ pub struct Party<
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedEncDHWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub(super) party_id: PartyID,
     pub parties: HashSet<PartyID>,
@@ -53,7 +123,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedEncDHWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -255,7 +325,8 @@ where
                             self.party_id,
                             self.parties.clone(),
                             language_public_parameters,
-                            self.protocol_context.clone(),
+                            self.protocol_context
+                                .round(RoundId::PresignEncryptedMaskedNonces),
                             vec![witness],
                             rng,
                         )
@@ -265,4 +336,729 @@ where
             )
             .collect()
     }
+
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(
+        self,
+    ) -> State<
+        group::Value<EncryptionKey::PlaintextSpaceGroupElement>,
+        group::Value<EncryptionKey::RandomnessSpaceGroupElement>,
+    > {
+        State {
+            party_id: self.party_id,
+            parties: self.parties,
+            shares_of_signature_nonce_shares_witnesses: self
+                .shares_of_signature_nonce_shares_witnesses
+                .into_iter()
+                .map(|share_of_signature_nonce_share| share_of_signature_nonce_share.value())
+                .collect(),
+            shares_of_signature_nonce_shares_encryption_randomness: self
+                .shares_of_signature_nonce_shares_encryption_randomness
+                .into_iter()
+                .map(|encryption_randomness| encryption_randomness.value())
+                .collect(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The public parameters and protocol context are not a part of the state, and must be the
+    /// same ones used to instantiate the party prior to it being saved.
+    pub fn from_state<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        state: State<
+            group::Value<EncryptionKey::PlaintextSpaceGroupElement>,
+            group::Value<EncryptionKey::RandomnessSpaceGroupElement>,
+        >,
+        protocol_context: ProtocolContext,
+    ) -> Result<Self> {
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let shares_of_signature_nonce_shares_witnesses = state
+            .shares_of_signature_nonce_shares_witnesses
+            .into_iter()
+            .map(|share_of_signature_nonce_share| {
+                EncryptionKey::PlaintextSpaceGroupElement::new(
+                    share_of_signature_nonce_share,
+                    encryption_scheme_public_parameters.plaintext_space_public_parameters(),
+                )
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let shares_of_signature_nonce_shares_encryption_randomness = state
+            .shares_of_signature_nonce_shares_encryption_randomness
+            .into_iter()
+            .map(|encryption_randomness| {
+                EncryptionKey::RandomnessSpaceGroupElement::new(
+                    encryption_randomness,
+                    encryption_scheme_public_parameters.randomness_space_public_parameters(),
+                )
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        if !state.parties.contains(&state.party_id)
+            || shares_of_signature_nonce_shares_witnesses.len()
+                != shares_of_signature_nonce_shares_encryption_randomness.len()
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(Self {
+            party_id: state.party_id,
+            parties: state.parties,
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_encdh_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdh_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            shares_of_signature_nonce_shares_witnesses,
+            shares_of_signature_nonce_shares_encryption_randomness,
+        })
+    }
+}
+
+/// The round in which the decentralized party, having aggregated the proofs of the encryptions of
+/// the masks, masked key shares and nonce shares, computes the output to be sent to the
+/// centralized party and starts the aggregation of the encrypted masked nonces.
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct EncryptedMaskedNoncesAggregationRound<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) party: Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >,
+    pub(super) encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    pub(super) centralized_party_nonce_shares_commitments_and_batched_proof:
+        SignatureNonceSharesCommitmentsAndBatchedProof<
+            SCALAR_LIMBS,
+            GroupElement::Value,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                knowledge_of_decommitment::Language<
+                    SOUND_PROOFS_REPETITIONS,
+                    SCALAR_LIMBS,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+        >,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) _unbounded_encdl_witness_choice: PhantomData<UnboundedEncDLWitness>,
+}
+
+/// The round in which the decentralized party, having aggregated the proofs of the encryptions of
+/// the masked nonces, outputs its presigns and sends the output of the presign protocol to the
+/// centralized party.
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct PresignOutputRound<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    parties: HashSet<PartyID>,
+    encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    centralized_party_nonce_shares_commitments_and_batched_proof:
+        SignatureNonceSharesCommitmentsAndBatchedProof<
+            SCALAR_LIMBS,
+            GroupElement::Value,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                knowledge_of_decommitment::Language<
+                    SOUND_PROOFS_REPETITIONS,
+                    SCALAR_LIMBS,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+        >,
+    group_public_parameters: GroupElement::PublicParameters,
+    output: decentralized_party::Output<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        encryption_of_tuple::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        >,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >,
+    masks_and_encrypted_masked_key_share: Vec<
+        encryption_of_tuple::StatementSpaceGroupElement<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            EncryptionKey,
+        >,
+    >,
+    individual_encrypted_nonce_shares_and_public_shares: HashMap<
+        PartyID,
+        Vec<
+            group::Value<
+                encryption_of_discrete_log::StatementSpaceGroupElement<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+            >,
+        >,
+    >,
+    encrypted_nonce_shares_and_public_shares: Vec<
+        encryption_of_discrete_log::StatementSpaceGroupElement<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >,
+    >,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for EncryptedMaskedNoncesAggregationRound<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    /// The decommitments of all parties, and the aggregated proofs of the encryptions of the masks
+    /// and masked key shares, and of the nonce shares.
+    type Incoming = (
+        HashMap<
+            PartyID,
+            aggregation::Decommitment<
+                MaskedKeyShareAndPublicNonceSharesAggregation<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >,
+            >,
+        >,
+        aggregation::AggregatedOutput<
+            MaskedKeyShareAndPublicNonceSharesAggregation<
+                SCALAR_LIMBS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                UnboundedEncDHWitness,
+                ProtocolContext,
+            >,
+        >,
+    );
+    type Message = aggregation::Commitment<
+        MaskedNoncesAggregation<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        >,
+    >;
+    type NextRound = aggregation::DecommitmentRound<
+        <MaskedNoncesAggregation<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        > as aggregation::CommitmentStage>::DecommitmentStage,
+        PresignOutputRound<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        >,
+    >;
+    type Output = Vec<
+        Presign<
+            GroupElement::Value,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+        >,
+    >;
+    type Error = Error;
+
+    fn process(
+        self,
+        (
+            decommitments,
+            (
+                masks_and_encrypted_masked_key_share_aggregation_outputs,
+                encrypted_nonce_shares_and_public_shares_aggregation_outputs,
+            ),
+        ): Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (masks_and_encrypted_masked_key_share_proof, masks_and_encrypted_masked_key_share) =
+            masks_and_encrypted_masked_key_share_aggregation_outputs
+                .into_iter()
+                .next()
+                .ok_or(Error::InternalError)?;
+
+        let (
+            encrypted_nonce_shares_and_public_shares_proof,
+            encrypted_nonce_shares_and_public_shares,
+        ) = encrypted_nonce_shares_and_public_shares_aggregation_outputs
+            .into_iter()
+            .next()
+            .ok_or(Error::InternalError)?;
+
+        let output = decentralized_party::Output::new(
+            masks_and_encrypted_masked_key_share.clone(),
+            masks_and_encrypted_masked_key_share_proof,
+            encrypted_nonce_shares_and_public_shares.clone(),
+            encrypted_nonce_shares_and_public_shares_proof,
+        )?;
+
+        let masks_and_encrypted_masked_key_share: Vec<_> = masks_and_encrypted_masked_key_share
+            .into_iter()
+            .map(|mask_and_encrypted_masked_key_share| {
+                *mask_and_encrypted_masked_key_share.language_statement()
+            })
+            .collect();
+
+        let encrypted_nonce_shares_and_public_shares: Vec<_> =
+            encrypted_nonce_shares_and_public_shares
+                .into_iter()
+                .map(|encrypted_nonce_share_and_public_share| {
+                    *encrypted_nonce_share_and_public_share.language_statement()
+                })
+                .collect();
+
+        let individual_encrypted_nonce_shares_and_public_shares = decommitments
+            .into_iter()
+            .map(|(party_id, (_, decommitments))| {
+                (
+                    party_id,
+                    decommitments
+                        .into_iter()
+                        .flat_map(|(maurer_decommitment, _)| {
+                            maurer_decommitment.statements.into_iter().map(|statement| {
+                                let (_, language_statement) = statement.into();
+
+                                language_statement
+                            })
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let parties = self.party.parties.clone();
+
+        let encrypted_masked_nonce_shares_commitment_round_parties =
+            self.party.initialize_proof_aggregation(
+                masks_and_encrypted_masked_key_share.clone(),
+                encrypted_nonce_shares_and_public_shares.clone(),
+                rng,
+            )?;
+
+        let (commitment, decommitment_round) = aggregation::commit(
+            Batch::new(encrypted_masked_nonce_shares_commitment_round_parties),
+            PresignOutputRound {
+                parties,
+                encrypted_secret_key_share: self.encrypted_secret_key_share,
+                centralized_party_nonce_shares_commitments_and_batched_proof: self
+                    .centralized_party_nonce_shares_commitments_and_batched_proof,
+                group_public_parameters: self.group_public_parameters,
+                output,
+                masks_and_encrypted_masked_key_share,
+                individual_encrypted_nonce_shares_and_public_shares,
+                encrypted_nonce_shares_and_public_shares,
+            },
+            rng,
+        )?;
+
+        Ok(Transition::Advance(commitment, decommitment_round))
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for PresignOutputRound<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        UnboundedEncDHWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+    encryption_of_tuple::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_tuple::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_tuple::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_tuple::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDHWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    /// The decommitments of all parties, and the aggregated proofs of the encryptions of the
+    /// masked nonces.
+    type Incoming = (
+        HashMap<
+            PartyID,
+            aggregation::Decommitment<
+                MaskedNoncesAggregation<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDHWitness,
+                    ProtocolContext,
+                >,
+            >,
+        >,
+        aggregation::AggregatedOutput<
+            MaskedNoncesAggregation<
+                SCALAR_LIMBS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDHWitness,
+                ProtocolContext,
+            >,
+        >,
+    );
+    /// The output of the presign protocol, from which the centralized party computes its presigns.
+    /// It is the same for all decentralized parties, so that the centralized party needs only one
+    /// of them.
+    type Message = decentralized_party::Output<
+        GroupElement::Value,
+        range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >,
+        homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        encryption_of_tuple::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDHWitness,
+            ProtocolContext,
+        >,
+        encryption_of_discrete_log::Proof<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    >;
+    type NextRound = core::convert::Infallible;
+    type Output = Vec<
+        Presign<
+            GroupElement::Value,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+        >,
+    >;
+    type Error = Error;
+
+    fn process(
+        self,
+        (decommitments, encrypted_masked_nonce_shares_aggregation_outputs): Self::Incoming,
+        _rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let encrypted_masked_nonce_shares: Vec<_> =
+            encrypted_masked_nonce_shares_aggregation_outputs
+                .into_iter()
+                .flat_map(|(_, encrypted_masked_nonce_shares)| encrypted_masked_nonce_shares)
+                .map(|encrypted_masked_nonce_share| {
+                    *encrypted_masked_nonce_share.language_statement()
+                })
+                .collect();
+
+        let individual_encrypted_masked_nonce_shares = decommitments
+            .into_iter()
+            .map(|(party_id, decommitments)| {
+                (
+                    party_id,
+                    decommitments
+                        .into_iter()
+                        .flat_map(|(maurer_decommitment, _)| {
+                            maurer_decommitment.statements.into_iter().map(|statement| {
+                                let (_, language_statement) = statement.into();
+
+                                language_statement
+                            })
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let presigns = Presign::new_batch::<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            ProtocolContext,
+        >(
+            self.parties,
+            self.centralized_party_nonce_shares_commitments_and_batched_proof,
+            self.masks_and_encrypted_masked_key_share,
+            self.individual_encrypted_nonce_shares_and_public_shares,
+            self.encrypted_nonce_shares_and_public_shares,
+            individual_encrypted_masked_nonce_shares,
+            encrypted_masked_nonce_shares,
+            &self.group_public_parameters,
+        )?
+        .into_iter()
+        .map(|presign| {
+            presign.bind_to_encrypted_secret_key_share(self.encrypted_secret_key_share.value())
+        })
+        .collect();
+
+        Ok(Transition::Finalize(self.output, presigns))
+    }
 }
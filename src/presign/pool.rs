@@ -0,0 +1,532 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Storage of presigns which enforces that each presign is used at most once.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+use merlin::Transcript;
+use proof::TranscriptProtocol;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    presign::{centralized_party, decentralized_party},
+    Error, Result,
+};
+
+pub mod file;
+pub mod in_memory;
+
+/// A stable identifier of a presign, derived from its contents.
+///
+/// Only the parts of the presign known to both parties are absorbed, so that the centralized and
+/// decentralized parties compute the same identifier for their respective presigns, which can
+/// then be used to agree on the presign of a signing session.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PresignId(pub [u8; 32]);
+
+impl fmt::Display for PresignId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// A presign that can be stored in a [`PresignPool`].
+pub trait IdentifiablePresign: Serialize + DeserializeOwned + Clone {
+    /// The stable identifier of this presign.
+    fn id(&self) -> Result<PresignId>;
+}
+
+fn presign_id<GroupElementValue: Serialize, CiphertextValue: Serialize>(
+    nonce_public_share: &GroupElementValue,
+    encrypted_mask: &CiphertextValue,
+    encrypted_masked_key_share: &CiphertextValue,
+) -> Result<PresignId> {
+    let mut transcript = Transcript::new(b"2PC-MPC presign identifier");
+
+    transcript.serialize_to_transcript_as_json(
+        b"decentralized party nonce public share",
+        nonce_public_share,
+    )?;
+    transcript.serialize_to_transcript_as_json(b"encrypted mask", encrypted_mask)?;
+    transcript.serialize_to_transcript_as_json(
+        b"encrypted masked key share",
+        encrypted_masked_key_share,
+    )?;
+
+    let mut id = [0u8; 32];
+    transcript.challenge_bytes(b"presign identifier", &mut id);
+
+    Ok(PresignId(id))
+}
+
+impl<GroupElementValue, ScalarValue, CiphertextValue> IdentifiablePresign
+    for centralized_party::Presign<GroupElementValue, ScalarValue, CiphertextValue>
+where
+    GroupElementValue: Serialize + DeserializeOwned + Clone,
+    ScalarValue: Serialize + DeserializeOwned + Clone,
+    CiphertextValue: Serialize + DeserializeOwned + Clone,
+{
+    fn id(&self) -> Result<PresignId> {
+        presign_id(
+            &self.decentralized_party_nonce_public_share,
+            &self.encrypted_mask,
+            &self.encrypted_masked_key_share,
+        )
+    }
+}
+
+impl<GroupElementValue, CiphertextValue> IdentifiablePresign
+    for decentralized_party::Presign<GroupElementValue, CiphertextValue>
+where
+    GroupElementValue: Serialize + DeserializeOwned + Clone,
+    CiphertextValue: Serialize + DeserializeOwned + Clone,
+{
+    fn id(&self) -> Result<PresignId> {
+        presign_id(
+            &self.nonce_public_share,
+            &self.encrypted_mask,
+            &self.encrypted_masked_key_share,
+        )
+    }
+}
+
+/// A pool of presigns which enforces that each of them is used for at most one signature.
+///
+/// A presign is first reserved for a signing session, which takes it out of the pool, and then
+/// consumed once the signing session is over. A reserved or consumed presign can never be reserved
+/// again, nor can it be re-inserted into the pool. All operations are atomic, so that concurrent
+/// signing sessions sharing a pool can never be handed the same presign.
+pub trait PresignPool<Presign: IdentifiablePresign> {
+    /// Insert a batch of presigns, e.g. as output by
+    /// [`centralized_party::proof_verification_round::Party::verify_presign_output`] or
+    /// [`decentralized_party::Presign::new_batch`], returning their identifiers in order.
+    ///
+    /// Fails without inserting any presign if any of them was already inserted.
+    fn insert_batch(&self, presigns: Vec<Presign>) -> Result<Vec<PresignId>>;
+
+    /// Reserve the presign identified by `id` for a signing session, e.g. the one chosen by the
+    /// other party.
+    fn reserve(&self, id: PresignId) -> Result<Presign>;
+
+    /// Reserve the oldest available presign for a signing session, if any.
+    fn reserve_next(&self) -> Result<Option<(PresignId, Presign)>>;
+
+    /// Mark a reserved presign as consumed.
+    fn consume(&self, id: PresignId) -> Result<()>;
+
+    /// Return a reserved presign to the pool.
+    ///
+    /// This must only be called if no message computed from the presign was ever sent, e.g. when
+    /// the signing session was aborted before its first round.
+    fn release(&self, id: PresignId) -> Result<()>;
+
+    /// The number of presigns available for reservation.
+    fn available(&self) -> Result<usize>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+enum PresignState<Presign> {
+    Available(Presign),
+    Reserved(Presign),
+    Consumed,
+}
+
+/// The bookkeeping shared by all [`PresignPool`] implementations.
+///
+/// Consumed presigns are kept as tombstones, without their secrets, so that they can never be
+/// re-inserted.
+#[derive(Clone, Debug)]
+struct Ledger<Presign> {
+    presigns: HashMap<PresignId, PresignState<Presign>>,
+    available: VecDeque<PresignId>,
+}
+
+impl<Presign> Default for Ledger<Presign> {
+    fn default() -> Self {
+        Self {
+            presigns: HashMap::new(),
+            available: VecDeque::new(),
+        }
+    }
+}
+
+impl<Presign: IdentifiablePresign> Ledger<Presign> {
+    fn insert_batch(&mut self, presigns: Vec<Presign>) -> Result<Vec<PresignId>> {
+        let presigns = presigns
+            .into_iter()
+            .map(|presign| presign.id().map(|id| (id, presign)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let ids: Vec<_> = presigns.iter().map(|(id, _)| *id).collect();
+
+        if ids.iter().collect::<HashSet<_>>().len() != ids.len()
+            || ids.iter().any(|id| self.presigns.contains_key(id))
+        {
+            return Err(Error::ReusedPresign);
+        }
+
+        presigns.into_iter().for_each(|(id, presign)| {
+            self.presigns.insert(id, PresignState::Available(presign));
+            self.available.push_back(id);
+        });
+
+        Ok(ids)
+    }
+
+    fn reserve(&mut self, id: PresignId) -> Result<Presign> {
+        let state = self.presigns.get_mut(&id).ok_or(Error::UnknownPresign)?;
+
+        match state {
+            PresignState::Available(presign) => {
+                let presign = presign.clone();
+                *state = PresignState::Reserved(presign.clone());
+                self.available.retain(|available_id| *available_id != id);
+
+                Ok(presign)
+            }
+            PresignState::Reserved(_) | PresignState::Consumed => Err(Error::ReusedPresign),
+        }
+    }
+
+    fn reserve_next(&mut self) -> Result<Option<(PresignId, Presign)>> {
+        self.available
+            .front()
+            .copied()
+            .map(|id| self.reserve(id).map(|presign| (id, presign)))
+            .transpose()
+    }
+
+    fn consume(&mut self, id: PresignId) -> Result<()> {
+        let state = self.presigns.get_mut(&id).ok_or(Error::UnknownPresign)?;
+
+        match state {
+            PresignState::Reserved(_) => {
+                *state = PresignState::Consumed;
+
+                Ok(())
+            }
+            PresignState::Available(_) => Err(Error::InvalidParameters),
+            PresignState::Consumed => Err(Error::ReusedPresign),
+        }
+    }
+
+    fn release(&mut self, id: PresignId) -> Result<()> {
+        let state = self.presigns.get_mut(&id).ok_or(Error::UnknownPresign)?;
+
+        match state {
+            PresignState::Reserved(presign) => {
+                *state = PresignState::Available(presign.clone());
+                self.available.push_front(id);
+
+                Ok(())
+            }
+            PresignState::Available(_) => Err(Error::InvalidParameters),
+            PresignState::Consumed => Err(Error::ReusedPresign),
+        }
+    }
+
+    fn available(&self) -> usize {
+        self.available.len()
+    }
+
+    /// Consume every reserved presign.
+    ///
+    /// Used when recovering from a crash, after which it is unknown whether any message computed
+    /// from a reserved presign was sent.
+    fn consume_reserved(&mut self) {
+        self.presigns
+            .values_mut()
+            .filter(|state| matches!(state, PresignState::Reserved(_)))
+            .for_each(|state| *state = PresignState::Consumed);
+    }
+
+    /// The entries of this ledger, with the available presigns first in their order of
+    /// reservation.
+    fn entries(&self) -> Vec<(PresignId, PresignState<Presign>)> {
+        let available: HashSet<_> = self.available.iter().collect();
+
+        self.available
+            .iter()
+            .chain(self.presigns.keys().filter(|id| !available.contains(id)))
+            .filter_map(|id| self.presigns.get(id).map(|state| (*id, state.clone())))
+            .collect()
+    }
+
+    fn from_entries(entries: Vec<(PresignId, PresignState<Presign>)>) -> Result<Self> {
+        let mut ledger = Self::default();
+
+        for (id, state) in entries {
+            if matches!(state, PresignState::Available(_)) {
+                ledger.available.push_back(id);
+            }
+
+            if ledger.presigns.insert(id, state).is_some() {
+                return Err(Error::InvalidParameters);
+            }
+        }
+
+        Ok(ledger)
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use group::secp256k1;
+    use homomorphic_encryption::GroupsPublicParametersAccessors;
+    use rand_core::{OsRng, RngCore};
+    use tiresias::test_exports::N;
+
+    use super::*;
+    use crate::{
+        dkg::tests::generates_distributed_key_internal,
+        presign::{
+            pool::{file::FilePresignPool, in_memory::InMemoryPresignPool},
+            tests::generates_presignatures_internal,
+        },
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+    };
+
+    type CentralizedPartyPresign = centralized_party::Presign<
+        secp256k1::group_element::Value,
+        secp256k1::Scalar,
+        tiresias::CiphertextSpaceValue,
+    >;
+
+    type DecentralizedPartyPresign = decentralized_party::Presign<
+        secp256k1::group_element::Value,
+        tiresias::CiphertextSpaceValue,
+    >;
+
+    fn presigns(
+        batch_size: usize,
+    ) -> (Vec<CentralizedPartyPresign>, Vec<DecentralizedPartyPresign>) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (_, decentralized_party_dkg_output) = generates_distributed_key_internal(2, 2);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presigns, _, decentralized_party_presigns) =
+            generates_presignatures_internal(2, 2, batch_size, encrypted_secret_key_share, false)
+                .unwrap();
+
+        (centralized_party_presigns, decentralized_party_presigns)
+    }
+
+    fn pool_path() -> PathBuf {
+        env::temp_dir().join(format!("presign-pool-{:016x}.json", OsRng.next_u64()))
+    }
+
+    fn refuses_reuse<Presign: IdentifiablePresign + PartialEq + fmt::Debug>(
+        pool: impl PresignPool<Presign>,
+        presigns: Vec<Presign>,
+    ) {
+        let ids = pool.insert_batch(presigns.clone()).unwrap();
+        assert_eq!(pool.available().unwrap(), presigns.len());
+
+        assert!(
+            matches!(
+                pool.insert_batch(vec![presigns[0].clone()]).err().unwrap(),
+                Error::ReusedPresign
+            ),
+            "presigns should not be inserted twice"
+        );
+
+        let (id, presign) = pool.reserve_next().unwrap().unwrap();
+        assert_eq!((id, &presign), (ids[0], &presigns[0]));
+
+        assert!(
+            matches!(pool.reserve(id).err().unwrap(), Error::ReusedPresign),
+            "reserved presigns should not be reserved again"
+        );
+
+        pool.release(id).unwrap();
+        assert_eq!(pool.reserve(id).unwrap(), presign);
+        pool.consume(id).unwrap();
+
+        assert!(
+            matches!(pool.reserve(id).err().unwrap(), Error::ReusedPresign),
+            "consumed presigns should not be reserved again"
+        );
+        assert!(
+            matches!(pool.release(id).err().unwrap(), Error::ReusedPresign),
+            "consumed presigns should not be released"
+        );
+        assert!(
+            matches!(
+                pool.insert_batch(vec![presign]).err().unwrap(),
+                Error::ReusedPresign
+            ),
+            "consumed presigns should not be re-inserted"
+        );
+        assert!(
+            matches!(
+                pool.consume(ids[1]).err().unwrap(),
+                Error::InvalidParameters
+            ),
+            "presigns should be reserved before being consumed"
+        );
+        assert!(
+            matches!(
+                pool.reserve(PresignId([0; 32])).err().unwrap(),
+                Error::UnknownPresign
+            ),
+            "unknown presigns should not be reserved"
+        );
+
+        ids.into_iter().skip(1).for_each(|id| {
+            pool.reserve(id).unwrap();
+            pool.consume(id).unwrap();
+        });
+
+        assert_eq!(pool.available().unwrap(), 0);
+        assert!(pool.reserve_next().unwrap().is_none());
+    }
+
+    #[test]
+    fn parties_agree_on_presign_ids() {
+        let (centralized_party_presigns, decentralized_party_presigns) = presigns(3);
+
+        let centralized_party_ids = centralized_party_presigns
+            .iter()
+            .map(|presign| presign.id().unwrap())
+            .collect::<Vec<_>>();
+
+        let decentralized_party_ids = decentralized_party_presigns
+            .iter()
+            .map(|presign| presign.id().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(centralized_party_ids, decentralized_party_ids);
+        assert_eq!(
+            centralized_party_ids.iter().collect::<HashSet<_>>().len(),
+            3
+        );
+    }
+
+    #[test]
+    fn in_memory_pool_refuses_reuse() {
+        let (centralized_party_presigns, decentralized_party_presigns) = presigns(3);
+
+        refuses_reuse(InMemoryPresignPool::new(), centralized_party_presigns);
+        refuses_reuse(InMemoryPresignPool::new(), decentralized_party_presigns);
+    }
+
+    #[test]
+    fn file_pool_refuses_reuse() {
+        let (centralized_party_presigns, decentralized_party_presigns) = presigns(3);
+
+        let path = pool_path();
+        refuses_reuse(
+            FilePresignPool::open(&path).unwrap(),
+            centralized_party_presigns,
+        );
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(file::lock_path(&path)).unwrap();
+
+        let path = pool_path();
+        refuses_reuse(
+            FilePresignPool::open(&path).unwrap(),
+            decentralized_party_presigns,
+        );
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(file::lock_path(&path)).unwrap();
+    }
+
+    #[test]
+    fn file_pool_is_crash_safe() {
+        let (_, presigns) = presigns(4);
+        let path = pool_path();
+
+        let pool = FilePresignPool::open(&path).unwrap();
+        let ids = pool.insert_batch(presigns.clone()).unwrap();
+
+        pool.reserve(ids[0]).unwrap();
+        pool.consume(ids[0]).unwrap();
+        let (reserved_id, _) = pool.reserve_next().unwrap().unwrap();
+        assert_eq!(reserved_id, ids[1]);
+
+        // Crash while a signing session is in progress, and while writing an update.
+        drop(pool);
+        fs::write(file::temporary_path(&path), b"{\"pres").unwrap();
+
+        let pool = FilePresignPool::<DecentralizedPartyPresign>::open(&path).unwrap();
+        assert_eq!(pool.available().unwrap(), 2);
+
+        assert!(
+            matches!(pool.reserve(ids[0]).err().unwrap(), Error::ReusedPresign),
+            "consumed presigns should remain consumed after a crash"
+        );
+        assert!(
+            matches!(pool.reserve(ids[1]).err().unwrap(), Error::ReusedPresign),
+            "presigns reserved prior to a crash should never be reserved again"
+        );
+        assert!(
+            matches!(
+                pool.insert_batch(vec![presigns[1].clone()]).err().unwrap(),
+                Error::ReusedPresign
+            ),
+            "presigns reserved prior to a crash should never be re-inserted"
+        );
+
+        assert_eq!(
+            pool.reserve_next().unwrap().unwrap(),
+            (ids[2], presigns[2].clone())
+        );
+        assert_eq!(pool.reserve(ids[3]).unwrap(), presigns[3]);
+
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(file::lock_path(&path)).unwrap();
+    }
+
+    #[test]
+    fn file_pool_is_exclusive() {
+        let (_, presigns) = presigns(1);
+        let path = pool_path();
+
+        let pool = FilePresignPool::<DecentralizedPartyPresign>::open(&path).unwrap();
+        let ids = pool.insert_batch(presigns.clone()).unwrap();
+
+        assert!(
+            matches!(
+                FilePresignPool::<DecentralizedPartyPresign>::open(&path)
+                    .err()
+                    .unwrap(),
+                Error::PresignPoolInUse
+            ),
+            "a pool should not be opened twice, lest both hand out the same presign"
+        );
+
+        assert_eq!(pool.reserve(ids[0]).unwrap(), presigns[0]);
+
+        // The lock is released once the pool is dropped.
+        drop(pool);
+        let pool = FilePresignPool::<DecentralizedPartyPresign>::open(&path).unwrap();
+
+        assert!(
+            matches!(pool.reserve(ids[0]).err().unwrap(), Error::ReusedPresign),
+            "presigns reserved by a previous holder of the lock should never be reserved again"
+        );
+
+        drop(pool);
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(file::lock_path(&path)).unwrap();
+    }
+}
@@ -0,0 +1,173 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use std::{
+    fs,
+    fs::{File, OpenOptions, TryLockError},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    presign::pool::{IdentifiablePresign, Ledger, PresignId, PresignPool, PresignState},
+    Error, Result,
+};
+
+/// A [`PresignPool`] persisted to a file, which survives crashes.
+///
+/// Every operation is written to the file before it returns, by atomically replacing the file
+/// with its updated contents. Presigns which were reserved when the process crashed are consumed
+/// when the pool is re-opened, as messages computed from them might have been sent.
+///
+/// The file holds the presigns' secret nonce shares in the clear, and must therefore be protected
+/// as key material.
+///
+/// The pool holds an exclusive lock on a lock file alongside it for as long as it is open, so that
+/// no two pools (e.g. of two processes) ever hand out the same presign.
+pub struct FilePresignPool<Presign> {
+    path: PathBuf,
+    // Released when the pool is dropped, or when the process exits.
+    _lock: File,
+    ledger: Mutex<Ledger<Presign>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot<Presign> {
+    presigns: Vec<(PresignId, PresignState<Presign>)>,
+}
+
+/// The path of the temporary file to which updates of the pool at `path` are first written.
+pub(super) fn temporary_path(path: &Path) -> PathBuf {
+    let mut temporary_path = path.as_os_str().to_owned();
+    temporary_path.push(".tmp");
+
+    temporary_path.into()
+}
+
+/// The path of the lock file which is held by the pool at `path` while it is open.
+pub(super) fn lock_path(path: &Path) -> PathBuf {
+    let mut lock_path = path.as_os_str().to_owned();
+    lock_path.push(".lock");
+
+    lock_path.into()
+}
+
+impl<Presign: IdentifiablePresign> FilePresignPool<Presign> {
+    /// Open the pool persisted at `path`, creating it if it does not exist.
+    ///
+    /// Fails with [`Error::PresignPoolInUse`] if the pool is already open.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let lock = lock(&path)?;
+
+        let mut ledger = if path.exists() {
+            let snapshot: Snapshot<Presign> = serde_json::from_slice(&fs::read(&path)?)?;
+
+            Ledger::from_entries(snapshot.presigns)?
+        } else {
+            Ledger::default()
+        };
+
+        ledger.consume_reserved();
+        persist(&path, &ledger)?;
+
+        Ok(Self {
+            path,
+            _lock: lock,
+            ledger: Mutex::new(ledger),
+        })
+    }
+
+    /// Apply `operation` to the ledger, and persist its outcome before making it visible.
+    fn update<T>(&self, operation: impl FnOnce(&mut Ledger<Presign>) -> Result<T>) -> Result<T> {
+        let mut ledger = self.ledger()?;
+
+        let mut updated_ledger = ledger.clone();
+        let output = operation(&mut updated_ledger)?;
+        persist(&self.path, &updated_ledger)?;
+        *ledger = updated_ledger;
+
+        Ok(output)
+    }
+
+    fn ledger(&self) -> Result<MutexGuard<'_, Ledger<Presign>>> {
+        self.ledger.lock().map_err(|_| Error::InternalError)
+    }
+}
+
+/// Take an exclusive lock on the lock file of the pool at `path`, without blocking.
+fn lock(path: &Path) -> Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(false);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let file = options.open(lock_path(path))?;
+
+    match file.try_lock() {
+        Ok(()) => Ok(file),
+        Err(TryLockError::WouldBlock) => Err(Error::PresignPoolInUse),
+        Err(TryLockError::Error(error)) => Err(error.into()),
+    }
+}
+
+/// Persist `ledger` to `path` by writing it to a temporary file, syncing it, and then renaming it
+/// over `path`, so that a crash at any point leaves either the previous or the updated contents.
+fn persist<Presign: IdentifiablePresign>(path: &Path, ledger: &Ledger<Presign>) -> Result<()> {
+    let snapshot = Snapshot {
+        presigns: ledger.entries(),
+    };
+
+    let temporary_path = temporary_path(path);
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(&temporary_path)?;
+    file.write_all(&serde_json::to_vec(&snapshot)?)?;
+    file.sync_all()?;
+
+    fs::rename(&temporary_path, path)?;
+
+    #[cfg(unix)]
+    if let Some(directory) = path
+        .parent()
+        .filter(|directory| !directory.as_os_str().is_empty())
+    {
+        fs::File::open(directory)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+impl<Presign: IdentifiablePresign> PresignPool<Presign> for FilePresignPool<Presign> {
+    fn insert_batch(&self, presigns: Vec<Presign>) -> Result<Vec<PresignId>> {
+        self.update(|ledger| ledger.insert_batch(presigns))
+    }
+
+    fn reserve(&self, id: PresignId) -> Result<Presign> {
+        self.update(|ledger| ledger.reserve(id))
+    }
+
+    fn reserve_next(&self) -> Result<Option<(PresignId, Presign)>> {
+        self.update(|ledger| ledger.reserve_next())
+    }
+
+    fn consume(&self, id: PresignId) -> Result<()> {
+        self.update(|ledger| ledger.consume(id))
+    }
+
+    fn release(&self, id: PresignId) -> Result<()> {
+        self.update(|ledger| ledger.release(id))
+    }
+
+    fn available(&self) -> Result<usize> {
+        Ok(self.ledger()?.available())
+    }
+}
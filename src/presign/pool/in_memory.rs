@@ -0,0 +1,58 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    presign::pool::{IdentifiablePresign, Ledger, PresignId, PresignPool},
+    Error, Result,
+};
+
+/// A [`PresignPool`] held in memory, which is lost when the process exits.
+pub struct InMemoryPresignPool<Presign> {
+    ledger: Mutex<Ledger<Presign>>,
+}
+
+impl<Presign> InMemoryPresignPool<Presign> {
+    pub fn new() -> Self {
+        Self {
+            ledger: Mutex::new(Ledger::default()),
+        }
+    }
+
+    fn ledger(&self) -> Result<MutexGuard<'_, Ledger<Presign>>> {
+        self.ledger.lock().map_err(|_| Error::InternalError)
+    }
+}
+
+impl<Presign> Default for InMemoryPresignPool<Presign> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Presign: IdentifiablePresign> PresignPool<Presign> for InMemoryPresignPool<Presign> {
+    fn insert_batch(&self, presigns: Vec<Presign>) -> Result<Vec<PresignId>> {
+        self.ledger()?.insert_batch(presigns)
+    }
+
+    fn reserve(&self, id: PresignId) -> Result<Presign> {
+        self.ledger()?.reserve(id)
+    }
+
+    fn reserve_next(&self) -> Result<Option<(PresignId, Presign)>> {
+        self.ledger()?.reserve_next()
+    }
+
+    fn consume(&self, id: PresignId) -> Result<()> {
+        self.ledger()?.consume(id)
+    }
+
+    fn release(&self, id: PresignId) -> Result<()> {
+        self.ledger()?.release(id)
+    }
+
+    fn available(&self) -> Result<usize> {
+        Ok(self.ledger()?.available())
+    }
+}
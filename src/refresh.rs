@@ -0,0 +1,420 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Proactive refresh of the key shares output by the DKG, keeping the public key unchanged.
+
+pub mod centralized_party;
+pub mod decentralized_party;
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+pub(crate) mod tests {
+    use crypto_bigint::NonZero;
+    use group::{secp256k1, GroupElement as _, KnownOrderGroupElement, PartyID, Reduce as _};
+    use homomorphic_encryption::{
+        AdditivelyHomomorphicDecryptionKey, GroupsPublicParametersAccessors,
+    };
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::test_exports::{N, SECRET_KEY};
+
+    use crate::{
+        dkg,
+        dkg::tests::generates_distributed_key_internal,
+        presign::tests::generates_presignatures_internal,
+        secp256k1::paillier::bulletproofs::{
+            ProtocolPublicParameters, RefreshCommitmentRoundParty,
+            RefreshDecommitmentProofVerificationRoundParty, RefreshKeyShareRoundParty,
+            SignatureHomomorphicEvaluationParty, SignaturePartialDecryptionParty,
+        },
+        session::{tests::session_context, Protocol},
+        sign::tests::{presign_signs_internal, setup_decryption_key_shares},
+        Error,
+    };
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(2, 4)]
+    #[case(6, 9)]
+    fn refreshes_secret_key_share(#[case] threshold: PartyID, #[case] number_of_parties: PartyID) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presigns, _, decentralized_party_presigns) =
+            generates_presignatures_internal(
+                threshold,
+                number_of_parties,
+                1,
+                encrypted_secret_key_share,
+                false,
+            )
+            .unwrap();
+
+        let (centralized_party_refreshed_output, decentralized_party_refreshed_output) =
+            refreshes_secret_key_share_internal(
+                centralized_party_dkg_output.clone(),
+                decentralized_party_dkg_output.clone(),
+            );
+
+        assert_eq!(
+            centralized_party_refreshed_output.public_key,
+            centralized_party_dkg_output.public_key
+        );
+
+        assert_eq!(
+            decentralized_party_refreshed_output.public_key,
+            decentralized_party_dkg_output.public_key
+        );
+
+        assert_ne!(
+            centralized_party_refreshed_output.secret_key_share,
+            centralized_party_dkg_output.secret_key_share
+        );
+
+        assert_ne!(
+            decentralized_party_refreshed_output.encrypted_secret_key_share,
+            decentralized_party_dkg_output.encrypted_secret_key_share
+        );
+
+        assert_eq!(centralized_party_refreshed_output.key_share_updates, 1);
+        assert_eq!(decentralized_party_refreshed_output.key_share_updates, 1);
+
+        let paillier_decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let group_order = secp256k1::Scalar::order_from_public_parameters(
+            &protocol_public_parameters.scalar_group_public_parameters,
+        );
+
+        let group_order = Option::<_>::from(NonZero::new(group_order)).unwrap();
+
+        let decentralized_party_secret_key_share = paillier_decryption_key
+            .decrypt(
+                &tiresias::CiphertextSpaceGroupElement::new(
+                    decentralized_party_refreshed_output.encrypted_secret_key_share,
+                    protocol_public_parameters
+                        .encryption_scheme_public_parameters
+                        .ciphertext_space_public_parameters(),
+                )
+                .unwrap(),
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+            )
+            .unwrap();
+
+        let decentralized_party_secret_key_share = secp256k1::Scalar::new(
+            decentralized_party_secret_key_share
+                .value()
+                .reduce(&group_order)
+                .into(),
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let secret_key_share = secp256k1::Scalar::new(
+            centralized_party_refreshed_output.secret_key_share,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let public_key = secp256k1::GroupElement::new(
+            decentralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        assert_eq!(
+            (secret_key_share + decentralized_party_secret_key_share) * public_key.generator(),
+            public_key,
+            "refreshed secret key shares should sum to the same secret key"
+        );
+
+        assert!(
+            matches!(
+                SignatureHomomorphicEvaluationParty::new(
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    centralized_party_refreshed_output.clone(),
+                    centralized_party_presigns.first().unwrap().clone(),
+                )
+                .err()
+                .unwrap(),
+                Error::StalePresign
+            ),
+            "presigns generated prior to a refresh should be rejected by the centralized party"
+        );
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, _) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let decryption_key_share = decryption_key_shares.into_values().next().unwrap();
+
+        assert!(
+            matches!(
+                SignaturePartialDecryptionParty::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters,
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_refreshed_output.clone(),
+                    decentralized_party_presigns.first().unwrap().clone(),
+                )
+                .err()
+                .unwrap(),
+                Error::StalePresign
+            ),
+            "presigns generated prior to a refresh should be rejected by the decentralized party"
+        );
+
+        presign_signs_internal(
+            threshold,
+            number_of_parties,
+            1,
+            centralized_party_refreshed_output,
+            decentralized_party_refreshed_output,
+        );
+    }
+
+    #[test]
+    fn rejects_mismatching_delta_encryption() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 2);
+
+        let (commitment, decommitment_round_party) = RefreshCommitmentRoundParty::new(
+            session_context(Protocol::Refresh),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output.clone(),
+        )
+        .unwrap()
+        .sample_and_commit_delta(&mut OsRng)
+        .unwrap();
+
+        let (key_share, decommitment_proof_verification_round_party) =
+            RefreshKeyShareRoundParty::new(
+                session_context(Protocol::Refresh),
+                protocol_public_parameters.clone(),
+                decentralized_party_dkg_output,
+            )
+            .unwrap()
+            .receive_commitment_and_send_key_share(commitment);
+
+        let (delta_decommitment_and_proof, _) = decommitment_round_party
+            .decommit_and_prove_delta(key_share.clone(), &mut OsRng)
+            .unwrap();
+
+        let (_, other_decommitment_round_party) = RefreshCommitmentRoundParty::new(
+            session_context(Protocol::Refresh),
+            protocol_public_parameters,
+            centralized_party_dkg_output,
+        )
+        .unwrap()
+        .sample_and_commit_delta(&mut OsRng)
+        .unwrap();
+
+        let (other_delta_decommitment_and_proof, _) = other_decommitment_round_party
+            .decommit_and_prove_delta(key_share, &mut OsRng)
+            .unwrap();
+
+        let mut mismatching_delta_decommitment_and_proof = delta_decommitment_and_proof;
+        mismatching_delta_decommitment_and_proof.encrypted_negated_delta =
+            other_delta_decommitment_and_proof.encrypted_negated_delta;
+
+        assert!(
+            decommitment_proof_verification_round_party
+                .verify_decommitment_and_proof_and_refresh_secret_key_share(
+                    mismatching_delta_decommitment_and_proof,
+                    &mut OsRng,
+                )
+                .is_err(),
+            "a delta encryption that does not match the committed public share should be rejected"
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_decommitment() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 2);
+
+        let (commitment, _) = RefreshCommitmentRoundParty::new(
+            session_context(Protocol::Refresh),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output.clone(),
+        )
+        .unwrap()
+        .sample_and_commit_delta(&mut OsRng)
+        .unwrap();
+
+        let (key_share, decommitment_proof_verification_round_party) =
+            RefreshKeyShareRoundParty::new(
+                session_context(Protocol::Refresh),
+                protocol_public_parameters.clone(),
+                decentralized_party_dkg_output,
+            )
+            .unwrap()
+            .receive_commitment_and_send_key_share(commitment);
+
+        let (_, other_decommitment_round_party) = RefreshCommitmentRoundParty::new(
+            session_context(Protocol::Refresh),
+            protocol_public_parameters,
+            centralized_party_dkg_output,
+        )
+        .unwrap()
+        .sample_and_commit_delta(&mut OsRng)
+        .unwrap();
+
+        let (other_delta_decommitment_and_proof, _) = other_decommitment_round_party
+            .decommit_and_prove_delta(key_share, &mut OsRng)
+            .unwrap();
+
+        assert!(
+            matches!(
+                decommitment_proof_verification_round_party
+                    .verify_decommitment_and_proof_and_refresh_secret_key_share(
+                        other_delta_decommitment_and_proof,
+                        &mut OsRng,
+                    )
+                    .err()
+                    .unwrap(),
+                Error::WrongDecommitment
+            ),
+            "a delta other than the committed one should be rejected"
+        );
+    }
+
+    #[test]
+    fn rejects_mismatching_key_share() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 2);
+
+        let (_, decentralized_party_refreshed_output) = refreshes_secret_key_share_internal(
+            centralized_party_dkg_output.clone(),
+            decentralized_party_dkg_output,
+        );
+
+        let (commitment, decommitment_round_party) = RefreshCommitmentRoundParty::new(
+            session_context(Protocol::Refresh),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output,
+        )
+        .unwrap()
+        .sample_and_commit_delta(&mut OsRng)
+        .unwrap();
+
+        let (refreshed_key_share, _) = RefreshKeyShareRoundParty::new(
+            session_context(Protocol::Refresh),
+            protocol_public_parameters,
+            decentralized_party_refreshed_output,
+        )
+        .unwrap()
+        .receive_commitment_and_send_key_share(commitment);
+
+        assert!(
+            matches!(
+                decommitment_round_party
+                    .decommit_and_prove_delta(refreshed_key_share, &mut OsRng)
+                    .err()
+                    .unwrap(),
+                Error::InvalidParameters
+            ),
+            "the centralized party should not decommit to a refresh of another key share"
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn refreshes_secret_key_share_internal(
+        centralized_party_dkg_output: dkg::centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        decentralized_party_dkg_output: dkg::decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+    ) -> (
+        dkg::centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        dkg::decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (commitment, decommitment_round_party) = RefreshCommitmentRoundParty::new(
+            session_context(Protocol::Refresh),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output,
+        )
+        .unwrap()
+        .sample_and_commit_delta(&mut OsRng)
+        .unwrap();
+
+        let (key_share, decommitment_proof_verification_round_party) =
+            RefreshKeyShareRoundParty::new(
+                session_context(Protocol::Refresh),
+                protocol_public_parameters,
+                decentralized_party_dkg_output,
+            )
+            .unwrap()
+            .receive_commitment_and_send_key_share(commitment);
+
+        let (delta_decommitment_and_proof, centralized_party_refreshed_output) =
+            decommitment_round_party
+                .decommit_and_prove_delta(key_share, &mut OsRng)
+                .unwrap();
+
+        let decentralized_party_refreshed_output = decommitment_proof_verification_round_party
+            .verify_decommitment_and_proof_and_refresh_secret_key_share(
+                delta_decommitment_and_proof,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert_eq!(
+            centralized_party_refreshed_output.encrypted_decentralized_party_secret_key_share,
+            decentralized_party_refreshed_output.encrypted_secret_key_share
+        );
+
+        assert_eq!(
+            centralized_party_refreshed_output.public_key_share,
+            decentralized_party_refreshed_output.centralized_party_public_key_share
+        );
+
+        assert_eq!(
+            centralized_party_refreshed_output.decentralized_party_public_key_share,
+            decentralized_party_refreshed_output.public_key_share
+        );
+
+        (
+            centralized_party_refreshed_output,
+            decentralized_party_refreshed_output,
+        )
+    }
+}
@@ -0,0 +1,200 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use std::ops::Neg;
+
+use commitment::Commitment;
+use crypto_bigint::{rand_core::CryptoRngCore, Random};
+use group::{ComputationalSecuritySizedNumber, GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use proof::AggregatableRangeProof;
+
+use crate::{
+    dkg::{self, centralized_party::commitment_round::commit_public_key_share},
+    refresh::centralized_party::decommitment_round,
+    session::RoundContext,
+    ProtocolPublicParameters, CENTRALIZED_PARTY_ID,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    protocol_context: ProtocolContext,
+    scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: GroupElement::PublicParameters,
+    encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    secret_key_share: GroupElement::Scalar,
+    public_key_share: GroupElement,
+    public_key: GroupElement,
+    encrypted_decentralized_party_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    decentralized_party_public_key_share: GroupElement,
+    key_share_updates: u32,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+{
+    /// Sample a random $\delta$ and commit to $-\delta \cdot G$, as in the commitment round of the
+    /// DKG.
+    ///
+    /// The commitment is sent before the decentralized party reveals the key share it is about to
+    /// refresh, so that $\delta$ is fixed independently of it.
+    pub fn sample_and_commit_delta(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        Commitment,
+        decommitment_round::Party<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    )> {
+        let delta = GroupElement::Scalar::sample(&self.scalar_group_public_parameters, rng)?;
+
+        let generator = GroupElement::new(
+            GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            &self.group_public_parameters,
+        )?;
+        let public_negated_delta = delta.neg() * generator;
+
+        let commitment_randomness = ComputationalSecuritySizedNumber::random(rng);
+
+        let commitment = commit_public_key_share(
+            CENTRALIZED_PARTY_ID,
+            &public_negated_delta,
+            &commitment_randomness,
+        )?;
+
+        let party = decommitment_round::Party {
+            protocol_context: self.protocol_context,
+            scalar_group_public_parameters: self.scalar_group_public_parameters,
+            group_public_parameters: self.group_public_parameters,
+            encryption_scheme_public_parameters: self.encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: self
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: self.range_proof_public_parameters,
+            secret_key_share: self.secret_key_share,
+            public_key_share: self.public_key_share,
+            public_key: self.public_key,
+            encrypted_decentralized_party_secret_key_share: self
+                .encrypted_decentralized_party_secret_key_share,
+            decentralized_party_public_key_share: self.decentralized_party_public_key_share,
+            key_share_updates: self.key_share_updates,
+            delta,
+            public_negated_delta,
+            commitment_randomness,
+        };
+
+        Ok((commitment, party))
+    }
+
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<Self> {
+        let scalar_group_public_parameters =
+            protocol_public_parameters.scalar_group_public_parameters;
+        let group_public_parameters = protocol_public_parameters.group_public_parameters;
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let secret_key_share = GroupElement::Scalar::new(
+            dkg_output.secret_key_share,
+            &scalar_group_public_parameters,
+        )?;
+
+        let public_key_share =
+            GroupElement::new(dkg_output.public_key_share, &group_public_parameters)?;
+
+        let public_key = GroupElement::new(dkg_output.public_key, &group_public_parameters)?;
+
+        let encrypted_decentralized_party_secret_key_share =
+            EncryptionKey::CiphertextSpaceGroupElement::new(
+                dkg_output.encrypted_decentralized_party_secret_key_share,
+                encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+            )?;
+
+        let decentralized_party_public_key_share = GroupElement::new(
+            dkg_output.decentralized_party_public_key_share,
+            &group_public_parameters,
+        )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            secret_key_share,
+            public_key_share,
+            public_key,
+            encrypted_decentralized_party_secret_key_share,
+            decentralized_party_public_key_share,
+            key_share_updates: dkg_output.key_share_updates,
+        })
+    }
+}
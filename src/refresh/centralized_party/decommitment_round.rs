@@ -0,0 +1,292 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use std::ops::Neg;
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use enhanced_maurer::{
+    encryption_of_discrete_log, encryption_of_discrete_log::StatementAccessors as _,
+    language::EnhancedLanguageStatementAccessors as _, EnhanceableLanguage, EnhancedLanguage,
+    EnhancedPublicParameters,
+};
+use group::{ComputationalSecuritySizedNumber, GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, AggregatableRangeProof};
+
+use crate::{
+    dkg,
+    refresh::{centralized_party::DeltaDecommitmentAndProof, decentralized_party},
+    session::{RoundContext, RoundId},
+    Error,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) secret_key_share: GroupElement::Scalar,
+    pub(super) public_key_share: GroupElement,
+    pub(super) public_key: GroupElement,
+    pub(super) encrypted_decentralized_party_secret_key_share:
+        EncryptionKey::CiphertextSpaceGroupElement,
+    pub(super) decentralized_party_public_key_share: GroupElement,
+    pub(super) key_share_updates: u32,
+    pub(super) delta: GroupElement::Scalar,
+    pub(super) public_negated_delta: GroupElement,
+    pub(super) commitment_randomness: ComputationalSecuritySizedNumber,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Verify the decentralized party is about to refresh the same key share as we are, then
+    /// decommit $-\delta \cdot G$ and prove the encryption of $-\delta$, which the decentralized
+    /// party homomorphically adds to its encrypted secret key share whilst we add $\delta$ to
+    /// ours, so that the public key stays the same.
+    ///
+    /// We encrypt $-\delta \bmod q$ rather than subtracting an encryption of $\delta$, so that the
+    /// plaintext stays non-negative: it grows by less than $q$ per refresh, which is accounted for
+    /// by [`dkg::count_key_share_update`].
+    ///
+    /// The refreshed output must only replace the current one once the decentralized party has
+    /// accepted the refresh, otherwise the two parties would end up holding mismatching shares.
+    pub fn decommit_and_prove_delta(
+        self,
+        key_share: decentralized_party::KeyShare<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        DeltaDecommitmentAndProof<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+        dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    )> {
+        if key_share.public_key_share != self.decentralized_party_public_key_share.value()
+            || key_share.encrypted_secret_key_share
+                != self.encrypted_decentralized_party_secret_key_share.value()
+            || key_share.key_share_updates != self.key_share_updates
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        let key_share_updates = dkg::count_key_share_update(self.key_share_updates)?;
+
+        let negated_delta = self.delta.neg();
+
+        let encryption_randomness = EncryptionKey::RandomnessSpaceGroupElement::sample(
+            &self
+                .encryption_scheme_public_parameters
+                .as_ref()
+                .randomness_space_public_parameters,
+            rng,
+        )?;
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters.clone(),
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters.clone(),
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = EnhancedPublicParameters::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        let negated_delta_value: Uint<SCALAR_LIMBS> = negated_delta.into();
+
+        let witness = EnhancedLanguage::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::generate_witness(
+            (
+                EncryptionKey::PlaintextSpaceGroupElement::new(
+                    Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(&negated_delta_value).into(),
+                    self.encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )?,
+                encryption_randomness,
+            )
+                .into(),
+            &language_public_parameters,
+            rng,
+        )?;
+
+        let (encryption_of_negated_delta_proof, statements) = encryption_of_discrete_log::Proof::<
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >::prove(
+            &self.protocol_context.round(RoundId::RefreshDecommitment),
+            &language_public_parameters,
+            vec![witness],
+            rng,
+        )?;
+
+        let statement = statements.first().ok_or(Error::InternalError)?.clone();
+
+        let encrypted_negated_delta = statement
+            .language_statement()
+            .encrypted_discrete_log()
+            .clone();
+
+        let delta_decommitment_and_proof = DeltaDecommitmentAndProof::new(
+            self.commitment_randomness,
+            statement,
+            encryption_of_negated_delta_proof,
+        );
+
+        let public_negated_delta = self.public_negated_delta;
+        let secret_key_share = self.secret_key_share + self.delta;
+        let public_key_share = self.public_key_share + public_negated_delta.clone().neg();
+        let decentralized_party_public_key_share =
+            self.decentralized_party_public_key_share + public_negated_delta;
+        let encrypted_decentralized_party_secret_key_share =
+            self.encrypted_decentralized_party_secret_key_share + encrypted_negated_delta;
+
+        let output = dkg::centralized_party::Output {
+            secret_key_share: secret_key_share.value(),
+            public_key_share: public_key_share.value(),
+            public_key: self.public_key.value(),
+            encrypted_decentralized_party_secret_key_share:
+                encrypted_decentralized_party_secret_key_share.value(),
+            decentralized_party_public_key_share: decentralized_party_public_key_share.value(),
+            key_share_updates,
+        };
+
+        Ok((delta_decommitment_and_proof, output))
+    }
+}
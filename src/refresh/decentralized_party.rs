@@ -0,0 +1,17 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use serde::{Deserialize, Serialize};
+
+pub mod decommitment_proof_verification_round;
+pub mod key_share_round;
+
+/// The message sent by the decentralized party to the centralized party in the refresh protocol,
+/// once it received its commitment: the key share it is about to refresh, for the centralized
+/// party to verify both parties refresh the same one before decommitting.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeyShare<GroupElementValue, CiphertextValue> {
+    pub(in crate::refresh) public_key_share: GroupElementValue,
+    pub(in crate::refresh) encrypted_secret_key_share: CiphertextValue,
+    pub(in crate::refresh) key_share_updates: u32,
+}
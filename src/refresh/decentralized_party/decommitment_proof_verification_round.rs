@@ -0,0 +1,231 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use std::ops::Neg;
+
+use commitment::{Commitment, GroupsPublicParametersAccessors as _};
+use crypto_bigint::rand_core::CryptoRngCore;
+use enhanced_maurer::{encryption_of_discrete_log, EnhanceableLanguage};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, range::PublicParametersAccessors, AggregatableRangeProof};
+
+use crate::{
+    dkg::{self, centralized_party::commitment_round::commit_public_key_share},
+    refresh::centralized_party::DeltaDecommitmentAndProof,
+    session::{RoundContext, RoundId},
+    Error, CENTRALIZED_PARTY_ID,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) public_key_share: GroupElement,
+    pub(super) public_key: GroupElement,
+    pub(super) encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    pub(super) centralized_party_public_key_share: GroupElement,
+    pub(super) key_share_updates: u32,
+    pub(super) commitment_to_public_negated_delta: Commitment,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Verify the centralized party's decommitment and proof, and homomorphically add the
+    /// encryption of $-\delta$ to our encrypted secret key share, shifting the public key shares
+    /// accordingly.
+    ///
+    /// The public key is left unchanged, whereas presigns generated prior to the refresh mask the
+    /// previous encrypted secret key share and can no longer yield valid signatures.
+    pub fn verify_decommitment_and_proof_and_refresh_secret_key_share(
+        self,
+        delta_decommitment_and_proof: DeltaDecommitmentAndProof<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    > {
+        let key_share_updates = dkg::count_key_share_update(self.key_share_updates)?;
+
+        let encrypted_negated_delta = EncryptionKey::CiphertextSpaceGroupElement::new(
+            delta_decommitment_and_proof.encrypted_negated_delta,
+            self.encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )?;
+
+        let public_negated_delta = GroupElement::new(
+            delta_decommitment_and_proof.public_negated_delta,
+            &self.group_public_parameters,
+        )?;
+
+        let reconstructed_commitment = commit_public_key_share(
+            CENTRALIZED_PARTY_ID,
+            &public_negated_delta,
+            &delta_decommitment_and_proof.commitment_randomness,
+        )?;
+
+        if reconstructed_commitment != self.commitment_to_public_negated_delta {
+            return Err(Error::WrongDecommitment);
+        }
+
+        let range_proof_commitment = range::CommitmentSchemeCommitmentSpaceGroupElement::<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RangeProof,
+        >::new(
+            delta_decommitment_and_proof.range_proof_commitment,
+            self.range_proof_public_parameters
+                .commitment_scheme_public_parameters()
+                .commitment_space_public_parameters(),
+        )?;
+
+        let statement = (
+            range_proof_commitment,
+            (
+                encrypted_negated_delta.clone(),
+                public_negated_delta.clone(),
+            )
+                .into(),
+        )
+            .into();
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters,
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters,
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = enhanced_maurer::PublicParameters::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        delta_decommitment_and_proof
+            .encryption_of_negated_delta_proof
+            .verify(
+                &self.protocol_context.round(RoundId::RefreshDecommitment),
+                &language_public_parameters,
+                vec![statement],
+                rng,
+            )?;
+
+        let encrypted_secret_key_share = self.encrypted_secret_key_share + encrypted_negated_delta;
+        let public_key_share = self.public_key_share + public_negated_delta.clone();
+        let centralized_party_public_key_share =
+            self.centralized_party_public_key_share + public_negated_delta.neg();
+
+        Ok(dkg::decentralized_party::Output {
+            public_key_share: public_key_share.value(),
+            public_key: self.public_key.value(),
+            encrypted_secret_key_share: encrypted_secret_key_share.value(),
+            centralized_party_public_key_share: centralized_party_public_key_share.value(),
+            key_share_updates,
+        })
+    }
+}
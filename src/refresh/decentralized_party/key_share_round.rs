@@ -0,0 +1,170 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use commitment::Commitment;
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use proof::AggregatableRangeProof;
+
+use crate::{
+    dkg,
+    refresh::decentralized_party::{decommitment_proof_verification_round, KeyShare},
+    session::RoundContext,
+    ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    protocol_context: ProtocolContext,
+    scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: GroupElement::PublicParameters,
+    encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    public_key_share: GroupElement,
+    public_key: GroupElement,
+    encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    centralized_party_public_key_share: GroupElement,
+    key_share_updates: u32,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+{
+    /// Record the centralized party's commitment to $-\delta \cdot G$, and send it the key share
+    /// we are about to refresh, for it to verify that both parties refresh the same one.
+    pub fn receive_commitment_and_send_key_share(
+        self,
+        commitment_to_public_negated_delta: Commitment,
+    ) -> (
+        KeyShare<GroupElement::Value, group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+        decommitment_proof_verification_round::Party<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    ) {
+        let key_share = KeyShare {
+            public_key_share: self.public_key_share.value(),
+            encrypted_secret_key_share: self.encrypted_secret_key_share.value(),
+            key_share_updates: self.key_share_updates,
+        };
+
+        let party = decommitment_proof_verification_round::Party {
+            protocol_context: self.protocol_context,
+            scalar_group_public_parameters: self.scalar_group_public_parameters,
+            group_public_parameters: self.group_public_parameters,
+            encryption_scheme_public_parameters: self.encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: self
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: self.range_proof_public_parameters,
+            public_key_share: self.public_key_share,
+            public_key: self.public_key,
+            encrypted_secret_key_share: self.encrypted_secret_key_share,
+            centralized_party_public_key_share: self.centralized_party_public_key_share,
+            key_share_updates: self.key_share_updates,
+            commitment_to_public_negated_delta,
+        };
+
+        (key_share, party)
+    }
+
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<Self> {
+        let group_public_parameters = protocol_public_parameters.group_public_parameters;
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let public_key_share =
+            GroupElement::new(dkg_output.public_key_share, &group_public_parameters)?;
+
+        let public_key = GroupElement::new(dkg_output.public_key, &group_public_parameters)?;
+
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            dkg_output.encrypted_secret_key_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let centralized_party_public_key_share = GroupElement::new(
+            dkg_output.centralized_party_public_key_share,
+            &group_public_parameters,
+        )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+            public_key_share,
+            public_key,
+            encrypted_secret_key_share,
+            centralized_party_public_key_share,
+            key_share_updates: dkg_output.key_share_updates,
+        })
+    }
+}
@@ -0,0 +1,1367 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Resharing of the threshold Paillier decryption key from one committee to another.
+
+use std::collections::{HashMap, HashSet};
+
+use crypto_bigint::{
+    modular::runtime_mod::{DynResidue, DynResidueParams},
+    rand_core::CryptoRngCore,
+    CheckedAdd, CheckedMul, CheckedSub, NonZero, Random, U64,
+};
+use group::{PartyID, StatisticalSecuritySizedNumber};
+use serde::{Deserialize, Serialize};
+use tiresias::{PaillierModulusSizedNumber, SecretKeyShareSizedNumber};
+
+use crate::Error;
+
+/// The message broadcast by a dealer of the current committee to the new committee: Feldman
+/// commitments $ g^{a_k} \bmod N^2 $ to the coefficients $a_1, \ldots, a_{t'-1}$ of the polynomial
+/// $ x_i + \Delta \sum_{k \geq 1} a_k X^k $ with which it re-shares its decryption key share
+/// $x_i$, where $g$ is the base of the decryption key's public verification keys.
+///
+/// The commitment to the constant coefficient is not sent, but is the dealer's public
+/// verification key $ g^{x_i} $, which binds the dealing to the dealer's share.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Dealing {
+    pub(in crate::resharing) coefficient_commitments: Vec<PaillierModulusSizedNumber>,
+}
+
+/// Deal our decryption key share `secret_key_share` of the current `threshold`-out-of-
+/// `number_of_parties` committee to a new `new_threshold`-out-of-`new_number_of_parties`
+/// committee, keeping the Paillier modulus $N$.
+///
+/// We re-share $x_i$ over the integers with a polynomial whose non-constant coefficients are
+/// multiples of $ \Delta = n! $, so that the receivers can divide the Lagrange-weighted sum of
+/// their shares by $\Delta$ and obtain a sharing of the decryption key $d$ itself. The size of the
+/// new committee's shares hence only depends on $N$ and the sizes of the committees, and does not
+/// grow from one resharing to the next.
+///
+/// The returned [`Dealing`] must be broadcast to the new committee, whereas each of the returned
+/// shares must only be sent to its receiver, over a private channel.
+#[allow(clippy::too_many_arguments)]
+pub fn deal_decryption_key_share(
+    party_id: PartyID,
+    secret_key_share: SecretKeyShareSizedNumber,
+    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+    threshold: PartyID,
+    number_of_parties: PartyID,
+    dealers: &[PartyID],
+    new_threshold: PartyID,
+    new_number_of_parties: PartyID,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<(Dealing, HashMap<PartyID, SecretKeyShareSizedNumber>)> {
+    validate_dealers(threshold, number_of_parties, dealers)?;
+
+    if new_threshold == 0 || new_threshold > new_number_of_parties || !dealers.contains(&party_id) {
+        return Err(Error::InvalidParameters);
+    }
+
+    let delta = factorial(number_of_parties)?;
+    let coefficient_bits = coefficient_bits(new_number_of_parties)?;
+
+    let coefficients: Vec<_> = (1..new_threshold)
+        .map(|_| {
+            SecretKeyShareSizedNumber::random(rng)
+                >> (SecretKeyShareSizedNumber::BITS - coefficient_bits)
+        })
+        .collect();
+
+    let paillier_associated_bi_prime_squared =
+        paillier_associated_bi_prime_squared(decryption_key_share_public_parameters);
+    let base = DynResidue::new(
+        &decryption_key_share_public_parameters.base,
+        paillier_associated_bi_prime_squared,
+    );
+
+    let coefficient_commitments = coefficients
+        .iter()
+        .map(|coefficient| {
+            base.pow_bounded_exp(coefficient, coefficient_bits)
+                .retrieve()
+        })
+        .collect();
+
+    let shares = (1..=new_number_of_parties)
+        .map(|receiver_party_id| {
+            let share = checked_add(
+                &secret_key_share,
+                &checked_mul(&delta, &evaluate(&coefficients, receiver_party_id)?)?,
+            )?;
+
+            Ok((receiver_party_id, share))
+        })
+        .collect::<crate::Result<_>>()?;
+
+    Ok((
+        Dealing {
+            coefficient_commitments,
+        },
+        shares,
+    ))
+}
+
+/// The message broadcast by a receiver of the new committee, complaining about the dealers whose
+/// share to it does not match their [`Dealing`], or which sent it no share.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Complaint {
+    pub(in crate::resharing) dealers: Vec<PartyID>,
+}
+
+/// The message broadcast by a dealer in answer to the [`Complaint`]s against it: the shares it
+/// sent to the complaining receivers, which everyone verifies against its [`Dealing`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Justification {
+    pub(in crate::resharing) shares: HashMap<PartyID, SecretKeyShareSizedNumber>,
+}
+
+/// Verify the `shares` sent to us by the `dealers` of the current committee against the
+/// `dealings` they broadcast, and complain about the dealers whose share is invalid or missing.
+///
+/// The returned [`Complaint`] must be broadcast, even when empty. Dealings that are missing or
+/// malformed are not complained about, as every party identifies them on its own.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_decryption_key_share_dealings(
+    party_id: PartyID,
+    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+    threshold: PartyID,
+    number_of_parties: PartyID,
+    dealers: &[PartyID],
+    dealings: &HashMap<PartyID, Dealing>,
+    shares: &HashMap<PartyID, SecretKeyShareSizedNumber>,
+    new_threshold: PartyID,
+    new_number_of_parties: PartyID,
+) -> crate::Result<Complaint> {
+    validate_dealers(threshold, number_of_parties, dealers)?;
+    validate_receiver(party_id, new_threshold, new_number_of_parties)?;
+
+    let delta = factorial(number_of_parties)?;
+
+    let dealers = dealers
+        .iter()
+        .copied()
+        .filter(|dealer_party_id| {
+            dealing_commitments(
+                decryption_key_share_public_parameters,
+                &delta,
+                *dealer_party_id,
+                dealings,
+                new_threshold,
+            )
+            .is_some_and(|commitments| {
+                shares.get(dealer_party_id).is_none_or(|share| {
+                    !verify_share(
+                        decryption_key_share_public_parameters,
+                        &commitments,
+                        share,
+                        party_id,
+                    )
+                })
+            })
+        })
+        .collect();
+
+    Ok(Complaint { dealers })
+}
+
+/// Answer the `complaints` broadcast by the receivers of the new committee against us, by opening
+/// the `shares` we dealt to them.
+///
+/// The returned [`Justification`] must be broadcast. As the private channels are reliable, honest
+/// receivers only complain about malicious dealers, so that an honest dealer only reveals the
+/// shares of malicious receivers, which already know them.
+pub fn justify_decryption_key_share_dealing(
+    party_id: PartyID,
+    complaints: &HashMap<PartyID, Complaint>,
+    shares: &HashMap<PartyID, SecretKeyShareSizedNumber>,
+) -> Justification {
+    let shares = complaints
+        .iter()
+        .filter(|(_, complaint)| complaint.dealers.contains(&party_id))
+        .filter_map(|(&receiver_party_id, _)| {
+            shares
+                .get(&receiver_party_id)
+                .map(|share| (receiver_party_id, *share))
+        })
+        .collect();
+
+    Justification { shares }
+}
+
+/// Verify the `dealings` broadcast by the `dealers` of the current committee, their
+/// `justifications` of the `complaints` against them, and the `shares` they sent us, and combine
+/// them into our share of the decryption key of the new committee, returning its public
+/// parameters alongside it.
+///
+/// A dealer is malicious if its dealing is missing or malformed, or if it did not answer a
+/// complaint with a share that matches its dealing. As this only depends on broadcast messages,
+/// all honest parties identify the same dealers, in which case the resharing aborts, and must be
+/// restarted without them. Otherwise, shares opened in answer to our complaints replace the ones
+/// sent to us.
+///
+/// The Paillier modulus $N$ is kept, so that ciphertexts encrypted prior to the resharing - the
+/// encrypted secret key shares of existing dWallets and their presigns - remain valid. The base
+/// of the public verification keys is raised to $\Delta$, as only $ g^{\Delta x'_j} $ can be
+/// computed from the dealings. Our share is passed to `tiresias::DecryptionKeyShare::new`, and
+/// dealt as is in the next resharing; the new committee's decryption key shares and
+/// `new_threshold` then replace the current ones in
+/// `signature_partial_decryption_round::Party::new`, while the current committee's shares must be
+/// deleted, as they do not combine with the new ones.
+#[allow(clippy::too_many_arguments)]
+pub fn reshare_decryption_key_share(
+    party_id: PartyID,
+    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+    threshold: PartyID,
+    number_of_parties: PartyID,
+    dealers: &[PartyID],
+    dealings: HashMap<PartyID, Dealing>,
+    shares: HashMap<PartyID, SecretKeyShareSizedNumber>,
+    complaints: HashMap<PartyID, Complaint>,
+    justifications: HashMap<PartyID, Justification>,
+    new_threshold: PartyID,
+    new_number_of_parties: PartyID,
+) -> crate::Result<(
+    tiresias::decryption_key_share::PublicParameters,
+    SecretKeyShareSizedNumber,
+)> {
+    validate_dealers(threshold, number_of_parties, dealers)?;
+    validate_receiver(party_id, new_threshold, new_number_of_parties)?;
+
+    let delta = factorial(number_of_parties)?;
+
+    let mut malicious_parties = vec![];
+    let mut commitments = HashMap::new();
+
+    for &dealer_party_id in dealers {
+        let Some(dealer_commitments) = dealing_commitments(
+            decryption_key_share_public_parameters,
+            &delta,
+            dealer_party_id,
+            &dealings,
+            new_threshold,
+        ) else {
+            malicious_parties.push(dealer_party_id);
+            continue;
+        };
+
+        let justified = complaints
+            .iter()
+            .filter(|&(&receiver_party_id, complaint)| {
+                receiver_party_id != 0
+                    && receiver_party_id <= new_number_of_parties
+                    && complaint.dealers.contains(&dealer_party_id)
+            })
+            .all(|(receiver_party_id, _)| {
+                justifications
+                    .get(&dealer_party_id)
+                    .and_then(|justification| justification.shares.get(receiver_party_id))
+                    .is_some_and(|share| {
+                        verify_share(
+                            decryption_key_share_public_parameters,
+                            &dealer_commitments,
+                            share,
+                            *receiver_party_id,
+                        )
+                    })
+            });
+
+        if !justified {
+            malicious_parties.push(dealer_party_id);
+            continue;
+        }
+
+        commitments.insert(dealer_party_id, dealer_commitments);
+    }
+
+    if !malicious_parties.is_empty() {
+        malicious_parties.sort();
+
+        return Err(Error::MaliciousDealers(malicious_parties));
+    }
+
+    let paillier_associated_bi_prime_squared =
+        paillier_associated_bi_prime_squared(decryption_key_share_public_parameters);
+    let base = DynResidue::new(
+        &decryption_key_share_public_parameters.base,
+        paillier_associated_bi_prime_squared,
+    );
+
+    let mut positive_weighted_shares = SecretKeyShareSizedNumber::ZERO;
+    let mut negative_weighted_shares = SecretKeyShareSizedNumber::ZERO;
+    let mut negative_lagrange_coefficients = SecretKeyShareSizedNumber::ZERO;
+    let mut lagrange_coefficients = HashMap::new();
+
+    for &dealer_party_id in dealers {
+        // A share opened in answer to our complaint is verified, whereas any other share sent to
+        // us must be valid, or else we should have complained about it.
+        let share = justifications
+            .get(&dealer_party_id)
+            .and_then(|justification| justification.shares.get(&party_id))
+            .or_else(|| shares.get(&dealer_party_id))
+            .filter(|share| {
+                verify_share(
+                    decryption_key_share_public_parameters,
+                    &commitments[&dealer_party_id],
+                    share,
+                    party_id,
+                )
+            })
+            .ok_or(Error::InvalidParameters)?;
+
+        let (negative_lagrange_coefficient, lagrange_coefficient) =
+            lagrange_coefficient(dealer_party_id, dealers, number_of_parties)?;
+        let weighted_share = checked_mul(&lagrange_coefficient, share)?;
+
+        if negative_lagrange_coefficient {
+            negative_weighted_shares = checked_add(&negative_weighted_shares, &weighted_share)?;
+            negative_lagrange_coefficients =
+                checked_add(&negative_lagrange_coefficients, &lagrange_coefficient)?;
+        } else {
+            positive_weighted_shares = checked_add(&positive_weighted_shares, &weighted_share)?;
+        }
+
+        lagrange_coefficients.insert(
+            dealer_party_id,
+            (negative_lagrange_coefficient, lagrange_coefficient),
+        );
+    }
+
+    // The shares of dealers with a negative Lagrange coefficient subtract their coefficients from
+    // those of the new committee's polynomial. Adding the public polynomial
+    // $ \Delta \sum_{k \geq 1} o X^k $ for $ o = 2^{b} \sum_{\lambda_i < 0} |\lambda_i| $, which
+    // has no constant coefficient, keeps them non-negative without changing the secret.
+    let offset_coefficients = vec![
+        checked_mul(
+            &negative_lagrange_coefficients,
+            &(SecretKeyShareSizedNumber::ONE << coefficient_bits(new_number_of_parties)?),
+        )?;
+        usize::from(new_threshold - 1)
+    ];
+    let offset = |receiver_party_id| {
+        checked_mul(&delta, &evaluate(&offset_coefficients, receiver_party_id)?)
+    };
+
+    // $ \sum_i \lambda_i x_i = \Delta d $, hence the weighted sum of our shares is divisible by
+    // $\Delta$, and dividing it gives our share $x'_j$ of $d$.
+    let weighted_shares = checked_sub(
+        &checked_add(&positive_weighted_shares, &offset(party_id)?)?,
+        &negative_weighted_shares,
+    )?;
+    let (secret_key_share, remainder) = weighted_shares
+        .div_rem(&Option::<_>::from(NonZero::new(delta)).ok_or(Error::InternalError)?);
+
+    if remainder != SecretKeyShareSizedNumber::ZERO {
+        return Err(Error::InvalidParameters);
+    }
+
+    // The public verification key $ (g^\Delta)^{x'_j} $ of every party $j$ of the new committee is
+    // the product of the dealers' commitments evaluated at $j$, raised to their Lagrange
+    // coefficients, and of $ g^{\Delta o \sum_{k \geq 1} j^k} $.
+    let public_verification_keys = (1..=new_number_of_parties)
+        .map(|receiver_party_id| {
+            let offset = offset(receiver_party_id)?;
+
+            let public_verification_key =
+                commitments.iter().try_fold(
+                    base.pow_bounded_exp(&offset, offset.bits()),
+                    |public_verification_key,
+                     (
+                        dealer_party_id,
+                        (dealer_public_verification_key, coefficient_commitments),
+                    )| {
+                        let (negative_lagrange_coefficient, lagrange_coefficient) =
+                            &lagrange_coefficients[dealer_party_id];
+
+                        let evaluation = evaluate_in_the_exponent(
+                            *dealer_public_verification_key,
+                            coefficient_commitments,
+                            receiver_party_id,
+                        )
+                        .pow_bounded_exp(lagrange_coefficient, lagrange_coefficient.bits());
+
+                        Ok::<_, Error>(if *negative_lagrange_coefficient {
+                            public_verification_key * invert(evaluation)?
+                        } else {
+                            public_verification_key * evaluation
+                        })
+                    },
+                )?;
+
+            Ok((receiver_party_id, public_verification_key.retrieve()))
+        })
+        .collect::<crate::Result<_>>()?;
+
+    let new_decryption_key_share_public_parameters =
+        tiresias::decryption_key_share::PublicParameters::new(
+            new_threshold,
+            new_number_of_parties,
+            base.pow_bounded_exp(&delta, delta.bits()).retrieve(),
+            public_verification_keys,
+            decryption_key_share_public_parameters
+                .encryption_scheme_public_parameters
+                .clone(),
+        )?;
+
+    Ok((new_decryption_key_share_public_parameters, secret_key_share))
+}
+
+/// At least `threshold` distinct parties of the current committee must take part as `dealers`.
+fn validate_dealers(
+    threshold: PartyID,
+    number_of_parties: PartyID,
+    dealers: &[PartyID],
+) -> crate::Result<()> {
+    if dealers
+        .iter()
+        .any(|&party_id| party_id == 0 || party_id > number_of_parties)
+        || dealers.iter().collect::<HashSet<_>>().len() != dealers.len()
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    if dealers.len() < usize::from(threshold) {
+        return Err(Error::ThresholdNotReached);
+    }
+
+    Ok(())
+}
+
+/// The size $b$ of the coefficients $a_k$ of a dealing, which statistically hide the decryption key
+/// $ d < N^2 $ in the shares of any $t'-1$ receivers. It is independent of the dealer's share, so
+/// that the new committee's shares are bounded regardless of the current committee's.
+fn coefficient_bits(new_number_of_parties: PartyID) -> crate::Result<usize> {
+    let coefficient_bits = PaillierModulusSizedNumber::BITS
+        + 2 * factorial(new_number_of_parties)?.bits()
+        + StatisticalSecuritySizedNumber::BITS;
+
+    if coefficient_bits >= SecretKeyShareSizedNumber::BITS {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(coefficient_bits)
+}
+
+/// We must be one of the `new_number_of_parties` parties of the new committee.
+fn validate_receiver(
+    party_id: PartyID,
+    new_threshold: PartyID,
+    new_number_of_parties: PartyID,
+) -> crate::Result<()> {
+    if new_threshold == 0
+        || new_threshold > new_number_of_parties
+        || party_id == 0
+        || party_id > new_number_of_parties
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(())
+}
+
+/// The commitments $ g^{x_i} $ and $ g^{\Delta a_k} $ of the dealing of `dealer_party_id`, or
+/// `None` if it is missing or malformed.
+fn dealing_commitments(
+    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+    delta: &SecretKeyShareSizedNumber,
+    dealer_party_id: PartyID,
+    dealings: &HashMap<PartyID, Dealing>,
+    new_threshold: PartyID,
+) -> Option<(
+    DynResidue<{ PaillierModulusSizedNumber::LIMBS }>,
+    Vec<DynResidue<{ PaillierModulusSizedNumber::LIMBS }>>,
+)> {
+    let dealing = dealings.get(&dealer_party_id)?;
+    let public_verification_key = decryption_key_share_public_parameters
+        .public_verification_keys
+        .get(&dealer_party_id)?;
+
+    if dealing.coefficient_commitments.len() != usize::from(new_threshold - 1) {
+        return None;
+    }
+
+    let paillier_associated_bi_prime_squared =
+        paillier_associated_bi_prime_squared(decryption_key_share_public_parameters);
+
+    let coefficient_commitments = dealing
+        .coefficient_commitments
+        .iter()
+        .map(|commitment| {
+            DynResidue::new(commitment, paillier_associated_bi_prime_squared)
+                .pow_bounded_exp(delta, delta.bits())
+        })
+        .collect();
+
+    Some((
+        DynResidue::new(
+            public_verification_key,
+            paillier_associated_bi_prime_squared,
+        ),
+        coefficient_commitments,
+    ))
+}
+
+/// Verify the `share` of `receiver_party_id` against the commitments of its dealing.
+fn verify_share(
+    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+    (public_verification_key, coefficient_commitments): &(
+        DynResidue<{ PaillierModulusSizedNumber::LIMBS }>,
+        Vec<DynResidue<{ PaillierModulusSizedNumber::LIMBS }>>,
+    ),
+    share: &SecretKeyShareSizedNumber,
+    receiver_party_id: PartyID,
+) -> bool {
+    let base = DynResidue::new(
+        &decryption_key_share_public_parameters.base,
+        *public_verification_key.params(),
+    );
+
+    base.pow_bounded_exp(share, share.bits())
+        == evaluate_in_the_exponent(
+            *public_verification_key,
+            coefficient_commitments,
+            receiver_party_id,
+        )
+}
+
+/// $ n! $.
+fn factorial(number_of_parties: PartyID) -> crate::Result<SecretKeyShareSizedNumber> {
+    (1..=number_of_parties).try_fold(SecretKeyShareSizedNumber::ONE, |factorial, i| {
+        checked_mul(&factorial, &SecretKeyShareSizedNumber::from(i))
+    })
+}
+
+/// The integer Lagrange coefficient
+/// $ \lambda_i = \Delta \prod_{j \in S \setminus \{i\}} \frac{j}{j - i} $ of `party_id` for the
+/// set $S$ of `dealers`, where $ \Delta = n! $, returned as its sign and absolute value.
+fn lagrange_coefficient(
+    party_id: PartyID,
+    dealers: &[PartyID],
+    number_of_parties: PartyID,
+) -> crate::Result<(bool, SecretKeyShareSizedNumber)> {
+    let others = dealers.iter().filter(|&&j| j != party_id);
+
+    let numerator = others
+        .clone()
+        .try_fold(factorial(number_of_parties)?, |numerator, &j| {
+            checked_mul(&numerator, &SecretKeyShareSizedNumber::from(j))
+        })?;
+
+    let denominator =
+        others
+            .clone()
+            .try_fold(SecretKeyShareSizedNumber::ONE, |denominator, &j| {
+                checked_mul(
+                    &denominator,
+                    &SecretKeyShareSizedNumber::from(j.abs_diff(party_id)),
+                )
+            })?;
+
+    let denominator = Option::<_>::from(NonZero::new(denominator)).ok_or(Error::InternalError)?;
+    let (lagrange_coefficient, remainder) = numerator.div_rem(&denominator);
+
+    // $\Delta$ clears the denominators of the Lagrange coefficients.
+    if remainder != SecretKeyShareSizedNumber::ZERO {
+        return Err(Error::InternalError);
+    }
+
+    let negative = others.filter(|&&j| j < party_id).count() % 2 == 1;
+
+    Ok((negative, lagrange_coefficient))
+}
+
+fn paillier_associated_bi_prime_squared(
+    decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+) -> DynResidueParams<{ PaillierModulusSizedNumber::LIMBS }> {
+    let paillier_associated_bi_prime_squared: PaillierModulusSizedNumber =
+        decryption_key_share_public_parameters
+            .encryption_scheme_public_parameters
+            .associated_bi_prime
+            .square();
+
+    DynResidueParams::new(&paillier_associated_bi_prime_squared)
+}
+
+/// $ \sum_{k \geq 1} a_k j^k $ for the `coefficients` $a_k$ and `party_id` $j$, by Horner's rule.
+fn evaluate(
+    coefficients: &[SecretKeyShareSizedNumber],
+    party_id: PartyID,
+) -> crate::Result<SecretKeyShareSizedNumber> {
+    let party_id = SecretKeyShareSizedNumber::from(party_id);
+
+    coefficients.iter().rev().try_fold(
+        SecretKeyShareSizedNumber::ZERO,
+        |evaluation, coefficient| checked_mul(&checked_add(&evaluation, coefficient)?, &party_id),
+    )
+}
+
+/// $ C_0 \prod_{k \geq 1} C_k^{j^k} $ for the commitments $C_k$ of a dealing and `party_id` $j$,
+/// by Horner's rule.
+fn evaluate_in_the_exponent(
+    constant_coefficient_commitment: DynResidue<{ PaillierModulusSizedNumber::LIMBS }>,
+    coefficient_commitments: &[DynResidue<{ PaillierModulusSizedNumber::LIMBS }>],
+    party_id: PartyID,
+) -> DynResidue<{ PaillierModulusSizedNumber::LIMBS }> {
+    let party_id = U64::from(party_id);
+
+    coefficient_commitments.iter().rev().fold(
+        DynResidue::one(*constant_coefficient_commitment.params()),
+        |evaluation, commitment| (evaluation * commitment).pow_bounded_exp(&party_id, 16),
+    ) * constant_coefficient_commitment
+}
+
+fn invert(
+    element: DynResidue<{ PaillierModulusSizedNumber::LIMBS }>,
+) -> crate::Result<DynResidue<{ PaillierModulusSizedNumber::LIMBS }>> {
+    let (inverse, is_invertible) = element.invert();
+
+    if !bool::from(is_invertible) {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(inverse)
+}
+
+fn checked_add(
+    lhs: &SecretKeyShareSizedNumber,
+    rhs: &SecretKeyShareSizedNumber,
+) -> crate::Result<SecretKeyShareSizedNumber> {
+    Option::from(lhs.checked_add(rhs)).ok_or(Error::InvalidParameters)
+}
+
+fn checked_sub(
+    lhs: &SecretKeyShareSizedNumber,
+    rhs: &SecretKeyShareSizedNumber,
+) -> crate::Result<SecretKeyShareSizedNumber> {
+    Option::from(lhs.checked_sub(rhs)).ok_or(Error::InvalidParameters)
+}
+
+fn checked_mul(
+    lhs: &SecretKeyShareSizedNumber,
+    rhs: &SecretKeyShareSizedNumber,
+) -> crate::Result<SecretKeyShareSizedNumber> {
+    Option::from(lhs.checked_mul(rhs)).ok_or(Error::InvalidParameters)
+}
+
+#[cfg(all(test, feature = "secp256k1", feature = "bulletproofs"))]
+mod tests {
+    use crypto_bigint::Uint;
+    use homomorphic_encryption::{
+        AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+        GroupsPublicParametersAccessors,
+    };
+    use rand::prelude::IteratorRandom;
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::{
+        test_exports::{deal_trusted_shares, BASE, N, SECRET_KEY},
+        DecryptionKeyShare,
+    };
+
+    use super::*;
+    use crate::{
+        dkg::tests::generates_distributed_key_internal,
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+        sign::tests::presign_signs_with_decryption_key_shares_internal,
+    };
+
+    fn deal(
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        secret_key_shares: &HashMap<PartyID, SecretKeyShareSizedNumber>,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        dealers: &[PartyID],
+        new_threshold: PartyID,
+        new_number_of_parties: PartyID,
+    ) -> (
+        HashMap<PartyID, Dealing>,
+        HashMap<PartyID, HashMap<PartyID, SecretKeyShareSizedNumber>>,
+    ) {
+        dealers
+            .iter()
+            .map(|&party_id| {
+                let (dealing, shares) = deal_decryption_key_share(
+                    party_id,
+                    secret_key_shares[&party_id],
+                    decryption_key_share_public_parameters,
+                    threshold,
+                    number_of_parties,
+                    dealers,
+                    new_threshold,
+                    new_number_of_parties,
+                    &mut OsRng,
+                )
+                .unwrap();
+
+                ((party_id, dealing), (party_id, shares))
+            })
+            .unzip()
+    }
+
+    /// The shares sent to `party_id` by each dealer.
+    fn received_shares(
+        party_id: PartyID,
+        shares: &HashMap<PartyID, HashMap<PartyID, SecretKeyShareSizedNumber>>,
+    ) -> HashMap<PartyID, SecretKeyShareSizedNumber> {
+        shares
+            .iter()
+            .filter_map(|(&dealer_party_id, shares)| {
+                shares.get(&party_id).map(|share| (dealer_party_id, *share))
+            })
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn complain(
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        dealers: &[PartyID],
+        dealings: &HashMap<PartyID, Dealing>,
+        shares: &HashMap<PartyID, HashMap<PartyID, SecretKeyShareSizedNumber>>,
+        new_threshold: PartyID,
+        new_number_of_parties: PartyID,
+    ) -> HashMap<PartyID, Complaint> {
+        (1..=new_number_of_parties)
+            .map(|party_id| {
+                let complaint = verify_decryption_key_share_dealings(
+                    party_id,
+                    decryption_key_share_public_parameters,
+                    threshold,
+                    number_of_parties,
+                    dealers,
+                    dealings,
+                    &received_shares(party_id, shares),
+                    new_threshold,
+                    new_number_of_parties,
+                )
+                .unwrap();
+
+                (party_id, complaint)
+            })
+            .collect()
+    }
+
+    fn justify(
+        dealers: &[PartyID],
+        complaints: &HashMap<PartyID, Complaint>,
+        shares: &HashMap<PartyID, HashMap<PartyID, SecretKeyShareSizedNumber>>,
+    ) -> HashMap<PartyID, Justification> {
+        dealers
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    justify_decryption_key_share_dealing(party_id, complaints, &shares[&party_id]),
+                )
+            })
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn reshare(
+        party_id: PartyID,
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        dealers: &[PartyID],
+        dealings: &HashMap<PartyID, Dealing>,
+        shares: &HashMap<PartyID, HashMap<PartyID, SecretKeyShareSizedNumber>>,
+        complaints: &HashMap<PartyID, Complaint>,
+        justifications: &HashMap<PartyID, Justification>,
+        new_threshold: PartyID,
+        new_number_of_parties: PartyID,
+    ) -> crate::Result<(
+        tiresias::decryption_key_share::PublicParameters,
+        SecretKeyShareSizedNumber,
+    )> {
+        reshare_decryption_key_share(
+            party_id,
+            decryption_key_share_public_parameters,
+            threshold,
+            number_of_parties,
+            dealers,
+            dealings.clone(),
+            received_shares(party_id, shares),
+            complaints.clone(),
+            justifications.clone(),
+            new_threshold,
+            new_number_of_parties,
+        )
+    }
+
+    /// Reshare the `secret_key_shares` of the current committee from `threshold` random dealers
+    /// to all parties of the new committee, which should agree on its public parameters.
+    fn reshare_committee(
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        secret_key_shares: &HashMap<PartyID, SecretKeyShareSizedNumber>,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        new_threshold: PartyID,
+        new_number_of_parties: PartyID,
+    ) -> (
+        tiresias::decryption_key_share::PublicParameters,
+        HashMap<PartyID, SecretKeyShareSizedNumber>,
+    ) {
+        let mut dealers: Vec<PartyID> =
+            (1..=number_of_parties).choose_multiple(&mut OsRng, usize::from(threshold));
+        dealers.sort();
+
+        let (dealings, shares) = deal(
+            decryption_key_share_public_parameters,
+            secret_key_shares,
+            threshold,
+            number_of_parties,
+            &dealers,
+            new_threshold,
+            new_number_of_parties,
+        );
+
+        let complaints = complain(
+            decryption_key_share_public_parameters,
+            threshold,
+            number_of_parties,
+            &dealers,
+            &dealings,
+            &shares,
+            new_threshold,
+            new_number_of_parties,
+        );
+
+        assert!(
+            complaints
+                .values()
+                .all(|complaint| complaint.dealers.is_empty()),
+            "honest dealers should not be complained about"
+        );
+
+        let justifications = justify(&dealers, &complaints, &shares);
+
+        let (new_decryption_key_share_public_parameters, new_secret_key_shares): (
+            Vec<_>,
+            HashMap<_, _>,
+        ) = (1..=new_number_of_parties)
+            .map(|party_id| {
+                let (public_parameters, secret_key_share) = reshare(
+                    party_id,
+                    decryption_key_share_public_parameters,
+                    threshold,
+                    number_of_parties,
+                    &dealers,
+                    &dealings,
+                    &shares,
+                    &complaints,
+                    &justifications,
+                    new_threshold,
+                    new_number_of_parties,
+                )
+                .unwrap();
+
+                (public_parameters, (party_id, secret_key_share))
+            })
+            .unzip();
+
+        let new_decryption_key_share_public_parameters = new_decryption_key_share_public_parameters
+            .into_iter()
+            .reduce(|public_parameters, other_public_parameters| {
+                assert_eq!(
+                    public_parameters, other_public_parameters,
+                    "all parties of the new committee should agree on its public parameters"
+                );
+
+                public_parameters
+            })
+            .unwrap();
+
+        // $ d < N^2 $, and the coefficients of the new committee's polynomial are smaller than
+        // $ 2^b \sum_i |\lambda_i| $.
+        let lagrange_coefficients = dealers.iter().fold(
+            SecretKeyShareSizedNumber::ZERO,
+            |lagrange_coefficients, &party_id| {
+                let (_, lagrange_coefficient) =
+                    lagrange_coefficient(party_id, &dealers, number_of_parties).unwrap();
+
+                lagrange_coefficients.wrapping_add(&lagrange_coefficient)
+            },
+        );
+        let secret_key_share_upper_bound =
+            (SecretKeyShareSizedNumber::ONE << PaillierModulusSizedNumber::BITS).wrapping_add(
+                &evaluate(
+                    &vec![
+                        lagrange_coefficients << coefficient_bits(new_number_of_parties).unwrap();
+                        usize::from(new_threshold - 1)
+                    ],
+                    new_number_of_parties,
+                )
+                .unwrap(),
+            );
+
+        assert!(
+            new_secret_key_shares
+                .values()
+                .all(|secret_key_share| *secret_key_share < secret_key_share_upper_bound),
+            "the new committee's shares should be bounded regardless of the current committee's"
+        );
+
+        (
+            new_decryption_key_share_public_parameters,
+            new_secret_key_shares,
+        )
+    }
+
+    fn decryption_key_shares(
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        secret_key_shares: &HashMap<PartyID, SecretKeyShareSizedNumber>,
+    ) -> HashMap<PartyID, DecryptionKeyShare> {
+        secret_key_shares
+            .iter()
+            .map(|(&party_id, &secret_key_share)| {
+                (
+                    party_id,
+                    DecryptionKeyShare::new(
+                        party_id,
+                        secret_key_share,
+                        decryption_key_share_public_parameters,
+                    )
+                    .unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn encrypt(
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        plaintext: Uint<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>,
+    ) -> tiresias::CiphertextSpaceGroupElement {
+        let encryption_key = tiresias::EncryptionKey::new(
+            &decryption_key_share_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let (_, ciphertext) = encryption_key
+            .encrypt(
+                &tiresias::PlaintextSpaceGroupElement::new(
+                    plaintext,
+                    decryption_key_share_public_parameters
+                        .encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )
+                .unwrap(),
+                &decryption_key_share_public_parameters.encryption_scheme_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        ciphertext
+    }
+
+    fn decrypt(
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        number_of_parties: PartyID,
+        decryption_key_shares: &HashMap<PartyID, DecryptionKeyShare>,
+        ciphertext: &tiresias::CiphertextSpaceGroupElement,
+    ) -> crate::Result<Uint<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>> {
+        let decrypters: Vec<PartyID> = decryption_key_shares.keys().copied().collect();
+
+        let decryption_shares: HashMap<_, _> = decryption_key_shares
+            .iter()
+            .map(|(&party_id, decryption_key_share)| {
+                (
+                    party_id,
+                    decryption_key_share
+                        .generate_decryption_share_semi_honest(
+                            ciphertext,
+                            decryption_key_share_public_parameters,
+                        )
+                        .unwrap(),
+                )
+            })
+            .collect();
+
+        let lagrange_coefficients: HashMap<_, _> = decrypters
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    DecryptionKeyShare::compute_lagrange_coefficient(
+                        party_id,
+                        number_of_parties,
+                        decrypters.clone(),
+                        decryption_key_share_public_parameters,
+                    ),
+                )
+            })
+            .collect();
+
+        Ok(DecryptionKeyShare::combine_decryption_shares_semi_honest(
+            decryption_shares,
+            lagrange_coefficients,
+            decryption_key_share_public_parameters,
+        )?
+        .into())
+    }
+
+    /// Sign with the `decryption_key_shares` of a `threshold`-out-of-`number_of_parties`
+    /// committee.
+    fn signs(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        decryption_key_share_public_parameters: tiresias::decryption_key_share::PublicParameters,
+        decryption_key_shares: HashMap<PartyID, DecryptionKeyShare>,
+    ) {
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        presign_signs_with_decryption_key_shares_internal(
+            threshold,
+            number_of_parties,
+            1,
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+            ProtocolPublicParameters::new(N),
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+        );
+    }
+
+    #[rstest]
+    #[case(2, 2, 2, 4)]
+    #[case(2, 4, 3, 3)]
+    #[case(6, 9, 4, 7)]
+    fn reshares_decryption_key_shares(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] new_threshold: PartyID,
+        #[case] new_number_of_parties: PartyID,
+    ) {
+        let (decryption_key_share_public_parameters, secret_key_shares) =
+            deal_trusted_shares(threshold, number_of_parties, N, SECRET_KEY, BASE);
+
+        // Encrypt prior to the resharing, like the encrypted secret key shares of existing
+        // dWallets.
+        let plaintext = Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::from_u64(42);
+        let ciphertext = encrypt(&decryption_key_share_public_parameters, plaintext);
+
+        let (new_decryption_key_share_public_parameters, new_secret_key_shares) = reshare_committee(
+            &decryption_key_share_public_parameters,
+            &secret_key_shares,
+            threshold,
+            number_of_parties,
+            new_threshold,
+            new_number_of_parties,
+        );
+
+        let new_decryption_key_shares = decryption_key_shares(
+            &new_decryption_key_share_public_parameters,
+            &new_secret_key_shares,
+        );
+
+        let new_decrypters: HashMap<_, _> = new_decryption_key_shares
+            .clone()
+            .into_iter()
+            .choose_multiple(&mut OsRng, usize::from(new_threshold))
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            decrypt(
+                &new_decryption_key_share_public_parameters,
+                new_number_of_parties,
+                &new_decrypters,
+                &ciphertext,
+            )
+            .unwrap(),
+            plaintext,
+            "the new committee should decrypt ciphertexts encrypted prior to the resharing"
+        );
+
+        let mut mixed_decrypters: HashMap<_, _> = new_decryption_key_shares
+            .clone()
+            .into_iter()
+            .filter(|(party_id, _)| *party_id != 1)
+            .take(usize::from(new_threshold - 1))
+            .collect();
+        mixed_decrypters.insert(
+            1,
+            DecryptionKeyShare::new(
+                1,
+                secret_key_shares[&1],
+                &decryption_key_share_public_parameters,
+            )
+            .unwrap(),
+        );
+
+        assert!(
+            !matches!(
+                decrypt(
+                    &new_decryption_key_share_public_parameters,
+                    new_number_of_parties,
+                    &mixed_decrypters,
+                    &ciphertext,
+                ),
+                Ok(decrypted_plaintext) if decrypted_plaintext == plaintext
+            ),
+            "shares of the current committee should not combine with those of the new committee"
+        );
+
+        signs(
+            new_threshold,
+            new_number_of_parties,
+            new_decryption_key_share_public_parameters,
+            new_decryption_key_shares,
+        );
+    }
+
+    #[test]
+    fn reshares_decryption_key_shares_every_epoch() {
+        let committees = [(2, 3), (3, 5), (2, 4), (4, 6), (3, 5)];
+        let (threshold, number_of_parties) = committees[0];
+
+        let (mut decryption_key_share_public_parameters, mut secret_key_shares) =
+            deal_trusted_shares(threshold, number_of_parties, N, SECRET_KEY, BASE);
+
+        let plaintext = Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::from_u64(42);
+        let ciphertext = encrypt(&decryption_key_share_public_parameters, plaintext);
+
+        for committees in committees.windows(2) {
+            let ((threshold, number_of_parties), (new_threshold, new_number_of_parties)) =
+                (committees[0], committees[1]);
+
+            (decryption_key_share_public_parameters, secret_key_shares) = reshare_committee(
+                &decryption_key_share_public_parameters,
+                &secret_key_shares,
+                threshold,
+                number_of_parties,
+                new_threshold,
+                new_number_of_parties,
+            );
+        }
+
+        let (threshold, number_of_parties) = committees[committees.len() - 1];
+        let decryption_key_shares =
+            decryption_key_shares(&decryption_key_share_public_parameters, &secret_key_shares);
+
+        let decrypters: HashMap<_, _> = decryption_key_shares
+            .clone()
+            .into_iter()
+            .choose_multiple(&mut OsRng, usize::from(threshold))
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            decrypt(
+                &decryption_key_share_public_parameters,
+                number_of_parties,
+                &decrypters,
+                &ciphertext,
+            )
+            .unwrap(),
+            plaintext,
+            "the last committee should decrypt ciphertexts encrypted prior to the first resharing"
+        );
+
+        signs(
+            threshold,
+            number_of_parties,
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+        );
+    }
+
+    #[test]
+    fn identifies_malicious_dealers() {
+        let (decryption_key_share_public_parameters, secret_key_shares) =
+            deal_trusted_shares(2, 4, N, SECRET_KEY, BASE);
+        let dealers = [1, 2, 3, 4];
+
+        let (mut dealings, mut shares) = deal(
+            &decryption_key_share_public_parameters,
+            &secret_key_shares,
+            2,
+            4,
+            &dealers,
+            3,
+            5,
+        );
+
+        // Dealer 2 sends receiver 1 a share inconsistent with its dealing, and opens it in answer
+        // to the complaint.
+        let share = shares.get_mut(&2).unwrap().get_mut(&1).unwrap();
+        *share = share.wrapping_add(&SecretKeyShareSizedNumber::ONE);
+
+        // Dealer 3 re-shares a secret other than its decryption key share.
+        let (other_dealing, other_shares) = deal_decryption_key_share(
+            3,
+            secret_key_shares[&4],
+            &decryption_key_share_public_parameters,
+            2,
+            4,
+            &dealers,
+            3,
+            5,
+            &mut OsRng,
+        )
+        .unwrap();
+        dealings.insert(3, other_dealing);
+        shares.insert(3, other_shares);
+
+        // Dealer 4 does not send its dealing.
+        dealings.remove(&4);
+
+        let mut complaints = complain(
+            &decryption_key_share_public_parameters,
+            2,
+            4,
+            &dealers,
+            &dealings,
+            &shares,
+            3,
+            5,
+        );
+
+        assert_eq!(complaints[&1].dealers, vec![2, 3]);
+        assert!((2..=5).all(|party_id| complaints[&party_id].dealers == vec![3]));
+
+        // Receiver 5 falsely complains about dealer 1, which opens a valid share.
+        complaints.get_mut(&5).unwrap().dealers.push(1);
+
+        let justifications = justify(&dealers, &complaints, &shares);
+
+        assert!(
+            (1..=5).all(|party_id| matches!(
+                reshare(
+                    party_id,
+                    &decryption_key_share_public_parameters,
+                    2,
+                    4,
+                    &dealers,
+                    &dealings,
+                    &shares,
+                    &complaints,
+                    &justifications,
+                    3,
+                    5,
+                )
+                .err()
+                .unwrap(),
+                Error::MaliciousDealers(parties) if parties == vec![2, 3, 4]
+            )),
+            "all receivers should identify the same malicious dealers"
+        );
+    }
+
+    #[test]
+    fn resolves_false_complaints() {
+        let (decryption_key_share_public_parameters, secret_key_shares) =
+            deal_trusted_shares(2, 4, N, SECRET_KEY, BASE);
+        let dealers = [1, 3];
+
+        let (dealings, shares) = deal(
+            &decryption_key_share_public_parameters,
+            &secret_key_shares,
+            2,
+            4,
+            &dealers,
+            3,
+            5,
+        );
+
+        // Receiver 2 falsely complains about dealer 1, and receiver 4 about dealer 3.
+        let mut complaints = complain(
+            &decryption_key_share_public_parameters,
+            2,
+            4,
+            &dealers,
+            &dealings,
+            &shares,
+            3,
+            5,
+        );
+        complaints.get_mut(&2).unwrap().dealers.push(1);
+        complaints.get_mut(&4).unwrap().dealers.push(3);
+
+        let justifications = justify(&dealers, &complaints, &shares);
+
+        let new_decryption_key_share_public_parameters: Vec<_> = (1..=5)
+            .map(|party_id| {
+                let (public_parameters, secret_key_share) = reshare(
+                    party_id,
+                    &decryption_key_share_public_parameters,
+                    2,
+                    4,
+                    &dealers,
+                    &dealings,
+                    &shares,
+                    &complaints,
+                    &justifications,
+                    3,
+                    5,
+                )
+                .unwrap();
+
+                DecryptionKeyShare::new(party_id, secret_key_share, &public_parameters).unwrap();
+
+                public_parameters
+            })
+            .collect();
+
+        assert!(
+            new_decryption_key_share_public_parameters
+                .windows(2)
+                .all(|public_parameters| public_parameters[0] == public_parameters[1]),
+            "all parties of the new committee should agree on its public parameters"
+        );
+    }
+
+    #[test]
+    fn rejects_insufficient_dealers() {
+        let (decryption_key_share_public_parameters, secret_key_shares) =
+            deal_trusted_shares(3, 4, N, SECRET_KEY, BASE);
+
+        assert!(matches!(
+            deal_decryption_key_share(
+                1,
+                secret_key_shares[&1],
+                &decryption_key_share_public_parameters,
+                3,
+                4,
+                &[1, 2],
+                3,
+                5,
+                &mut OsRng,
+            )
+            .err()
+            .unwrap(),
+            Error::ThresholdNotReached
+        ));
+
+        let (dealings, shares) = deal(
+            &decryption_key_share_public_parameters,
+            &secret_key_shares,
+            3,
+            4,
+            &[1, 2, 3],
+            3,
+            5,
+        );
+
+        assert!(matches!(
+            reshare(
+                1,
+                &decryption_key_share_public_parameters,
+                3,
+                4,
+                &[1, 2],
+                &dealings,
+                &shares,
+                &HashMap::new(),
+                &HashMap::new(),
+                3,
+                5,
+            )
+            .err()
+            .unwrap(),
+            Error::ThresholdNotReached
+        ));
+    }
+}
@@ -0,0 +1,219 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! A common [`Round`] trait for the parties of the DKG, presign and sign protocols.
+
+use crypto_bigint::rand_core::CryptoRngCore;
+
+use crate::Error;
+
+pub mod aggregation;
+
+/// The outcome of a party processing a [`Round`].
+pub enum Transition<Message, NextRound, Output> {
+    /// Send `Message` and proceed to `NextRound`.
+    Advance(Message, NextRound),
+    /// Send `Message` and terminate the protocol with `Output`.
+    Finalize(Message, Output),
+}
+
+impl<Message, NextRound, Output> Transition<Message, NextRound, Output> {
+    /// The message to send and the next round, for a round which is not the last of its protocol.
+    pub fn advance(self) -> crate::Result<(Message, NextRound)> {
+        match self {
+            Transition::Advance(message, next_round) => Ok((message, next_round)),
+            Transition::Finalize(..) => Err(Error::InternalError),
+        }
+    }
+
+    /// The message to send and the output, for the last round of its protocol.
+    pub fn finalize(self) -> crate::Result<(Message, Output)> {
+        match self {
+            Transition::Advance(..) => Err(Error::InternalError),
+            Transition::Finalize(message, output) => Ok((message, output)),
+        }
+    }
+}
+
+/// A round of a protocol, from the point of view of a single party.
+///
+/// A party processes the messages it received in this round, and either sends a message and
+/// proceeds to the next round, or terminates with the output of the protocol. All rounds of a
+/// protocol share the same `Output`, and the last round has an uninhabited `NextRound`.
+pub trait Round: Sized {
+    /// The messages processed in this round, e.g. the message of the centralized party or a map
+    /// of the messages broadcast by each of the decentralized parties.
+    type Incoming;
+    /// The message sent by this party at the end of this round.
+    type Message;
+    /// The party of the next round.
+    type NextRound;
+    /// The output of the protocol.
+    type Output;
+    /// The error returned when processing this round fails, e.g. when a party sent an invalid
+    /// message, in which case it should identify the malicious parties where possible.
+    type Error;
+
+    fn process(
+        self,
+        incoming: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>, Self::Error>;
+}
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs"
+))]
+pub(crate) mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use group::PartyID;
+    use rand::seq::IteratorRandom;
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::test_exports::N;
+
+    use super::*;
+    use crate::{
+        dkg,
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+        session::{tests::session_context, Protocol},
+    };
+
+    /// Have every party process `incoming`, returning the messages they sent and the parties of
+    /// the next round.
+    pub(crate) fn advance<R: Round<Error = Error>>(
+        parties: HashMap<PartyID, R>,
+        incoming: R::Incoming,
+    ) -> (HashMap<PartyID, R::Message>, HashMap<PartyID, R::NextRound>)
+    where
+        R::Incoming: Clone,
+    {
+        parties
+            .into_iter()
+            .map(|(party_id, party)| {
+                let (message, next_round) = party
+                    .process(incoming.clone(), &mut OsRng)
+                    .unwrap()
+                    .advance()
+                    .unwrap();
+
+                ((party_id, message), (party_id, next_round))
+            })
+            .unzip()
+    }
+
+    /// Have every party process `incoming` in the last round, returning the messages they sent
+    /// and their outputs.
+    pub(crate) fn finalize<R: Round<Error = Error>>(
+        parties: HashMap<PartyID, R>,
+        incoming: R::Incoming,
+    ) -> (HashMap<PartyID, R::Message>, HashMap<PartyID, R::Output>)
+    where
+        R::Incoming: Clone,
+    {
+        parties
+            .into_iter()
+            .map(|(party_id, party)| {
+                let (message, output) = party
+                    .process(incoming.clone(), &mut OsRng)
+                    .unwrap()
+                    .finalize()
+                    .unwrap();
+
+                ((party_id, message), (party_id, output))
+            })
+            .unzip()
+    }
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(2, 4)]
+    fn generates_distributed_key_through_rounds(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (commitment, centralized_party_decommitment_round_party) =
+            dkg::centralized_party::commitment_round::Party::new(
+                protocol_public_parameters.clone(),
+                session_context(Protocol::Dkg),
+            )
+            .process((), &mut OsRng)
+            .unwrap()
+            .advance()
+            .unwrap();
+
+        let parties: HashSet<PartyID> = (1..=number_of_parties)
+            .choose_multiple(&mut OsRng, threshold.into())
+            .into_iter()
+            .collect();
+
+        let encryption_of_secret_key_share_round_parties: HashMap<_, _> = parties
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    dkg::decentralized_party::encryption_of_secret_key_share_round::Party::new(
+                        protocol_public_parameters.clone(),
+                        party_id,
+                        threshold,
+                        parties.clone(),
+                        session_context(Protocol::Dkg),
+                    ),
+                )
+            })
+            .collect();
+
+        let (commitments, decommitment_round_parties) =
+            advance(encryption_of_secret_key_share_round_parties, commitment);
+        let (decommitments, proof_share_round_parties) =
+            advance(decommitment_round_parties, commitments);
+        let (proof_shares, proof_aggregation_round_parties) =
+            advance(proof_share_round_parties, decommitments);
+        let (secret_key_share_encryptions_and_proofs, verification_round_parties) =
+            advance(proof_aggregation_round_parties, proof_shares);
+
+        let secret_key_share_encryption_and_proof = secret_key_share_encryptions_and_proofs
+            .into_values()
+            .next()
+            .unwrap();
+
+        let (public_key_share_decommitment_and_proof, centralized_party_output) =
+            centralized_party_decommitment_round_party
+                .process(secret_key_share_encryption_and_proof, &mut OsRng)
+                .unwrap()
+                .finalize()
+                .unwrap();
+
+        let (_, decentralized_party_outputs) = finalize(
+            verification_round_parties,
+            public_key_share_decommitment_and_proof,
+        );
+
+        let decentralized_party_output = decentralized_party_outputs.values().next().unwrap();
+
+        assert!(decentralized_party_outputs
+            .values()
+            .all(|output| output == decentralized_party_output));
+
+        assert_eq!(
+            decentralized_party_output.public_key,
+            centralized_party_output.public_key
+        );
+
+        assert_eq!(
+            decentralized_party_output.public_key_share,
+            centralized_party_output.decentralized_party_public_key_share
+        );
+
+        assert_eq!(
+            decentralized_party_output.centralized_party_public_key_share,
+            centralized_party_output.public_key_share
+        );
+    }
+}
@@ -0,0 +1,471 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use std::{collections::HashMap, marker::PhantomData};
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::PartyID;
+use proof::aggregation::{
+    CommitmentRoundParty, DecommitmentRoundParty, ProofAggregationRoundParty, ProofShareRoundParty,
+};
+
+use crate::{
+    round::{Round, Transition},
+    Error, Result,
+};
+
+/// The commitment sent in the first round of the proof aggregation started from `Stage`.
+pub type Commitment<Stage> = <Stage as CommitmentStage>::Commitment;
+
+/// The decommitment sent in the second round of the proof aggregation started from `Stage`.
+pub type Decommitment<Stage> =
+    <<Stage as CommitmentStage>::DecommitmentStage as DecommitmentStage>::Decommitment;
+
+/// The output of the proof aggregation started from `Stage`.
+pub type AggregatedOutput<Stage> = <<<<Stage as CommitmentStage>::DecommitmentStage as DecommitmentStage>::ProofShareStage as ProofShareStage>::ProofAggregationStage as ProofAggregationStage>::Output;
+
+/// The commitment round of one or more proof aggregation sessions run in lockstep.
+pub trait CommitmentStage: Sized {
+    type Commitment;
+    type DecommitmentStage: DecommitmentStage;
+
+    fn commit(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Commitment, Self::DecommitmentStage)>;
+}
+
+/// The decommitment round of one or more proof aggregation sessions run in lockstep.
+pub trait DecommitmentStage: Sized {
+    type Commitment;
+    type Decommitment;
+    type ProofShareStage: ProofShareStage;
+
+    fn decommit(
+        self,
+        commitments: HashMap<PartyID, Self::Commitment>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Decommitment, Self::ProofShareStage)>;
+}
+
+/// The proof share round of one or more proof aggregation sessions run in lockstep.
+pub trait ProofShareStage: Sized {
+    type Decommitment;
+    type ProofShare;
+    type ProofAggregationStage: ProofAggregationStage;
+
+    fn generate_proof_share(
+        self,
+        decommitments: HashMap<PartyID, Self::Decommitment>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::ProofShare, Self::ProofAggregationStage)>;
+}
+
+/// The proof aggregation round of one or more proof aggregation sessions run in lockstep.
+pub trait ProofAggregationStage: Sized {
+    type ProofShare;
+    type Output;
+
+    fn aggregate_proof_shares(
+        self,
+        proof_shares: HashMap<PartyID, Self::ProofShare>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self::Output>;
+}
+
+/// A batch of proof aggregation sessions over the same language, run in lockstep: each party
+/// sends one message per session in every round, in order.
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Batch<Output, Party> {
+    parties: Vec<Party>,
+    _output_choice: PhantomData<Output>,
+}
+
+impl<Output, Party> Batch<Output, Party> {
+    pub fn new(parties: Vec<Party>) -> Self {
+        Self {
+            parties,
+            _output_choice: PhantomData,
+        }
+    }
+}
+
+/// Split the messages sent by each party for a batch of sessions into the messages of each
+/// session.
+///
+/// Parties which did not send exactly one message per session are identified as malicious.
+fn transpose<Message>(
+    batch_size: usize,
+    messages: HashMap<PartyID, Vec<Message>>,
+) -> Result<Vec<HashMap<PartyID, Message>>> {
+    let mut malicious_parties: Vec<_> = messages
+        .iter()
+        .filter(|(_, messages)| messages.len() != batch_size)
+        .map(|(&party_id, _)| party_id)
+        .collect();
+
+    if !malicious_parties.is_empty() {
+        malicious_parties.sort();
+
+        return Err(Error::MaliciousParties(malicious_parties));
+    }
+
+    let mut messages: HashMap<_, _> = messages
+        .into_iter()
+        .map(|(party_id, messages)| (party_id, messages.into_iter()))
+        .collect();
+
+    (0..batch_size)
+        .map(|_| {
+            messages
+                .iter_mut()
+                .map(|(party_id, messages)| {
+                    messages
+                        .next()
+                        .map(|message| (*party_id, message))
+                        .ok_or(Error::InternalError)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn unzip<First, Second>(
+    messages: HashMap<PartyID, (First, Second)>,
+) -> (HashMap<PartyID, First>, HashMap<PartyID, Second>) {
+    messages
+        .into_iter()
+        .map(|(party_id, (first, second))| ((party_id, first), (party_id, second)))
+        .unzip()
+}
+
+impl<Output, Party: CommitmentRoundParty<Output>> CommitmentStage for Batch<Output, Party>
+where
+    Error: From<Party::Error>,
+{
+    type Commitment = Vec<Party::Commitment>;
+    type DecommitmentStage = Batch<Output, Party::DecommitmentRoundParty>;
+
+    fn commit(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Commitment, Self::DecommitmentStage)> {
+        let (commitments, parties): (Vec<_>, Vec<_>) = self
+            .parties
+            .into_iter()
+            .map(|party| party.commit_statements_and_statement_mask(rng))
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        Ok((commitments, Batch::new(parties)))
+    }
+}
+
+impl<Output, Party: DecommitmentRoundParty<Output>> DecommitmentStage for Batch<Output, Party>
+where
+    Error: From<Party::Error>,
+{
+    type Commitment = Vec<Party::Commitment>;
+    type Decommitment = Vec<Party::Decommitment>;
+    type ProofShareStage = Batch<Output, Party::ProofShareRoundParty>;
+
+    fn decommit(
+        self,
+        commitments: HashMap<PartyID, Self::Commitment>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Decommitment, Self::ProofShareStage)> {
+        let batch_size = self.parties.len();
+        let (decommitments, parties): (Vec<_>, Vec<_>) = self
+            .parties
+            .into_iter()
+            .zip(transpose(batch_size, commitments)?)
+            .map(|(party, commitments)| {
+                party.decommit_statements_and_statement_mask(commitments, rng)
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        Ok((decommitments, Batch::new(parties)))
+    }
+}
+
+impl<Output, Party: ProofShareRoundParty<Output>> ProofShareStage for Batch<Output, Party>
+where
+    Error: From<Party::Error>,
+{
+    type Decommitment = Vec<Party::Decommitment>;
+    type ProofShare = Vec<Party::ProofShare>;
+    type ProofAggregationStage = Batch<Output, Party::ProofAggregationRoundParty>;
+
+    fn generate_proof_share(
+        self,
+        decommitments: HashMap<PartyID, Self::Decommitment>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::ProofShare, Self::ProofAggregationStage)> {
+        let batch_size = self.parties.len();
+        let (proof_shares, parties): (Vec<_>, Vec<_>) = self
+            .parties
+            .into_iter()
+            .zip(transpose(batch_size, decommitments)?)
+            .map(|(party, decommitments)| party.generate_proof_share(decommitments, rng))
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        Ok((proof_shares, Batch::new(parties)))
+    }
+}
+
+impl<Output, Party: ProofAggregationRoundParty<Output>> ProofAggregationStage
+    for Batch<Output, Party>
+where
+    Error: From<Party::Error>,
+{
+    type ProofShare = Vec<Party::ProofShare>;
+    type Output = Vec<Output>;
+
+    fn aggregate_proof_shares(
+        self,
+        proof_shares: HashMap<PartyID, Self::ProofShare>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self::Output> {
+        let batch_size = self.parties.len();
+
+        Ok(self
+            .parties
+            .into_iter()
+            .zip(transpose(batch_size, proof_shares)?)
+            .map(|(party, proof_shares)| party.aggregate_proof_shares(proof_shares, rng))
+            .collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+}
+
+impl<First: CommitmentStage, Second: CommitmentStage> CommitmentStage for (First, Second) {
+    type Commitment = (First::Commitment, Second::Commitment);
+    type DecommitmentStage = (First::DecommitmentStage, Second::DecommitmentStage);
+
+    fn commit(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Commitment, Self::DecommitmentStage)> {
+        let (first, second) = self;
+
+        let (first_commitment, first) = first.commit(rng)?;
+        let (second_commitment, second) = second.commit(rng)?;
+
+        Ok(((first_commitment, second_commitment), (first, second)))
+    }
+}
+
+impl<First: DecommitmentStage, Second: DecommitmentStage> DecommitmentStage for (First, Second) {
+    type Commitment = (First::Commitment, Second::Commitment);
+    type Decommitment = (First::Decommitment, Second::Decommitment);
+    type ProofShareStage = (First::ProofShareStage, Second::ProofShareStage);
+
+    fn decommit(
+        self,
+        commitments: HashMap<PartyID, Self::Commitment>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Decommitment, Self::ProofShareStage)> {
+        let (first, second) = self;
+        let (first_commitments, second_commitments) = unzip(commitments);
+
+        let (first_decommitment, first) = first.decommit(first_commitments, rng)?;
+        let (second_decommitment, second) = second.decommit(second_commitments, rng)?;
+
+        Ok(((first_decommitment, second_decommitment), (first, second)))
+    }
+}
+
+impl<First: ProofShareStage, Second: ProofShareStage> ProofShareStage for (First, Second) {
+    type Decommitment = (First::Decommitment, Second::Decommitment);
+    type ProofShare = (First::ProofShare, Second::ProofShare);
+    type ProofAggregationStage = (First::ProofAggregationStage, Second::ProofAggregationStage);
+
+    fn generate_proof_share(
+        self,
+        decommitments: HashMap<PartyID, Self::Decommitment>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::ProofShare, Self::ProofAggregationStage)> {
+        let (first, second) = self;
+        let (first_decommitments, second_decommitments) = unzip(decommitments);
+
+        let (first_proof_share, first) = first.generate_proof_share(first_decommitments, rng)?;
+        let (second_proof_share, second) =
+            second.generate_proof_share(second_decommitments, rng)?;
+
+        Ok(((first_proof_share, second_proof_share), (first, second)))
+    }
+}
+
+impl<First: ProofAggregationStage, Second: ProofAggregationStage> ProofAggregationStage
+    for (First, Second)
+{
+    type ProofShare = (First::ProofShare, Second::ProofShare);
+    type Output = (First::Output, Second::Output);
+
+    fn aggregate_proof_shares(
+        self,
+        proof_shares: HashMap<PartyID, Self::ProofShare>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self::Output> {
+        let (first, second) = self;
+        let (first_proof_shares, second_proof_shares) = unzip(proof_shares);
+
+        Ok((
+            first.aggregate_proof_shares(first_proof_shares, rng)?,
+            second.aggregate_proof_shares(second_proof_shares, rng)?,
+        ))
+    }
+}
+
+/// Start a proof aggregation, returning the commitment to broadcast and the round in which to
+/// process those of the other parties.
+///
+/// Once the proof is aggregated, the party proceeds with `then`, which processes the
+/// decommitments of all parties alongside the output of the aggregation.
+pub fn commit<Stage: CommitmentStage, Then>(
+    stage: Stage,
+    then: Then,
+    rng: &mut impl CryptoRngCore,
+) -> Result<(
+    Stage::Commitment,
+    DecommitmentRound<Stage::DecommitmentStage, Then>,
+)> {
+    let (commitment, stage) = stage.commit(rng)?;
+
+    Ok((commitment, DecommitmentRound { stage, then }))
+}
+
+/// The decommitment round of a proof aggregation, see [`commit`].
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct DecommitmentRound<Stage, Then> {
+    stage: Stage,
+    then: Then,
+}
+
+impl<Stage: DecommitmentStage, Then: Round<Error = Error>> Round
+    for DecommitmentRound<Stage, Then>
+{
+    type Incoming = HashMap<PartyID, Stage::Commitment>;
+    type Message = Stage::Decommitment;
+    type NextRound = ProofShareRound<Stage::ProofShareStage, Then>;
+    type Output = Then::Output;
+    type Error = Error;
+
+    fn process(
+        self,
+        commitments: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (decommitment, stage) = self.stage.decommit(commitments, rng)?;
+
+        Ok(Transition::Advance(
+            decommitment,
+            ProofShareRound {
+                stage,
+                then: self.then,
+            },
+        ))
+    }
+}
+
+/// The proof share round of a proof aggregation, see [`commit`].
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct ProofShareRound<Stage, Then> {
+    stage: Stage,
+    then: Then,
+}
+
+impl<Stage: ProofShareStage, Then: Round<Error = Error>> Round for ProofShareRound<Stage, Then>
+where
+    Stage::Decommitment: Clone,
+{
+    type Incoming = HashMap<PartyID, Stage::Decommitment>;
+    type Message = Stage::ProofShare;
+    type NextRound = ProofAggregationRound<Stage::ProofAggregationStage, Stage::Decommitment, Then>;
+    type Output = Then::Output;
+    type Error = Error;
+
+    fn process(
+        self,
+        decommitments: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (proof_share, stage) = self
+            .stage
+            .generate_proof_share(decommitments.clone(), rng)?;
+
+        Ok(Transition::Advance(
+            proof_share,
+            ProofAggregationRound {
+                stage,
+                decommitments,
+                then: self.then,
+            },
+        ))
+    }
+}
+
+/// The proof aggregation round of a proof aggregation, see [`commit`], which proceeds directly
+/// with the round that follows the aggregation.
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct ProofAggregationRound<Stage, Decommitment, Then> {
+    stage: Stage,
+    decommitments: HashMap<PartyID, Decommitment>,
+    then: Then,
+}
+
+impl<Stage: ProofAggregationStage, Decommitment, Then> Round
+    for ProofAggregationRound<Stage, Decommitment, Then>
+where
+    Then: Round<Incoming = (HashMap<PartyID, Decommitment>, Stage::Output), Error = Error>,
+{
+    type Incoming = HashMap<PartyID, Stage::ProofShare>;
+    type Message = Then::Message;
+    type NextRound = Then::NextRound;
+    type Output = Then::Output;
+    type Error = Error;
+
+    fn process(
+        self,
+        proof_shares: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let output = self.stage.aggregate_proof_shares(proof_shares, rng)?;
+
+        self.then.process((self.decommitments, output), rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn transposes_batched_messages() {
+        let messages = HashMap::from([(1, vec![10, 11]), (2, vec![20, 21])]);
+
+        assert_eq!(
+            transpose(2, messages).unwrap(),
+            vec![
+                HashMap::from([(1, 10), (2, 20)]),
+                HashMap::from([(1, 11), (2, 21)])
+            ]
+        );
+    }
+
+    #[test]
+    fn identifies_parties_sending_wrong_batch_sizes() {
+        let messages = HashMap::from([(1, vec![10, 11]), (2, vec![20]), (3, vec![30, 31, 32])]);
+
+        assert!(matches!(
+            transpose(2, messages).err().unwrap(),
+            Error::MaliciousParties(parties) if parties == vec![2, 3]
+        ));
+    }
+}
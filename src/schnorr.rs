@@ -0,0 +1,604 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! BIP-340 Schnorr signing over secp256k1, using the same DKG output and presigns as ECDSA.
+
+use std::ops::Neg;
+
+use crypto_bigint::{NonZero, Uint};
+use group::{GroupElement, KnownOrderGroupElement, PrimeGroupElement, Reduce};
+use k256::elliptic_curve::point::AffineCoordinates;
+use sha2::{Digest, Sha256};
+
+use crate::Error;
+
+pub mod centralized_party;
+pub mod decentralized_party;
+
+/// The size in bytes of a BIP-340 signature: the x-coordinate of the public nonce $R$, followed by
+/// the big-endian encoding of $s$.
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// The size in bytes of a BIP-340 x-only public key.
+pub const X_ONLY_PUBLIC_KEY_SIZE: usize = 32;
+
+/// A group element which can be encoded as a BIP-340 x-only point.
+pub trait XOnlyGroupElement<const SCALAR_LIMBS: usize>: PrimeGroupElement<SCALAR_LIMBS> {
+    /// The big-endian encoding of the affine x-coordinate of this point.
+    fn x_only_bytes(&self) -> [u8; X_ONLY_PUBLIC_KEY_SIZE];
+
+    /// Whether the affine y-coordinate of this point is even.
+    fn has_even_y(&self) -> bool;
+}
+
+impl XOnlyGroupElement<{ group::secp256k1::SCALAR_LIMBS }> for group::secp256k1::GroupElement {
+    fn x_only_bytes(&self) -> [u8; X_ONLY_PUBLIC_KEY_SIZE] {
+        let point: k256::AffinePoint = self.value().into();
+
+        point.x().into()
+    }
+
+    fn has_even_y(&self) -> bool {
+        let point: k256::AffinePoint = self.value().into();
+
+        !bool::from(point.y_is_odd())
+    }
+}
+
+/// Returns `point` if its y-coordinate is even, and its negation otherwise, alongside whether it
+/// was negated. BIP-340 implicitly works with the even-y representative of every x-only point.
+pub(crate) fn to_even_y<
+    const SCALAR_LIMBS: usize,
+    GroupElement: XOnlyGroupElement<SCALAR_LIMBS>,
+>(
+    point: GroupElement,
+) -> (GroupElement, bool) {
+    if point.has_even_y() {
+        (point, false)
+    } else {
+        (point.neg(), true)
+    }
+}
+
+/// The BIP-340 x-only encoding of `public_key`.
+pub fn x_only_public_key<
+    const SCALAR_LIMBS: usize,
+    GroupElement: XOnlyGroupElement<SCALAR_LIMBS>,
+>(
+    public_key: GroupElement::Value,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<[u8; X_ONLY_PUBLIC_KEY_SIZE]> {
+    let public_key = GroupElement::new(public_key, group_public_parameters)?;
+
+    Ok(public_key.x_only_bytes())
+}
+
+/// $ \textsf{hash}_{tag}(x) = SHA256(SHA256(tag) || SHA256(tag) || x) $, as defined in BIP-340.
+fn tagged_hash(tag: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag);
+
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    data.iter().for_each(|data| hasher.update(data));
+
+    hasher.finalize().into()
+}
+
+/// Compute the BIP-340 challenge
+/// $ e = \textsf{int}(\textsf{hash}_{BIP0340/challenge}(R_x || P_x || m)) \mod q $.
+pub fn challenge<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>(
+    nonce_x_coordinate: &[u8; X_ONLY_PUBLIC_KEY_SIZE],
+    public_key_x_coordinate: &[u8; X_ONLY_PUBLIC_KEY_SIZE],
+    message: &[u8],
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<GroupElement::Scalar> {
+    if Uint::<SCALAR_LIMBS>::BYTES != 32 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let challenge = tagged_hash(
+        b"BIP0340/challenge",
+        &[nonce_x_coordinate, public_key_x_coordinate, message],
+    );
+    let challenge = Uint::<SCALAR_LIMBS>::from_be_slice(&challenge);
+
+    let group_order =
+        GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters);
+    let group_order = Option::<_>::from(NonZero::new(group_order)).ok_or(Error::InternalError)?;
+
+    Ok(GroupElement::Scalar::new(
+        challenge.reduce(&group_order).into(),
+        scalar_group_public_parameters,
+    )?)
+}
+
+/// Encode a signature $(R_x, s)$ in the 64-byte BIP-340 format. Assumes a 256-bit `signature_s`,
+/// which [`challenge`] and [`verify_signature`] enforce.
+pub(crate) fn encode_signature<const SCALAR_LIMBS: usize>(
+    nonce_x_coordinate: [u8; X_ONLY_PUBLIC_KEY_SIZE],
+    signature_s: Uint<SCALAR_LIMBS>,
+) -> [u8; SIGNATURE_SIZE] {
+    let mut signature = [0u8; SIGNATURE_SIZE];
+
+    signature[..X_ONLY_PUBLIC_KEY_SIZE].copy_from_slice(&nonce_x_coordinate);
+
+    let signature_s: Vec<u8> = signature_s
+        .as_words()
+        .iter()
+        .rev()
+        .flat_map(|word| word.to_be_bytes())
+        .collect();
+    signature[X_ONLY_PUBLIC_KEY_SIZE..].copy_from_slice(&signature_s);
+
+    signature
+}
+
+/// Verify a BIP-340 `signature` on `message` under `public_key`, of which only the x-coordinate
+/// is used.
+pub fn verify_signature<
+    const SCALAR_LIMBS: usize,
+    GroupElement: XOnlyGroupElement<SCALAR_LIMBS>,
+>(
+    signature: &[u8; SIGNATURE_SIZE],
+    message: &[u8],
+    public_key: GroupElement,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<()> {
+    if Uint::<SCALAR_LIMBS>::BYTES != 32 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let mut nonce_x_coordinate = [0u8; X_ONLY_PUBLIC_KEY_SIZE];
+    nonce_x_coordinate.copy_from_slice(&signature[..X_ONLY_PUBLIC_KEY_SIZE]);
+
+    let signature_s = Uint::<SCALAR_LIMBS>::from_be_slice(&signature[X_ONLY_PUBLIC_KEY_SIZE..]);
+    if signature_s
+        >= GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters)
+    {
+        return Err(Error::SignatureVerification);
+    }
+    let signature_s =
+        GroupElement::Scalar::new(signature_s.into(), scalar_group_public_parameters)?;
+
+    let (public_key, _) = to_even_y::<SCALAR_LIMBS, GroupElement>(public_key);
+
+    let challenge = challenge::<SCALAR_LIMBS, GroupElement>(
+        &nonce_x_coordinate,
+        &public_key.x_only_bytes(),
+        message,
+        scalar_group_public_parameters,
+    )?;
+
+    // $ R = s \cdot G - e \cdot P $
+    let nonce = (signature_s * public_key.generator()) + (challenge * public_key).neg();
+
+    if nonce == nonce.neutral() || !nonce.has_even_y() || nonce.x_only_bytes() != nonce_x_coordinate
+    {
+        return Err(Error::SignatureVerification);
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "paillier", feature = "bulletproofs"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use crypto_bigint::{Encoding, U256};
+    use group::{secp256k1, PartyID};
+    use homomorphic_encryption::{
+        AdditivelyHomomorphicDecryptionKey, AdditivelyHomomorphicDecryptionKeyShare,
+        GroupsPublicParametersAccessors,
+    };
+    use k256::schnorr::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::{
+        test_exports::{N, SECRET_KEY},
+        DecryptionKeyShare,
+    };
+
+    use super::*;
+    use crate::{
+        dkg::tests::generates_distributed_key_internal,
+        presign::tests::generates_presignatures_internal,
+        schnorr::{
+            centralized_party::signature_share_round,
+            decentralized_party::signature_partial_decryption_round,
+        },
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+        session::{tests::session_context, Protocol, SessionContext},
+        sign::tests::setup_decryption_key_shares,
+    };
+
+    fn signature_from_hex(signature: &str) -> [u8; SIGNATURE_SIZE] {
+        let mut bytes = [0u8; SIGNATURE_SIZE];
+        bytes[..32].copy_from_slice(&U256::from_be_hex(&signature[..64]).to_be_bytes());
+        bytes[32..].copy_from_slice(&U256::from_be_hex(&signature[64..]).to_be_bytes());
+
+        bytes
+    }
+
+    // Test vectors 0-3 of BIP-340.
+    #[rstest]
+    #[case(
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0"
+    )]
+    #[case(
+        "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+        "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A"
+    )]
+    #[case(
+        "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+        "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+        "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+        "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7"
+    )]
+    #[case(
+        "0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710",
+        "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+        "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3"
+    )]
+    fn verifies_bip340_test_vectors(
+        #[case] secret_key: &str,
+        #[case] public_key: &str,
+        #[case] message: &str,
+        #[case] signature: &str,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let generator = secp256k1::GroupElement::new(
+            protocol_public_parameters.group_public_parameters.generator,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let secret_key: secp256k1::Scalar = U256::from_be_hex(secret_key).into();
+        let public_key_point = secret_key * generator;
+
+        assert_eq!(
+            public_key_point.x_only_bytes(),
+            U256::from_be_hex(public_key).to_be_bytes()
+        );
+
+        let message = U256::from_be_hex(message).to_be_bytes();
+        let mut signature = signature_from_hex(signature);
+
+        assert!(verify_signature::<{ secp256k1::SCALAR_LIMBS }, _>(
+            &signature,
+            &message,
+            public_key_point,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .is_ok());
+
+        signature[SIGNATURE_SIZE - 1] ^= 1;
+
+        assert!(matches!(
+            verify_signature::<{ secp256k1::SCALAR_LIMBS }, _>(
+                &signature,
+                &message,
+                public_key_point,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            ),
+            Err(Error::SignatureVerification)
+        ));
+    }
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(2, 4)]
+    #[case(6, 9)]
+    fn dkg_presign_signs(#[case] threshold: PartyID, #[case] number_of_parties: PartyID) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presign, _, decentralized_party_presign) =
+            generates_presignatures_internal(
+                threshold,
+                number_of_parties,
+                1,
+                encrypted_secret_key_share,
+                false,
+            )
+            .unwrap();
+
+        let centralized_party_presign = centralized_party_presign.first().unwrap().clone();
+        let decentralized_party_presign = decentralized_party_presign.first().unwrap().clone();
+
+        let public_key = secp256k1::GroupElement::new(
+            centralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let x_only_public_key =
+            x_only_public_key::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+                centralized_party_dkg_output.public_key,
+                &protocol_public_parameters.group_public_parameters,
+            )
+            .unwrap();
+        assert_eq!(x_only_public_key, public_key.x_only_bytes());
+
+        // BIP-340 signs arbitrary messages, but k256 only verifies 32-byte ones.
+        let message: [u8; 32] = Sha256::digest("signing with schnorr!").into();
+
+        let centralized_party = signature_share_round::Party::new(
+            session_context(Protocol::Schnorr),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output,
+            centralized_party_presign,
+        )
+        .unwrap();
+
+        let (public_nonce_share_signature_share_and_proof, signature_verification_round_party) =
+            centralized_party
+                .sign_signature_share(&message, &mut OsRng)
+                .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let (decryption_shares, mut signature_threshold_decryption_round_parties): (
+            HashMap<_, _>,
+            HashMap<_, _>,
+        ) = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                let party = signature_partial_decryption_round::Party::<
+                    { secp256k1::SCALAR_LIMBS },
+                    { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                    secp256k1::GroupElement,
+                    tiresias::EncryptionKey,
+                    DecryptionKeyShare,
+                    SessionContext,
+                >::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters.clone(),
+                    session_context(Protocol::Schnorr),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output.clone(),
+                    decentralized_party_presign.clone(),
+                )
+                .unwrap();
+
+                let (decryption_share, signature_threshold_decryption_round_party) = party
+                    .partially_decrypt_signature_share(
+                        &message,
+                        public_nonce_share_signature_share_and_proof.clone(),
+                    )
+                    .unwrap();
+
+                (
+                    (party_id, decryption_share),
+                    (party_id, signature_threshold_decryption_round_party),
+                )
+            })
+            .unzip();
+
+        let designated_decrypting_party_id = *signature_threshold_decryption_round_parties
+            .keys()
+            .next()
+            .unwrap();
+
+        let designated_decrypting_party = signature_threshold_decryption_round_parties
+            .remove(&designated_decrypting_party_id)
+            .unwrap();
+
+        let signature = designated_decrypting_party
+            .decrypt_signature(lagrange_coefficients, decryption_shares)
+            .unwrap();
+
+        for (_, party) in signature_threshold_decryption_round_parties {
+            assert_eq!(
+                party
+                    .verify_decrypted_signature(signature, designated_decrypting_party_id)
+                    .unwrap(),
+                signature
+            );
+        }
+
+        assert!(signature_verification_round_party
+            .verify_signature(signature)
+            .is_ok());
+
+        let verifying_key = VerifyingKey::from_bytes(&x_only_public_key).unwrap();
+        let k256_signature = Signature::try_from(&signature[..]).unwrap();
+
+        assert!(
+            verifying_key
+                .verify_prehash(&message, &k256_signature)
+                .is_ok(),
+            "signature should verify under the x-only public key using k256's BIP-340 implementation"
+        );
+    }
+
+    #[test]
+    fn masks_decrypted_signature_share() {
+        let threshold = 2;
+        let number_of_parties = 4;
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presign, _, decentralized_party_presign) =
+            generates_presignatures_internal(
+                threshold,
+                number_of_parties,
+                1,
+                encrypted_secret_key_share,
+                false,
+            )
+            .unwrap();
+
+        let centralized_party_presign = centralized_party_presign.first().unwrap().clone();
+        let decentralized_party_presign = decentralized_party_presign.first().unwrap().clone();
+
+        let message: [u8; 32] = Sha256::digest("signing with schnorr!").into();
+
+        let (public_nonce_share_signature_share_and_proof, _) = signature_share_round::Party::new(
+            session_context(Protocol::Schnorr),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output.clone(),
+            centralized_party_presign,
+        )
+        .unwrap()
+        .sign_signature_share(&message, &mut OsRng)
+        .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let decryption_shares: HashMap<_, _> = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                let (decryption_share, _) = signature_partial_decryption_round::Party::<
+                    { secp256k1::SCALAR_LIMBS },
+                    { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                    secp256k1::GroupElement,
+                    tiresias::EncryptionKey,
+                    DecryptionKeyShare,
+                    SessionContext,
+                >::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters.clone(),
+                    session_context(Protocol::Schnorr),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output.clone(),
+                    decentralized_party_presign.clone(),
+                )
+                .unwrap()
+                .partially_decrypt_signature_share(
+                    &message,
+                    public_nonce_share_signature_share_and_proof.clone(),
+                )
+                .unwrap();
+
+                (party_id, decryption_share)
+            })
+            .collect();
+
+        let decrypted_signature_share = DecryptionKeyShare::combine_decryption_shares_semi_honest(
+            decryption_shares,
+            lagrange_coefficients,
+            &decryption_key_share_public_parameters,
+        )
+        .unwrap();
+
+        // Compute the unmasked $ k_B \pm e \cdot x_B $ the network would otherwise have decrypted.
+        let public_nonce_share = secp256k1::GroupElement::new(
+            public_nonce_share_signature_share_and_proof.public_nonce_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let nonce_public_share = secp256k1::GroupElement::new(
+            decentralized_party_presign.nonce_public_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let public_key = secp256k1::GroupElement::new(
+            centralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let (public_nonce, negated_nonce) =
+            to_even_y::<{ secp256k1::SCALAR_LIMBS }, _>(public_nonce_share + nonce_public_share);
+        let (public_key, negated_public_key) =
+            to_even_y::<{ secp256k1::SCALAR_LIMBS }, _>(public_key);
+
+        let challenge = challenge::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+            &public_nonce.x_only_bytes(),
+            &public_key.x_only_bytes(),
+            &message,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let key_share_coefficient = if negated_nonce != negated_public_key {
+            challenge.neg()
+        } else {
+            challenge
+        };
+        let key_share_coefficient: Uint<{ secp256k1::SCALAR_LIMBS }> = key_share_coefficient.into();
+
+        let paillier_decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let [nonce_share, secret_key_share] = [
+            decentralized_party_presign.encrypted_nonce_share,
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+        ]
+        .map(|ciphertext| {
+            paillier_decryption_key
+                .decrypt(
+                    &tiresias::CiphertextSpaceGroupElement::new(
+                        ciphertext,
+                        protocol_public_parameters
+                            .encryption_scheme_public_parameters
+                            .ciphertext_space_public_parameters(),
+                    )
+                    .unwrap(),
+                    &protocol_public_parameters.encryption_scheme_public_parameters,
+                )
+                .unwrap()
+        });
+
+        let unmasked_signature_share =
+            nonce_share + secret_key_share.scalar_mul(&key_share_coefficient);
+
+        assert_ne!(
+            decrypted_signature_share, unmasked_signature_share,
+            "the decentralized party's signature share should be decrypted masked"
+        );
+
+        let group_order = Option::<_>::from(NonZero::new(
+            secp256k1::Scalar::order_from_public_parameters(
+                &protocol_public_parameters.scalar_group_public_parameters,
+            ),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            (decrypted_signature_share - unmasked_signature_share)
+                .value()
+                .reduce(&group_order),
+            Uint::<{ secp256k1::SCALAR_LIMBS }>::ZERO,
+            "the mask should be a multiple of the group order"
+        );
+    }
+}
@@ -0,0 +1,20 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use serde::{Deserialize, Serialize};
+
+pub mod signature_share_round;
+pub mod signature_verification_round;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PublicNonceShareSignatureShareAndProof<
+    GroupElementValue,
+    ScalarValue,
+    CiphertextValue,
+    ComDLProof,
+> {
+    pub public_nonce_share: GroupElementValue, // $R_A$
+    pub(super) public_nonce_share_proof: ComDLProof,
+    pub(super) signature_share: ScalarValue,    // $s_A$
+    pub(super) encrypted_mask: CiphertextValue, // $ \textsf{Enc}(\eta \cdot q) $
+}
@@ -0,0 +1,264 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use std::ops::Neg;
+
+use commitment::Pedersen;
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use enhanced_maurer::language::composed_witness_upper_bound;
+use group::{GroupElement as _, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use maurer::{committment_of_discrete_log, SOUND_PROOFS_REPETITIONS};
+use proof::AggregatableRangeProof;
+
+use crate::{
+    dkg, presign,
+    schnorr::{
+        centralized_party::{signature_verification_round, PublicNonceShareSignatureShareAndProof},
+        challenge, to_even_y, XOnlyGroupElement,
+    },
+    schnorr_like::{encrypt_mask, prove_public_nonce_share},
+    session::{RoundContext, RoundId},
+    ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: XOnlyGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    ProtocolContext: RoundContext,
+> {
+    pub(in crate::schnorr) protocol_context: ProtocolContext,
+    pub(in crate::schnorr) scalar_group_public_parameters:
+        group::PublicParameters<GroupElement::Scalar>,
+    pub(in crate::schnorr) group_public_parameters: GroupElement::PublicParameters,
+    pub(in crate::schnorr) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(in crate::schnorr) public_key: GroupElement,
+    pub(in crate::schnorr) secret_key_share: GroupElement::Scalar,
+    pub(in crate::schnorr) nonce_share_commitment_randomness: GroupElement::Scalar,
+    pub(in crate::schnorr) nonce_share: GroupElement::Scalar,
+    pub(in crate::schnorr) decentralized_party_nonce_public_share: GroupElement,
+    pub(in crate::schnorr) encrypted_nonce_share: EncryptionKey::CiphertextSpaceGroupElement,
+    pub(in crate::schnorr) encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    pub(in crate::schnorr) encrypted_share_upper_bound: Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: XOnlyGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        ProtocolContext: RoundContext,
+    >
+    Party<SCALAR_LIMBS, PLAINTEXT_SPACE_SCALAR_LIMBS, GroupElement, EncryptionKey, ProtocolContext>
+{
+    /// Compute the centralized party's share $ s_A = k_A + e \cdot x_A $ of the BIP-340 signature
+    /// over `message`, where $k_A$ and $x_A$ are negated as needed for the public nonce
+    /// $R = R_A + R_B$ and the public key to have an even y-coordinate.
+    ///
+    /// The public nonce share $R_A = k_A \cdot G$ is sent alongside a proof that it is consistent
+    /// with the commitment to $k_A$ from the presign protocol, so that it could not have been
+    /// chosen after seeing $R_B$.
+    ///
+    /// An encryption of $ \eta \cdot q $ for a random statistical mask $\eta$ is sent as well,
+    /// which the decentralized party adds to its share of the signature before decrypting it, so
+    /// that the decrypted integer does not reveal $ k_B \pm e \cdot x_B $ beyond its value
+    /// modulo $q$ (as in ECDSA, see
+    /// [`AdditivelyHomomorphicEncryptionKey::sample_mask_for_secure_function_evaluation`]).
+    pub fn sign_signature_share(
+        self,
+        message: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        PublicNonceShareSignatureShareAndProof<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+        >,
+        signature_verification_round::Party<SCALAR_LIMBS, GroupElement>,
+    )> {
+        let generator = self.public_key.generator();
+
+        let public_nonce_share = self.nonce_share * generator; // $R_A$
+
+        let (public_nonce, negated_nonce) = to_even_y::<SCALAR_LIMBS, GroupElement>(
+            public_nonce_share + self.decentralized_party_nonce_public_share,
+        ); // $R$
+
+        let (public_key, negated_public_key) =
+            to_even_y::<SCALAR_LIMBS, GroupElement>(self.public_key);
+
+        let challenge = challenge::<SCALAR_LIMBS, GroupElement>(
+            &public_nonce.x_only_bytes(),
+            &public_key.x_only_bytes(),
+            message,
+            &self.scalar_group_public_parameters,
+        )?; // $e$
+
+        let nonce_share = if negated_nonce {
+            self.nonce_share.neg()
+        } else {
+            self.nonce_share
+        };
+
+        let secret_key_share = if negated_public_key {
+            self.secret_key_share.neg()
+        } else {
+            self.secret_key_share
+        };
+
+        let signature_share = nonce_share + (challenge * secret_key_share); // $s_A$
+
+        let public_nonce_share_proof =
+            prove_public_nonce_share::<SCALAR_LIMBS, GroupElement, ProtocolContext>(
+                &self.protocol_context.round(RoundId::SchnorrSignatureShare),
+                self.nonce_share,
+                self.nonce_share_commitment_randomness,
+                generator,
+                &self.scalar_group_public_parameters,
+                &self.group_public_parameters,
+                rng,
+            )?;
+
+        // The nonce share is added to the key share multiplied by $ \pm e < q $, both of which
+        // were proven to be bounded by `encrypted_share_upper_bound`.
+        let encrypted_mask = encrypt_mask::<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+        >(
+            self.encrypted_nonce_share,
+            self.encrypted_secret_key_share,
+            self.encrypted_share_upper_bound,
+            &self.scalar_group_public_parameters,
+            &self.encryption_scheme_public_parameters,
+            rng,
+        )?;
+
+        let public_nonce_share_signature_share_and_proof = PublicNonceShareSignatureShareAndProof {
+            public_nonce_share: public_nonce_share.value(),
+            public_nonce_share_proof,
+            signature_share: signature_share.value(),
+            encrypted_mask: encrypted_mask.value(),
+        };
+
+        let signature_verification_round_party = signature_verification_round::Party {
+            message: message.to_vec(),
+            public_key: self.public_key,
+            scalar_group_public_parameters: self.scalar_group_public_parameters,
+        };
+
+        Ok((
+            public_nonce_share_signature_share_and_proof,
+            signature_verification_round_party,
+        ))
+    }
+
+    pub fn new<
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        presign: presign::centralized_party::Presign<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<Self> {
+        let scalar_group_public_parameters =
+            protocol_public_parameters.scalar_group_public_parameters;
+        let group_public_parameters = protocol_public_parameters.group_public_parameters;
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let public_key = GroupElement::new(dkg_output.public_key, &group_public_parameters)?;
+
+        let secret_key_share = GroupElement::Scalar::new(
+            dkg_output.secret_key_share,
+            &scalar_group_public_parameters,
+        )?;
+
+        let nonce_share_commitment_randomness = GroupElement::Scalar::new(
+            presign.commitment_randomness,
+            &scalar_group_public_parameters,
+        )?;
+
+        let nonce_share =
+            GroupElement::Scalar::new(presign.nonce_share, &scalar_group_public_parameters)?;
+
+        let decentralized_party_nonce_public_share = GroupElement::new(
+            presign.decentralized_party_nonce_public_share,
+            &group_public_parameters,
+        )?;
+
+        let encrypted_nonce_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            presign.encrypted_nonce_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            dkg_output.encrypted_decentralized_party_secret_key_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_share_upper_bound = composed_witness_upper_bound::<
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+        >()?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            public_key,
+            secret_key_share,
+            nonce_share_commitment_randomness,
+            nonce_share,
+            decentralized_party_nonce_public_share,
+            encrypted_nonce_share,
+            encrypted_secret_key_share,
+            encrypted_share_upper_bound,
+        })
+    }
+}
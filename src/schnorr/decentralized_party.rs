@@ -0,0 +1,7 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+pub mod signature_partial_decryption_round;
+pub mod signature_threshold_decryption_round;
@@ -0,0 +1,292 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use std::ops::Neg;
+
+use commitment::Pedersen;
+use group::{GroupElement, PartyID, Samplable};
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+    GroupsPublicParametersAccessors,
+};
+use maurer::{committment_of_discrete_log, SOUND_PROOFS_REPETITIONS};
+use proof::AggregatableRangeProof;
+
+use crate::{
+    dkg, presign,
+    schnorr::{
+        centralized_party::PublicNonceShareSignatureShareAndProof, challenge,
+        decentralized_party::signature_threshold_decryption_round, to_even_y, XOnlyGroupElement,
+    },
+    schnorr_like::{partially_decrypt_signature_share, verify_public_nonce_share},
+    session::{RoundContext, RoundId},
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: XOnlyGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+    ProtocolContext: RoundContext,
+> {
+    pub(in crate::schnorr) threshold: PartyID,
+    pub(in crate::schnorr) decryption_key_share: DecryptionKeyShare,
+    pub(in crate::schnorr) decryption_key_share_public_parameters:
+        DecryptionKeyShare::PublicParameters,
+    pub(in crate::schnorr) protocol_context: ProtocolContext,
+    pub(in crate::schnorr) scalar_group_public_parameters:
+        group::PublicParameters<GroupElement::Scalar>,
+    pub(in crate::schnorr) group_public_parameters: GroupElement::PublicParameters,
+    pub(in crate::schnorr) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(in crate::schnorr) public_key: GroupElement,
+    pub(in crate::schnorr) nonce_public_share: GroupElement,
+    pub(in crate::schnorr) encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    pub(in crate::schnorr) encrypted_nonce_share: EncryptionKey::CiphertextSpaceGroupElement,
+    pub(in crate::schnorr) centralized_party_public_key_share: GroupElement,
+    pub(in crate::schnorr) centralized_party_nonce_share_commitment: GroupElement,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: XOnlyGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+        ProtocolContext,
+    >
+where
+    Error: From<DecryptionKeyShare::Error>,
+{
+    /// Verify the signature share sent by the centralized party over `message`, and partially
+    /// decrypt the decentralized party's share of the signature
+    /// $ \ct_3 \oplus (\pm e \odot \textsf{ct}_{\textsf{key}}) $,
+    /// i.e. an encryption of $ k_B \pm e \cdot x_B $, masked by the centralized party's
+    /// encryption of $ \eta \cdot q $ so that decrypting it only reveals $s_B$ modulo $q$.
+    ///
+    /// Unlike ECDSA, the centralized party's signature share is sent in the clear and can be
+    /// verified directly against its public key and nonce shares, so no homomorphic evaluation is
+    /// required of it.
+    pub fn partially_decrypt_signature_share(
+        self,
+        message: &[u8],
+        public_nonce_share_signature_share_and_proof: PublicNonceShareSignatureShareAndProof<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+        >,
+    ) -> crate::Result<(
+        DecryptionKeyShare::DecryptionShare,
+        signature_threshold_decryption_round::Party<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            DecryptionKeyShare,
+        >,
+    )> {
+        let generator = self.public_key.generator();
+
+        let public_nonce_share = GroupElement::new(
+            public_nonce_share_signature_share_and_proof.public_nonce_share,
+            &self.group_public_parameters,
+        )?; // $R_A$
+
+        let signature_share = GroupElement::Scalar::new(
+            public_nonce_share_signature_share_and_proof.signature_share,
+            &self.scalar_group_public_parameters,
+        )?; // $s_A$
+
+        let encrypted_mask = EncryptionKey::CiphertextSpaceGroupElement::new(
+            public_nonce_share_signature_share_and_proof.encrypted_mask,
+            self.encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )?; // $ \textsf{Enc}(\eta \cdot q) $
+
+        verify_public_nonce_share::<SCALAR_LIMBS, GroupElement, ProtocolContext>(
+            &self.protocol_context.round(RoundId::SchnorrSignatureShare),
+            &public_nonce_share_signature_share_and_proof.public_nonce_share_proof,
+            self.centralized_party_nonce_share_commitment,
+            public_nonce_share,
+            generator,
+            &self.scalar_group_public_parameters,
+            &self.group_public_parameters,
+        )?;
+
+        let (public_nonce, negated_nonce) =
+            to_even_y::<SCALAR_LIMBS, GroupElement>(public_nonce_share + self.nonce_public_share); // $R$
+
+        let (public_key, negated_public_key) =
+            to_even_y::<SCALAR_LIMBS, GroupElement>(self.public_key);
+
+        let challenge = challenge::<SCALAR_LIMBS, GroupElement>(
+            &public_nonce.x_only_bytes(),
+            &public_key.x_only_bytes(),
+            message,
+            &self.scalar_group_public_parameters,
+        )?; // $e$
+
+        let centralized_party_public_nonce_share = if negated_nonce {
+            public_nonce_share.neg()
+        } else {
+            public_nonce_share
+        };
+
+        let centralized_party_public_key_share = if negated_public_key {
+            self.centralized_party_public_key_share.neg()
+        } else {
+            self.centralized_party_public_key_share
+        };
+
+        // $ s_A \cdot G = R_A + e \cdot X_A $, up to the even-y negations.
+        if signature_share * generator
+            != centralized_party_public_nonce_share
+                + (challenge * centralized_party_public_key_share)
+        {
+            return Err(Error::SignatureVerification);
+        }
+
+        // The decrypted value is later negated if the nonce was, so the key share's coefficient
+        // must only account for the relative negation of the two.
+        let key_share_coefficient = if negated_nonce != negated_public_key {
+            challenge.neg()
+        } else {
+            challenge
+        };
+
+        let signature_share_decryption_share = partially_decrypt_signature_share::<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            EncryptionKey,
+            DecryptionKeyShare,
+        >(
+            &self.decryption_key_share,
+            &self.decryption_key_share_public_parameters,
+            self.encrypted_nonce_share,
+            self.encrypted_secret_key_share,
+            key_share_coefficient.into(),
+            encrypted_mask,
+        )?;
+
+        let signature_threshold_decryption_round_party =
+            signature_threshold_decryption_round::Party {
+                threshold: self.threshold,
+                decryption_key_share_public_parameters: self.decryption_key_share_public_parameters,
+                scalar_group_public_parameters: self.scalar_group_public_parameters,
+                message: message.to_vec(),
+                public_key: self.public_key,
+                public_nonce,
+                negated_nonce,
+                centralized_party_signature_share: signature_share,
+            };
+
+        Ok((
+            signature_share_decryption_share,
+            signature_threshold_decryption_round_party,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        threshold: PartyID,
+        decryption_key_share: DecryptionKeyShare,
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        presign: presign::decentralized_party::Presign<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<Self> {
+        let scalar_group_public_parameters =
+            protocol_public_parameters.scalar_group_public_parameters;
+        let group_public_parameters = protocol_public_parameters.group_public_parameters;
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let public_key = GroupElement::new(dkg_output.public_key, &group_public_parameters)?;
+
+        let centralized_party_public_key_share = GroupElement::new(
+            dkg_output.centralized_party_public_key_share,
+            &group_public_parameters,
+        )?;
+
+        let centralized_party_nonce_share_commitment = GroupElement::new(
+            presign.centralized_party_nonce_share_commitment,
+            &group_public_parameters,
+        )?;
+
+        let nonce_public_share =
+            GroupElement::new(presign.nonce_public_share, &group_public_parameters)?;
+
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            dkg_output.encrypted_secret_key_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_nonce_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            presign.encrypted_nonce_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            threshold,
+            decryption_key_share,
+            decryption_key_share_public_parameters,
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            public_key,
+            nonce_public_share,
+            encrypted_secret_key_share,
+            encrypted_nonce_share,
+            centralized_party_public_key_share,
+            centralized_party_nonce_share_commitment,
+        })
+    }
+}
@@ -0,0 +1,252 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Signing logic shared by BIP-340 Schnorr and Ed25519, whose signatures are linear in the keys.
+
+use std::collections::{HashMap, HashSet};
+
+use commitment::{pedersen, Pedersen};
+use crypto_bigint::{rand_core::CryptoRngCore, NonZero, Uint};
+use group::{GroupElement as _, KnownOrderGroupElement, PartyID, PrimeGroupElement, Reduce};
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+};
+use maurer::{committment_of_discrete_log, SOUND_PROOFS_REPETITIONS};
+
+use crate::{session::RoundContext, Error};
+
+/// Prove that the centralized party's public nonce share $ R_A = k_A \cdot G $ is consistent
+/// with its commitment to $k_A$ from the presign protocol.
+pub(crate) fn prove_public_nonce_share<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    ProtocolContext: RoundContext,
+>(
+    protocol_context: &ProtocolContext,
+    nonce_share: GroupElement::Scalar,
+    nonce_share_commitment_randomness: GroupElement::Scalar,
+    generator: GroupElement,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<
+    maurer::Proof<
+        SOUND_PROOFS_REPETITIONS,
+        committment_of_discrete_log::Language<
+            SCALAR_LIMBS,
+            GroupElement::Scalar,
+            GroupElement,
+            Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+        >,
+        ProtocolContext,
+    >,
+> {
+    let commitment_scheme_public_parameters =
+        pedersen::PublicParameters::derive::<SCALAR_LIMBS, GroupElement>(
+            scalar_group_public_parameters.clone(),
+            group_public_parameters.clone(),
+        )?;
+
+    let language_public_parameters = committment_of_discrete_log::PublicParameters::new::<
+        SCALAR_LIMBS,
+        GroupElement::Scalar,
+        GroupElement,
+        Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+    >(
+        scalar_group_public_parameters.clone(),
+        group_public_parameters.clone(),
+        commitment_scheme_public_parameters,
+        generator.value(),
+    );
+
+    let (public_nonce_share_proof, _) = maurer::Proof::<
+        SOUND_PROOFS_REPETITIONS,
+        committment_of_discrete_log::Language<
+            SCALAR_LIMBS,
+            GroupElement::Scalar,
+            GroupElement,
+            Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+        >,
+        ProtocolContext,
+    >::prove(
+        protocol_context,
+        &language_public_parameters,
+        vec![[nonce_share, nonce_share_commitment_randomness].into()],
+        rng,
+    )?;
+
+    Ok(public_nonce_share_proof)
+}
+
+/// Verify a proof generated by [`prove_public_nonce_share`] of `public_nonce_share` against the
+/// centralized party's commitment to its nonce share from the presign protocol.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn verify_public_nonce_share<
+    const SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    ProtocolContext: RoundContext,
+>(
+    protocol_context: &ProtocolContext,
+    public_nonce_share_proof: &maurer::Proof<
+        SOUND_PROOFS_REPETITIONS,
+        committment_of_discrete_log::Language<
+            SCALAR_LIMBS,
+            GroupElement::Scalar,
+            GroupElement,
+            Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+        >,
+        ProtocolContext,
+    >,
+    nonce_share_commitment: GroupElement,
+    public_nonce_share: GroupElement,
+    generator: GroupElement,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: &GroupElement::PublicParameters,
+) -> crate::Result<()> {
+    let commitment_scheme_public_parameters =
+        pedersen::PublicParameters::derive::<SCALAR_LIMBS, GroupElement>(
+            scalar_group_public_parameters.clone(),
+            group_public_parameters.clone(),
+        )?;
+
+    let language_public_parameters = committment_of_discrete_log::PublicParameters::new::<
+        SCALAR_LIMBS,
+        GroupElement::Scalar,
+        GroupElement,
+        Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+    >(
+        scalar_group_public_parameters.clone(),
+        group_public_parameters.clone(),
+        commitment_scheme_public_parameters,
+        generator.value(),
+    );
+
+    public_nonce_share_proof.verify(
+        protocol_context,
+        &language_public_parameters,
+        vec![[nonce_share_commitment, public_nonce_share].into()],
+    )?;
+
+    Ok(())
+}
+
+/// Sample a statistical mask $\eta$ and encrypt $ \eta \cdot q $, for the decentralized party to
+/// add to its share of the signature before decrypting it.
+///
+/// The decentralized party's share $ k_B + e \cdot x_B $ is evaluated over the integers, so
+/// decrypting it as is would reveal more than its value modulo $q$. As in ECDSA, the mask is
+/// sampled by [`AdditivelyHomomorphicEncryptionKey::sample_mask_for_secure_function_evaluation`],
+/// from the upper bound `encrypted_share_upper_bound` proven on the plaintexts of both
+/// `encrypted_nonce_share` and `encrypted_secret_key_share`, and the coefficient $ e < q $.
+pub(crate) fn encrypt_mask<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+>(
+    encrypted_nonce_share: EncryptionKey::CiphertextSpaceGroupElement,
+    encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    encrypted_share_upper_bound: Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<EncryptionKey::CiphertextSpaceGroupElement> {
+    let ciphertexts_and_upper_bounds = [
+        (encrypted_nonce_share, encrypted_share_upper_bound),
+        (encrypted_secret_key_share, encrypted_share_upper_bound),
+    ];
+
+    let mask = EncryptionKey::sample_mask_for_secure_function_evaluation(
+        &ciphertexts_and_upper_bounds,
+        encryption_scheme_public_parameters,
+        rng,
+    )?; // $\eta$
+
+    let group_order =
+        GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters);
+
+    let encryption_key = EncryptionKey::new(encryption_scheme_public_parameters)?;
+
+    let (_, encrypted_mask) = encryption_key.encrypt(
+        &mask.scalar_mul(&group_order),
+        encryption_scheme_public_parameters,
+        rng,
+    )?;
+
+    Ok(encrypted_mask)
+}
+
+/// Partially decrypt the decentralized party's masked share of the signature
+/// $ \ct_3 \oplus (e \odot \textsf{ct}_{\textsf{key}}) \oplus \textsf{Enc}(\eta \cdot q) $,
+/// i.e. an encryption of $ k_B + e \cdot x_B + \eta \cdot q $.
+pub(crate) fn partially_decrypt_signature_share<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+>(
+    decryption_key_share: &DecryptionKeyShare,
+    decryption_key_share_public_parameters: &DecryptionKeyShare::PublicParameters,
+    encrypted_nonce_share: EncryptionKey::CiphertextSpaceGroupElement,
+    encrypted_secret_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+    key_share_coefficient: Uint<SCALAR_LIMBS>,
+    encrypted_mask: EncryptionKey::CiphertextSpaceGroupElement,
+) -> crate::Result<DecryptionKeyShare::DecryptionShare> {
+    let encrypted_signature_share = encrypted_nonce_share
+        + encrypted_secret_key_share.scalar_mul(&key_share_coefficient)
+        + encrypted_mask;
+
+    Option::from(decryption_key_share.generate_decryption_share_semi_honest(
+        &encrypted_signature_share,
+        decryption_key_share_public_parameters,
+    ))
+    .ok_or(Error::InternalError)
+}
+
+/// Combine the decryption shares of the decentralized party's masked share of the signature, and
+/// reduce it modulo $q$, under which the mask $ \eta \cdot q $ vanishes.
+pub(crate) fn decrypt_signature_share<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+>(
+    threshold: PartyID,
+    lagrange_coefficients: HashMap<PartyID, DecryptionKeyShare::LagrangeCoefficient>,
+    signature_share_decryption_shares: HashMap<PartyID, DecryptionKeyShare::DecryptionShare>,
+    decryption_key_share_public_parameters: &DecryptionKeyShare::PublicParameters,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<GroupElement::Scalar>
+where
+    Error: From<DecryptionKeyShare::Error>,
+{
+    let decrypters: HashSet<_> = lagrange_coefficients.clone().into_keys().collect();
+    if decrypters.len() != usize::from(threshold)
+        || decrypters
+            != signature_share_decryption_shares
+                .keys()
+                .cloned()
+                .collect::<HashSet<_>>()
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    let signature_share: Uint<PLAINTEXT_SPACE_SCALAR_LIMBS> =
+        DecryptionKeyShare::combine_decryption_shares_semi_honest(
+            signature_share_decryption_shares,
+            lagrange_coefficients,
+            decryption_key_share_public_parameters,
+        )?
+        .into();
+
+    let group_order =
+        GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters);
+
+    let group_order = Option::<_>::from(NonZero::new(group_order)).ok_or(Error::InternalError)?;
+
+    Ok(GroupElement::Scalar::new(
+        signature_share.reduce(&group_order).into(),
+        scalar_group_public_parameters,
+    )?)
+}
@@ -0,0 +1,317 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Typed session identifiers and the [`SessionContext`] which binds proofs to a session.
+
+use std::collections::BTreeSet;
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::PartyID;
+use serde::{Deserialize, Serialize};
+
+/// The unique identifier of a protocol session.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SessionId(pub [u8; 32]);
+
+impl SessionId {
+    /// Sample a fresh session identifier.
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        let mut session_id = [0u8; 32];
+        rng.fill_bytes(&mut session_id);
+
+        Self(session_id)
+    }
+}
+
+/// The identifier of the dWallet, i.e. the key generated by the [`crate::dkg`], on which a session
+/// operates.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DWalletId(pub [u8; 32]);
+
+/// The protocols run in a session.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Protocol {
+    Dkg,
+    Presign,
+    Sign,
+    Schnorr,
+    EdDSA,
+    Refresh,
+    Import,
+    Backup,
+    Transfer,
+    Cosigner,
+}
+
+/// The rounds in which proofs are generated, each of which derives its own sub-context of the
+/// session's protocol context.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundId {
+    DkgCentralizedPartyCommitment,
+    DkgEncryptionOfSecretKeyShare,
+    PresignCentralizedPartyCommitment,
+    PresignEncryptedMaskedKeyShareAndPublicNonceShares,
+    PresignEncryptedMaskedNonces,
+    SignSignatureHomomorphicEvaluation,
+    SchnorrSignatureShare,
+    EdDSASignatureShare,
+    RefreshDecommitment,
+    ImportEncryptionOfSecretKeyShare,
+    BackupEncryption,
+    TransferDecommitment,
+    CosignerPublicKeyShare,
+    CosignerEncryptedMaskedKeyShare,
+}
+
+/// A protocol context to which the proofs of a session are bound.
+///
+/// Every round derives the sub-context of the proofs it generates, and of those it verifies, from
+/// the protocol context of the party, so that a proof generated in one round cannot be replayed
+/// into another.
+pub trait RoundContext: Clone + Serialize {
+    /// The sub-context of `round`.
+    fn round(&self, round: RoundId) -> Self;
+}
+
+/// The protocol context of a session, which binds its proofs to the session, the dWallet, the
+/// parties and the public parameters, so that they cannot be replayed into another session.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SessionContext {
+    session_id: SessionId,
+    protocol: Protocol,
+    round: Option<RoundId>,
+    dwallet_id: DWalletId,
+    parties: BTreeSet<PartyID>,
+    public_parameters_fingerprint: [u8; 32],
+}
+
+impl SessionContext {
+    /// The protocol context of session `session_id` of `protocol` on `dwallet_id`, between the
+    /// centralized party and the decentralized `parties`, under the public parameters whose
+    /// [`crate::ProtocolPublicParameters::fingerprint`] is `public_parameters_fingerprint`.
+    pub fn new(
+        session_id: SessionId,
+        protocol: Protocol,
+        dwallet_id: DWalletId,
+        parties: impl IntoIterator<Item = PartyID>,
+        public_parameters_fingerprint: [u8; 32],
+    ) -> Self {
+        Self {
+            session_id,
+            protocol,
+            round: None,
+            dwallet_id,
+            parties: parties.into_iter().collect(),
+            public_parameters_fingerprint,
+        }
+    }
+
+    pub fn session_id(&self) -> SessionId {
+        self.session_id
+    }
+
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    pub fn dwallet_id(&self) -> DWalletId {
+        self.dwallet_id
+    }
+}
+
+impl RoundContext for SessionContext {
+    fn round(&self, round: RoundId) -> Self {
+        Self {
+            round: Some(round),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs"
+))]
+pub(crate) mod tests {
+    use std::collections::HashSet;
+
+    use group::{secp256k1, Samplable};
+    use maurer::knowledge_of_discrete_log;
+    use rand_core::OsRng;
+    use tiresias::test_exports::N;
+
+    use super::*;
+    use crate::{
+        dkg,
+        round::{tests::advance, Round},
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+    };
+
+    /// The protocol context of a test session of `protocol`.
+    pub(crate) fn session_context(protocol: Protocol) -> SessionContext {
+        SessionContext::new(
+            SessionId([0; 32]),
+            protocol,
+            DWalletId([0; 32]),
+            [],
+            [0; 32],
+        )
+    }
+
+    type SchnorrProof = knowledge_of_discrete_log::Proof<
+        secp256k1::Scalar,
+        secp256k1::GroupElement,
+        SessionContext,
+    >;
+
+    #[test]
+    fn rejects_proofs_replayed_into_another_session_or_round() {
+        let scalar_group_public_parameters = secp256k1::scalar::PublicParameters::default();
+        let group_public_parameters = secp256k1::group_element::PublicParameters::default();
+
+        let language_public_parameters = knowledge_of_discrete_log::PublicParameters::new::<
+            secp256k1::Scalar,
+            secp256k1::GroupElement,
+        >(
+            scalar_group_public_parameters.clone(),
+            group_public_parameters.clone(),
+            group_public_parameters.generator,
+        );
+
+        let secret_key_share =
+            secp256k1::Scalar::sample(&scalar_group_public_parameters, &mut OsRng).unwrap();
+
+        let session_context = session_context(Protocol::Dkg);
+        let round_context = session_context.round(RoundId::DkgCentralizedPartyCommitment);
+
+        let (proof, statements) = SchnorrProof::prove(
+            &round_context,
+            &language_public_parameters,
+            vec![secret_key_share],
+            &mut OsRng,
+        )
+        .unwrap();
+
+        assert!(proof
+            .verify(
+                &round_context,
+                &language_public_parameters,
+                statements.clone()
+            )
+            .is_ok());
+
+        let other_sessions = [
+            SessionContext::new(
+                SessionId::random(&mut OsRng),
+                Protocol::Dkg,
+                DWalletId([0; 32]),
+                [],
+                [0; 32],
+            ),
+            SessionContext::new(
+                SessionId([0; 32]),
+                Protocol::Dkg,
+                DWalletId([1; 32]),
+                [],
+                [0; 32],
+            ),
+            SessionContext::new(
+                SessionId([0; 32]),
+                Protocol::Dkg,
+                DWalletId([0; 32]),
+                [1, 2],
+                [0; 32],
+            ),
+            SessionContext::new(
+                SessionId([0; 32]),
+                Protocol::Dkg,
+                DWalletId([0; 32]),
+                [],
+                [1; 32],
+            ),
+        ];
+
+        for other_session_context in other_sessions {
+            assert!(proof
+                .verify(
+                    &other_session_context.round(RoundId::DkgCentralizedPartyCommitment),
+                    &language_public_parameters,
+                    statements.clone(),
+                )
+                .is_err());
+        }
+
+        // The public key share proof of the co-signer is over the same language.
+        assert!(proof
+            .verify(
+                &session_context.round(RoundId::CosignerPublicKeyShare),
+                &language_public_parameters,
+                statements.clone(),
+            )
+            .is_err());
+
+        assert!(proof
+            .verify(&session_context, &language_public_parameters, statements)
+            .is_err());
+    }
+
+    #[test]
+    fn dkg_rejects_commitment_proof_of_another_session() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+        let parties: HashSet<PartyID> = [1, 2].into();
+
+        let (commitment, centralized_party_decommitment_round_party) =
+            dkg::centralized_party::commitment_round::Party::new(
+                protocol_public_parameters.clone(),
+                session_context(Protocol::Dkg),
+            )
+            .process((), &mut OsRng)
+            .unwrap()
+            .advance()
+            .unwrap();
+
+        // The decentralized party runs a different session, into which the centralized party's
+        // commitment and proof are replayed.
+        let other_session_context = SessionContext::new(
+            SessionId::random(&mut OsRng),
+            Protocol::Dkg,
+            DWalletId([0; 32]),
+            [],
+            [0; 32],
+        );
+
+        let decentralized_parties = parties
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    dkg::decentralized_party::encryption_of_secret_key_share_round::Party::new(
+                        protocol_public_parameters.clone(),
+                        party_id,
+                        2,
+                        parties.clone(),
+                        other_session_context.clone(),
+                    ),
+                )
+            })
+            .collect();
+
+        let (commitments, decentralized_parties) = advance(decentralized_parties, commitment);
+        let (decommitments, decentralized_parties) = advance(decentralized_parties, commitments);
+        let (proof_shares, decentralized_parties) = advance(decentralized_parties, decommitments);
+        let (secret_key_share_encryptions_and_proofs, _) =
+            advance(decentralized_parties, proof_shares);
+
+        // The centralized party rejects the decentralized party's proof from the other session.
+        let secret_key_share_encryption_and_proof = secret_key_share_encryptions_and_proofs
+            .into_values()
+            .next()
+            .unwrap();
+
+        assert!(centralized_party_decommitment_round_party
+            .process(secret_key_share_encryption_and_proof, &mut OsRng)
+            .is_err());
+    }
+}
@@ -5,16 +5,140 @@ use std::ops::Neg;
 
 #[cfg(feature = "benchmarking")]
 pub(crate) use benches::benchmark;
-use group::{AffineXCoordinate, GroupElement, Invert, PrimeGroupElement};
+use crypto_bigint::{NonZero, Uint};
+use group::{
+    AffineXCoordinate, GroupElement, Invert, KnownOrderGroupElement, PrimeGroupElement, Reduce,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512_256};
+use sha3::Keccak256;
 
 use crate::Error;
 
 pub mod centralized_party;
 pub mod decentralized_party;
+pub mod signature;
+
+pub use signature::{RecoverableSignature, Signature};
 
 /// The dimension of the Committed Affine Evaluation language used in the signing protocol.
 pub const DIMENSION: usize = 2;
 
+/// The hash function used to digest the message being signed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hash {
+    Sha256,
+    Keccak256,
+    /// $ SHA256(SHA256(m)) $, as used by Bitcoin.
+    DoubleSha256,
+    Sha512_256,
+}
+
+impl Hash {
+    /// Hash `message` using this hash function.
+    pub fn digest(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            Hash::Sha256 => Sha256::digest(message).to_vec(),
+            Hash::Keccak256 => Keccak256::digest(message).to_vec(),
+            Hash::DoubleSha256 => Sha256::digest(Sha256::digest(message)).to_vec(),
+            Hash::Sha512_256 => Sha512_256::digest(message).to_vec(),
+        }
+    }
+}
+
+/// Compute the scalar to be signed for `message`, by hashing it using `hash` and converting the
+/// digest into a scalar following the ECDSA standard (SEC 1, Section 4.1.3): the digest is
+/// truncated to its leftmost $ \lceil \log_2(q) \rceil $ bits (`bits2int`) and then reduced
+/// modulo the group order $q$.
+///
+/// Both the centralized and the decentralized party derive the signed scalar using this function,
+/// so that they can never disagree on it.
+pub fn message_digest<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>(
+    message: &[u8],
+    hash: Hash,
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<GroupElement::Scalar> {
+    let digest = hash.digest(message);
+    let digest = &digest[..digest.len().min(Uint::<SCALAR_LIMBS>::BYTES)];
+
+    let mut digest_bytes = vec![0u8; Uint::<SCALAR_LIMBS>::BYTES - digest.len()];
+    digest_bytes.extend_from_slice(digest);
+    let digest_number = Uint::<SCALAR_LIMBS>::from_be_slice(&digest_bytes);
+
+    let group_order =
+        GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters);
+
+    let digest_bits = digest.len() * 8;
+    let group_order_bits = group_order.bits();
+    let digest_number = if digest_bits > group_order_bits {
+        digest_number.shr_vartime(digest_bits - group_order_bits)
+    } else {
+        digest_number
+    };
+
+    let group_order = Option::<_>::from(NonZero::new(group_order)).ok_or(Error::InternalError)?;
+
+    Ok(GroupElement::Scalar::new(
+        digest_number.reduce(&group_order).into(),
+        scalar_group_public_parameters,
+    )?)
+}
+
+/// A group element whose affine coordinates carry the information needed to compute the recovery
+/// id of an ECDSA signature, allowing its verifier to recover the public key from it.
+pub trait RecoverableGroupElement<const SCALAR_LIMBS: usize>:
+    PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS>
+{
+    /// Whether the affine y-coordinate of this point is odd.
+    fn is_y_odd(&self) -> bool;
+
+    /// Whether the affine x-coordinate of this point, as an integer, is larger than or equal to
+    /// the group order, and was hence reduced when taken as the signature's $r$.
+    fn is_x_reduced(&self) -> bool;
+}
+
+#[cfg(feature = "secp256k1")]
+impl RecoverableGroupElement<{ group::secp256k1::SCALAR_LIMBS }>
+    for group::secp256k1::GroupElement
+{
+    fn is_y_odd(&self) -> bool {
+        use k256::elliptic_curve::point::AffineCoordinates;
+
+        let point: k256::AffinePoint = self.value().into();
+
+        point.y_is_odd().into()
+    }
+
+    fn is_x_reduced(&self) -> bool {
+        use k256::elliptic_curve::{ff::PrimeField, point::AffineCoordinates};
+
+        let point: k256::AffinePoint = self.value().into();
+
+        k256::Scalar::from_repr(point.x()).is_none().into()
+    }
+}
+
+#[cfg(feature = "secp256r1")]
+impl RecoverableGroupElement<{ group::secp256r1::SCALAR_LIMBS }>
+    for group::secp256r1::GroupElement
+{
+    fn is_y_odd(&self) -> bool {
+        use p256::elliptic_curve::point::AffineCoordinates;
+
+        let point: p256::AffinePoint = self.value().into();
+
+        point.y_is_odd().into()
+    }
+
+    fn is_x_reduced(&self) -> bool {
+        use p256::elliptic_curve::{ff::PrimeField, point::AffineCoordinates};
+
+        let point: p256::AffinePoint = self.value().into();
+
+        p256::Scalar::from_repr(point.x()).is_none().into()
+    }
+}
+
 pub fn verify_signature<
     const SCALAR_LIMBS: usize,
     GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS>,
@@ -47,8 +171,12 @@ pub fn verify_signature<
 ))]
 #[allow(unused_imports)]
 pub(crate) mod tests {
-    use core::marker::PhantomData;
-    use std::{collections::HashMap, iter, ops::Neg, time::Duration};
+    use std::{
+        collections::{HashMap, HashSet},
+        iter,
+        ops::Neg,
+        time::Duration,
+    };
 
     use commitment::{pedersen, HomomorphicCommitmentScheme, Pedersen};
     use criterion::measurement::{Measurement, WallTime};
@@ -57,7 +185,7 @@ pub(crate) mod tests {
         elliptic_curve::{ops::Reduce, Scalar},
         hazmat::{bits2field, DigestPrimitive},
         signature::{digest::Digest, Verifier},
-        Signature, VerifyingKey,
+        RecoveryId, Signature, VerifyingKey,
     };
     use group::{
         direct_product, ristretto, secp256k1, self_product, AffineXCoordinate, GroupElement as _,
@@ -81,28 +209,61 @@ pub(crate) mod tests {
 
     use super::*;
     use crate::{
-        dkg::tests::generates_distributed_key_internal,
-        presign::tests::generates_presignatures_internal,
+        dkg::{self, derivation, tests::generates_distributed_key_internal},
+        presign::tests::{
+            generates_presignatures_internal,
+            generates_presignatures_with_public_parameters_internal,
+        },
+        round::{
+            tests::{advance, finalize},
+            Round,
+        },
         secp256k1::{
             bulletproofs::{NUM_RANGE_CLAIMS, RANGE_CLAIMS_PER_MASK, RANGE_CLAIMS_PER_SCALAR},
             paillier::bulletproofs::ProtocolPublicParameters,
         },
+        session::{tests::session_context, Protocol, SessionContext},
         sign::decentralized_party::{
             identifiable_abort::{
                 signature_partial_decryption_proof_round,
                 signature_partial_decryption_verification_round,
             },
-            signature_partial_decryption_round,
+            signature_partial_decryption_round, signature_threshold_decryption_round,
         },
     };
 
-    fn setup_decryption_key_shares(
+    pub(crate) fn setup_decryption_key_shares(
         threshold: u16,
         number_of_parties: u16,
     ) -> (
         tiresias::decryption_key_share::PublicParameters,
         HashMap<PartyID, DecryptionKeyShare>,
         HashMap<PartyID, AdjustedLagrangeCoefficientSizedNumber>,
+    ) {
+        let (decryption_key_share_public_parameters, decryption_key_shares) =
+            deal_decryption_key_shares(threshold, number_of_parties);
+
+        let (decryption_key_shares, lagrange_coefficients) = choose_decrypters(
+            threshold,
+            number_of_parties,
+            &decryption_key_share_public_parameters,
+            decryption_key_shares,
+        );
+
+        (
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+            lagrange_coefficients,
+        )
+    }
+
+    /// Deal the decryption key shares of all `number_of_parties` parties by a trusted dealer.
+    pub(crate) fn deal_decryption_key_shares(
+        threshold: u16,
+        number_of_parties: u16,
+    ) -> (
+        tiresias::decryption_key_share::PublicParameters,
+        HashMap<PartyID, DecryptionKeyShare>,
     ) {
         let (decryption_key_share_public_parameters, decryption_key_shares) =
             deal_trusted_shares(threshold, number_of_parties, N, SECRET_KEY, BASE);
@@ -121,6 +282,23 @@ pub(crate) mod tests {
             })
             .collect();
 
+        (
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+        )
+    }
+
+    /// Choose `threshold` random decrypters out of `decryption_key_shares`, and compute their
+    /// Lagrange coefficients.
+    pub(crate) fn choose_decrypters(
+        threshold: u16,
+        number_of_parties: u16,
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        decryption_key_shares: HashMap<PartyID, DecryptionKeyShare>,
+    ) -> (
+        HashMap<PartyID, DecryptionKeyShare>,
+        HashMap<PartyID, AdjustedLagrangeCoefficientSizedNumber>,
+    ) {
         let decryption_key_shares: HashMap<_, _> = decryption_key_shares
             .into_iter()
             .choose_multiple(&mut OsRng, usize::from(threshold))
@@ -140,17 +318,53 @@ pub(crate) mod tests {
                             j,
                             number_of_parties,
                             decrypters.clone(),
-                            &decryption_key_share_public_parameters,
+                            decryption_key_share_public_parameters,
                         ),
                     )
                 })
                 .collect();
 
-        (
+        (decryption_key_shares, lagrange_coefficients)
+    }
+
+    /// Decrypt `ciphertext` jointly by `threshold` of the holders of `decryption_key_shares`,
+    /// which stand in for the Paillier decryption key in tests.
+    pub(crate) fn threshold_decrypt(
+        threshold: u16,
+        number_of_parties: u16,
+        ciphertext: &tiresias::CiphertextSpaceGroupElement,
+        decryption_key_share_public_parameters: &tiresias::decryption_key_share::PublicParameters,
+        decryption_key_shares: &HashMap<PartyID, DecryptionKeyShare>,
+    ) -> LargeBiPrimeSizedNumber {
+        let (decryption_key_shares, lagrange_coefficients) = choose_decrypters(
+            threshold,
+            number_of_parties,
             decryption_key_share_public_parameters,
-            decryption_key_shares,
+            decryption_key_shares.clone(),
+        );
+
+        let decryption_shares: HashMap<_, _> = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                (
+                    party_id,
+                    decryption_key_share
+                        .generate_decryption_share_semi_honest(
+                            ciphertext,
+                            decryption_key_share_public_parameters,
+                        )
+                        .unwrap(),
+                )
+            })
+            .collect();
+
+        DecryptionKeyShare::combine_decryption_shares_semi_honest(
+            decryption_shares,
             lagrange_coefficients,
+            decryption_key_share_public_parameters,
         )
+        .unwrap()
+        .into()
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -171,13 +385,61 @@ pub(crate) mod tests {
         encrypted_masked_nonce_share: tiresias::CiphertextSpaceGroupElement,
         malicious_decrypter: bool,
         designated_sending_wrong_signature: bool,
+    ) {
+        let (decryption_key_share_public_parameters, decryption_key_shares) =
+            deal_decryption_key_shares(threshold, number_of_parties);
+
+        signs_with_decryption_key_shares_internal(
+            threshold,
+            number_of_parties,
+            ProtocolPublicParameters::new(N),
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+            centralized_party_secret_key_share,
+            centralized_party_public_key_share,
+            decentralized_party_secret_key_share,
+            decentralized_party_public_key_share,
+            centralized_party_nonce_share,
+            centralized_party_nonce_share_commitment,
+            decentralized_party_nonce_share,
+            decentralized_party_nonce_public_share,
+            nonce_share_commitment_randomness,
+            encrypted_mask,
+            encrypted_masked_key_share,
+            encrypted_masked_nonce_share,
+            malicious_decrypter,
+            designated_sending_wrong_signature,
+        )
+    }
+
+    /// Sign under `protocol_public_parameters`, decrypting with `threshold` of the
+    /// `decryption_key_shares`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn signs_with_decryption_key_shares_internal(
+        threshold: u16,
+        number_of_parties: u16,
+        protocol_public_parameters: ProtocolPublicParameters,
+        decryption_key_share_public_parameters: tiresias::decryption_key_share::PublicParameters,
+        decryption_key_shares: HashMap<PartyID, DecryptionKeyShare>,
+        centralized_party_secret_key_share: secp256k1::Scalar,
+        centralized_party_public_key_share: secp256k1::GroupElement,
+        decentralized_party_secret_key_share: secp256k1::Scalar,
+        decentralized_party_public_key_share: secp256k1::GroupElement,
+        centralized_party_nonce_share: secp256k1::Scalar,
+        centralized_party_nonce_share_commitment: secp256k1::GroupElement,
+        decentralized_party_nonce_share: secp256k1::Scalar,
+        decentralized_party_nonce_public_share: secp256k1::GroupElement,
+        nonce_share_commitment_randomness: secp256k1::Scalar,
+        encrypted_mask: tiresias::CiphertextSpaceGroupElement,
+        encrypted_masked_key_share: tiresias::CiphertextSpaceGroupElement,
+        encrypted_masked_nonce_share: tiresias::CiphertextSpaceGroupElement,
+        malicious_decrypter: bool,
+        designated_sending_wrong_signature: bool,
     ) {
         let measurement = WallTime;
         let mut centralized_party_total_time = Duration::ZERO;
         let mut decentralized_party_decryption_share_time = Duration::ZERO;
 
-        let protocol_public_parameters = ProtocolPublicParameters::new(N);
-
         let generator = secp256k1::GroupElement::new(
             protocol_public_parameters.group_public_parameters.generator,
             &protocol_public_parameters.group_public_parameters,
@@ -208,9 +470,9 @@ pub(crate) mod tests {
                     self_product::GroupElement<DIMENSION, secp256k1::Scalar>,
                     tiresias::RandomnessSpaceGroupElement,
                 >,
-                PhantomData<()>,
+                SessionContext,
             > {
-                protocol_context: PhantomData::<()>,
+                protocol_context: session_context(Protocol::Sign),
                 scalar_group_public_parameters: protocol_public_parameters
                     .scalar_group_public_parameters
                     .clone(),
@@ -255,8 +517,12 @@ pub(crate) mod tests {
         centralized_party_total_time =
             measurement.add(&centralized_party_total_time, &measurement.end(now));
 
-        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
-            setup_decryption_key_shares(threshold, number_of_parties);
+        let (decryption_key_shares, lagrange_coefficients) = choose_decrypters(
+            threshold,
+            number_of_parties,
+            &decryption_key_share_public_parameters,
+            decryption_key_shares,
+        );
 
         let evaluation_party_id = *decryption_key_shares.keys().next().unwrap();
 
@@ -280,13 +546,13 @@ pub(crate) mod tests {
                             self_product::GroupElement<DIMENSION, secp256k1::Scalar>,
                             tiresias::RandomnessSpaceGroupElement,
                         >,
-                        PhantomData<()>,
+                        SessionContext,
                     > {
                         threshold,
                         decryption_key_share,
                         decryption_key_share_public_parameters:
                             decryption_key_share_public_parameters.clone(),
-                        protocol_context: PhantomData::<()>,
+                        protocol_context: session_context(Protocol::Sign),
                         scalar_group_public_parameters: protocol_public_parameters
                             .scalar_group_public_parameters
                             .clone(),
@@ -395,13 +661,18 @@ pub(crate) mod tests {
 
             return;
         }
-        let (returned_nonce_x_coordinate, signature_s) = if designated_sending_wrong_signature {
-            (nonce_x_coordinate, nonce_x_coordinate.neutral())
+        let recoverable_signature = if designated_sending_wrong_signature {
+            RecoverableSignature {
+                r: nonce_x_coordinate,
+                s: nonce_x_coordinate.neutral(),
+                recovery_id: 0,
+            }
         } else {
             res.unwrap()
         };
 
-        assert_eq!(nonce_x_coordinate, returned_nonce_x_coordinate);
+        assert_eq!(nonce_x_coordinate, recoverable_signature.r);
+        let signature_s = recoverable_signature.s;
 
         // now do the amortized threshold decryption logic which just verifies the signature.
         signature_threshold_decryption_round_parties.for_each(
@@ -418,8 +689,9 @@ pub(crate) mod tests {
                         "Malicious designated decryption party which sends an invalid signature must be blamed"
                     );
                 } else {
-                    assert!(
-                        res.is_ok(),
+                    assert_eq!(
+                        res.unwrap(),
+                        recoverable_signature,
                         "Signature verification should pass in case of an honest designated decryption party"
                     );
                 }
@@ -492,6 +764,41 @@ pub(crate) mod tests {
         );
 
         assert!(res.is_ok(), "generated signatures should be valid");
+
+        let recovery_id = RecoveryId::from_byte(recoverable_signature.recovery_id).unwrap();
+        let recovered_verifying_key = VerifyingKey::<k256::Secp256k1>::recover_from_msg(
+            message.as_bytes(),
+            &signature,
+            recovery_id,
+        )
+        .unwrap();
+
+        assert_eq!(
+            verifying_key, recovered_verifying_key,
+            "the public key recovered from the signature should be the dWallet's public key"
+        );
+
+        assert_eq!(
+            Signature::<k256::Secp256k1>::try_from(recoverable_signature.signature()).unwrap(),
+            signature
+        );
+
+        let ethereum_signature = recoverable_signature
+            .to_ethereum_bytes::<{ secp256k1::SCALAR_LIMBS }>()
+            .unwrap();
+        assert_eq!(ethereum_signature[..64], signature.to_bytes()[..]);
+        assert_eq!(ethereum_signature[64], 27 + recovery_id.to_byte());
+        assert_eq!(
+            RecoverableSignature::from_ethereum_bytes::<
+                { secp256k1::SCALAR_LIMBS },
+                secp256k1::GroupElement,
+            >(
+                &ethereum_signature,
+                &protocol_public_parameters.scalar_group_public_parameters
+            )
+            .unwrap(),
+            recoverable_signature
+        );
     }
 
     #[rstest]
@@ -508,7 +815,7 @@ pub(crate) mod tests {
         #[case] malicious_decrypter: bool,
         #[case] designated_sending_wrong_signature: bool,
     ) {
-        let protocol_public_parameters = crate::ProtocolPublicParameters::new(N);
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
 
         let paillier_encryption_key = tiresias::EncryptionKey::new(
             &protocol_public_parameters.encryption_scheme_public_parameters,
@@ -655,23 +962,46 @@ pub(crate) mod tests {
     }
 
     #[rstest]
-    #[case(2, 2, 1)]
-    #[case(2, 4, 4)]
-    #[case(6, 9, 1)]
-    fn dkg_presign_signs(
-        #[case] threshold: PartyID,
-        #[case] number_of_parties: PartyID,
-        #[case] batch_size: usize,
-    ) {
-        dkg_presign_signs_internal(threshold, number_of_parties, batch_size)
+    #[case(Hash::Sha256)]
+    #[case(Hash::Keccak256)]
+    #[case(Hash::DoubleSha256)]
+    #[case(Hash::Sha512_256)]
+    fn message_digest_truncates_and_reduces_like_ecdsa(#[case] hash: Hash) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let message = "singing!";
+
+        let m = bits2field::<k256::Secp256k1>(&hash.digest(message.as_bytes())).unwrap();
+        let m = <Scalar<k256::Secp256k1> as Reduce<U256>>::reduce_bytes(&m);
+        let m: secp256k1::Scalar = U256::from(m).into();
+
+        assert_eq!(
+            message_digest::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+                message.as_bytes(),
+                hash,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap(),
+            m
+        );
     }
 
-    pub fn dkg_presign_signs_internal(
-        threshold: PartyID,
-        number_of_parties: PartyID,
-        batch_size: usize,
-    ) {
-        let protocol_public_parameters = crate::ProtocolPublicParameters::new(N);
+    #[rstest]
+    #[case(Hash::Sha256)]
+    #[case(Hash::Keccak256)]
+    #[case(Hash::DoubleSha256)]
+    #[case(Hash::Sha512_256)]
+    fn signs_hashed_messages(#[case] hash: Hash) {
+        use ecdsa::signature::hazmat::PrehashVerifier;
+
+        use crate::secp256k1::paillier::bulletproofs::{
+            SignatureHomomorphicEvaluationParty, SignaturePartialDecryptionParty,
+        };
+
+        let threshold = 2;
+        let number_of_parties = 4;
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
 
         let (centralized_party_dkg_output, decentralized_party_dkg_output) =
             generates_distributed_key_internal(threshold, number_of_parties);
@@ -684,135 +1014,1210 @@ pub(crate) mod tests {
         )
         .unwrap();
 
-        let (centralized_party_presign, encrypted_nonce, decentralized_party_presign) =
+        let (centralized_party_presigns, _, decentralized_party_presigns) =
             generates_presignatures_internal(
                 threshold,
                 number_of_parties,
-                batch_size,
+                1,
                 encrypted_secret_key_share,
                 false,
             )
             .unwrap();
 
-        let centralized_party_presign = centralized_party_presign.first().unwrap().clone();
-        let decentralized_party_presign = decentralized_party_presign.first().unwrap().clone();
+        let message = "singing!";
 
-        let centralized_party_nonce_share_commitment = secp256k1::GroupElement::new(
-            decentralized_party_presign.centralized_party_nonce_share_commitment,
-            &protocol_public_parameters.group_public_parameters,
+        let (
+            public_nonce_encrypted_partial_signature_and_proof,
+            signature_verification_round_party,
+        ) = SignatureHomomorphicEvaluationParty::new(
+            session_context(Protocol::Sign),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output.clone(),
+            centralized_party_presigns.first().unwrap().clone(),
         )
+        .unwrap()
+        .evaluate_encrypted_partial_signature(message.as_bytes(), hash, &mut OsRng)
         .unwrap();
 
-        let decentralized_party_nonce_public_share = secp256k1::GroupElement::new(
-            decentralized_party_presign.nonce_public_share,
-            &protocol_public_parameters.group_public_parameters,
-        )
-        .unwrap();
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
 
-        let encrypted_mask = tiresias::CiphertextSpaceGroupElement::new(
-            centralized_party_presign.encrypted_mask,
-            protocol_public_parameters
-                .encryption_scheme_public_parameters
-                .ciphertext_space_public_parameters(),
-        )
-        .unwrap();
+        let (decryption_shares, signature_threshold_decryption_round_parties): (Vec<_>, Vec<_>) =
+            decryption_key_shares
+                .into_iter()
+                .map(|(party_id, decryption_key_share)| {
+                    let (
+                        (partial_signature_decryption_share, masked_nonce_decryption_share),
+                        signature_threshold_decryption_round_party,
+                    ) = SignaturePartialDecryptionParty::new(
+                        threshold,
+                        decryption_key_share,
+                        decryption_key_share_public_parameters.clone(),
+                        session_context(Protocol::Sign),
+                        protocol_public_parameters.clone(),
+                        decentralized_party_dkg_output.clone(),
+                        decentralized_party_presigns.first().unwrap().clone(),
+                    )
+                    .unwrap()
+                    .partially_decrypt_encrypted_signature_parts(
+                        message.as_bytes(),
+                        hash,
+                        public_nonce_encrypted_partial_signature_and_proof.clone(),
+                        &mut OsRng,
+                    )
+                    .unwrap();
 
-        let encrypted_masked_key_share = tiresias::CiphertextSpaceGroupElement::new(
-            centralized_party_presign.encrypted_masked_key_share,
-            protocol_public_parameters
-                .encryption_scheme_public_parameters
-                .ciphertext_space_public_parameters(),
-        )
-        .unwrap();
+                    (
+                        (
+                            (party_id, partial_signature_decryption_share),
+                            (party_id, masked_nonce_decryption_share),
+                        ),
+                        signature_threshold_decryption_round_party,
+                    )
+                })
+                .unzip();
 
-        let encrypted_masked_nonce_share = tiresias::CiphertextSpaceGroupElement::new(
-            decentralized_party_presign.encrypted_masked_nonce_share,
-            protocol_public_parameters
-                .encryption_scheme_public_parameters
-                .ciphertext_space_public_parameters(),
-        )
-        .unwrap();
+        let (partial_signature_decryption_shares, masked_nonce_decryption_shares): (
+            HashMap<_, _>,
+            HashMap<_, _>,
+        ) = decryption_shares.into_iter().unzip();
 
-        let paillier_decryption_key = tiresias::DecryptionKey::new(
-            SECRET_KEY,
-            &protocol_public_parameters.encryption_scheme_public_parameters,
+        let recoverable_signature = signature_threshold_decryption_round_parties
+            .into_iter()
+            .next()
+            .unwrap()
+            .decrypt_signature(
+                lagrange_coefficients,
+                partial_signature_decryption_shares,
+                masked_nonce_decryption_shares,
+            )
+            .unwrap();
+
+        signature_verification_round_party
+            .verify_signature(recoverable_signature.r, recoverable_signature.s)
+            .unwrap();
+
+        let public_key = secp256k1::GroupElement::new(
+            centralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
         )
         .unwrap();
 
-        let group_order = secp256k1::Scalar::order_from_public_parameters(
-            &protocol_public_parameters.scalar_group_public_parameters,
-        );
+        let verifying_key =
+            VerifyingKey::<k256::Secp256k1>::from_affine(public_key.value().into()).unwrap();
 
-        let group_order = Option::<_>::from(NonZero::new(group_order)).unwrap();
+        let signature =
+            Signature::<k256::Secp256k1>::try_from(recoverable_signature.signature()).unwrap();
 
-        let decentralized_party_secret_key_share = paillier_decryption_key
-            .decrypt(
-                &encrypted_secret_key_share,
-                &protocol_public_parameters.encryption_scheme_public_parameters,
-            )
-            .unwrap();
+        assert!(
+            verifying_key
+                .verify_prehash(&hash.digest(message.as_bytes()), &signature)
+                .is_ok(),
+            "signatures over hashed messages should verify against the digest of the message"
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "E4B7F6C0A31E1F2D92D1E1B0A5D0F9C3A6E87C2B5D9A0E3B2C1D4F5A6B7C8D9E",
+        "0000000000000000000000000000000000000000000000000000000000000001"
+    )]
+    #[case(
+        "00000000000000000000000000000000000000000000000000000000000000FF",
+        "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0"
+    )]
+    #[case(
+        "0080000000000000000000000000000000000000000000000000000000000000",
+        "3C9E5D2A1B8F7E6D5C4B3A291807F6E5D4C3B2A1908F7E6D5C4B3A2918070605"
+    )]
+    fn encodes_signatures_like_ecdsa(#[case] r: &str, #[case] s: &str) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let r: secp256k1::Scalar = U256::from_be_hex(r).into();
+        let s: secp256k1::Scalar = U256::from_be_hex(s).into();
+        let signature = super::Signature { r, s };
+
+        let ecdsa_signature = Signature::<k256::Secp256k1>::try_from(signature).unwrap();
+
+        let der = signature.to_der::<{ secp256k1::SCALAR_LIMBS }>();
+        assert_eq!(der, ecdsa_signature.to_der().as_bytes());
+        assert_eq!(
+            super::Signature::from_der::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+                &der,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap(),
+            signature
+        );
+
+        let compact_bytes = signature.to_compact_bytes::<{ secp256k1::SCALAR_LIMBS }>();
+        assert_eq!(compact_bytes, ecdsa_signature.to_bytes().to_vec());
+        assert_eq!(
+            super::Signature::try_from(ecdsa_signature).unwrap(),
+            signature
+        );
+
+        // Trailing bytes, non-minimal integers and truncated encodings must all be rejected.
+        let mut trailing_der = der.clone();
+        trailing_der.push(0);
+        let mut non_minimal_der = der.clone();
+        non_minimal_der[1] += 1;
+        non_minimal_der[3] += 1;
+        non_minimal_der.insert(4, 0);
+
+        for invalid_der in [trailing_der, non_minimal_der, der[..der.len() - 1].to_vec()] {
+            assert!(matches!(
+                super::Signature::from_der::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+                    &invalid_der,
+                    &protocol_public_parameters.scalar_group_public_parameters,
+                )
+                .err()
+                .unwrap(),
+                Error::InvalidSignatureEncoding
+            ));
+        }
+    }
+
+    #[rstest]
+    #[case(2, 2, 1)]
+    #[case(2, 4, 4)]
+    #[case(6, 9, 1)]
+    fn dkg_presign_signs(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] batch_size: usize,
+    ) {
+        dkg_presign_signs_internal(threshold, number_of_parties, batch_size)
+    }
+
+    pub fn dkg_presign_signs_internal(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        batch_size: usize,
+    ) {
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        presign_signs_internal(
+            threshold,
+            number_of_parties,
+            batch_size,
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+        )
+    }
+
+    #[rstest]
+    #[case(2, 2, &[0])]
+    #[case(2, 4, &[44, 0, 0, 1, 7])]
+    fn dkg_derive_presign_signs(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] derivation_path: &[u32],
+    ) {
+        dkg_derive_presign_signs_internal(threshold, number_of_parties, derivation_path)
+    }
+
+    /// Sign using a key derived along `derivation_path` from the DKG output, with presignatures
+    /// generated for the derived key.
+    pub fn dkg_derive_presign_signs_internal(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        derivation_path: &[u32],
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let chain_code = [1u8; derivation::CHAIN_CODE_SIZE];
+
+        let (centralized_party_dkg_output, _) = derivation::derive_centralized_party_output::<
+            { secp256k1::SCALAR_LIMBS },
+            { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+            secp256k1::GroupElement,
+            tiresias::EncryptionKey,
+        >(
+            centralized_party_dkg_output,
+            &chain_code,
+            derivation_path,
+            &protocol_public_parameters.scalar_group_public_parameters,
+            &protocol_public_parameters.group_public_parameters,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let (decentralized_party_dkg_output, _) = derivation::derive_decentralized_party_output::<
+            { secp256k1::SCALAR_LIMBS },
+            { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+            secp256k1::GroupElement,
+            tiresias::EncryptionKey,
+        >(
+            decentralized_party_dkg_output,
+            &chain_code,
+            derivation_path,
+            &protocol_public_parameters.scalar_group_public_parameters,
+            &protocol_public_parameters.group_public_parameters,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        presign_signs_internal(
+            threshold,
+            number_of_parties,
+            1,
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+        )
+    }
+
+    /// Generate presignatures for the key shares in the given DKG outputs, and sign with them.
+    pub fn presign_signs_internal(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        batch_size: usize,
+        centralized_party_dkg_output: dkg::centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        decentralized_party_dkg_output: dkg::decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+    ) {
+        let (decryption_key_share_public_parameters, decryption_key_shares) =
+            deal_decryption_key_shares(threshold, number_of_parties);
+
+        presign_signs_with_decryption_key_shares_internal(
+            threshold,
+            number_of_parties,
+            batch_size,
+            centralized_party_dkg_output,
+            decentralized_party_dkg_output,
+            ProtocolPublicParameters::new(N),
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+        )
+    }
+
+    /// Generate presignatures under `protocol_public_parameters` for the key shares in the given
+    /// DKG outputs, and sign with them, decrypting with `threshold` of the `decryption_key_shares`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn presign_signs_with_decryption_key_shares_internal(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        batch_size: usize,
+        centralized_party_dkg_output: dkg::centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        decentralized_party_dkg_output: dkg::decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+        protocol_public_parameters: ProtocolPublicParameters,
+        decryption_key_share_public_parameters: tiresias::decryption_key_share::PublicParameters,
+        decryption_key_shares: HashMap<PartyID, DecryptionKeyShare>,
+    ) {
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presign, encrypted_nonce, decentralized_party_presign) =
+            generates_presignatures_with_public_parameters_internal(
+                threshold,
+                number_of_parties,
+                batch_size,
+                encrypted_secret_key_share,
+                false,
+                protocol_public_parameters.clone(),
+            )
+            .unwrap();
+
+        let centralized_party_presign = centralized_party_presign.first().unwrap().clone();
+        let decentralized_party_presign = decentralized_party_presign.first().unwrap().clone();
+
+        let centralized_party_nonce_share_commitment = secp256k1::GroupElement::new(
+            decentralized_party_presign.centralized_party_nonce_share_commitment,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let decentralized_party_nonce_public_share = secp256k1::GroupElement::new(
+            decentralized_party_presign.nonce_public_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let encrypted_mask = tiresias::CiphertextSpaceGroupElement::new(
+            centralized_party_presign.encrypted_mask,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let encrypted_masked_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            centralized_party_presign.encrypted_masked_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let encrypted_masked_nonce_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_presign.encrypted_masked_nonce_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let group_order = secp256k1::Scalar::order_from_public_parameters(
+            &protocol_public_parameters.scalar_group_public_parameters,
+        );
+
+        let group_order = Option::<_>::from(NonZero::new(group_order)).unwrap();
+
+        let decentralized_party_secret_key_share = threshold_decrypt(
+            threshold,
+            number_of_parties,
+            &encrypted_secret_key_share,
+            &decryption_key_share_public_parameters,
+            &decryption_key_shares,
+        );
 
         let decentralized_party_secret_key_share = secp256k1::Scalar::new(
             decentralized_party_secret_key_share
-                .value()
                 .reduce(&group_order)
                 .into(),
             &protocol_public_parameters.scalar_group_public_parameters,
         )
         .unwrap();
 
-        let decentralized_party_nonce_share = paillier_decryption_key
-            .decrypt(
-                encrypted_nonce.first().unwrap(),
-                &protocol_public_parameters.encryption_scheme_public_parameters,
-            )
+        let decentralized_party_nonce_share = threshold_decrypt(
+            threshold,
+            number_of_parties,
+            encrypted_nonce.first().unwrap(),
+            &decryption_key_share_public_parameters,
+            &decryption_key_shares,
+        );
+
+        let decentralized_party_nonce_share = secp256k1::Scalar::new(
+            decentralized_party_nonce_share.reduce(&group_order).into(),
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let secret_key_share = secp256k1::Scalar::new(
+            centralized_party_dkg_output.secret_key_share,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let public_key_share = secp256k1::GroupElement::new(
+            centralized_party_dkg_output.public_key_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        let decentralized_party_public_key_share = secp256k1::GroupElement::new(
+            decentralized_party_dkg_output.public_key_share,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        signs_with_decryption_key_shares_internal(
+            threshold,
+            number_of_parties,
+            protocol_public_parameters,
+            decryption_key_share_public_parameters,
+            decryption_key_shares,
+            secret_key_share,
+            public_key_share,
+            decentralized_party_secret_key_share,
+            decentralized_party_public_key_share,
+            centralized_party_presign.nonce_share,
+            centralized_party_nonce_share_commitment,
+            decentralized_party_nonce_share,
+            decentralized_party_nonce_public_share,
+            centralized_party_presign.commitment_randomness,
+            encrypted_mask,
+            encrypted_masked_key_share,
+            encrypted_masked_nonce_share,
+            false,
+            false,
+        );
+    }
+
+    #[rstest]
+    #[case(2, 2, 3)]
+    #[case(2, 4, 2)]
+    fn batch_signs(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] batch_size: usize,
+    ) {
+        use crate::secp256k1::paillier::bulletproofs::{
+            BatchedSignatureHomomorphicEvaluationParty, BatchedSignaturePartialDecryptionParty,
+        };
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presigns, _, decentralized_party_presigns) =
+            generates_presignatures_internal(
+                threshold,
+                number_of_parties,
+                batch_size,
+                encrypted_secret_key_share,
+                false,
+            )
+            .unwrap();
+
+        let messages: Vec<_> = (0..batch_size)
+            .map(|i| format!("withdrawal #{i}"))
+            .collect();
+
+        let reused_presign = centralized_party_presigns.first().unwrap().clone();
+        assert!(
+            matches!(
+                BatchedSignatureHomomorphicEvaluationParty::new(
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    centralized_party_dkg_output.clone(),
+                    vec![
+                        ("singing!".as_bytes(), reused_presign.clone()),
+                        ("stealing!".as_bytes(), reused_presign),
+                    ],
+                    Hash::Sha256,
+                )
+                .err()
+                .unwrap(),
+                Error::ReusedPresign
+            ),
+            "a presign should not be used to sign more than one message of a batch"
+        );
+
+        let (
+            batched_public_nonce_encrypted_partial_signature_and_proof,
+            signature_verification_round_parties,
+        ) = BatchedSignatureHomomorphicEvaluationParty::new(
+            session_context(Protocol::Sign),
+            protocol_public_parameters.clone(),
+            centralized_party_dkg_output,
+            messages
+                .iter()
+                .map(|message| message.as_bytes())
+                .zip(centralized_party_presigns)
+                .collect(),
+            Hash::Sha256,
+        )
+        .unwrap()
+        .evaluate_encrypted_partial_signatures(&mut OsRng)
+        .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        // Swap the proofs of the nonce shares by the key share of the first two messages, which
+        // are valid proofs, but not for the statements of these messages.
+        let mut swapped_proofs = batched_public_nonce_encrypted_partial_signature_and_proof.clone();
+        let first_proof = swapped_proofs.public_nonce_encrypted_partial_signatures_and_proofs[0]
+            .nonce_share_by_key_share_proof
+            .clone();
+        swapped_proofs.public_nonce_encrypted_partial_signatures_and_proofs[0]
+            .nonce_share_by_key_share_proof = swapped_proofs
+            .public_nonce_encrypted_partial_signatures_and_proofs[1]
+            .nonce_share_by_key_share_proof
+            .clone();
+        swapped_proofs.public_nonce_encrypted_partial_signatures_and_proofs[1]
+            .nonce_share_by_key_share_proof = first_proof;
+
+        let (&party_id, decryption_key_share) = decryption_key_shares.iter().next().unwrap();
+        assert!(
+            matches!(
+                BatchedSignaturePartialDecryptionParty::new(
+                    threshold,
+                    decryption_key_share.clone(),
+                    decryption_key_share_public_parameters.clone(),
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output.clone(),
+                    messages
+                        .iter()
+                        .map(|message| message.as_bytes())
+                        .zip(decentralized_party_presigns.clone())
+                        .collect(),
+                    Hash::Sha256,
+                )
+                .unwrap()
+                .partially_decrypt_encrypted_signature_parts(swapped_proofs, &mut OsRng)
+                .err()
+                .unwrap(),
+                Error::Maurer(_)
+            ),
+            "party {party_id} should reject a batch with a proof over the wrong statement"
+        );
+
+        let (decryption_shares, signature_threshold_decryption_round_parties): (
+            HashMap<_, _>,
+            HashMap<_, _>,
+        ) = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                let (decryption_shares, signature_threshold_decryption_round_party) =
+                    BatchedSignaturePartialDecryptionParty::new(
+                        threshold,
+                        decryption_key_share,
+                        decryption_key_share_public_parameters.clone(),
+                        session_context(Protocol::Sign),
+                        protocol_public_parameters.clone(),
+                        decentralized_party_dkg_output.clone(),
+                        messages
+                            .iter()
+                            .map(|message| message.as_bytes())
+                            .zip(decentralized_party_presigns.clone())
+                            .collect(),
+                        Hash::Sha256,
+                    )
+                    .unwrap()
+                    .partially_decrypt_encrypted_signature_parts(
+                        batched_public_nonce_encrypted_partial_signature_and_proof.clone(),
+                        &mut OsRng,
+                    )
+                    .unwrap();
+
+                (
+                    (party_id, decryption_shares),
+                    (party_id, signature_threshold_decryption_round_party),
+                )
+            })
+            .unzip();
+
+        let (_, signature_threshold_decryption_round_party) =
+            signature_threshold_decryption_round_parties
+                .into_iter()
+                .next()
+                .unwrap();
+
+        let signatures = signature_threshold_decryption_round_party
+            .decrypt_signatures(lagrange_coefficients, decryption_shares)
+            .unwrap();
+
+        assert_eq!(signatures.len(), batch_size);
+
+        signatures
+            .into_iter()
+            .zip(signature_verification_round_parties)
+            .for_each(|(signature, signature_verification_round_party)| {
+                signature_verification_round_party
+                    .verify_signature(signature.r, signature.s)
+                    .unwrap()
+            });
+    }
+
+    /// Run the DKG, presign and sign protocols end to end through the [`Round`]s of the
+    /// centralized party and of `threshold` out of `number_of_parties` decentralized parties,
+    /// returning the output of the centralized party's DKG and the signature on `message`.
+    ///
+    /// Only the first round of each protocol depends on the curve, and is constructed by the
+    /// given closures; the rest of the rounds are chained by their [`Round`] types.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn dkg_presign_signs_through_rounds_internal<
+        CentralizedPartyPresign,
+        DecentralizedPartyPresign,
+        DKGCommitmentRound,
+        DKGDecommitmentRound,
+        EncryptionOfSecretKeyShareRound,
+        EncryptionOfSecretKeyShareDecommitmentRound,
+        EncryptionOfSecretKeyShareProofShareRound,
+        EncryptionOfSecretKeyShareProofAggregationRound,
+        DecommitmentProofVerificationRound,
+        PresignCommitmentRound,
+        PresignProofVerificationRound,
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound,
+        MaskedNoncesDecommitmentRound,
+        MaskedNoncesProofShareRound,
+        MaskedNoncesProofAggregationRound,
+        SignatureHomomorphicEvaluationRound,
+        SignatureVerificationRound,
+        SignaturePartialDecryptionRound,
+        SignatureThresholdDecryptionRound,
+    >(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        batch_size: usize,
+        dkg_commitment_round_party: DKGCommitmentRound,
+        encryption_of_secret_key_share_round_party: impl Fn(
+            PartyID,
+            HashSet<PartyID>,
+        ) -> EncryptionOfSecretKeyShareRound,
+        presign_commitment_round_party: impl FnOnce(
+            DKGDecommitmentRound::Output,
+        ) -> PresignCommitmentRound,
+        encrypted_masked_key_share_and_public_nonce_shares_round_party: impl Fn(
+            PartyID,
+            HashSet<PartyID>,
+            DecommitmentProofVerificationRound::Output,
+        )
+            -> EncryptedMaskedKeyShareAndPublicNonceSharesRound,
+        signature_homomorphic_evaluation_round_party: impl FnOnce(
+            DKGDecommitmentRound::Output,
+            CentralizedPartyPresign,
+        )
+            -> SignatureHomomorphicEvaluationRound,
+        signature_partial_decryption_round_party: impl Fn(
+            DecryptionKeyShare,
+            tiresias::decryption_key_share::PublicParameters,
+            DecommitmentProofVerificationRound::Output,
+            DecentralizedPartyPresign,
+        ) -> SignaturePartialDecryptionRound,
+        message: SignatureHomomorphicEvaluationRound::Incoming,
+    ) -> (
+        DKGDecommitmentRound::Output,
+        SignatureVerificationRound::Output,
+    )
+    where
+        DecentralizedPartyPresign: Clone + PartialEq,
+        DKGCommitmentRound: Round<Error = Error, Incoming = (), NextRound = DKGDecommitmentRound>,
+        DKGCommitmentRound::Message: Clone,
+        DKGDecommitmentRound: Round<
+            Error = Error,
+            Incoming = EncryptionOfSecretKeyShareProofAggregationRound::Message,
+        >,
+        DKGDecommitmentRound::Message: Clone,
+        DKGDecommitmentRound::Output: Clone,
+        EncryptionOfSecretKeyShareRound: Round<
+            Error = Error,
+            Incoming = DKGCommitmentRound::Message,
+            NextRound = EncryptionOfSecretKeyShareDecommitmentRound,
+        >,
+        EncryptionOfSecretKeyShareRound::Message: Clone,
+        EncryptionOfSecretKeyShareDecommitmentRound: Round<
+            Error = Error,
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareRound::Message>,
+            NextRound = EncryptionOfSecretKeyShareProofShareRound,
+        >,
+        EncryptionOfSecretKeyShareDecommitmentRound::Message: Clone,
+        EncryptionOfSecretKeyShareProofShareRound: Round<
+            Error = Error,
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareDecommitmentRound::Message>,
+            NextRound = EncryptionOfSecretKeyShareProofAggregationRound,
+        >,
+        EncryptionOfSecretKeyShareProofShareRound::Message: Clone,
+        EncryptionOfSecretKeyShareProofAggregationRound: Round<
+            Error = Error,
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareProofShareRound::Message>,
+            NextRound = DecommitmentProofVerificationRound,
+        >,
+        DecommitmentProofVerificationRound:
+            Round<Error = Error, Incoming = DKGDecommitmentRound::Message>,
+        DecommitmentProofVerificationRound::Output: Clone + PartialEq,
+        PresignCommitmentRound:
+            Round<Error = Error, Incoming = usize, NextRound = PresignProofVerificationRound>,
+        PresignCommitmentRound::Message: Clone,
+        PresignProofVerificationRound: Round<
+            Error = Error,
+            Incoming = MaskedNoncesProofAggregationRound::Message,
+            Output = Vec<CentralizedPartyPresign>,
+        >,
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound: Round<
+            Error = Error,
+            Incoming = PresignCommitmentRound::Message,
+            NextRound = MaskedKeyShareAndPublicNonceSharesDecommitmentRound,
+        >,
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound::Message: Clone,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound: Round<
+            Error = Error,
+            Incoming = HashMap<PartyID, EncryptedMaskedKeyShareAndPublicNonceSharesRound::Message>,
+            NextRound = MaskedKeyShareAndPublicNonceSharesProofShareRound,
+        >,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound::Message: Clone,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound: Round<
+            Error = Error,
+            Incoming = HashMap<
+                PartyID,
+                MaskedKeyShareAndPublicNonceSharesDecommitmentRound::Message,
+            >,
+            NextRound = MaskedKeyShareAndPublicNonceSharesProofAggregationRound,
+        >,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound::Message: Clone,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound: Round<
+            Error = Error,
+            Incoming = HashMap<PartyID, MaskedKeyShareAndPublicNonceSharesProofShareRound::Message>,
+            NextRound = MaskedNoncesDecommitmentRound,
+        >,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound::Message: Clone,
+        MaskedNoncesDecommitmentRound: Round<
+            Error = Error,
+            Incoming = HashMap<
+                PartyID,
+                MaskedKeyShareAndPublicNonceSharesProofAggregationRound::Message,
+            >,
+            NextRound = MaskedNoncesProofShareRound,
+        >,
+        MaskedNoncesDecommitmentRound::Message: Clone,
+        MaskedNoncesProofShareRound: Round<
+            Error = Error,
+            Incoming = HashMap<PartyID, MaskedNoncesDecommitmentRound::Message>,
+            NextRound = MaskedNoncesProofAggregationRound,
+        >,
+        MaskedNoncesProofShareRound::Message: Clone,
+        MaskedNoncesProofAggregationRound: Round<
+            Error = Error,
+            Incoming = HashMap<PartyID, MaskedNoncesProofShareRound::Message>,
+            Output = Vec<DecentralizedPartyPresign>,
+        >,
+        SignatureHomomorphicEvaluationRound:
+            Round<Error = Error, NextRound = SignatureVerificationRound>,
+        SignatureHomomorphicEvaluationRound::Incoming: Clone,
+        SignatureHomomorphicEvaluationRound::Message: Clone,
+        SignatureVerificationRound:
+            Round<Error = Error, Incoming = SignatureThresholdDecryptionRound::Output>,
+        SignaturePartialDecryptionRound: Round<
+            Error = Error,
+            Incoming = (
+                SignatureHomomorphicEvaluationRound::Incoming,
+                SignatureHomomorphicEvaluationRound::Message,
+            ),
+            NextRound = SignatureThresholdDecryptionRound,
+        >,
+        SignaturePartialDecryptionRound::Message: Clone,
+        SignatureThresholdDecryptionRound: Round<
+            Error = Error,
+            Incoming = (
+                HashMap<PartyID, AdjustedLagrangeCoefficientSizedNumber>,
+                HashMap<PartyID, SignaturePartialDecryptionRound::Message>,
+            ),
+        >,
+        SignatureThresholdDecryptionRound::Output: Clone + PartialEq,
+    {
+        let parties: HashSet<PartyID> = (1..=number_of_parties)
+            .choose_multiple(&mut OsRng, threshold.into())
+            .into_iter()
+            .collect();
+
+        // DKG
+        let (commitment, centralized_party_decommitment_round_party) = dkg_commitment_round_party
+            .process((), &mut OsRng)
+            .unwrap()
+            .advance()
+            .unwrap();
+
+        let encryption_of_secret_key_share_round_parties: HashMap<_, _> = parties
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    encryption_of_secret_key_share_round_party(party_id, parties.clone()),
+                )
+            })
+            .collect();
+
+        let (commitments, decommitment_round_parties) =
+            advance(encryption_of_secret_key_share_round_parties, commitment);
+        let (decommitments, proof_share_round_parties) =
+            advance(decommitment_round_parties, commitments);
+        let (proof_shares, proof_aggregation_round_parties) =
+            advance(proof_share_round_parties, decommitments);
+        let (secret_key_share_encryptions_and_proofs, verification_round_parties) =
+            advance(proof_aggregation_round_parties, proof_shares);
+
+        let (public_key_share_decommitment_and_proof, centralized_party_dkg_output) =
+            centralized_party_decommitment_round_party
+                .process(
+                    secret_key_share_encryptions_and_proofs
+                        .into_values()
+                        .next()
+                        .unwrap(),
+                    &mut OsRng,
+                )
+                .unwrap()
+                .finalize()
+                .unwrap();
+
+        let (_, decentralized_party_dkg_outputs) = finalize(
+            verification_round_parties,
+            public_key_share_decommitment_and_proof,
+        );
+
+        let decentralized_party_dkg_output = decentralized_party_dkg_outputs
+            .values()
+            .next()
+            .unwrap()
+            .clone();
+
+        assert!(decentralized_party_dkg_outputs
+            .values()
+            .all(|output| *output == decentralized_party_dkg_output));
+
+        // Presign
+        let (nonce_shares_commitments_and_batched_proof, proof_verification_round_party) =
+            presign_commitment_round_party(centralized_party_dkg_output.clone())
+                .process(batch_size, &mut OsRng)
+                .unwrap()
+                .advance()
+                .unwrap();
+
+        let encrypted_masked_key_share_and_public_nonce_shares_round_parties: HashMap<_, _> =
+            parties
+                .iter()
+                .map(|&party_id| {
+                    (
+                        party_id,
+                        encrypted_masked_key_share_and_public_nonce_shares_round_party(
+                            party_id,
+                            parties.clone(),
+                            decentralized_party_dkg_output.clone(),
+                        ),
+                    )
+                })
+                .collect();
+
+        let (commitments, decommitment_round_parties) = advance(
+            encrypted_masked_key_share_and_public_nonce_shares_round_parties,
+            nonce_shares_commitments_and_batched_proof,
+        );
+        let (decommitments, proof_share_round_parties) =
+            advance(decommitment_round_parties, commitments);
+        let (proof_shares, proof_aggregation_round_parties) =
+            advance(proof_share_round_parties, decommitments);
+        let (commitments, decommitment_round_parties) =
+            advance(proof_aggregation_round_parties, proof_shares);
+        let (decommitments, proof_share_round_parties) =
+            advance(decommitment_round_parties, commitments);
+        let (proof_shares, proof_aggregation_round_parties) =
+            advance(proof_share_round_parties, decommitments);
+        let (outputs, decentralized_party_presigns) =
+            finalize(proof_aggregation_round_parties, proof_shares);
+
+        let (_, centralized_party_presigns) = proof_verification_round_party
+            .process(outputs.into_values().next().unwrap(), &mut OsRng)
+            .unwrap()
+            .finalize()
+            .unwrap();
+
+        assert_eq!(centralized_party_presigns.len(), batch_size);
+
+        let decentralized_party_presign = decentralized_party_presigns.values().next().unwrap();
+        assert!(decentralized_party_presigns
+            .values()
+            .all(|presigns| presigns == decentralized_party_presign));
+        assert_eq!(decentralized_party_presign.len(), batch_size);
+
+        // Sign
+        let (
+            public_nonce_encrypted_partial_signature_and_proof,
+            signature_verification_round_party,
+        ) = signature_homomorphic_evaluation_round_party(
+            centralized_party_dkg_output.clone(),
+            centralized_party_presigns.into_iter().next().unwrap(),
+        )
+        .process(message.clone(), &mut OsRng)
+        .unwrap()
+        .advance()
+        .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let decentralized_party_presign = decentralized_party_presign.first().unwrap();
+        let signature_partial_decryption_round_parties: HashMap<_, _> = decryption_key_shares
+            .into_iter()
+            .map(|(party_id, decryption_key_share)| {
+                (
+                    party_id,
+                    signature_partial_decryption_round_party(
+                        decryption_key_share,
+                        decryption_key_share_public_parameters.clone(),
+                        decentralized_party_dkg_output.clone(),
+                        decentralized_party_presign.clone(),
+                    ),
+                )
+            })
+            .collect();
+
+        let (decryption_shares, signature_threshold_decryption_round_parties) = advance(
+            signature_partial_decryption_round_parties,
+            (message, public_nonce_encrypted_partial_signature_and_proof),
+        );
+        let (_, signatures) = finalize(
+            signature_threshold_decryption_round_parties,
+            (lagrange_coefficients, decryption_shares),
+        );
+
+        let signature = signatures.values().next().unwrap().clone();
+        assert!(signatures
+            .values()
+            .all(|other_signature| *other_signature == signature));
+
+        let (_, signature) = signature_verification_round_party
+            .process(signature, &mut OsRng)
+            .unwrap()
+            .finalize()
             .unwrap();
 
-        let decentralized_party_nonce_share = secp256k1::Scalar::new(
-            decentralized_party_nonce_share
-                .value()
-                .reduce(&group_order)
-                .into(),
-            &protocol_public_parameters.scalar_group_public_parameters,
+        (centralized_party_dkg_output, signature)
+    }
+
+    #[rstest]
+    #[case(2, 2, 1)]
+    #[case(2, 4, 2)]
+    fn dkg_presign_signs_through_rounds(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] batch_size: usize,
+    ) {
+        use crate::secp256k1::paillier::bulletproofs::{
+            DKGCommitmentRoundParty, EncryptedMaskedKeyShareRoundParty,
+            EncryptionOfSecretKeyShareRoundParty, PresignCommitmentRoundParty,
+            SignatureHomomorphicEvaluationParty, SignaturePartialDecryptionParty,
+        };
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+        let message = "singing!";
+
+        let (centralized_party_dkg_output, signature) = dkg_presign_signs_through_rounds_internal(
+            threshold,
+            number_of_parties,
+            batch_size,
+            DKGCommitmentRoundParty::new(
+                protocol_public_parameters.clone(),
+                session_context(Protocol::Dkg),
+            ),
+            |party_id, parties| {
+                EncryptionOfSecretKeyShareRoundParty::new(
+                    protocol_public_parameters.clone(),
+                    party_id,
+                    threshold,
+                    parties,
+                    session_context(Protocol::Dkg),
+                )
+            },
+            |centralized_party_dkg_output| {
+                PresignCommitmentRoundParty::new(
+                    session_context(Protocol::Presign),
+                    protocol_public_parameters.clone(),
+                    centralized_party_dkg_output,
+                )
+                .unwrap()
+            },
+            |party_id, parties, decentralized_party_dkg_output| {
+                EncryptedMaskedKeyShareRoundParty::new(
+                    party_id,
+                    threshold,
+                    parties,
+                    session_context(Protocol::Presign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output,
+                )
+                .unwrap()
+            },
+            |centralized_party_dkg_output, centralized_party_presign| {
+                SignatureHomomorphicEvaluationParty::new(
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    centralized_party_dkg_output,
+                    centralized_party_presign,
+                )
+                .unwrap()
+            },
+            |decryption_key_share,
+             decryption_key_share_public_parameters,
+             decentralized_party_dkg_output,
+             decentralized_party_presign| {
+                SignaturePartialDecryptionParty::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters,
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output,
+                    decentralized_party_presign,
+                )
+                .unwrap()
+            },
+            message_digest::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+                message.as_bytes(),
+                Hash::Sha256,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap(),
+        );
+
+        let public_key = secp256k1::GroupElement::new(
+            centralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
         )
         .unwrap();
 
-        let secret_key_share = secp256k1::Scalar::new(
-            centralized_party_dkg_output.secret_key_share,
-            &protocol_public_parameters.scalar_group_public_parameters,
+        let verifying_key =
+            VerifyingKey::<k256::Secp256k1>::from_affine(public_key.value().into()).unwrap();
+
+        let ecdsa_signature = Signature::<k256::Secp256k1>::from_scalars(
+            k256::Scalar::from(signature.r),
+            k256::Scalar::from(signature.s),
         )
         .unwrap();
 
-        let public_key_share = secp256k1::GroupElement::new(
-            centralized_party_dkg_output.public_key_share,
-            &protocol_public_parameters.group_public_parameters,
+        let res = <VerifyingKey<k256::Secp256k1> as Verifier<Signature<k256::Secp256k1>>>::verify(
+            &verifying_key,
+            message.as_bytes(),
+            &ecdsa_signature,
+        );
+
+        assert!(res.is_ok(), "generated signatures should be valid");
+
+        let recovered_verifying_key = VerifyingKey::<k256::Secp256k1>::recover_from_msg(
+            message.as_bytes(),
+            &ecdsa_signature,
+            RecoveryId::from_byte(signature.recovery_id).unwrap(),
         )
         .unwrap();
 
-        let decentralized_party_public_key_share = secp256k1::GroupElement::new(
-            decentralized_party_dkg_output.public_key_share,
+        assert_eq!(verifying_key, recovered_verifying_key);
+    }
+
+    #[cfg(all(test, feature = "secp256r1"))]
+    #[rstest]
+    #[case(2, 2, 1)]
+    #[case(2, 4, 2)]
+    fn dkg_presign_signs_secp256r1(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] batch_size: usize,
+    ) {
+        use group::secp256r1;
+
+        use crate::secp256r1::paillier::bulletproofs::{
+            DKGCommitmentRoundParty, EncryptedMaskedKeyShareRoundParty,
+            EncryptionOfSecretKeyShareRoundParty, PresignCommitmentRoundParty,
+            ProtocolPublicParameters, SignatureHomomorphicEvaluationParty,
+            SignaturePartialDecryptionParty,
+        };
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+        let message = "singing!";
+
+        let (centralized_party_dkg_output, signature) = dkg_presign_signs_through_rounds_internal(
+            threshold,
+            number_of_parties,
+            batch_size,
+            DKGCommitmentRoundParty::new(
+                protocol_public_parameters.clone(),
+                session_context(Protocol::Dkg),
+            ),
+            |party_id, parties| {
+                EncryptionOfSecretKeyShareRoundParty::new(
+                    protocol_public_parameters.clone(),
+                    party_id,
+                    threshold,
+                    parties,
+                    session_context(Protocol::Dkg),
+                )
+            },
+            |centralized_party_dkg_output| {
+                PresignCommitmentRoundParty::new(
+                    session_context(Protocol::Presign),
+                    protocol_public_parameters.clone(),
+                    centralized_party_dkg_output,
+                )
+                .unwrap()
+            },
+            |party_id, parties, decentralized_party_dkg_output| {
+                EncryptedMaskedKeyShareRoundParty::new(
+                    party_id,
+                    threshold,
+                    parties,
+                    session_context(Protocol::Presign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output,
+                )
+                .unwrap()
+            },
+            |centralized_party_dkg_output, centralized_party_presign| {
+                SignatureHomomorphicEvaluationParty::new(
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    centralized_party_dkg_output,
+                    centralized_party_presign,
+                )
+                .unwrap()
+            },
+            |decryption_key_share,
+             decryption_key_share_public_parameters,
+             decentralized_party_dkg_output,
+             decentralized_party_presign| {
+                SignaturePartialDecryptionParty::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters,
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_dkg_output,
+                    decentralized_party_presign,
+                )
+                .unwrap()
+            },
+            message_digest::<{ secp256r1::SCALAR_LIMBS }, secp256r1::GroupElement>(
+                message.as_bytes(),
+                Hash::Sha256,
+                &protocol_public_parameters.scalar_group_public_parameters,
+            )
+            .unwrap(),
+        );
+
+        let public_key = secp256r1::GroupElement::new(
+            centralized_party_dkg_output.public_key,
             &protocol_public_parameters.group_public_parameters,
         )
         .unwrap();
 
-        signs_internal(
-            threshold,
-            number_of_parties,
-            secret_key_share,
-            public_key_share,
-            decentralized_party_secret_key_share,
-            decentralized_party_public_key_share,
-            centralized_party_presign.nonce_share,
-            centralized_party_nonce_share_commitment,
-            decentralized_party_nonce_share,
-            decentralized_party_nonce_public_share,
-            centralized_party_presign.commitment_randomness,
-            encrypted_mask,
-            encrypted_masked_key_share,
-            encrypted_masked_nonce_share,
-            false,
-            false,
+        let verifying_key =
+            VerifyingKey::<p256::NistP256>::from_affine(public_key.value().into()).unwrap();
+
+        let ecdsa_signature = Signature::<p256::NistP256>::from_scalars(
+            p256::Scalar::from(signature.r),
+            p256::Scalar::from(signature.s),
+        )
+        .unwrap();
+
+        let res = <VerifyingKey<p256::NistP256> as Verifier<Signature<p256::NistP256>>>::verify(
+            &verifying_key,
+            message.as_bytes(),
+            &ecdsa_signature,
         );
+
+        assert!(res.is_ok(), "generated P-256 signatures should be valid");
+
+        let recovered_verifying_key = VerifyingKey::<p256::NistP256>::recover_from_msg(
+            message.as_bytes(),
+            &ecdsa_signature,
+            RecoveryId::from_byte(signature.recovery_id).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(verifying_key, recovered_verifying_key);
     }
 
     #[rstest]
@@ -982,6 +2387,239 @@ pub(crate) mod tests {
                 }
             });
     }
+    #[test]
+    fn restores_round_parties_from_state() {
+        use crate::secp256k1::paillier::bulletproofs::{
+            SignatureHomomorphicEvaluationParty, SignaturePartialDecryptionParty,
+        };
+
+        let threshold = 2;
+        let number_of_parties = 4;
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (centralized_party_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presigns, _, decentralized_party_presigns) =
+            generates_presignatures_internal(
+                threshold,
+                number_of_parties,
+                1,
+                encrypted_secret_key_share,
+                false,
+            )
+            .unwrap();
+
+        let message = "singing!";
+
+        let signature_homomorphic_evaluation_round_party =
+            SignatureHomomorphicEvaluationParty::new(
+                session_context(Protocol::Sign),
+                protocol_public_parameters.clone(),
+                centralized_party_dkg_output,
+                centralized_party_presigns.first().unwrap().clone(),
+            )
+            .unwrap();
+
+        let state =
+            serde_json::to_vec(&signature_homomorphic_evaluation_round_party.to_state()).unwrap();
+        let signature_homomorphic_evaluation_round_party =
+            SignatureHomomorphicEvaluationParty::from_state(
+                protocol_public_parameters.clone(),
+                serde_json::from_slice(&state).unwrap(),
+                session_context(Protocol::Sign),
+            )
+            .unwrap();
+
+        let (
+            public_nonce_encrypted_partial_signature_and_proof,
+            signature_verification_round_party,
+        ) = signature_homomorphic_evaluation_round_party
+            .evaluate_encrypted_partial_signature(message.as_bytes(), Hash::Sha256, &mut OsRng)
+            .unwrap();
+
+        let state = signature_verification_round_party.to_state();
+        let restored_party = centralized_party::signature_verification_round::Party::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >::from_state(
+            &protocol_public_parameters.scalar_group_public_parameters,
+            &protocol_public_parameters.group_public_parameters,
+            serde_json::from_slice(&serde_json::to_vec(&state).unwrap()).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            restored_party.to_state(),
+            state,
+            "restoring a party from its serialized state should not change it"
+        );
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, _) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+        let decryption_key_share = decryption_key_shares.into_values().next().unwrap();
+
+        let signature_partial_decryption_round_party = SignaturePartialDecryptionParty::new(
+            threshold,
+            decryption_key_share.clone(),
+            decryption_key_share_public_parameters.clone(),
+            session_context(Protocol::Sign),
+            protocol_public_parameters.clone(),
+            decentralized_party_dkg_output,
+            decentralized_party_presigns.first().unwrap().clone(),
+        )
+        .unwrap();
+
+        // The decryption key share is not a part of the state, and is supplied separately.
+        let state =
+            serde_json::to_vec(&signature_partial_decryption_round_party.to_state()).unwrap();
+        let signature_partial_decryption_round_party =
+            signature_partial_decryption_round::Party::from_state(
+                decryption_key_share,
+                decryption_key_share_public_parameters.clone(),
+                protocol_public_parameters.clone(),
+                serde_json::from_slice(&state).unwrap(),
+                session_context(Protocol::Sign),
+            )
+            .unwrap();
+
+        let (_, signature_threshold_decryption_round_party) =
+            signature_partial_decryption_round_party
+                .partially_decrypt_encrypted_signature_parts(
+                    message.as_bytes(),
+                    Hash::Sha256,
+                    public_nonce_encrypted_partial_signature_and_proof,
+                    &mut OsRng,
+                )
+                .unwrap();
+
+        let state = signature_threshold_decryption_round_party.to_state();
+        let restored_party = signature_threshold_decryption_round::Party::<
+            { secp256k1::SCALAR_LIMBS },
+            { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+            secp256k1::GroupElement,
+            tiresias::EncryptionKey,
+            DecryptionKeyShare,
+        >::from_state(
+            decryption_key_share_public_parameters,
+            protocol_public_parameters
+                .scalar_group_public_parameters
+                .clone(),
+            protocol_public_parameters.group_public_parameters.clone(),
+            serde_json::from_slice(&serde_json::to_vec(&state).unwrap()).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            restored_party.to_state(),
+            state,
+            "restoring a party from its serialized state should not change it"
+        );
+    }
+
+    #[test]
+    fn restores_identifiable_abort_parties_from_state() {
+        let threshold = 2;
+        let number_of_parties = 4;
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, _) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let encryption_scheme_public_parameters = decryption_key_share_public_parameters
+            .encryption_scheme_public_parameters
+            .clone();
+
+        let paillier_encryption_key =
+            tiresias::EncryptionKey::new(&encryption_scheme_public_parameters).unwrap();
+
+        // As in `sign_identifiable_abort`, dummy ciphertexts suffice for checking decryption.
+        let (_, encrypted_partial_signature) = paillier_encryption_key
+            .encrypt(
+                &tiresias::PlaintextSpaceGroupElement::new(
+                    Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::ZERO,
+                    encryption_scheme_public_parameters.plaintext_space_public_parameters(),
+                )
+                .unwrap(),
+                &encryption_scheme_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        let (_, encrypted_masked_nonce_share) = paillier_encryption_key
+            .encrypt(
+                &tiresias::PlaintextSpaceGroupElement::new(
+                    Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::ONE,
+                    encryption_scheme_public_parameters.plaintext_space_public_parameters(),
+                )
+                .unwrap(),
+                &encryption_scheme_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        let (designated_decrypting_party_id, decryption_key_share) =
+            decryption_key_shares.into_iter().next().unwrap();
+
+        let partial_decryption_proof_round_party =
+            signature_partial_decryption_proof_round::Party::<
+                { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                tiresias::EncryptionKey,
+                DecryptionKeyShare,
+            > {
+                threshold,
+                designated_decrypting_party_id,
+                decryption_key_share: decryption_key_share.clone(),
+                decryption_key_share_public_parameters: decryption_key_share_public_parameters
+                    .clone(),
+                encrypted_partial_signature,
+                encrypted_masked_nonce_share,
+            };
+
+        let state = serde_json::to_vec(&partial_decryption_proof_round_party.to_state()).unwrap();
+        let partial_decryption_proof_round_party =
+            signature_partial_decryption_proof_round::Party::<
+                { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                tiresias::EncryptionKey,
+                DecryptionKeyShare,
+            >::from_state(
+                decryption_key_share,
+                decryption_key_share_public_parameters.clone(),
+                encryption_scheme_public_parameters.clone(),
+                serde_json::from_slice(&state).unwrap(),
+            )
+            .unwrap();
+
+        let (_, partial_decryption_verification_round_party) = partial_decryption_proof_round_party
+            .prove_correct_signature_partial_decryption(&mut OsRng)
+            .unwrap();
+
+        let state = partial_decryption_verification_round_party.to_state();
+        let restored_party = signature_partial_decryption_verification_round::Party::<
+            { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+            tiresias::EncryptionKey,
+            DecryptionKeyShare,
+        >::from_state(
+            decryption_key_share_public_parameters,
+            encryption_scheme_public_parameters,
+            serde_json::from_slice(&serde_json::to_vec(&state).unwrap()).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            restored_party.to_state(),
+            state,
+            "restoring a party from its serialized state should not change it"
+        );
+    }
 }
 
 #[cfg(feature = "benchmarking")]
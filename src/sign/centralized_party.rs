@@ -3,6 +3,7 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod batched_signature_homomorphic_evaluation_round;
 pub mod signature_homomorphic_evaluation_round;
 pub mod signature_verification_round;
 
@@ -25,3 +26,26 @@ pub struct PublicNonceEncryptedPartialSignatureAndProof<
     pub(super) encrypted_partial_signature_range_proof_commitment: RangeProofCommitmentValue,
     pub(super) encrypted_partial_signature_proof: DComEvalProof,
 }
+
+/// The message sent by the centralized party in batched signing: the public nonce, encrypted
+/// partial signature and proofs over each of the messages being signed, in order.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BatchedPublicNonceEncryptedPartialSignatureAndProof<
+    GroupElementValue,
+    RangeProofCommitmentValue,
+    CiphertextValue,
+    ComDLProof,
+    ComRatioProof,
+    DComEvalProof,
+> {
+    pub(super) public_nonce_encrypted_partial_signatures_and_proofs: Vec<
+        PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElementValue,
+            RangeProofCommitmentValue,
+            CiphertextValue,
+            ComDLProof,
+            ComRatioProof,
+            DComEvalProof,
+        >,
+    >,
+}
@@ -0,0 +1,334 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use commitment::Pedersen;
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
+use enhanced_maurer::{committed_linear_evaluation, EnhanceableLanguage};
+use group::{AffineXCoordinate, PrimeGroupElement, Samplable};
+use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
+use maurer::{
+    committment_of_discrete_log, discrete_log_ratio_of_committed_values, SOUND_PROOFS_REPETITIONS,
+};
+use proof::AggregatableRangeProof;
+
+use crate::{
+    dkg, presign,
+    session::RoundContext,
+    sign::{
+        centralized_party::{
+            signature_homomorphic_evaluation_round, signature_verification_round,
+            BatchedPublicNonceEncryptedPartialSignatureAndProof,
+        },
+        message_digest, Hash, DIMENSION,
+    },
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const RANGE_CLAIMS_PER_MASK: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const NUM_RANGE_CLAIMS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(in crate::sign) messages_and_parties: Vec<(
+        GroupElement::Scalar,
+        signature_homomorphic_evaluation_round::Party<
+            SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RANGE_CLAIMS_PER_MASK,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedDComEvalWitness,
+            ProtocolContext,
+        >,
+    )>,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const RANGE_CLAIMS_PER_MASK: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedDComEvalWitness,
+        ProtocolContext,
+    >
+where
+    committed_linear_evaluation::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        DIMENSION,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = committed_linear_evaluation::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = committed_linear_evaluation::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = committed_linear_evaluation::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            NUM_RANGE_CLAIMS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedDComEvalWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    /// Evaluate the encrypted partial signatures over all messages of the batch, each using its
+    /// own presign.
+    ///
+    /// The returned message carries the public nonce, encrypted partial signature and proofs of
+    /// each message in the order in which they were given, alongside a signature verification
+    /// party for each.
+    pub fn evaluate_encrypted_partial_signatures(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        BatchedPublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+        Vec<signature_verification_round::Party<SCALAR_LIMBS, GroupElement>>,
+    )> {
+        let (
+            public_nonce_encrypted_partial_signatures_and_proofs,
+            signature_verification_round_parties,
+        ) = self
+            .messages_and_parties
+            .into_iter()
+            .map(|(message, party)| {
+                party.evaluate_encrypted_partial_signature_prehash(message, rng)
+            })
+            .collect::<crate::Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        Ok((
+            BatchedPublicNonceEncryptedPartialSignatureAndProof {
+                public_nonce_encrypted_partial_signatures_and_proofs,
+            },
+            signature_verification_round_parties,
+        ))
+    }
+
+    /// Instantiate a batched signing party for `messages_and_presigns`, in which every message is
+    /// hashed using `hash` and converted into a scalar via [`message_digest`].
+    ///
+    /// See [`Self::new_prehash`].
+    pub fn new<
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        messages_and_presigns: Vec<(
+            &[u8],
+            presign::centralized_party::Presign<
+                GroupElement::Value,
+                group::Value<GroupElement::Scalar>,
+                group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            >,
+        )>,
+        hash: Hash,
+    ) -> crate::Result<Self> {
+        let messages_and_presigns = messages_and_presigns
+            .into_iter()
+            .map(|(message, presign)| {
+                message_digest::<SCALAR_LIMBS, GroupElement>(
+                    message,
+                    hash,
+                    &protocol_public_parameters.scalar_group_public_parameters,
+                )
+                .map(|message| (message, presign))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Self::new_prehash(
+            protocol_context,
+            protocol_public_parameters,
+            dkg_output,
+            messages_and_presigns,
+        )
+    }
+
+    /// Instantiate a batched signing party for `messages_and_presigns`, pairing every message to
+    /// be signed with a distinct presign.
+    ///
+    /// Note: every message is a `Scalar` which must be a hash on the message bytes translated into
+    /// a 32-byte number.
+    pub fn new_prehash<
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        messages_and_presigns: Vec<(
+            GroupElement::Scalar,
+            presign::centralized_party::Presign<
+                GroupElement::Value,
+                group::Value<GroupElement::Scalar>,
+                group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            >,
+        )>,
+    ) -> crate::Result<Self> {
+        if messages_and_presigns.is_empty() {
+            return Err(Error::InvalidParameters);
+        }
+
+        // Signing two messages with the same presign would reveal the secret key.
+        if messages_and_presigns
+            .iter()
+            .enumerate()
+            .any(|(i, (_, presign))| {
+                messages_and_presigns[..i].iter().any(|(_, other_presign)| {
+                    other_presign.decentralized_party_nonce_public_share
+                        == presign.decentralized_party_nonce_public_share
+                })
+            })
+        {
+            return Err(Error::ReusedPresign);
+        }
+
+        let messages_and_parties = messages_and_presigns
+            .into_iter()
+            .map(|(message, presign)| {
+                signature_homomorphic_evaluation_round::Party::new(
+                    protocol_context.clone(),
+                    protocol_public_parameters.clone(),
+                    dkg_output.clone(),
+                    presign,
+                )
+                .map(|party| (message, party))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            messages_and_parties,
+        })
+    }
+}
@@ -21,19 +21,35 @@ use maurer::{
     discrete_log_ratio_of_committed_values::StatementAccessors as _, SOUND_PROOFS_REPETITIONS,
 };
 use proof::AggregatableRangeProof;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     dkg, presign,
+    round::{Round, Transition},
+    session::{RoundContext, RoundId},
     sign::{
         centralized_party::{
             signature_verification_round, PublicNonceEncryptedPartialSignatureAndProof,
         },
-        DIMENSION,
+        message_digest, Hash, RecoverableSignature, DIMENSION,
     },
     Error, ProtocolPublicParameters,
 };
 
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<GroupElementValue, ScalarValue, CiphertextSpaceValue> {
+    public_key: GroupElementValue,
+    secret_key_share: ScalarValue,
+    public_key_share: GroupElementValue,
+    nonce_share_commitment_randomness: ScalarValue,
+    nonce_share: ScalarValue,
+    decentralized_party_nonce_public_share: GroupElementValue,
+    encrypted_mask: CiphertextSpaceValue,
+    encrypted_masked_key_share: CiphertextSpaceValue,
+}
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
@@ -46,7 +62,7 @@ pub struct Party<
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedDComEvalWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub(in crate::sign) protocol_context: ProtocolContext,
     pub(in crate::sign) scalar_group_public_parameters:
@@ -78,7 +94,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedDComEvalWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -133,6 +149,70 @@ where
         >,
     Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
 {
+    /// Evaluate the encrypted partial signature over `message`, which is hashed using `hash` and
+    /// converted into a scalar via [`message_digest`].
+    pub fn evaluate_encrypted_partial_signature(
+        self,
+        message: &[u8],
+        hash: Hash,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+        signature_verification_round::Party<SCALAR_LIMBS, GroupElement>,
+    )> {
+        let message = message_digest::<SCALAR_LIMBS, GroupElement>(
+            message,
+            hash,
+            &self.scalar_group_public_parameters,
+        )?;
+
+        self.evaluate_encrypted_partial_signature_prehash(message, rng)
+    }
+
     /// Evaluate the encrypted partial signature.
     /// Note: `message` is a `Scalar` which must be a hash on the message bytes translated into a
     /// 32-byte number.
@@ -227,7 +307,9 @@ where
             >,
             ProtocolContext,
         >::prove(
-            &self.protocol_context,
+            &self
+                .protocol_context
+                .round(RoundId::SignSignatureHomomorphicEvaluation),
             &language_public_parameters,
             vec![[self.nonce_share, self.nonce_share_commitment_randomness].into()],
             rng,
@@ -257,7 +339,9 @@ where
             >,
             ProtocolContext,
         >::prove(
-            &self.protocol_context,
+            &self
+                .protocol_context
+                .round(RoundId::SignSignatureHomomorphicEvaluation),
             &language_public_parameters,
             vec![[
                 self.nonce_share,
@@ -438,7 +522,9 @@ where
             >,
             ProtocolContext,
         >::prove(
-            &self.protocol_context,
+            &self
+                .protocol_context
+                .round(RoundId::SignSignatureHomomorphicEvaluation),
             &language_public_parameters,
             vec![witness],
             rng,
@@ -505,6 +591,16 @@ where
             group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
         >,
     ) -> crate::Result<Self> {
+        // The presign masks the decentralized party's encrypted secret key share, and is only
+        // valid for the key shares it was generated for. This only catches stale presigns if
+        // `dkg_output` is the current one, so the decentralized party checks its own presign
+        // against its key share as well.
+        if presign.encrypted_secret_key_share
+            != dkg_output.encrypted_decentralized_party_secret_key_share
+        {
+            return Err(Error::StalePresign);
+        }
+
         let scalar_group_public_parameters =
             protocol_public_parameters.scalar_group_public_parameters;
         let group_public_parameters = protocol_public_parameters.group_public_parameters;
@@ -563,4 +659,245 @@ where
             encrypted_masked_key_share,
         })
     }
+
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(
+        self,
+    ) -> State<
+        GroupElement::Value,
+        group::Value<GroupElement::Scalar>,
+        group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+    > {
+        State {
+            public_key: self.public_key.value(),
+            secret_key_share: self.secret_key_share.value(),
+            public_key_share: self.public_key_share.value(),
+            nonce_share_commitment_randomness: self.nonce_share_commitment_randomness.value(),
+            nonce_share: self.nonce_share.value(),
+            decentralized_party_nonce_public_share: self
+                .decentralized_party_nonce_public_share
+                .value(),
+            encrypted_mask: self.encrypted_mask.value(),
+            encrypted_masked_key_share: self.encrypted_masked_key_share.value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The public parameters and protocol context are not a part of the state, and must be the
+    /// same ones used to instantiate the party prior to it being saved.
+    pub fn from_state<
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        state: State<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        protocol_context: ProtocolContext,
+    ) -> crate::Result<Self> {
+        let scalar_group_public_parameters =
+            protocol_public_parameters.scalar_group_public_parameters;
+        let group_public_parameters = protocol_public_parameters.group_public_parameters;
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let public_key = GroupElement::new(state.public_key, &group_public_parameters)?;
+
+        let secret_key_share =
+            GroupElement::Scalar::new(state.secret_key_share, &scalar_group_public_parameters)?;
+
+        let public_key_share = GroupElement::new(state.public_key_share, &group_public_parameters)?;
+
+        if secret_key_share * public_key_share.generator() != public_key_share {
+            return Err(Error::InvalidParameters);
+        }
+
+        let nonce_share_commitment_randomness = GroupElement::Scalar::new(
+            state.nonce_share_commitment_randomness,
+            &scalar_group_public_parameters,
+        )?;
+
+        let nonce_share =
+            GroupElement::Scalar::new(state.nonce_share, &scalar_group_public_parameters)?;
+
+        let decentralized_party_nonce_public_share = GroupElement::new(
+            state.decentralized_party_nonce_public_share,
+            &group_public_parameters,
+        )?;
+
+        let encrypted_mask = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_mask,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_masked_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_masked_key_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_dcom_eval_witness_public_parameters: protocol_public_parameters
+                .unbounded_dcom_eval_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_dcom_eval_public_parameters,
+            public_key,
+            secret_key_share,
+            public_key_share,
+            nonce_share_commitment_randomness,
+            nonce_share,
+            decentralized_party_nonce_public_share,
+            encrypted_mask,
+            encrypted_masked_key_share,
+        })
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const RANGE_CLAIMS_PER_MASK: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedDComEvalWitness,
+        ProtocolContext,
+    >
+where
+    committed_linear_evaluation::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        DIMENSION,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = committed_linear_evaluation::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = committed_linear_evaluation::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = committed_linear_evaluation::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            NUM_RANGE_CLAIMS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedDComEvalWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+{
+    /// The message to sign, which must be a hash on the message bytes translated into a
+    /// 32-byte number, see [`message_digest`].
+    type Incoming = GroupElement::Scalar;
+    type Message = PublicNonceEncryptedPartialSignatureAndProof<
+        GroupElement::Value,
+        proof::range::CommitmentSchemeCommitmentSpaceValue<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            NUM_RANGE_CLAIMS,
+            RangeProof,
+        >,
+        homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        maurer::Proof<
+            SOUND_PROOFS_REPETITIONS,
+            committment_of_discrete_log::Language<
+                SCALAR_LIMBS,
+                GroupElement::Scalar,
+                GroupElement,
+                Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+            >,
+            ProtocolContext,
+        >,
+        maurer::Proof<
+            SOUND_PROOFS_REPETITIONS,
+            discrete_log_ratio_of_committed_values::Language<
+                SCALAR_LIMBS,
+                GroupElement::Scalar,
+                GroupElement,
+            >,
+            ProtocolContext,
+        >,
+        committed_linear_evaluation::Proof<
+            NUM_RANGE_CLAIMS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RANGE_CLAIMS_PER_MASK,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            DIMENSION,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedDComEvalWitness,
+            ProtocolContext,
+        >,
+    >;
+    type NextRound = signature_verification_round::Party<SCALAR_LIMBS, GroupElement>;
+    type Output = RecoverableSignature<GroupElement::Scalar>;
+    type Error = Error;
+
+    fn process(
+        self,
+        message: Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (public_nonce_encrypted_partial_signature_and_proof, party) =
+            self.evaluate_encrypted_partial_signature_prehash(message, rng)?;
+
+        Ok(Transition::Advance(
+            public_nonce_encrypted_partial_signature_and_proof,
+            party,
+        ))
+    }
 }
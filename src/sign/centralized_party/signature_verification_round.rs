@@ -1,9 +1,23 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use group::{AffineXCoordinate, PrimeGroupElement};
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::{AffineXCoordinate, GroupElement as _, PrimeGroupElement};
+use serde::{Deserialize, Serialize};
 
-use crate::{sign::verify_signature, Result};
+use crate::{
+    round::{Round, Transition},
+    sign::{verify_signature, RecoverableSignature},
+    Error, Result,
+};
+
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<ScalarValue, GroupElementValue> {
+    message: ScalarValue,
+    public_key: GroupElementValue,
+}
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>> {
@@ -42,4 +56,48 @@ impl<
             public_key,
         })
     }
+
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(self) -> State<group::Value<GroupElement::Scalar>, GroupElement::Value> {
+        State {
+            message: self.message.value(),
+            public_key: self.public_key.value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    pub fn from_state(
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: &group::PublicParameters<GroupElement>,
+        state: State<group::Value<GroupElement::Scalar>, GroupElement::Value>,
+    ) -> Result<Self> {
+        let message = GroupElement::Scalar::new(state.message, scalar_group_public_parameters)?;
+
+        Self::new(message, state.public_key, group_public_parameters)
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS>,
+    > Round for Party<SCALAR_LIMBS, GroupElement>
+{
+    /// The signature decrypted by the decentralized party.
+    type Incoming = RecoverableSignature<GroupElement::Scalar>;
+    /// The centralized party sends no message once the signature is verified.
+    type Message = ();
+    type NextRound = core::convert::Infallible;
+    type Output = RecoverableSignature<GroupElement::Scalar>;
+    type Error = Error;
+
+    fn process(
+        self,
+        signature: Self::Incoming,
+        _rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        self.verify_signature(signature.r, signature.s)?;
+
+        Ok(Transition::Finalize((), signature))
+    }
 }
@@ -3,6 +3,8 @@
 
 #![allow(clippy::type_complexity)]
 
+pub mod batched_signature_partial_decryption_round;
+pub mod batched_signature_threshold_decryption_round;
 pub mod identifiable_abort;
 pub mod signature_partial_decryption_round;
 pub mod signature_threshold_decryption_round;
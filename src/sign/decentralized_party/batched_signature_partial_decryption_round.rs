@@ -0,0 +1,505 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use commitment::Pedersen;
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Random, Uint};
+use enhanced_maurer::{committed_linear_evaluation, EnhanceableLanguage};
+use group::{
+    AffineXCoordinate, PartyID, PrimeGroupElement, Samplable, StatisticalSecuritySizedNumber,
+};
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+};
+use maurer::{
+    committment_of_discrete_log, discrete_log_ratio_of_committed_values, SOUND_PROOFS_REPETITIONS,
+};
+use proof::AggregatableRangeProof;
+
+use crate::{
+    dkg, presign,
+    session::RoundContext,
+    sign::{
+        centralized_party::BatchedPublicNonceEncryptedPartialSignatureAndProof,
+        decentralized_party::{
+            batched_signature_threshold_decryption_round,
+            signature_partial_decryption_round::{self, ProofInstance},
+        },
+        message_digest, Hash, RecoverableGroupElement, DIMENSION,
+    },
+    Error, ProtocolPublicParameters,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const RANGE_CLAIMS_PER_MASK: usize,
+    const NUM_RANGE_CLAIMS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(in crate::sign) messages_and_parties: Vec<(
+        GroupElement::Scalar,
+        signature_partial_decryption_round::Party<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            RANGE_CLAIMS_PER_MASK,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            DecryptionKeyShare,
+            RangeProof,
+            UnboundedDComEvalWitness,
+            ProtocolContext,
+        >,
+    )>,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const RANGE_CLAIMS_PER_MASK: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: RecoverableGroupElement<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+        RangeProof,
+        UnboundedDComEvalWitness,
+        ProtocolContext,
+    >
+where
+    committed_linear_evaluation::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        DIMENSION,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = committed_linear_evaluation::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = committed_linear_evaluation::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = committed_linear_evaluation::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            NUM_RANGE_CLAIMS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedDComEvalWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+    Error: From<DecryptionKeyShare::Error>,
+    DecryptionKeyShare: Clone,
+{
+    /// Partially decrypt the encrypted signature parts sent by the centralized party over all
+    /// messages of the batch.
+    ///
+    /// The encrypted signature parts of every message are verified against its presign before any
+    /// of them is partially decrypted, so that the whole batch is rejected if any of its proofs is
+    /// invalid. The Maurer proofs of the public nonces and of the nonce shares by the key share
+    /// are verified in a batch, by checking a random linear combination of their verification
+    /// equations. The enhanced Maurer proofs of the encrypted partial signatures are still
+    /// verified one by one, as their range proofs and the bounds on their responses do not
+    /// combine linearly.
+    ///
+    /// Returns the decryption shares of every message in order, which are sent together in a
+    /// single message to the other parties.
+    pub fn partially_decrypt_encrypted_signature_parts(
+        self,
+        batched_public_nonce_encrypted_partial_signature_and_proof: BatchedPublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        Vec<(
+            DecryptionKeyShare::DecryptionShare,
+            DecryptionKeyShare::DecryptionShare,
+        )>,
+        batched_signature_threshold_decryption_round::Party<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            DecryptionKeyShare,
+        >,
+    )> {
+        let public_nonce_encrypted_partial_signatures_and_proofs =
+            batched_public_nonce_encrypted_partial_signature_and_proof
+                .public_nonce_encrypted_partial_signatures_and_proofs;
+
+        if public_nonce_encrypted_partial_signatures_and_proofs.len()
+            != self.messages_and_parties.len()
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut public_nonce_proof_instances = vec![];
+        let mut nonce_share_by_key_share_proof_instances = vec![];
+        let mut encrypted_partial_signature_proof_instances = vec![];
+
+        for ((message, party), public_nonce_encrypted_partial_signature_and_proof) in self
+            .messages_and_parties
+            .iter()
+            .zip(public_nonce_encrypted_partial_signatures_and_proofs.iter())
+        {
+            let (
+                public_nonce_proof_instance,
+                nonce_share_by_key_share_proof_instance,
+                encrypted_partial_signature_proof_instance,
+            ) = party.presign_encrypted_signature_parts_proof_instances(
+                *message,
+                public_nonce_encrypted_partial_signature_and_proof,
+            )?;
+
+            public_nonce_proof_instances.push(public_nonce_proof_instance);
+            nonce_share_by_key_share_proof_instances.push(nonce_share_by_key_share_proof_instance);
+            encrypted_partial_signature_proof_instances
+                .push(encrypted_partial_signature_proof_instance);
+        }
+
+        verify_maurer_proofs(public_nonce_proof_instances, rng)?;
+        verify_maurer_proofs(nonce_share_by_key_share_proof_instances, rng)?;
+
+        encrypted_partial_signature_proof_instances
+            .into_iter()
+            .try_for_each(
+                |(protocol_context, proof, language_public_parameters, statement)| {
+                    proof.verify(
+                        &protocol_context,
+                        &language_public_parameters,
+                        vec![statement],
+                        rng,
+                    )
+                },
+            )?;
+
+        let (decryption_shares, signature_threshold_decryption_round_parties) = self
+            .messages_and_parties
+            .into_iter()
+            .zip(public_nonce_encrypted_partial_signatures_and_proofs)
+            .map(
+                |((message, party), public_nonce_encrypted_partial_signature_and_proof)| {
+                    party.partially_decrypt_verified_encrypted_signature_parts(
+                        message,
+                        public_nonce_encrypted_partial_signature_and_proof,
+                    )
+                },
+            )
+            .collect::<crate::Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        Ok((
+            decryption_shares,
+            batched_signature_threshold_decryption_round::Party {
+                signature_threshold_decryption_round_parties,
+            },
+        ))
+    }
+
+    /// Instantiate a batched signing party for `messages_and_presigns`, in which every message is
+    /// hashed using `hash` and converted into a scalar via [`message_digest`].
+    ///
+    /// See [`Self::new_prehash`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+    >(
+        threshold: PartyID,
+        decryption_key_share: DecryptionKeyShare,
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        messages_and_presigns: Vec<(
+            &[u8],
+            presign::decentralized_party::Presign<
+                GroupElement::Value,
+                group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            >,
+        )>,
+        hash: Hash,
+    ) -> crate::Result<Self> {
+        let messages_and_presigns = messages_and_presigns
+            .into_iter()
+            .map(|(message, presign)| {
+                message_digest::<SCALAR_LIMBS, GroupElement>(
+                    message,
+                    hash,
+                    &protocol_public_parameters.scalar_group_public_parameters,
+                )
+                .map(|message| (message, presign))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Self::new_prehash(
+            threshold,
+            decryption_key_share,
+            decryption_key_share_public_parameters,
+            protocol_context,
+            protocol_public_parameters,
+            dkg_output,
+            messages_and_presigns,
+        )
+    }
+
+    /// Instantiate a batched signing party for `messages_and_presigns`, pairing every message to
+    /// be signed with a distinct presign.
+    ///
+    /// Note: every message is a `Scalar` which must be a hash on the message bytes translated into
+    /// a 32-byte number.
+    pub fn new_prehash<
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+    >(
+        threshold: PartyID,
+        decryption_key_share: DecryptionKeyShare,
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        messages_and_presigns: Vec<(
+            GroupElement::Scalar,
+            presign::decentralized_party::Presign<
+                GroupElement::Value,
+                group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            >,
+        )>,
+    ) -> crate::Result<Self> {
+        if messages_and_presigns.is_empty() {
+            return Err(Error::InvalidParameters);
+        }
+
+        // Signing two messages with the same presign would reveal the secret key.
+        if messages_and_presigns
+            .iter()
+            .enumerate()
+            .any(|(i, (_, presign))| {
+                messages_and_presigns[..i].iter().any(|(_, other_presign)| {
+                    other_presign.nonce_public_share == presign.nonce_public_share
+                })
+            })
+        {
+            return Err(Error::ReusedPresign);
+        }
+
+        let messages_and_parties = messages_and_presigns
+            .into_iter()
+            .map(|(message, presign)| {
+                signature_partial_decryption_round::Party::new(
+                    threshold,
+                    decryption_key_share.clone(),
+                    decryption_key_share_public_parameters.clone(),
+                    protocol_context.clone(),
+                    protocol_public_parameters.clone(),
+                    dkg_output.clone(),
+                    presign,
+                )
+                .map(|party| (message, party))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            messages_and_parties,
+        })
+    }
+}
+
+/// Verify a batch of Maurer proofs over `Language` at once, by checking a random linear
+/// combination of their verification equations.
+///
+/// A Maurer proof with statement masks $A_r$ and responses $z_r$ is valid for the statement $X$
+/// if $ \phi(z_r) = A_r + e_r X $ for every repetition $r$, where $e_r$ are its Fiat-Shamir
+/// challenges. Each proof's equations are weighted by an independent random $ \rho < 2^{s} $,
+/// sampled after receiving the proofs, so that if any of the proofs is invalid, the weighted sums
+/// differ except with probability $ 2^{-s} $.
+fn verify_maurer_proofs<
+    Language: maurer::Language<SOUND_PROOFS_REPETITIONS>,
+    ProtocolContext: RoundContext,
+>(
+    proof_instances: Vec<
+        ProofInstance<
+            Language,
+            maurer::Proof<SOUND_PROOFS_REPETITIONS, Language, ProtocolContext>,
+            ProtocolContext,
+        >,
+    >,
+    rng: &mut impl CryptoRngCore,
+) -> crate::Result<()> {
+    let mut weighted_equations = proof_instances.into_iter().map(
+        |(protocol_context, proof, language_public_parameters, statement)| {
+            let challenges = proof.challenges(
+                &protocol_context,
+                &language_public_parameters,
+                vec![statement.clone()],
+            )?;
+
+            let randomizer = StatisticalSecuritySizedNumber::random(rng);
+            let groups_public_parameters = language_public_parameters.as_ref();
+
+            proof
+                .statement_masks
+                .iter()
+                .zip(proof.responses.iter())
+                .zip(challenges.iter())
+                .map(|((statement_mask, response), challenges)| {
+                    let statement_mask = Language::StatementSpaceGroupElement::new(
+                        statement_mask.clone(),
+                        &groups_public_parameters.statement_space_public_parameters,
+                    )?;
+
+                    let response = Language::WitnessSpaceGroupElement::new(
+                        response.clone(),
+                        &groups_public_parameters.witness_space_public_parameters,
+                    )?;
+
+                    // Each proof is over a single statement, and so has a single challenge per
+                    // repetition.
+                    let challenge = challenges.first().ok_or(Error::InternalError)?;
+
+                    // $ \rho \phi(z_r) $ and $ \rho (A_r + e_r X) $.
+                    Ok((
+                        Language::homomorphose(&response, &language_public_parameters)?
+                            .scalar_mul(&randomizer),
+                        (statement_mask + statement.scalar_mul(challenge)).scalar_mul(&randomizer),
+                    ))
+                })
+                .collect::<crate::Result<Vec<_>>>()
+        },
+    );
+
+    let first_weighted_equations = weighted_equations
+        .next()
+        .ok_or(Error::InvalidParameters)??;
+
+    let combined_equations = weighted_equations.try_fold(
+        first_weighted_equations,
+        |combined_equations, weighted_equations| {
+            Ok::<_, Error>(
+                combined_equations
+                    .into_iter()
+                    .zip(weighted_equations?)
+                    .map(|((lhs, rhs), (other_lhs, other_rhs))| (lhs + other_lhs, rhs + other_rhs))
+                    .collect(),
+            )
+        },
+    )?;
+
+    if combined_equations.into_iter().any(|(lhs, rhs)| lhs != rhs) {
+        return Err(maurer::Error::ProofVerification.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use std::collections::HashMap;
+
+use group::{PartyID, PrimeGroupElement};
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+};
+
+use crate::{
+    sign::{
+        decentralized_party::signature_threshold_decryption_round, RecoverableGroupElement,
+        RecoverableSignature,
+    },
+    Error,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+> {
+    pub(super) signature_threshold_decryption_round_parties: Vec<
+        signature_threshold_decryption_round::Party<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            DecryptionKeyShare,
+        >,
+    >,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: RecoverableGroupElement<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+    >
+    Party<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >
+where
+    Error: From<DecryptionKeyShare::Error>,
+{
+    /// The designated threshold decryption party logic over a batch, which decrypts the signature
+    /// of every message from the decryption shares sent by each party for the entire batch.
+    ///
+    /// Returns the signatures in the order of the messages of the batch, or an error if any of
+    /// them fails to verify.
+    ///
+    /// See [`signature_threshold_decryption_round::Party::decrypt_signature`].
+    pub fn decrypt_signatures(
+        self,
+        lagrange_coefficients: HashMap<PartyID, DecryptionKeyShare::LagrangeCoefficient>,
+        decryption_shares: HashMap<
+            PartyID,
+            Vec<(
+                DecryptionKeyShare::DecryptionShare,
+                DecryptionKeyShare::DecryptionShare,
+            )>,
+        >,
+    ) -> crate::Result<Vec<RecoverableSignature<GroupElement::Scalar>>> {
+        let batch_size = self.signature_threshold_decryption_round_parties.len();
+
+        if decryption_shares
+            .values()
+            .any(|decryption_shares| decryption_shares.len() != batch_size)
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut decryption_shares: HashMap<_, _> = decryption_shares
+            .into_iter()
+            .map(|(party_id, decryption_shares)| (party_id, decryption_shares.into_iter()))
+            .collect();
+
+        self.signature_threshold_decryption_round_parties
+            .into_iter()
+            .map(|party| {
+                let (partial_signature_decryption_shares, masked_nonce_decryption_shares): (
+                    HashMap<_, _>,
+                    HashMap<_, _>,
+                ) = decryption_shares
+                    .iter_mut()
+                    .map(|(party_id, decryption_shares)| {
+                        let (partial_signature_decryption_share, masked_nonce_decryption_share) =
+                            decryption_shares.next().ok_or(Error::InternalError)?;
+
+                        Ok((
+                            (*party_id, partial_signature_decryption_share),
+                            (*party_id, masked_nonce_decryption_share),
+                        ))
+                    })
+                    .collect::<crate::Result<Vec<_>>>()?
+                    .into_iter()
+                    .unzip();
+
+                party.decrypt_signature(
+                    lagrange_coefficients.clone(),
+                    partial_signature_decryption_shares,
+                    masked_nonce_decryption_shares,
+                )
+            })
+            .collect()
+    }
+
+    /// The lightweight threshold decryption logic over a batch, which verifies the signatures
+    /// decrypted by the designated decrypting party, given in the order of the messages of the
+    /// batch. Blames it in case any of them is invalid.
+    pub fn verify_decrypted_signatures(
+        self,
+        signatures_s: Vec<GroupElement::Scalar>,
+        designated_decrypting_party_id: PartyID,
+    ) -> crate::Result<Vec<RecoverableSignature<GroupElement::Scalar>>> {
+        if signatures_s.len() != self.signature_threshold_decryption_round_parties.len() {
+            return Err(Error::MaliciousDesignatedDecryptingParty(
+                designated_decrypting_party_id,
+            ));
+        }
+
+        self.signature_threshold_decryption_round_parties
+            .into_iter()
+            .zip(signatures_s)
+            .map(|(party, signature_s)| {
+                party.verify_decrypted_signature(signature_s, designated_decrypting_party_id)
+            })
+            .collect()
+    }
+}
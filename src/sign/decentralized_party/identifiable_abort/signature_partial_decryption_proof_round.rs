@@ -13,10 +13,11 @@ use maurer::{
     committment_of_discrete_log, discrete_log_ratio_of_committed_values, SOUND_PROOFS_REPETITIONS,
 };
 use proof::AggregatableRangeProof;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     presign,
+    session::RoundContext,
     sign::{
         centralized_party::PublicNonceEncryptedPartialSignatureAndProof,
         decentralized_party::identifiable_abort::signature_partial_decryption_verification_round,
@@ -25,6 +26,16 @@ use crate::{
     Error,
 };
 
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<CiphertextSpaceValue> {
+    threshold: PartyID,
+    designated_decrypting_party_id: PartyID,
+    encrypted_partial_signature: CiphertextSpaceValue,
+    encrypted_masked_nonce_share: CiphertextSpaceValue,
+}
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
@@ -48,6 +59,46 @@ impl<
 where
     Error: From<DecryptionKeyShare::Error>,
 {
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(self) -> State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>> {
+        State {
+            threshold: self.threshold,
+            designated_decrypting_party_id: self.designated_decrypting_party_id,
+            encrypted_partial_signature: self.encrypted_partial_signature.value(),
+            encrypted_masked_nonce_share: self.encrypted_masked_nonce_share.value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The decryption key share is not a part of the state, and must be supplied upon restoring.
+    pub fn from_state(
+        decryption_key_share: DecryptionKeyShare,
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+        state: State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+    ) -> crate::Result<Self> {
+        let encrypted_partial_signature = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_partial_signature,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_masked_nonce_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_masked_nonce_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            threshold: state.threshold,
+            designated_decrypting_party_id: state.designated_decrypting_party_id,
+            decryption_key_share,
+            decryption_key_share_public_parameters,
+            encrypted_partial_signature,
+            encrypted_masked_nonce_share,
+        })
+    }
+
     pub fn prove_correct_signature_partial_decryption(
         self,
         rng: &mut impl CryptoRngCore,
@@ -90,7 +141,7 @@ where
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedDComEvalWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >(
         threshold: PartyID,
         designated_decrypting_party_id: PartyID,
@@ -4,13 +4,25 @@
 use std::collections::{HashMap, HashSet};
 
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::PartyID;
+use group::{GroupElement, PartyID};
 use homomorphic_encryption::{
     AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+    GroupsPublicParametersAccessors,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::Error;
 
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<CiphertextSpaceValue> {
+    threshold: PartyID,
+    designated_decrypting_party_id: PartyID,
+    encrypted_partial_signature: CiphertextSpaceValue,
+    encrypted_masked_nonce_share: CiphertextSpaceValue,
+}
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
@@ -32,6 +44,42 @@ impl<
 where
     Error: From<DecryptionKeyShare::Error>,
 {
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(self) -> State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>> {
+        State {
+            threshold: self.threshold,
+            designated_decrypting_party_id: self.designated_decrypting_party_id,
+            encrypted_partial_signature: self.encrypted_partial_signature.value(),
+            encrypted_masked_nonce_share: self.encrypted_masked_nonce_share.value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    pub fn from_state(
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+        state: State<group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+    ) -> crate::Result<Self> {
+        let encrypted_partial_signature = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_partial_signature,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_masked_nonce_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_masked_nonce_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            threshold: state.threshold,
+            designated_decrypting_party_id: state.designated_decrypting_party_id,
+            decryption_key_share_public_parameters,
+            encrypted_partial_signature,
+            encrypted_masked_nonce_share,
+        })
+    }
+
     pub fn identify_malicious_decrypters(
         self,
         lagrange_coefficients: HashMap<PartyID, DecryptionKeyShare::LagrangeCoefficient>,
@@ -1,11 +1,13 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use std::collections::{HashMap, HashSet};
+
 use commitment::{pedersen, GroupsPublicParametersAccessors as _, Pedersen};
 use crypto_bigint::{rand_core::CryptoRngCore, CheckedMul, Encoding, Uint};
 use enhanced_maurer::{
     committed_linear_evaluation, language::composed_witness_upper_bound, EnhanceableLanguage,
-    EnhancedPublicParameters,
+    EnhancedLanguage, EnhancedPublicParameters,
 };
 use group::{AffineXCoordinate, GroupElement, PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::{
@@ -16,17 +18,46 @@ use maurer::{
     committment_of_discrete_log, discrete_log_ratio_of_committed_values, SOUND_PROOFS_REPETITIONS,
 };
 use proof::{range::PublicParametersAccessors, AggregatableRangeProof};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     dkg, presign,
+    round::{Round, Transition},
+    session::{RoundContext, RoundId},
     sign::{
         centralized_party::PublicNonceEncryptedPartialSignatureAndProof,
-        decentralized_party::signature_threshold_decryption_round, DIMENSION,
+        decentralized_party::signature_threshold_decryption_round, message_digest, Hash,
+        RecoverableGroupElement, RecoverableSignature, DIMENSION,
     },
+    weighted_threshold::WeightedThresholdAccessStructure,
     Error, ProtocolPublicParameters,
 };
 
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+///
+/// The decryption key share is not a part of the state, and must be supplied upon restoring.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<GroupElementValue, CiphertextSpaceValue> {
+    threshold: PartyID,
+    public_key: GroupElementValue,
+    nonce_public_share: GroupElementValue,
+    encrypted_mask: CiphertextSpaceValue,
+    encrypted_masked_key_share: CiphertextSpaceValue,
+    encrypted_masked_nonce_share: CiphertextSpaceValue,
+    centralized_party_public_key_share: GroupElementValue,
+    centralized_party_nonce_share_commitment: GroupElementValue,
+}
+
+/// A proof over `Language`, alongside the protocol context, language public parameters and
+/// statement it is verified against.
+pub(in crate::sign) type ProofInstance<Language, Proof, ProtocolContext> = (
+    ProtocolContext,
+    Proof,
+    <Language as maurer::Language<SOUND_PROOFS_REPETITIONS>>::PublicParameters,
+    <Language as maurer::Language<SOUND_PROOFS_REPETITIONS>>::StatementSpaceGroupElement,
+);
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
@@ -40,7 +71,7 @@ pub struct Party<
     DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
     UnboundedDComEvalWitness: group::GroupElement + Samplable,
-    ProtocolContext: Clone + Serialize,
+    ProtocolContext: RoundContext,
 > {
     pub(in crate::sign) threshold: PartyID,
     pub(in crate::sign) decryption_key_share: DecryptionKeyShare,
@@ -71,12 +102,12 @@ impl<
         const RANGE_CLAIMS_PER_MASK: usize,
         const NUM_RANGE_CLAIMS: usize,
         const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
-        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS> + group::HashToGroup,
+        GroupElement: RecoverableGroupElement<SCALAR_LIMBS> + group::HashToGroup,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         UnboundedDComEvalWitness: group::GroupElement + Samplable,
-        ProtocolContext: Clone + Serialize,
+        ProtocolContext: RoundContext,
     >
     Party<
         SCALAR_LIMBS,
@@ -133,12 +164,13 @@ where
     Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
     Error: From<DecryptionKeyShare::Error>,
 {
-    /// Partially decrypt the encrypted signature parts sent by the centralized party.
-    /// Note: `message` is a `Scalar` which must be a hash on the message bytes translated into a
-    /// 32-byte number.
-    pub fn partially_decrypt_encrypted_signature_parts_prehash(
+    /// Partially decrypt the encrypted signature parts sent by the centralized party over
+    /// `message`, which is hashed using `hash` and converted into a scalar via
+    /// [`message_digest`].
+    pub fn partially_decrypt_encrypted_signature_parts(
         self,
-        message: GroupElement::Scalar,
+        message: &[u8],
+        hash: Hash,
         public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
             GroupElement::Value,
             proof::range::CommitmentSchemeCommitmentSpaceValue<
@@ -195,75 +227,24 @@ where
             DecryptionKeyShare,
         >,
     )> {
-        Self::verify_encrypted_signature_parts_prehash_inner(
+        let message = message_digest::<SCALAR_LIMBS, GroupElement>(
             message,
-            public_nonce_encrypted_partial_signature_and_proof.clone(),
-            &self.protocol_context,
+            hash,
             &self.scalar_group_public_parameters,
-            &self.group_public_parameters,
-            &self.encryption_scheme_public_parameters,
-            &self.unbounded_dcom_eval_witness_public_parameters,
-            &self.range_proof_public_parameters,
-            self.nonce_public_share,
-            self.encrypted_mask,
-            self.encrypted_masked_key_share,
-            self.centralized_party_public_key_share,
-            self.centralized_party_nonce_share_commitment,
-            rng,
-        )?;
-
-        let encrypted_partial_signature = EncryptionKey::CiphertextSpaceGroupElement::new(
-            public_nonce_encrypted_partial_signature_and_proof.encrypted_partial_signature,
-            self.encryption_scheme_public_parameters
-                .ciphertext_space_public_parameters(),
         )?;
 
-        let public_nonce = GroupElement::new(
-            public_nonce_encrypted_partial_signature_and_proof.public_nonce,
-            &self.group_public_parameters,
-        )?; // $R$
-
-        let nonce_x_coordinate = public_nonce.x(); // $r$
-
-        let partial_signature_decryption_share = Option::from(
-            self.decryption_key_share
-                .generate_decryption_share_semi_honest(
-                    &encrypted_partial_signature,
-                    &self.decryption_key_share_public_parameters,
-                ),
-        )
-        .ok_or(Error::InternalError)?;
-
-        let masked_nonce_decryption_share = Option::from(
-            self.decryption_key_share
-                .generate_decryption_share_semi_honest(
-                    &self.encrypted_masked_nonce_share,
-                    &self.decryption_key_share_public_parameters,
-                ),
+        self.partially_decrypt_encrypted_signature_parts_prehash(
+            message,
+            public_nonce_encrypted_partial_signature_and_proof,
+            rng,
         )
-        .ok_or(Error::InternalError)?;
-
-        let signature_threshold_decryption_round_party =
-            signature_threshold_decryption_round::Party {
-                threshold: self.threshold,
-                decryption_key_share_public_parameters: self.decryption_key_share_public_parameters,
-                scalar_group_public_parameters: self.scalar_group_public_parameters,
-                message,
-                public_key: self.public_key,
-                nonce_x_coordinate,
-            };
-
-        Ok((
-            (
-                partial_signature_decryption_share,
-                masked_nonce_decryption_share,
-            ),
-            signature_threshold_decryption_round_party,
-        ))
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn verify_encrypted_signature_parts_prehash_inner(
+    /// Partially decrypt the encrypted signature parts sent by the centralized party.
+    /// Note: `message` is a `Scalar` which must be a hash on the message bytes translated into a
+    /// 32-byte number.
+    pub fn partially_decrypt_encrypted_signature_parts_prehash(
+        self,
         message: GroupElement::Scalar,
         public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
             GroupElement::Value,
@@ -307,212 +288,38 @@ where
                 ProtocolContext,
             >,
         >,
-        protocol_context: &ProtocolContext,
-        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
-        group_public_parameters: &GroupElement::PublicParameters,
-        encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
-        unbounded_dcom_eval_witness_public_parameters: &UnboundedDComEvalWitness::PublicParameters,
-        range_proof_public_parameters: &RangeProof::PublicParameters<NUM_RANGE_CLAIMS>,
-        nonce_public_share: GroupElement,
-        encrypted_mask: EncryptionKey::CiphertextSpaceGroupElement,
-        encrypted_masked_key_share: EncryptionKey::CiphertextSpaceGroupElement,
-        centralized_party_public_key_share: GroupElement,
-        centralized_party_nonce_share_commitment: GroupElement,
         rng: &mut impl CryptoRngCore,
-    ) -> crate::Result<()> {
-        let public_nonce = GroupElement::new(
-            public_nonce_encrypted_partial_signature_and_proof.public_nonce,
-            group_public_parameters,
-        )?; // $R$
-
-        let nonce_x_coordinate = public_nonce.x(); // $r$
-
-        let commitment_scheme_public_parameters =
-            pedersen::PublicParameters::derive::<SCALAR_LIMBS, GroupElement>(
-                scalar_group_public_parameters.clone(),
-                group_public_parameters.clone(),
-            )?;
-
-        let language_public_parameters = committment_of_discrete_log::PublicParameters::new::<
+    ) -> crate::Result<(
+        (
+            DecryptionKeyShare::DecryptionShare,
+            DecryptionKeyShare::DecryptionShare,
+        ),
+        signature_threshold_decryption_round::Party<
             SCALAR_LIMBS,
-            GroupElement::Scalar,
-            GroupElement,
-            Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
-        >(
-            scalar_group_public_parameters.clone(),
-            group_public_parameters.clone(),
-            commitment_scheme_public_parameters.clone(),
-            public_nonce_encrypted_partial_signature_and_proof.public_nonce,
-        );
-
-        public_nonce_encrypted_partial_signature_and_proof
-            .public_nonce_proof
-            .verify(
-                protocol_context,
-                &language_public_parameters,
-                vec![[
-                    centralized_party_nonce_share_commitment.clone(),
-                    nonce_public_share.clone(),
-                ]
-                .into()],
-            )?;
-
-        let language_public_parameters =
-            discrete_log_ratio_of_committed_values::PublicParameters::new::<
-                SCALAR_LIMBS,
-                GroupElement::Scalar,
-                GroupElement,
-            >(
-                scalar_group_public_parameters.clone(),
-                group_public_parameters.clone(),
-                commitment_scheme_public_parameters.clone(),
-                centralized_party_public_key_share.clone(),
-            );
-
-        let nonce_share_by_key_share_commitment = GroupElement::new(
-            public_nonce_encrypted_partial_signature_and_proof.nonce_share_by_key_share_commitment,
-            group_public_parameters,
-        )?;
-
-        public_nonce_encrypted_partial_signature_and_proof
-            .nonce_share_by_key_share_proof
-            .verify(
-                protocol_context,
-                &language_public_parameters,
-                vec![[
-                    centralized_party_nonce_share_commitment.clone(),
-                    nonce_share_by_key_share_commitment.clone(),
-                ]
-                .into()],
-            )?;
-
-        let encrypted_mask_upper_bound = composed_witness_upper_bound::<
-            RANGE_CLAIMS_PER_SCALAR,
-            PLAINTEXT_SPACE_SCALAR_LIMBS,
-            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
-            RangeProof,
-        >()?;
-
-        let encrypted_masked_key_share_upper_bound: Option<_> = composed_witness_upper_bound::<
-            RANGE_CLAIMS_PER_SCALAR,
-            PLAINTEXT_SPACE_SCALAR_LIMBS,
-            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
-            RangeProof,
-        >()?
-        .checked_mul(&encrypted_mask_upper_bound)
-        .into();
-
-        let ciphertexts_and_upper_bounds = [
-            (encrypted_mask.clone(), encrypted_mask_upper_bound),
-            (
-                encrypted_masked_key_share.clone(),
-                encrypted_masked_key_share_upper_bound.ok_or(Error::InvalidPublicParameters)?,
-            ),
-        ]
-        .map(|(ct, upper_bound)| (ct.value(), upper_bound));
-
-        let commitment_scheme_public_parameters = commitment_scheme_public_parameters.into();
-
-        let language_public_parameters = committed_linear_evaluation::PublicParameters::<
             PLAINTEXT_SPACE_SCALAR_LIMBS,
-            SCALAR_LIMBS,
-            DIMENSION,
             GroupElement,
             EncryptionKey,
-        >::new::<SCALAR_LIMBS, GroupElement, EncryptionKey>(
-            scalar_group_public_parameters.clone(),
-            group_public_parameters.clone(),
-            encryption_scheme_public_parameters.clone(),
-            commitment_scheme_public_parameters,
-            ciphertexts_and_upper_bounds,
-        );
-
-        let language_public_parameters = EnhancedPublicParameters::<
-            SOUND_PROOFS_REPETITIONS,
-            NUM_RANGE_CLAIMS,
-            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
-            RangeProof,
-            UnboundedDComEvalWitness,
-            committed_linear_evaluation::Language<
-                PLAINTEXT_SPACE_SCALAR_LIMBS,
-                SCALAR_LIMBS,
-                RANGE_CLAIMS_PER_SCALAR,
-                RANGE_CLAIMS_PER_MASK,
-                DIMENSION,
-                GroupElement,
-                EncryptionKey,
-            >,
-        >::new::<
-            RangeProof,
-            UnboundedDComEvalWitness,
-            committed_linear_evaluation::Language<
-                PLAINTEXT_SPACE_SCALAR_LIMBS,
-                SCALAR_LIMBS,
-                RANGE_CLAIMS_PER_SCALAR,
-                RANGE_CLAIMS_PER_MASK,
-                DIMENSION,
-                GroupElement,
-                EncryptionKey,
-            >,
-        >(
-            unbounded_dcom_eval_witness_public_parameters.clone(),
-            range_proof_public_parameters.clone(),
-            language_public_parameters,
-        )?;
-
-        let encrypted_partial_signature = EncryptionKey::CiphertextSpaceGroupElement::new(
-            public_nonce_encrypted_partial_signature_and_proof.encrypted_partial_signature,
-            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
-        )?;
-
-        let range_proof_commitment = proof::range::CommitmentSchemeCommitmentSpaceGroupElement::<
-            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
-            NUM_RANGE_CLAIMS,
-            RangeProof,
-        >::new(
-            public_nonce_encrypted_partial_signature_and_proof
-                .encrypted_partial_signature_range_proof_commitment,
-            range_proof_public_parameters
-                .commitment_scheme_public_parameters()
-                .commitment_space_public_parameters(),
+            DecryptionKeyShare,
+        >,
+    )> {
+        self.verify_presign_encrypted_signature_parts_prehash(
+            message,
+            &public_nonce_encrypted_partial_signature_and_proof,
+            rng,
         )?;
 
-        public_nonce_encrypted_partial_signature_and_proof
-            .encrypted_partial_signature_proof
-            .verify(
-                protocol_context,
-                &language_public_parameters,
-                vec![(
-                    range_proof_commitment,
-                    (
-                        encrypted_partial_signature.clone(),
-                        [
-                            ((nonce_x_coordinate * nonce_share_by_key_share_commitment)
-                                + (message * &centralized_party_nonce_share_commitment)),
-                            (nonce_x_coordinate * &centralized_party_nonce_share_commitment),
-                        ]
-                        .into(),
-                    )
-                        .into(),
-                )
-                    .into()],
-                rng,
-            )?;
-
-        Ok(())
+        self.partially_decrypt_verified_encrypted_signature_parts(
+            message,
+            public_nonce_encrypted_partial_signature_and_proof,
+        )
     }
 
-    /// Verify the validity of the encrypted signature parts sent by the centralized party.
-    /// If this function returns `Ok()`, it means that a valid signature over `message` is
-    /// guaranteed to be able to be generated by the decentralized party, whenever a threshold of
-    /// honest parties decides to engage in the signing protocol.
-    ///
-    /// to Note: `message` is a `Scalar` which must be a
-    /// hash on the message bytes translated into a 32-byte number.
-    #[allow(clippy::too_many_arguments)]
-    pub fn verify_encrypted_signature_parts_prehash(
+    /// Verify the encrypted signature parts sent by the centralized party over `message` against
+    /// the presign of this party, without partially decrypting them.
+    pub(in crate::sign) fn verify_presign_encrypted_signature_parts_prehash(
+        &self,
         message: GroupElement::Scalar,
-        public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
+        public_nonce_encrypted_partial_signature_and_proof: &PublicNonceEncryptedPartialSignatureAndProof<
             GroupElement::Value,
             proof::range::CommitmentSchemeCommitmentSpaceValue<
                 COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
@@ -554,18 +361,840 @@ where
                 ProtocolContext,
             >,
         >,
-        protocol_context: &ProtocolContext,
-        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
-        group_public_parameters: &GroupElement::PublicParameters,
-        encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
-        unbounded_dcom_eval_witness_public_parameters: &UnboundedDComEvalWitness::PublicParameters,
-        range_proof_public_parameters: &RangeProof::PublicParameters<NUM_RANGE_CLAIMS>,
-        dkg_output: dkg::decentralized_party::Output<
-            GroupElement::Value,
-            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
-        >,
-        presign: presign::decentralized_party::Presign<
-            GroupElement::Value,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<()> {
+        Self::verify_encrypted_signature_parts_prehash_inner(
+            message,
+            public_nonce_encrypted_partial_signature_and_proof.clone(),
+            &self.protocol_context,
+            &self.scalar_group_public_parameters,
+            &self.group_public_parameters,
+            &self.encryption_scheme_public_parameters,
+            &self.unbounded_dcom_eval_witness_public_parameters,
+            &self.range_proof_public_parameters,
+            self.nonce_public_share.clone(),
+            self.encrypted_mask.clone(),
+            self.encrypted_masked_key_share.clone(),
+            self.centralized_party_public_key_share.clone(),
+            self.centralized_party_nonce_share_commitment.clone(),
+            rng,
+        )
+    }
+
+    /// The instances of the proofs accompanying the encrypted signature parts sent by the
+    /// centralized party over `message`, against the presign of this party.
+    pub(in crate::sign) fn presign_encrypted_signature_parts_proof_instances(
+        &self,
+        message: GroupElement::Scalar,
+        public_nonce_encrypted_partial_signature_and_proof: &PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+    ) -> crate::Result<(
+        ProofInstance<
+            committment_of_discrete_log::Language<
+                SCALAR_LIMBS,
+                GroupElement::Scalar,
+                GroupElement,
+                Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            ProtocolContext,
+        >,
+        ProofInstance<
+            discrete_log_ratio_of_committed_values::Language<
+                SCALAR_LIMBS,
+                GroupElement::Scalar,
+                GroupElement,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            ProtocolContext,
+        >,
+        ProofInstance<
+            EnhancedLanguage<
+                SOUND_PROOFS_REPETITIONS,
+                NUM_RANGE_CLAIMS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                committed_linear_evaluation::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    DIMENSION,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+            ProtocolContext,
+        >,
+    )> {
+        Self::encrypted_signature_parts_proof_instances(
+            message,
+            public_nonce_encrypted_partial_signature_and_proof.clone(),
+            &self.protocol_context,
+            &self.scalar_group_public_parameters,
+            &self.group_public_parameters,
+            &self.encryption_scheme_public_parameters,
+            &self.unbounded_dcom_eval_witness_public_parameters,
+            &self.range_proof_public_parameters,
+            self.nonce_public_share.clone(),
+            self.encrypted_mask.clone(),
+            self.encrypted_masked_key_share.clone(),
+            self.centralized_party_public_key_share.clone(),
+            self.centralized_party_nonce_share_commitment.clone(),
+        )
+    }
+
+    /// Partially decrypt encrypted signature parts over `message` which were already verified
+    /// using [`Self::verify_presign_encrypted_signature_parts_prehash`].
+    pub(in crate::sign) fn partially_decrypt_verified_encrypted_signature_parts(
+        self,
+        message: GroupElement::Scalar,
+        public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+    ) -> crate::Result<(
+        (
+            DecryptionKeyShare::DecryptionShare,
+            DecryptionKeyShare::DecryptionShare,
+        ),
+        signature_threshold_decryption_round::Party<
+            SCALAR_LIMBS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            DecryptionKeyShare,
+        >,
+    )> {
+        let encrypted_partial_signature = EncryptionKey::CiphertextSpaceGroupElement::new(
+            public_nonce_encrypted_partial_signature_and_proof.encrypted_partial_signature,
+            self.encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )?;
+
+        let public_nonce = GroupElement::new(
+            public_nonce_encrypted_partial_signature_and_proof.public_nonce,
+            &self.group_public_parameters,
+        )?; // $R$
+
+        let partial_signature_decryption_share = Option::from(
+            self.decryption_key_share
+                .generate_decryption_share_semi_honest(
+                    &encrypted_partial_signature,
+                    &self.decryption_key_share_public_parameters,
+                ),
+        )
+        .ok_or(Error::InternalError)?;
+
+        let masked_nonce_decryption_share = Option::from(
+            self.decryption_key_share
+                .generate_decryption_share_semi_honest(
+                    &self.encrypted_masked_nonce_share,
+                    &self.decryption_key_share_public_parameters,
+                ),
+        )
+        .ok_or(Error::InternalError)?;
+
+        let signature_threshold_decryption_round_party =
+            signature_threshold_decryption_round::Party {
+                threshold: self.threshold,
+                decryption_key_share_public_parameters: self.decryption_key_share_public_parameters,
+                scalar_group_public_parameters: self.scalar_group_public_parameters,
+                message,
+                public_key: self.public_key,
+                public_nonce,
+            };
+
+        Ok((
+            (
+                partial_signature_decryption_share,
+                masked_nonce_decryption_share,
+            ),
+            signature_threshold_decryption_round_party,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_encrypted_signature_parts_prehash_inner(
+        message: GroupElement::Scalar,
+        public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+        protocol_context: &ProtocolContext,
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: &GroupElement::PublicParameters,
+        encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+        unbounded_dcom_eval_witness_public_parameters: &UnboundedDComEvalWitness::PublicParameters,
+        range_proof_public_parameters: &RangeProof::PublicParameters<NUM_RANGE_CLAIMS>,
+        nonce_public_share: GroupElement,
+        encrypted_mask: EncryptionKey::CiphertextSpaceGroupElement,
+        encrypted_masked_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+        centralized_party_public_key_share: GroupElement,
+        centralized_party_nonce_share_commitment: GroupElement,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<()> {
+        let (
+            (protocol_context, public_nonce_proof, language_public_parameters, statement),
+            nonce_share_by_key_share_proof_instance,
+            encrypted_partial_signature_proof_instance,
+        ) = Self::encrypted_signature_parts_proof_instances(
+            message,
+            public_nonce_encrypted_partial_signature_and_proof,
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_dcom_eval_witness_public_parameters,
+            range_proof_public_parameters,
+            nonce_public_share,
+            encrypted_mask,
+            encrypted_masked_key_share,
+            centralized_party_public_key_share,
+            centralized_party_nonce_share_commitment,
+        )?;
+
+        public_nonce_proof.verify(
+            &protocol_context,
+            &language_public_parameters,
+            vec![statement],
+        )?;
+
+        let (_, nonce_share_by_key_share_proof, language_public_parameters, statement) =
+            nonce_share_by_key_share_proof_instance;
+
+        nonce_share_by_key_share_proof.verify(
+            &protocol_context,
+            &language_public_parameters,
+            vec![statement],
+        )?;
+
+        let (_, encrypted_partial_signature_proof, language_public_parameters, statement) =
+            encrypted_partial_signature_proof_instance;
+
+        encrypted_partial_signature_proof.verify(
+            &protocol_context,
+            &language_public_parameters,
+            vec![statement],
+            rng,
+        )?;
+
+        Ok(())
+    }
+
+    /// The instances of the proofs accompanying the encrypted signature parts sent by the
+    /// centralized party over `message`, to be verified either one by one, or in a batch.
+    #[allow(clippy::too_many_arguments)]
+    fn encrypted_signature_parts_proof_instances(
+        message: GroupElement::Scalar,
+        public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+        protocol_context: &ProtocolContext,
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: &GroupElement::PublicParameters,
+        encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+        unbounded_dcom_eval_witness_public_parameters: &UnboundedDComEvalWitness::PublicParameters,
+        range_proof_public_parameters: &RangeProof::PublicParameters<NUM_RANGE_CLAIMS>,
+        nonce_public_share: GroupElement,
+        encrypted_mask: EncryptionKey::CiphertextSpaceGroupElement,
+        encrypted_masked_key_share: EncryptionKey::CiphertextSpaceGroupElement,
+        centralized_party_public_key_share: GroupElement,
+        centralized_party_nonce_share_commitment: GroupElement,
+    ) -> crate::Result<(
+        ProofInstance<
+            committment_of_discrete_log::Language<
+                SCALAR_LIMBS,
+                GroupElement::Scalar,
+                GroupElement,
+                Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            ProtocolContext,
+        >,
+        ProofInstance<
+            discrete_log_ratio_of_committed_values::Language<
+                SCALAR_LIMBS,
+                GroupElement::Scalar,
+                GroupElement,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            ProtocolContext,
+        >,
+        ProofInstance<
+            EnhancedLanguage<
+                SOUND_PROOFS_REPETITIONS,
+                NUM_RANGE_CLAIMS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                committed_linear_evaluation::Language<
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    RANGE_CLAIMS_PER_MASK,
+                    DIMENSION,
+                    GroupElement,
+                    EncryptionKey,
+                >,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+            ProtocolContext,
+        >,
+    )> {
+        let protocol_context = protocol_context.round(RoundId::SignSignatureHomomorphicEvaluation);
+
+        let public_nonce = GroupElement::new(
+            public_nonce_encrypted_partial_signature_and_proof.public_nonce,
+            group_public_parameters,
+        )?; // $R$
+
+        let nonce_x_coordinate = public_nonce.x(); // $r$
+
+        let commitment_scheme_public_parameters =
+            pedersen::PublicParameters::derive::<SCALAR_LIMBS, GroupElement>(
+                scalar_group_public_parameters.clone(),
+                group_public_parameters.clone(),
+            )?;
+
+        let language_public_parameters = committment_of_discrete_log::PublicParameters::new::<
+            SCALAR_LIMBS,
+            GroupElement::Scalar,
+            GroupElement,
+            Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+        >(
+            scalar_group_public_parameters.clone(),
+            group_public_parameters.clone(),
+            commitment_scheme_public_parameters.clone(),
+            public_nonce_encrypted_partial_signature_and_proof.public_nonce,
+        );
+
+        let public_nonce_proof_instance = (
+            protocol_context.clone(),
+            public_nonce_encrypted_partial_signature_and_proof.public_nonce_proof,
+            language_public_parameters,
+            [
+                centralized_party_nonce_share_commitment.clone(),
+                nonce_public_share,
+            ]
+            .into(),
+        );
+
+        let language_public_parameters =
+            discrete_log_ratio_of_committed_values::PublicParameters::new::<
+                SCALAR_LIMBS,
+                GroupElement::Scalar,
+                GroupElement,
+            >(
+                scalar_group_public_parameters.clone(),
+                group_public_parameters.clone(),
+                commitment_scheme_public_parameters.clone(),
+                centralized_party_public_key_share.clone(),
+            );
+
+        let nonce_share_by_key_share_commitment = GroupElement::new(
+            public_nonce_encrypted_partial_signature_and_proof.nonce_share_by_key_share_commitment,
+            group_public_parameters,
+        )?;
+
+        let nonce_share_by_key_share_proof_instance = (
+            protocol_context.clone(),
+            public_nonce_encrypted_partial_signature_and_proof.nonce_share_by_key_share_proof,
+            language_public_parameters,
+            [
+                centralized_party_nonce_share_commitment.clone(),
+                nonce_share_by_key_share_commitment.clone(),
+            ]
+            .into(),
+        );
+
+        let encrypted_mask_upper_bound = composed_witness_upper_bound::<
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+        >()?;
+
+        let encrypted_masked_key_share_upper_bound: Option<_> = composed_witness_upper_bound::<
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+        >()?
+        .checked_mul(&encrypted_mask_upper_bound)
+        .into();
+
+        let ciphertexts_and_upper_bounds = [
+            (encrypted_mask.clone(), encrypted_mask_upper_bound),
+            (
+                encrypted_masked_key_share.clone(),
+                encrypted_masked_key_share_upper_bound.ok_or(Error::InvalidPublicParameters)?,
+            ),
+        ]
+        .map(|(ct, upper_bound)| (ct.value(), upper_bound));
+
+        let commitment_scheme_public_parameters = commitment_scheme_public_parameters.into();
+
+        let language_public_parameters = committed_linear_evaluation::PublicParameters::<
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            SCALAR_LIMBS,
+            DIMENSION,
+            GroupElement,
+            EncryptionKey,
+        >::new::<SCALAR_LIMBS, GroupElement, EncryptionKey>(
+            scalar_group_public_parameters.clone(),
+            group_public_parameters.clone(),
+            encryption_scheme_public_parameters.clone(),
+            commitment_scheme_public_parameters,
+            ciphertexts_and_upper_bounds,
+        );
+
+        let language_public_parameters = EnhancedPublicParameters::<
+            SOUND_PROOFS_REPETITIONS,
+            NUM_RANGE_CLAIMS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedDComEvalWitness,
+            committed_linear_evaluation::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::new::<
+            RangeProof,
+            UnboundedDComEvalWitness,
+            committed_linear_evaluation::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            unbounded_dcom_eval_witness_public_parameters.clone(),
+            range_proof_public_parameters.clone(),
+            language_public_parameters,
+        )?;
+
+        let encrypted_partial_signature = EncryptionKey::CiphertextSpaceGroupElement::new(
+            public_nonce_encrypted_partial_signature_and_proof.encrypted_partial_signature,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let range_proof_commitment = proof::range::CommitmentSchemeCommitmentSpaceGroupElement::<
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            NUM_RANGE_CLAIMS,
+            RangeProof,
+        >::new(
+            public_nonce_encrypted_partial_signature_and_proof
+                .encrypted_partial_signature_range_proof_commitment,
+            range_proof_public_parameters
+                .commitment_scheme_public_parameters()
+                .commitment_space_public_parameters(),
+        )?;
+
+        let encrypted_partial_signature_proof_instance = (
+            protocol_context,
+            public_nonce_encrypted_partial_signature_and_proof.encrypted_partial_signature_proof,
+            language_public_parameters,
+            (
+                range_proof_commitment,
+                (
+                    encrypted_partial_signature,
+                    [
+                        ((nonce_x_coordinate * nonce_share_by_key_share_commitment)
+                            + (message * &centralized_party_nonce_share_commitment)),
+                        (nonce_x_coordinate * &centralized_party_nonce_share_commitment),
+                    ]
+                    .into(),
+                )
+                    .into(),
+            )
+                .into(),
+        );
+
+        Ok((
+            public_nonce_proof_instance,
+            nonce_share_by_key_share_proof_instance,
+            encrypted_partial_signature_proof_instance,
+        ))
+    }
+
+    /// Verify the validity of the encrypted signature parts sent by the centralized party over
+    /// `message`, which is hashed using `hash` and converted into a scalar via
+    /// [`message_digest`].
+    ///
+    /// See [`Self::verify_encrypted_signature_parts_prehash`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_encrypted_signature_parts(
+        message: &[u8],
+        hash: Hash,
+        public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+        protocol_context: &ProtocolContext,
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: &GroupElement::PublicParameters,
+        encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+        unbounded_dcom_eval_witness_public_parameters: &UnboundedDComEvalWitness::PublicParameters,
+        range_proof_public_parameters: &RangeProof::PublicParameters<NUM_RANGE_CLAIMS>,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        presign: presign::decentralized_party::Presign<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<()> {
+        let message = message_digest::<SCALAR_LIMBS, GroupElement>(
+            message,
+            hash,
+            scalar_group_public_parameters,
+        )?;
+
+        Self::verify_encrypted_signature_parts_prehash(
+            message,
+            public_nonce_encrypted_partial_signature_and_proof,
+            protocol_context,
+            scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_dcom_eval_witness_public_parameters,
+            range_proof_public_parameters,
+            dkg_output,
+            presign,
+            rng,
+        )
+    }
+
+    /// Verify the validity of the encrypted signature parts sent by the centralized party.
+    /// If this function returns `Ok()`, it means that a valid signature over `message` is
+    /// guaranteed to be able to be generated by the decentralized party, whenever a threshold of
+    /// honest parties decides to engage in the signing protocol.
+    ///
+    /// to Note: `message` is a `Scalar` which must be a
+    /// hash on the message bytes translated into a 32-byte number.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_encrypted_signature_parts_prehash(
+        message: GroupElement::Scalar,
+        public_nonce_encrypted_partial_signature_and_proof: PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+        protocol_context: &ProtocolContext,
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: &GroupElement::PublicParameters,
+        encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+        unbounded_dcom_eval_witness_public_parameters: &UnboundedDComEvalWitness::PublicParameters,
+        range_proof_public_parameters: &RangeProof::PublicParameters<NUM_RANGE_CLAIMS>,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        presign: presign::decentralized_party::Presign<
+            GroupElement::Value,
             group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
         >,
         rng: &mut impl CryptoRngCore,
@@ -611,6 +1240,108 @@ where
         )
     }
 
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(
+        self,
+    ) -> State<GroupElement::Value, group::Value<EncryptionKey::CiphertextSpaceGroupElement>> {
+        State {
+            threshold: self.threshold,
+            public_key: self.public_key.value(),
+            nonce_public_share: self.nonce_public_share.value(),
+            encrypted_mask: self.encrypted_mask.value(),
+            encrypted_masked_key_share: self.encrypted_masked_key_share.value(),
+            encrypted_masked_nonce_share: self.encrypted_masked_nonce_share.value(),
+            centralized_party_public_key_share: self.centralized_party_public_key_share.value(),
+            centralized_party_nonce_share_commitment: self
+                .centralized_party_nonce_share_commitment
+                .value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    ///
+    /// The decryption key share, public parameters and protocol context are not a part of the
+    /// state, and must be the same ones used to instantiate the party prior to it being saved.
+    pub fn from_state<
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+    >(
+        decryption_key_share: DecryptionKeyShare,
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        state: State<GroupElement::Value, group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+        protocol_context: ProtocolContext,
+    ) -> crate::Result<Self> {
+        let group_public_parameters = protocol_public_parameters.group_public_parameters;
+        let encryption_scheme_public_parameters =
+            protocol_public_parameters.encryption_scheme_public_parameters;
+
+        let public_key = GroupElement::new(state.public_key, &group_public_parameters)?;
+
+        let nonce_public_share =
+            GroupElement::new(state.nonce_public_share, &group_public_parameters)?;
+
+        let centralized_party_public_key_share = GroupElement::new(
+            state.centralized_party_public_key_share,
+            &group_public_parameters,
+        )?;
+
+        let centralized_party_nonce_share_commitment = GroupElement::new(
+            state.centralized_party_nonce_share_commitment,
+            &group_public_parameters,
+        )?;
+
+        let encrypted_mask = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_mask,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_masked_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_masked_key_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        let encrypted_masked_nonce_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            state.encrypted_masked_nonce_share,
+            encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            threshold: state.threshold,
+            decryption_key_share,
+            decryption_key_share_public_parameters,
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters,
+            encryption_scheme_public_parameters,
+            unbounded_dcom_eval_witness_public_parameters: protocol_public_parameters
+                .unbounded_dcom_eval_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_dcom_eval_public_parameters,
+            public_key,
+            nonce_public_share,
+            encrypted_mask,
+            encrypted_masked_key_share,
+            encrypted_masked_nonce_share,
+            centralized_party_public_key_share,
+            centralized_party_nonce_share_commitment,
+        })
+    }
+
     pub fn new<
         UnboundedEncDLWitness: group::GroupElement + Samplable,
         UnboundedEncDHWitness: group::GroupElement + Samplable,
@@ -641,6 +1372,18 @@ where
             group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
         >,
     ) -> crate::Result<Self> {
+        // The presign masks our encrypted secret key share, and is only valid for the key shares
+        // it was bound to.
+        if presign
+            .encrypted_secret_key_share
+            .as_ref()
+            .is_some_and(|encrypted_secret_key_share| {
+                *encrypted_secret_key_share != dkg_output.encrypted_secret_key_share
+            })
+        {
+            return Err(Error::StalePresign);
+        }
+
         let scalar_group_public_parameters =
             protocol_public_parameters.scalar_group_public_parameters;
         let group_public_parameters = protocol_public_parameters.group_public_parameters;
@@ -698,4 +1441,225 @@ where
             centralized_party_nonce_share_commitment,
         })
     }
+
+    /// Instantiate a party for each of the virtual shares held by `party_id` under a weighted
+    /// threshold `access_structure`, given its `decryption_key_shares` by their virtual party
+    /// IDs.
+    ///
+    /// Each of the returned parties partially decrypts the signature parts independently, and
+    /// their decryption shares are sent by their virtual party IDs.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_weighted<
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+    >(
+        access_structure: &WeightedThresholdAccessStructure,
+        party_id: PartyID,
+        decryption_key_shares: HashMap<PartyID, DecryptionKeyShare>,
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        presign: presign::decentralized_party::Presign<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    ) -> crate::Result<HashMap<PartyID, Self>> {
+        let virtual_party_ids: HashSet<_> = access_structure
+            .virtual_party_ids(party_id)?
+            .into_iter()
+            .collect();
+
+        if decryption_key_shares
+            .keys()
+            .copied()
+            .collect::<HashSet<_>>()
+            != virtual_party_ids
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        decryption_key_shares
+            .into_iter()
+            .map(|(virtual_party_id, decryption_key_share)| {
+                Ok((
+                    virtual_party_id,
+                    Self::new(
+                        access_structure.threshold(),
+                        decryption_key_share,
+                        decryption_key_share_public_parameters.clone(),
+                        protocol_context.clone(),
+                        protocol_public_parameters.clone(),
+                        dkg_output.clone(),
+                        presign.clone(),
+                    )?,
+                ))
+            })
+            .collect()
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const RANGE_CLAIMS_PER_MASK: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: RecoverableGroupElement<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        NUM_RANGE_CLAIMS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+        RangeProof,
+        UnboundedDComEvalWitness,
+        ProtocolContext,
+    >
+where
+    committed_linear_evaluation::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        RANGE_CLAIMS_PER_MASK,
+        DIMENSION,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = committed_linear_evaluation::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = committed_linear_evaluation::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = committed_linear_evaluation::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            NUM_RANGE_CLAIMS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedDComEvalWitness,
+        >,
+    Uint<PLAINTEXT_SPACE_SCALAR_LIMBS>: Encoding,
+    Error: From<DecryptionKeyShare::Error>,
+{
+    /// The message to sign, which must be a hash on the message bytes translated into a
+    /// 32-byte number, see [`message_digest`], and the encrypted signature parts sent by the
+    /// centralized party.
+    type Incoming = (
+        GroupElement::Scalar,
+        PublicNonceEncryptedPartialSignatureAndProof<
+            GroupElement::Value,
+            proof::range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                NUM_RANGE_CLAIMS,
+                RangeProof,
+            >,
+            homomorphic_encryption::CiphertextSpaceValue<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                committment_of_discrete_log::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                    Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+                >,
+                ProtocolContext,
+            >,
+            maurer::Proof<
+                SOUND_PROOFS_REPETITIONS,
+                discrete_log_ratio_of_committed_values::Language<
+                    SCALAR_LIMBS,
+                    GroupElement::Scalar,
+                    GroupElement,
+                >,
+                ProtocolContext,
+            >,
+            committed_linear_evaluation::Proof<
+                NUM_RANGE_CLAIMS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RANGE_CLAIMS_PER_MASK,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                DIMENSION,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedDComEvalWitness,
+                ProtocolContext,
+            >,
+        >,
+    );
+    /// The partial decryptions of the encrypted partial signature and of the masked nonce.
+    type Message = (
+        DecryptionKeyShare::DecryptionShare,
+        DecryptionKeyShare::DecryptionShare,
+    );
+    type NextRound = signature_threshold_decryption_round::Party<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >;
+    type Output = RecoverableSignature<GroupElement::Scalar>;
+    type Error = Error;
+
+    fn process(
+        self,
+        (message, public_nonce_encrypted_partial_signature_and_proof): Self::Incoming,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (decryption_shares, party) = self.partially_decrypt_encrypted_signature_parts_prehash(
+            message,
+            public_nonce_encrypted_partial_signature_and_proof,
+            rng,
+        )?;
+
+        Ok(Transition::Advance(decryption_shares, party))
+    }
 }
@@ -6,7 +6,7 @@ use std::{
     ops::Neg,
 };
 
-use crypto_bigint::{NonZero, Uint};
+use crypto_bigint::{rand_core::CryptoRngCore, NonZero, Uint};
 use group::{
     AffineXCoordinate, GroupElement, Invert, KnownOrderGroupElement, PartyID, PrimeGroupElement,
     Reduce,
@@ -14,8 +14,23 @@ use group::{
 use homomorphic_encryption::{
     AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
 };
+use serde::{Deserialize, Serialize};
 
-use crate::{sign::verify_signature, Error};
+use crate::{
+    round::{Round, Transition},
+    sign::{verify_signature, RecoverableGroupElement, RecoverableSignature},
+    Error,
+};
+
+/// This struct is a serializable state to use in case the `Party` struct cannot be saved in
+/// memory. See [`Party::to_state`] and [`Party::from_state`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct State<ScalarValue, GroupElementValue> {
+    threshold: PartyID,
+    message: ScalarValue,
+    public_key: GroupElementValue,
+    public_nonce: GroupElementValue,
+}
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
@@ -30,13 +45,13 @@ pub struct Party<
     pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
     pub(super) message: GroupElement::Scalar,
     pub(super) public_key: GroupElement,
-    pub(super) nonce_x_coordinate: GroupElement::Scalar,
+    pub(super) public_nonce: GroupElement,
 }
 
 impl<
         const SCALAR_LIMBS: usize,
         const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
-        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS> + group::HashToGroup,
+        GroupElement: RecoverableGroupElement<SCALAR_LIMBS> + group::HashToGroup,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
     >
@@ -50,6 +65,40 @@ impl<
 where
     Error: From<DecryptionKeyShare::Error>,
 {
+    /// Save this party into a serializable [`State`], to be restored later using
+    /// [`Self::from_state`].
+    pub fn to_state(self) -> State<group::Value<GroupElement::Scalar>, GroupElement::Value> {
+        State {
+            threshold: self.threshold,
+            message: self.message.value(),
+            public_key: self.public_key.value(),
+            public_nonce: self.public_nonce.value(),
+        }
+    }
+
+    /// Restore a party from a [`State`] produced by [`Self::to_state`].
+    pub fn from_state(
+        decryption_key_share_public_parameters: DecryptionKeyShare::PublicParameters,
+        scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: GroupElement::PublicParameters,
+        state: State<group::Value<GroupElement::Scalar>, GroupElement::Value>,
+    ) -> crate::Result<Self> {
+        let message = GroupElement::Scalar::new(state.message, &scalar_group_public_parameters)?;
+
+        let public_key = GroupElement::new(state.public_key, &group_public_parameters)?;
+
+        let public_nonce = GroupElement::new(state.public_nonce, &group_public_parameters)?;
+
+        Ok(Self {
+            threshold: state.threshold,
+            decryption_key_share_public_parameters,
+            scalar_group_public_parameters,
+            message,
+            public_key,
+            public_nonce,
+        })
+    }
+
     /// The designated threshold decryption party logic, which performs the amortized heavy-lifting
     /// $$ O(n) $$ public decryption logic. An honest party would verify the signature and
     /// output it if and only if it is valid, otherwise (i.e. when
@@ -58,12 +107,20 @@ where
     ///
     /// This function never returns an invalid signature, so that parties that receive an invalid
     /// signature can blame the decrypter.
+    ///
+    /// The signature is returned alongside its recovery id, which accounts for `s` being
+    /// normalized to its low form.
+    ///
+    /// Under a weighted threshold access structure, the decryption shares are keyed by the virtual
+    /// party IDs selected by `WeightedThresholdAccessStructure::virtual_decrypters`, with
+    /// Lagrange coefficients computed over them by
+    /// `WeightedThresholdAccessStructure::lagrange_coefficients`.
     pub fn decrypt_signature(
         self,
         lagrange_coefficients: HashMap<PartyID, DecryptionKeyShare::LagrangeCoefficient>,
         partial_signature_decryption_shares: HashMap<PartyID, DecryptionKeyShare::DecryptionShare>,
         masked_nonce_decryption_shares: HashMap<PartyID, DecryptionKeyShare::DecryptionShare>,
-    ) -> crate::Result<(GroupElement::Scalar, GroupElement::Scalar)> {
+    ) -> crate::Result<RecoverableSignature<GroupElement::Scalar>> {
         let decrypters: HashSet<_> = lagrange_coefficients.clone().into_keys().collect();
         if decrypters.len() != usize::from(self.threshold)
             || decrypters
@@ -129,14 +186,16 @@ where
             signature_s
         };
 
+        let nonce_x_coordinate = self.public_nonce.x(); // $r$
+
         verify_signature(
-            self.nonce_x_coordinate,
+            nonce_x_coordinate,
             signature_s,
             self.message,
             self.public_key,
         )?;
 
-        Ok((self.nonce_x_coordinate, signature_s))
+        Ok(self.recoverable_signature(nonce_x_coordinate, signature_s))
     }
 
     /// The lightweight $$ O(1) $$ threshold decryption logic, which simply verifies the output of
@@ -146,15 +205,105 @@ where
         self,
         signature_s: GroupElement::Scalar,
         designated_decrypting_party_id: PartyID,
-    ) -> crate::Result<(GroupElement::Scalar, GroupElement::Scalar)> {
+    ) -> crate::Result<RecoverableSignature<GroupElement::Scalar>> {
+        let nonce_x_coordinate = self.public_nonce.x(); // $r$
+
         verify_signature(
-            self.nonce_x_coordinate,
+            nonce_x_coordinate,
             signature_s,
             self.message,
             self.public_key,
         )
         .map_err(|_| Error::MaliciousDesignatedDecryptingParty(designated_decrypting_party_id))?;
 
-        Ok((self.nonce_x_coordinate, signature_s))
+        Ok(self.recoverable_signature(nonce_x_coordinate, signature_s))
+    }
+
+    /// Compute the recovery id of a verified signature. The nonce point recovered from the
+    /// signature is $R$ itself if $ s \cdot R = m \cdot G + r \cdot X $, and $-R$ otherwise, i.e.
+    /// when `s` was negated to its low form.
+    fn recoverable_signature(
+        &self,
+        nonce_x_coordinate: GroupElement::Scalar,
+        signature_s: GroupElement::Scalar,
+    ) -> RecoverableSignature<GroupElement::Scalar> {
+        let generator = self.public_key.generator();
+
+        let negated_signature_s = signature_s * self.public_nonce
+            != (self.message * generator) + (nonce_x_coordinate * self.public_key);
+
+        let is_y_odd = self.public_nonce.is_y_odd() != negated_signature_s;
+
+        RecoverableSignature {
+            r: nonce_x_coordinate,
+            s: signature_s,
+            recovery_id: u8::from(is_y_odd) | (u8::from(self.public_nonce.is_x_reduced()) << 1),
+        }
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: RecoverableGroupElement<SCALAR_LIMBS> + group::HashToGroup,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+    > Round
+    for Party<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >
+where
+    Error: From<DecryptionKeyShare::Error>,
+{
+    /// The Lagrange coefficients of the decrypters, and the decryption shares of the encrypted
+    /// partial signature and masked nonce sent by each of them.
+    type Incoming = (
+        HashMap<PartyID, DecryptionKeyShare::LagrangeCoefficient>,
+        HashMap<
+            PartyID,
+            (
+                DecryptionKeyShare::DecryptionShare,
+                DecryptionKeyShare::DecryptionShare,
+            ),
+        >,
+    );
+    /// The designated decrypter sends no message beyond the signature it outputs.
+    type Message = ();
+    type NextRound = core::convert::Infallible;
+    type Output = RecoverableSignature<GroupElement::Scalar>;
+    type Error = Error;
+
+    fn process(
+        self,
+        (lagrange_coefficients, decryption_shares): Self::Incoming,
+        _rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Transition<Self::Message, Self::NextRound, Self::Output>> {
+        let (partial_signature_decryption_shares, masked_nonce_decryption_shares) =
+            decryption_shares
+                .into_iter()
+                .map(
+                    |(
+                        party_id,
+                        (partial_signature_decryption_share, masked_nonce_decryption_share),
+                    )| {
+                        (
+                            (party_id, partial_signature_decryption_share),
+                            (party_id, masked_nonce_decryption_share),
+                        )
+                    },
+                )
+                .unzip();
+
+        let signature = self.decrypt_signature(
+            lagrange_coefficients,
+            partial_signature_decryption_shares,
+            masked_nonce_decryption_shares,
+        )?;
+
+        Ok(Transition::Finalize((), signature))
     }
 }
@@ -0,0 +1,383 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use crypto_bigint::Uint;
+use group::{GroupElement as _, KnownOrderGroupElement, PrimeGroupElement};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// The size in bytes of an Ethereum signature $ r || s || v $.
+pub const ETHEREUM_SIGNATURE_SIZE: usize = 65;
+
+const DER_SEQUENCE_TAG: u8 = 0x30;
+const DER_INTEGER_TAG: u8 = 0x02;
+
+/// An ECDSA signature $(r, s)$.
+///
+/// Besides its `serde` representation, it can be encoded as ASN.1 DER and in the fixed-width
+/// compact $ r || s $ format, both of which are understood by the `ecdsa` crate and most other
+/// ECDSA implementations.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<Scalar> {
+    pub r: Scalar,
+    pub s: Scalar,
+}
+
+/// An ECDSA signature $(r, s)$ alongside its recovery id, from which the public key it was signed
+/// under can be recovered.
+///
+/// The recovery id follows the convention of SEC 1, Section 4.1.6 (and the `ecdsa` crate's
+/// `RecoveryId`): its lowest bit is the parity of the y-coordinate of the nonce point recovered
+/// from $r$, and its second bit is set when the x-coordinate of that point was reduced modulo the
+/// group order.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecoverableSignature<Scalar> {
+    pub r: Scalar,
+    pub s: Scalar,
+    pub recovery_id: u8,
+}
+
+impl<Scalar: Copy> Signature<Scalar> {
+    /// Encode this signature as an ASN.1 DER `SEQUENCE { r INTEGER, s INTEGER }`.
+    pub fn to_der<const SCALAR_LIMBS: usize>(&self) -> Vec<u8>
+    where
+        Scalar: Into<Uint<SCALAR_LIMBS>>,
+    {
+        let mut integers = der_encode_integer(&to_be_bytes::<SCALAR_LIMBS>(self.r.into()));
+        integers.extend(der_encode_integer(&to_be_bytes::<SCALAR_LIMBS>(
+            self.s.into(),
+        )));
+
+        let mut der = vec![DER_SEQUENCE_TAG];
+        der.extend(der_encode_length(integers.len()));
+        der.extend(integers);
+
+        der
+    }
+
+    /// Decode a signature from its ASN.1 DER encoding, rejecting any non-canonical encoding or
+    /// values of $r$ and $s$ outside $[1, q)$.
+    pub fn from_der<const SCALAR_LIMBS: usize, GroupElement>(
+        der: &[u8],
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    ) -> crate::Result<Self>
+    where
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS, Scalar = Scalar>,
+    {
+        let (tag, sequence, rest) = der_decode_element(der)?;
+        if tag != DER_SEQUENCE_TAG || !rest.is_empty() {
+            return Err(Error::InvalidSignatureEncoding);
+        }
+
+        let (tag, r, rest) = der_decode_element(sequence)?;
+        if tag != DER_INTEGER_TAG {
+            return Err(Error::InvalidSignatureEncoding);
+        }
+
+        let (tag, s, rest) = der_decode_element(rest)?;
+        if tag != DER_INTEGER_TAG || !rest.is_empty() {
+            return Err(Error::InvalidSignatureEncoding);
+        }
+
+        Ok(Self {
+            r: scalar_from_be_bytes::<SCALAR_LIMBS, GroupElement>(
+                der_decode_integer(r)?,
+                scalar_group_public_parameters,
+            )?,
+            s: scalar_from_be_bytes::<SCALAR_LIMBS, GroupElement>(
+                der_decode_integer(s)?,
+                scalar_group_public_parameters,
+            )?,
+        })
+    }
+
+    /// Encode this signature in the fixed-width compact format $ r || s $, each written
+    /// big-endian in `Uint::<SCALAR_LIMBS>::BYTES` bytes.
+    pub fn to_compact_bytes<const SCALAR_LIMBS: usize>(&self) -> Vec<u8>
+    where
+        Scalar: Into<Uint<SCALAR_LIMBS>>,
+    {
+        let mut bytes = to_be_bytes::<SCALAR_LIMBS>(self.r.into());
+        bytes.extend(to_be_bytes::<SCALAR_LIMBS>(self.s.into()));
+
+        bytes
+    }
+
+    /// Decode a signature from the fixed-width compact format $ r || s $, rejecting values of $r$
+    /// and $s$ outside $[1, q)$.
+    pub fn from_compact_bytes<const SCALAR_LIMBS: usize, GroupElement>(
+        bytes: &[u8],
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    ) -> crate::Result<Self>
+    where
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS, Scalar = Scalar>,
+    {
+        if bytes.len() != 2 * Uint::<SCALAR_LIMBS>::BYTES {
+            return Err(Error::InvalidSignatureEncoding);
+        }
+
+        let (r, s) = bytes.split_at(Uint::<SCALAR_LIMBS>::BYTES);
+
+        Ok(Self {
+            r: scalar_from_be_bytes::<SCALAR_LIMBS, GroupElement>(
+                r,
+                scalar_group_public_parameters,
+            )?,
+            s: scalar_from_be_bytes::<SCALAR_LIMBS, GroupElement>(
+                s,
+                scalar_group_public_parameters,
+            )?,
+        })
+    }
+}
+
+impl<Scalar: Copy> RecoverableSignature<Scalar> {
+    /// The signature $(r, s)$, without its recovery id.
+    pub fn signature(&self) -> Signature<Scalar> {
+        Signature {
+            r: self.r,
+            s: self.s,
+        }
+    }
+
+    /// Encode this signature in the 65-byte Ethereum format $ r || s || v $, where
+    /// $ v = 27 + y_parity $.
+    ///
+    /// Ethereum cannot represent signatures whose nonce x-coordinate was reduced modulo the group
+    /// order (which happens with negligible probability), for which `Error::InvalidParameters` is
+    /// returned. Pre-EIP-155 `v` values are used; replay-protected transactions should adjust
+    /// them with their chain id.
+    pub fn to_ethereum_bytes<const SCALAR_LIMBS: usize>(
+        &self,
+    ) -> crate::Result<[u8; ETHEREUM_SIGNATURE_SIZE]>
+    where
+        Scalar: Into<Uint<SCALAR_LIMBS>>,
+    {
+        if Uint::<SCALAR_LIMBS>::BYTES != 32 || self.recovery_id > 1 {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut bytes = [0u8; ETHEREUM_SIGNATURE_SIZE];
+        bytes[..ETHEREUM_SIGNATURE_SIZE - 1]
+            .copy_from_slice(&self.signature().to_compact_bytes::<SCALAR_LIMBS>());
+        bytes[ETHEREUM_SIGNATURE_SIZE - 1] = 27 + self.recovery_id;
+
+        Ok(bytes)
+    }
+
+    /// Decode a signature from the 65-byte Ethereum format $ r || s || v $, accepting both
+    /// $ v \in \{27, 28\} $ and raw y-parity values $ v \in \{0, 1\} $.
+    pub fn from_ethereum_bytes<const SCALAR_LIMBS: usize, GroupElement>(
+        bytes: &[u8; ETHEREUM_SIGNATURE_SIZE],
+        scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+    ) -> crate::Result<Self>
+    where
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS, Scalar = Scalar>,
+    {
+        if Uint::<SCALAR_LIMBS>::BYTES != 32 {
+            return Err(Error::InvalidParameters);
+        }
+
+        let recovery_id = match bytes[ETHEREUM_SIGNATURE_SIZE - 1] {
+            v @ (0 | 1) => v,
+            v @ (27 | 28) => v - 27,
+            _ => return Err(Error::InvalidSignatureEncoding),
+        };
+
+        let signature = Signature::from_compact_bytes::<SCALAR_LIMBS, GroupElement>(
+            &bytes[..ETHEREUM_SIGNATURE_SIZE - 1],
+            scalar_group_public_parameters,
+        )?;
+
+        Ok(Self {
+            r: signature.r,
+            s: signature.s,
+            recovery_id,
+        })
+    }
+}
+
+#[cfg(feature = "secp256k1")]
+impl TryFrom<Signature<group::secp256k1::Scalar>> for k256::ecdsa::Signature {
+    type Error = Error;
+
+    fn try_from(signature: Signature<group::secp256k1::Scalar>) -> crate::Result<Self> {
+        k256::ecdsa::Signature::from_scalars(
+            k256::Scalar::from(signature.r),
+            k256::Scalar::from(signature.s),
+        )
+        .map_err(|_| Error::InvalidSignatureEncoding)
+    }
+}
+
+#[cfg(feature = "secp256k1")]
+impl TryFrom<k256::ecdsa::Signature> for Signature<group::secp256k1::Scalar> {
+    type Error = Error;
+
+    fn try_from(signature: k256::ecdsa::Signature) -> crate::Result<Self> {
+        Signature::from_compact_bytes::<
+            { group::secp256k1::SCALAR_LIMBS },
+            group::secp256k1::GroupElement,
+        >(
+            &signature.to_bytes(),
+            &group::secp256k1::scalar::PublicParameters::default(),
+        )
+    }
+}
+
+#[cfg(feature = "secp256r1")]
+impl TryFrom<Signature<group::secp256r1::Scalar>> for p256::ecdsa::Signature {
+    type Error = Error;
+
+    fn try_from(signature: Signature<group::secp256r1::Scalar>) -> crate::Result<Self> {
+        p256::ecdsa::Signature::from_scalars(
+            p256::Scalar::from(signature.r),
+            p256::Scalar::from(signature.s),
+        )
+        .map_err(|_| Error::InvalidSignatureEncoding)
+    }
+}
+
+#[cfg(feature = "secp256r1")]
+impl TryFrom<p256::ecdsa::Signature> for Signature<group::secp256r1::Scalar> {
+    type Error = Error;
+
+    fn try_from(signature: p256::ecdsa::Signature) -> crate::Result<Self> {
+        Signature::from_compact_bytes::<
+            { group::secp256r1::SCALAR_LIMBS },
+            group::secp256r1::GroupElement,
+        >(
+            &signature.to_bytes(),
+            &group::secp256r1::scalar::PublicParameters::default(),
+        )
+    }
+}
+
+fn to_be_bytes<const SCALAR_LIMBS: usize>(number: Uint<SCALAR_LIMBS>) -> Vec<u8> {
+    number
+        .as_words()
+        .iter()
+        .rev()
+        .flat_map(|word| word.to_be_bytes())
+        .collect()
+}
+
+/// Convert a big-endian number into a scalar, rejecting zero and numbers that are not smaller than
+/// the group order.
+fn scalar_from_be_bytes<const SCALAR_LIMBS: usize, GroupElement>(
+    bytes: &[u8],
+    scalar_group_public_parameters: &group::PublicParameters<GroupElement::Scalar>,
+) -> crate::Result<GroupElement::Scalar>
+where
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+{
+    if bytes.len() > Uint::<SCALAR_LIMBS>::BYTES {
+        return Err(Error::InvalidSignatureEncoding);
+    }
+
+    let mut padded_bytes = vec![0u8; Uint::<SCALAR_LIMBS>::BYTES - bytes.len()];
+    padded_bytes.extend_from_slice(bytes);
+    let number = Uint::<SCALAR_LIMBS>::from_be_slice(&padded_bytes);
+
+    if number == Uint::<SCALAR_LIMBS>::ZERO
+        || number
+            >= GroupElement::Scalar::order_from_public_parameters(scalar_group_public_parameters)
+    {
+        return Err(Error::InvalidSignatureEncoding);
+    }
+
+    Ok(GroupElement::Scalar::new(
+        number.into(),
+        scalar_group_public_parameters,
+    )?)
+}
+
+fn der_encode_length(length: usize) -> Vec<u8> {
+    if length < 0x80 {
+        return vec![length as u8];
+    }
+
+    let length_bytes: Vec<u8> = length
+        .to_be_bytes()
+        .into_iter()
+        .skip_while(|byte| *byte == 0)
+        .collect();
+
+    let mut encoded_length = vec![0x80 | length_bytes.len() as u8];
+    encoded_length.extend(length_bytes);
+
+    encoded_length
+}
+
+/// Encode an unsigned big-endian number as a minimal DER `INTEGER`.
+fn der_encode_integer(bytes: &[u8]) -> Vec<u8> {
+    let bytes: Vec<u8> = bytes
+        .iter()
+        .copied()
+        .skip_while(|byte| *byte == 0)
+        .collect();
+
+    let mut integer = Vec::with_capacity(bytes.len() + 1);
+    // Keep the integer positive, and encode zero as a single zero byte.
+    if bytes.is_empty() || bytes[0] & 0x80 != 0 {
+        integer.push(0);
+    }
+    integer.extend(bytes);
+
+    let mut encoded_integer = vec![DER_INTEGER_TAG];
+    encoded_integer.extend(der_encode_length(integer.len()));
+    encoded_integer.extend(integer);
+
+    encoded_integer
+}
+
+/// Split a DER element off `der`, returning its tag, contents and the remaining bytes.
+fn der_decode_element(der: &[u8]) -> crate::Result<(u8, &[u8], &[u8])> {
+    let (&tag, der) = der.split_first().ok_or(Error::InvalidSignatureEncoding)?;
+    let (&first_length_byte, der) = der.split_first().ok_or(Error::InvalidSignatureEncoding)?;
+
+    let (length, der) = if first_length_byte < 0x80 {
+        (usize::from(first_length_byte), der)
+    } else {
+        let length_bytes_count = usize::from(first_length_byte & 0x7f);
+        if length_bytes_count == 0
+            || length_bytes_count > std::mem::size_of::<usize>()
+            || der.len() < length_bytes_count
+        {
+            return Err(Error::InvalidSignatureEncoding);
+        }
+
+        let (length_bytes, der) = der.split_at(length_bytes_count);
+        let length = length_bytes
+            .iter()
+            .fold(0usize, |length, byte| (length << 8) | usize::from(*byte));
+
+        // DER requires the shortest length encoding.
+        if length < 0x80 || length_bytes[0] == 0 {
+            return Err(Error::InvalidSignatureEncoding);
+        }
+
+        (length, der)
+    };
+
+    if der.len() < length {
+        return Err(Error::InvalidSignatureEncoding);
+    }
+
+    let (contents, rest) = der.split_at(length);
+
+    Ok((tag, contents, rest))
+}
+
+/// Decode the contents of a DER `INTEGER` into an unsigned big-endian number, rejecting negative
+/// and non-minimal encodings.
+fn der_decode_integer(integer: &[u8]) -> crate::Result<&[u8]> {
+    match integer {
+        [] => Err(Error::InvalidSignatureEncoding),
+        [first, ..] if first & 0x80 != 0 => Err(Error::InvalidSignatureEncoding),
+        [0, second, ..] if second & 0x80 == 0 => Err(Error::InvalidSignatureEncoding),
+        [0, rest @ ..] => Ok(rest),
+        _ => Ok(integer),
+    }
+}
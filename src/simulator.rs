@@ -0,0 +1,835 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! An in-process simulator running the protocol parties over an in-memory broadcast bus.
+
+use std::collections::{HashMap, HashSet};
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::PartyID;
+
+use crate::{
+    round::{Round, Transition},
+    Error, Result,
+};
+
+/// A participant of a protocol session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Participant {
+    Centralized,
+    Decentralized(PartyID),
+}
+
+/// The conditions of the simulated network, under which every message is independently delayed
+/// or dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NetworkConditions {
+    /// The maximal delay of a message in ticks, up to which each message is delayed uniformly at
+    /// random.
+    pub max_delay: u64,
+    /// The probability, in `[0, 1]`, that a message is never delivered.
+    pub drop_probability: f64,
+    /// Whether messages are delivered in the order in which they arrive, rather than in the order
+    /// in which they were sent.
+    pub reorder: bool,
+    /// The number of ticks a party waits for the messages of a round before it aborts.
+    pub timeout: u64,
+}
+
+impl NetworkConditions {
+    /// A reliable broadcast channel, which delivers every message immediately and in order.
+    pub fn reliable() -> Self {
+        Self {
+            max_delay: 0,
+            drop_probability: 0.0,
+            reorder: false,
+            timeout: 0,
+        }
+    }
+}
+
+impl Default for NetworkConditions {
+    fn default() -> Self {
+        Self::reliable()
+    }
+}
+
+/// The outcome of a simulated protocol session, holding the output or the error of every party.
+#[derive(Debug)]
+pub struct Outcome<CentralizedPartyOutput, DecentralizedPartyOutput> {
+    pub centralized_party: Result<CentralizedPartyOutput>,
+    pub decentralized_parties: HashMap<PartyID, Result<DecentralizedPartyOutput>>,
+    /// The number of ticks the session took.
+    pub elapsed: u64,
+}
+
+/// The messages each participant received in a round, in their order of delivery.
+type Received<Message> = HashMap<Participant, Vec<(Participant, Message)>>;
+
+/// An in-process simulator of a protocol session between the centralized party and the
+/// decentralized parties, which runs every party through its [`Round`]s and broadcasts their
+/// messages over an in-memory bus.
+///
+/// Every broadcast message is delivered unmodified to all of its recipients, unless it is dropped
+/// or delayed past the round's timeout by the [`NetworkConditions`]. A party which does not
+/// receive all the messages of a round aborts with [`Error::UnresponsiveParties`] or
+/// [`Error::UnresponsiveCentralizedParty`], as it would in a deployment, and the parties waiting
+/// on its messages abort in turn.
+pub struct Simulator<Rng> {
+    conditions: NetworkConditions,
+    rng: Rng,
+    elapsed: u64,
+}
+
+impl<Rng: CryptoRngCore> Simulator<Rng> {
+    pub fn new(conditions: NetworkConditions, rng: Rng) -> Result<Self> {
+        if !(0.0..=1.0).contains(&conditions.drop_probability) {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(Self {
+            conditions,
+            rng,
+            elapsed: 0,
+        })
+    }
+
+    /// Simulate a [`crate::dkg`] session between `centralized_party`, in its commitment round,
+    /// and `decentralized_parties`, in their encryption of secret key share round.
+    pub fn dkg<
+        CommitmentRound,
+        DecommitmentRound,
+        EncryptionOfSecretKeyShareRound,
+        EncryptionOfSecretKeyShareDecommitmentRound,
+        EncryptionOfSecretKeyShareProofShareRound,
+        EncryptionOfSecretKeyShareProofAggregationRound,
+        DecommitmentProofVerificationRound,
+    >(
+        &mut self,
+        centralized_party: CommitmentRound,
+        decentralized_parties: HashMap<PartyID, EncryptionOfSecretKeyShareRound>,
+    ) -> Outcome<CommitmentRound::Output, EncryptionOfSecretKeyShareRound::Output>
+    where
+        CommitmentRound: Round<Incoming = (), NextRound = DecommitmentRound, Error = Error>,
+        CommitmentRound::Message: Clone,
+        DecommitmentRound: Round<
+            Incoming = EncryptionOfSecretKeyShareProofAggregationRound::Message,
+            Output = CommitmentRound::Output,
+            Error = Error,
+        >,
+        DecommitmentRound::Message: Clone,
+        EncryptionOfSecretKeyShareRound: Round<
+            Incoming = CommitmentRound::Message,
+            NextRound = EncryptionOfSecretKeyShareDecommitmentRound,
+            Error = Error,
+        >,
+        EncryptionOfSecretKeyShareRound::Message: Clone,
+        EncryptionOfSecretKeyShareDecommitmentRound: Round<
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareRound::Message>,
+            NextRound = EncryptionOfSecretKeyShareProofShareRound,
+            Error = Error,
+        >,
+        EncryptionOfSecretKeyShareDecommitmentRound::Message: Clone,
+        EncryptionOfSecretKeyShareProofShareRound: Round<
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareDecommitmentRound::Message>,
+            NextRound = EncryptionOfSecretKeyShareProofAggregationRound,
+            Error = Error,
+        >,
+        EncryptionOfSecretKeyShareProofShareRound::Message: Clone,
+        EncryptionOfSecretKeyShareProofAggregationRound: Round<
+            Incoming = HashMap<PartyID, EncryptionOfSecretKeyShareProofShareRound::Message>,
+            NextRound = DecommitmentProofVerificationRound,
+            Error = Error,
+        >,
+        EncryptionOfSecretKeyShareProofAggregationRound::Message: Clone,
+        DecommitmentProofVerificationRound: Round<
+            Incoming = DecommitmentRound::Message,
+            Output = EncryptionOfSecretKeyShareRound::Output,
+            Error = Error,
+        >,
+    {
+        self.elapsed = 0;
+        let session: HashSet<PartyID> = decentralized_parties.keys().copied().collect();
+        let mut errors = HashMap::new();
+
+        let centralized_party = HashMap::from([(Participant::Centralized, centralized_party)]);
+        let decentralized_parties = into_participants(decentralized_parties);
+
+        let (commitment, centralized_party) =
+            self.advance(centralized_party, |_| Ok(()), &mut errors);
+        let mut received = self.broadcast(commitment, decentralized_parties.keys().copied());
+
+        let (commitments, decentralized_parties) = self.advance(
+            decentralized_parties,
+            |participant| centralized_party_message(&mut received, participant),
+            &mut errors,
+        );
+
+        let (secret_key_share_encryptions_and_proofs, decentralized_parties) =
+            self.aggregate(commitments, decentralized_parties, &session, &mut errors);
+        let mut received = self.broadcast(
+            secret_key_share_encryptions_and_proofs,
+            centralized_party.keys().copied(),
+        );
+
+        let (public_key_share_decommitment_and_proof, centralized_party_output) = self.finalize(
+            centralized_party,
+            |participant| decentralized_party_message(&mut received, participant, &session),
+            &mut errors,
+        );
+        let mut received = self.broadcast(
+            public_key_share_decommitment_and_proof,
+            decentralized_parties.keys().copied(),
+        );
+
+        let (_, decentralized_party_outputs) = self.finalize(
+            decentralized_parties,
+            |participant| centralized_party_message(&mut received, participant),
+            &mut errors,
+        );
+
+        self.outcome(
+            &session,
+            centralized_party_output,
+            decentralized_party_outputs,
+            errors,
+        )
+    }
+
+    /// Simulate a [`crate::presign`] session generating `batch_size` presigns between
+    /// `centralized_party`, in its commitment round, and `decentralized_parties`, in their
+    /// encrypted masked key share and public nonce shares round.
+    pub fn presign<
+        CommitmentRound,
+        ProofVerificationRound,
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound,
+        MaskedNoncesDecommitmentRound,
+        MaskedNoncesProofShareRound,
+        MaskedNoncesProofAggregationRound,
+    >(
+        &mut self,
+        batch_size: usize,
+        centralized_party: CommitmentRound,
+        decentralized_parties: HashMap<PartyID, EncryptedMaskedKeyShareAndPublicNonceSharesRound>,
+    ) -> Outcome<CommitmentRound::Output, EncryptedMaskedKeyShareAndPublicNonceSharesRound::Output>
+    where
+        CommitmentRound: Round<Incoming = usize, NextRound = ProofVerificationRound, Error = Error>,
+        CommitmentRound::Message: Clone,
+        ProofVerificationRound: Round<
+            Incoming = MaskedNoncesProofAggregationRound::Message,
+            Output = CommitmentRound::Output,
+            Error = Error,
+        >,
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound: Round<
+            Incoming = CommitmentRound::Message,
+            NextRound = MaskedKeyShareAndPublicNonceSharesDecommitmentRound,
+            Error = Error,
+        >,
+        EncryptedMaskedKeyShareAndPublicNonceSharesRound::Message: Clone,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound: Round<
+            Incoming = HashMap<PartyID, EncryptedMaskedKeyShareAndPublicNonceSharesRound::Message>,
+            NextRound = MaskedKeyShareAndPublicNonceSharesProofShareRound,
+            Error = Error,
+        >,
+        MaskedKeyShareAndPublicNonceSharesDecommitmentRound::Message: Clone,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound: Round<
+            Incoming = HashMap<
+                PartyID,
+                MaskedKeyShareAndPublicNonceSharesDecommitmentRound::Message,
+            >,
+            NextRound = MaskedKeyShareAndPublicNonceSharesProofAggregationRound,
+            Error = Error,
+        >,
+        MaskedKeyShareAndPublicNonceSharesProofShareRound::Message: Clone,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound: Round<
+            Incoming = HashMap<PartyID, MaskedKeyShareAndPublicNonceSharesProofShareRound::Message>,
+            NextRound = MaskedNoncesDecommitmentRound,
+            Error = Error,
+        >,
+        MaskedKeyShareAndPublicNonceSharesProofAggregationRound::Message: Clone,
+        MaskedNoncesDecommitmentRound: Round<
+            Incoming = HashMap<
+                PartyID,
+                MaskedKeyShareAndPublicNonceSharesProofAggregationRound::Message,
+            >,
+            NextRound = MaskedNoncesProofShareRound,
+            Error = Error,
+        >,
+        MaskedNoncesDecommitmentRound::Message: Clone,
+        MaskedNoncesProofShareRound: Round<
+            Incoming = HashMap<PartyID, MaskedNoncesDecommitmentRound::Message>,
+            NextRound = MaskedNoncesProofAggregationRound,
+            Error = Error,
+        >,
+        MaskedNoncesProofShareRound::Message: Clone,
+        MaskedNoncesProofAggregationRound: Round<
+            Incoming = HashMap<PartyID, MaskedNoncesProofShareRound::Message>,
+            Output = EncryptedMaskedKeyShareAndPublicNonceSharesRound::Output,
+            Error = Error,
+        >,
+        MaskedNoncesProofAggregationRound::Message: Clone,
+    {
+        self.elapsed = 0;
+        let session: HashSet<PartyID> = decentralized_parties.keys().copied().collect();
+        let mut errors = HashMap::new();
+
+        let centralized_party = HashMap::from([(Participant::Centralized, centralized_party)]);
+        let decentralized_parties = into_participants(decentralized_parties);
+
+        let (nonce_shares_commitments_and_batched_proof, centralized_party) =
+            self.advance(centralized_party, |_| Ok(batch_size), &mut errors);
+        let mut received = self.broadcast(
+            nonce_shares_commitments_and_batched_proof,
+            decentralized_parties.keys().copied(),
+        );
+
+        let (commitments, decentralized_parties) = self.advance(
+            decentralized_parties,
+            |participant| centralized_party_message(&mut received, participant),
+            &mut errors,
+        );
+
+        let (commitments, decentralized_parties) =
+            self.aggregate(commitments, decentralized_parties, &session, &mut errors);
+        let (decommitments, decentralized_parties) =
+            self.exchange(commitments, decentralized_parties, &session, &mut errors);
+        let (proof_shares, decentralized_parties) =
+            self.exchange(decommitments, decentralized_parties, &session, &mut errors);
+
+        let mut received = self.broadcast(proof_shares, decentralized_parties.keys().copied());
+        let (outputs, decentralized_party_outputs) = self.finalize(
+            decentralized_parties,
+            |participant| decentralized_party_messages(&mut received, participant, &session),
+            &mut errors,
+        );
+        let mut received = self.broadcast(outputs, centralized_party.keys().copied());
+
+        let (_, centralized_party_output) = self.finalize(
+            centralized_party,
+            |participant| decentralized_party_message(&mut received, participant, &session),
+            &mut errors,
+        );
+
+        self.outcome(
+            &session,
+            centralized_party_output,
+            decentralized_party_outputs,
+            errors,
+        )
+    }
+
+    /// Simulate a [`crate::sign`] session of `message` between `centralized_party`, in its
+    /// signature homomorphic evaluation round, and `decentralized_parties`, in their signature
+    /// partial decryption round, which threshold decrypt the signature using
+    /// `lagrange_coefficients`.
+    pub fn sign<
+        Message,
+        LagrangeCoefficients,
+        SignatureHomomorphicEvaluationRound,
+        SignatureVerificationRound,
+        SignaturePartialDecryptionRound,
+        SignatureThresholdDecryptionRound,
+    >(
+        &mut self,
+        message: Message,
+        lagrange_coefficients: LagrangeCoefficients,
+        centralized_party: SignatureHomomorphicEvaluationRound,
+        decentralized_parties: HashMap<PartyID, SignaturePartialDecryptionRound>,
+    ) -> Outcome<SignatureHomomorphicEvaluationRound::Output, SignaturePartialDecryptionRound::Output>
+    where
+        Message: Clone,
+        LagrangeCoefficients: Clone,
+        SignatureHomomorphicEvaluationRound:
+            Round<Incoming = Message, NextRound = SignatureVerificationRound, Error = Error>,
+        SignatureHomomorphicEvaluationRound::Message: Clone,
+        SignatureVerificationRound: Round<
+            Incoming = SignaturePartialDecryptionRound::Output,
+            Output = SignatureHomomorphicEvaluationRound::Output,
+            Error = Error,
+        >,
+        SignaturePartialDecryptionRound: Round<
+            Incoming = (Message, SignatureHomomorphicEvaluationRound::Message),
+            NextRound = SignatureThresholdDecryptionRound,
+            Error = Error,
+        >,
+        SignaturePartialDecryptionRound::Message: Clone,
+        SignaturePartialDecryptionRound::Output: Clone,
+        SignatureThresholdDecryptionRound: Round<
+            Incoming = (
+                LagrangeCoefficients,
+                HashMap<PartyID, SignaturePartialDecryptionRound::Message>,
+            ),
+            Output = SignaturePartialDecryptionRound::Output,
+            Error = Error,
+        >,
+    {
+        self.elapsed = 0;
+        let session: HashSet<PartyID> = decentralized_parties.keys().copied().collect();
+        let mut errors = HashMap::new();
+
+        let centralized_party = HashMap::from([(Participant::Centralized, centralized_party)]);
+        let decentralized_parties = into_participants(decentralized_parties);
+
+        let (public_nonce_encrypted_partial_signature_and_proof, centralized_party) =
+            self.advance(centralized_party, |_| Ok(message.clone()), &mut errors);
+        let mut received = self.broadcast(
+            public_nonce_encrypted_partial_signature_and_proof,
+            decentralized_parties.keys().copied(),
+        );
+
+        let (decryption_shares, decentralized_parties) = self.advance(
+            decentralized_parties,
+            |participant| {
+                centralized_party_message(&mut received, participant)
+                    .map(|message_to_sign| (message.clone(), message_to_sign))
+            },
+            &mut errors,
+        );
+        let mut received = self.broadcast(decryption_shares, decentralized_parties.keys().copied());
+
+        let (_, decentralized_party_outputs) = self.finalize(
+            decentralized_parties,
+            |participant| {
+                decentralized_party_messages(&mut received, participant, &session)
+                    .map(|decryption_shares| (lagrange_coefficients.clone(), decryption_shares))
+            },
+            &mut errors,
+        );
+        let mut received = self.broadcast(
+            decentralized_party_outputs.clone(),
+            centralized_party.keys().copied(),
+        );
+
+        let (_, centralized_party_output) = self.finalize(
+            centralized_party,
+            |participant| decentralized_party_message(&mut received, participant, &session),
+            &mut errors,
+        );
+
+        self.outcome(
+            &session,
+            centralized_party_output,
+            decentralized_party_outputs,
+            errors,
+        )
+    }
+
+    /// Run the decommitment, proof share and proof aggregation rounds of a proof aggregation
+    /// between the decentralized parties, starting from their `commitments`.
+    fn aggregate<Commitment: Clone, DecommitmentRound, ProofShareRound, ProofAggregationRound>(
+        &mut self,
+        commitments: HashMap<Participant, Commitment>,
+        parties: HashMap<Participant, DecommitmentRound>,
+        session: &HashSet<PartyID>,
+        errors: &mut HashMap<Participant, Error>,
+    ) -> (
+        HashMap<Participant, ProofAggregationRound::Message>,
+        HashMap<Participant, ProofAggregationRound::NextRound>,
+    )
+    where
+        DecommitmentRound: Round<
+            Incoming = HashMap<PartyID, Commitment>,
+            NextRound = ProofShareRound,
+            Error = Error,
+        >,
+        DecommitmentRound::Message: Clone,
+        ProofShareRound: Round<
+            Incoming = HashMap<PartyID, DecommitmentRound::Message>,
+            NextRound = ProofAggregationRound,
+            Error = Error,
+        >,
+        ProofShareRound::Message: Clone,
+        ProofAggregationRound:
+            Round<Incoming = HashMap<PartyID, ProofShareRound::Message>, Error = Error>,
+    {
+        let (decommitments, parties) = self.exchange(commitments, parties, session, errors);
+        let (proof_shares, parties) = self.exchange(decommitments, parties, session, errors);
+
+        self.exchange(proof_shares, parties, session, errors)
+    }
+
+    /// Broadcast the `messages` of the decentralized parties between them, and have each of them
+    /// process the messages it received.
+    fn exchange<Message: Clone, R: Round<Incoming = HashMap<PartyID, Message>, Error = Error>>(
+        &mut self,
+        messages: HashMap<Participant, Message>,
+        parties: HashMap<Participant, R>,
+        session: &HashSet<PartyID>,
+        errors: &mut HashMap<Participant, Error>,
+    ) -> (
+        HashMap<Participant, R::Message>,
+        HashMap<Participant, R::NextRound>,
+    ) {
+        let mut received = self.broadcast(messages, parties.keys().copied());
+
+        self.advance(
+            parties,
+            |participant| decentralized_party_messages(&mut received, participant, session),
+            errors,
+        )
+    }
+
+    /// Have each of `parties` process its incoming messages and proceed to its next round,
+    /// recording the error of every party which aborts.
+    fn advance<R: Round<Error = Error>>(
+        &mut self,
+        parties: HashMap<Participant, R>,
+        incoming: impl FnMut(Participant) -> Result<R::Incoming>,
+        errors: &mut HashMap<Participant, Error>,
+    ) -> (
+        HashMap<Participant, R::Message>,
+        HashMap<Participant, R::NextRound>,
+    ) {
+        self.process(parties, incoming, Transition::advance, errors)
+    }
+
+    /// Have each of `parties` process its incoming messages in the last round of the protocol,
+    /// recording the error of every party which aborts.
+    fn finalize<R: Round<Error = Error>>(
+        &mut self,
+        parties: HashMap<Participant, R>,
+        incoming: impl FnMut(Participant) -> Result<R::Incoming>,
+        errors: &mut HashMap<Participant, Error>,
+    ) -> (
+        HashMap<Participant, R::Message>,
+        HashMap<Participant, R::Output>,
+    ) {
+        self.process(parties, incoming, Transition::finalize, errors)
+    }
+
+    fn process<R: Round<Error = Error>, T>(
+        &mut self,
+        parties: HashMap<Participant, R>,
+        mut incoming: impl FnMut(Participant) -> Result<R::Incoming>,
+        transition: impl Fn(Transition<R::Message, R::NextRound, R::Output>) -> Result<(R::Message, T)>,
+        errors: &mut HashMap<Participant, Error>,
+    ) -> (HashMap<Participant, R::Message>, HashMap<Participant, T>) {
+        let mut messages = HashMap::new();
+        let mut next = HashMap::new();
+
+        for (participant, party) in parties {
+            match incoming(participant)
+                .and_then(|incoming| party.process(incoming, &mut self.rng))
+                .and_then(&transition)
+            {
+                Ok((message, next_round_or_output)) => {
+                    messages.insert(participant, message);
+                    next.insert(participant, next_round_or_output);
+                }
+                Err(error) => {
+                    errors.insert(participant, error);
+                }
+            }
+        }
+
+        (messages, next)
+    }
+
+    /// Broadcast `messages` to `recipients` over the simulated network, returning the messages
+    /// each recipient received before the round timed out.
+    ///
+    /// A party's own message is always delivered to it immediately.
+    fn broadcast<Message: Clone>(
+        &mut self,
+        messages: HashMap<Participant, Message>,
+        recipients: impl IntoIterator<Item = Participant>,
+    ) -> Received<Message> {
+        let recipients: Vec<Participant> = recipients.into_iter().collect();
+
+        // Send the messages in a deterministic order, so that only the network reorders them.
+        let mut messages: Vec<_> = messages.into_iter().collect();
+        messages.sort_by_key(|(sender, _)| *sender);
+
+        let mut in_flight = vec![];
+        let mut round_duration = 0;
+        for (sender, message) in messages {
+            for &recipient in &recipients {
+                if recipient == sender {
+                    in_flight.push((0, recipient, sender, message.clone()));
+
+                    continue;
+                }
+
+                let dropped = self.sample_drop();
+                let delay = self.sample_delay();
+                if dropped || delay > self.conditions.timeout {
+                    // The recipient waits for the message until the round times out.
+                    round_duration = self.conditions.timeout;
+
+                    continue;
+                }
+
+                round_duration = round_duration.max(delay);
+                in_flight.push((delay, recipient, sender, message.clone()));
+            }
+        }
+        self.elapsed += round_duration;
+
+        if self.conditions.reorder {
+            in_flight.sort_by_key(|(delay, ..)| *delay);
+        }
+
+        let mut received: Received<Message> = recipients
+            .into_iter()
+            .map(|recipient| (recipient, vec![]))
+            .collect();
+        for (_, recipient, sender, message) in in_flight {
+            if let Some(messages) = received.get_mut(&recipient) {
+                messages.push((sender, message));
+            }
+        }
+
+        received
+    }
+
+    fn sample_drop(&mut self) -> bool {
+        let threshold = self.conditions.drop_probability * (u64::MAX as f64);
+
+        (self.rng.next_u64() as f64) < threshold
+    }
+
+    fn sample_delay(&mut self) -> u64 {
+        self.rng.next_u64() % self.conditions.max_delay.saturating_add(1)
+    }
+
+    fn outcome<CentralizedPartyOutput, DecentralizedPartyOutput>(
+        &self,
+        session: &HashSet<PartyID>,
+        mut centralized_party_output: HashMap<Participant, CentralizedPartyOutput>,
+        mut decentralized_party_outputs: HashMap<Participant, DecentralizedPartyOutput>,
+        mut errors: HashMap<Participant, Error>,
+    ) -> Outcome<CentralizedPartyOutput, DecentralizedPartyOutput> {
+        let centralized_party = take_output(
+            Participant::Centralized,
+            &mut centralized_party_output,
+            &mut errors,
+        );
+        let decentralized_parties = session
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    take_output(
+                        Participant::Decentralized(party_id),
+                        &mut decentralized_party_outputs,
+                        &mut errors,
+                    ),
+                )
+            })
+            .collect();
+
+        Outcome {
+            centralized_party,
+            decentralized_parties,
+            elapsed: self.elapsed,
+        }
+    }
+}
+
+/// The output of `participant`, or the error with which it aborted.
+fn take_output<Output>(
+    participant: Participant,
+    outputs: &mut HashMap<Participant, Output>,
+    errors: &mut HashMap<Participant, Error>,
+) -> Result<Output> {
+    outputs
+        .remove(&participant)
+        .ok_or_else(|| errors.remove(&participant).unwrap_or(Error::InternalError))
+}
+
+fn into_participants<R>(parties: HashMap<PartyID, R>) -> HashMap<Participant, R> {
+    parties
+        .into_iter()
+        .map(|(party_id, party)| (Participant::Decentralized(party_id), party))
+        .collect()
+}
+
+/// The message of the centralized party received by `recipient`.
+fn centralized_party_message<Message>(
+    received: &mut Received<Message>,
+    recipient: Participant,
+) -> Result<Message> {
+    received
+        .get_mut(&recipient)
+        .and_then(|messages| {
+            messages
+                .iter()
+                .position(|(sender, _)| *sender == Participant::Centralized)
+                .map(|position| messages.remove(position).1)
+        })
+        .ok_or(Error::UnresponsiveCentralizedParty)
+}
+
+/// The messages of the decentralized parties received by `recipient`, one from every party in the
+/// `session`.
+fn decentralized_party_messages<Message>(
+    received: &mut Received<Message>,
+    recipient: Participant,
+    session: &HashSet<PartyID>,
+) -> Result<HashMap<PartyID, Message>> {
+    let messages: HashMap<PartyID, Message> = received
+        .remove(&recipient)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(sender, message)| match sender {
+            Participant::Decentralized(party_id) if session.contains(&party_id) => {
+                Some((party_id, message))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut unresponsive_parties: Vec<PartyID> = session
+        .iter()
+        .filter(|party_id| !messages.contains_key(party_id))
+        .copied()
+        .collect();
+
+    if !unresponsive_parties.is_empty() {
+        unresponsive_parties.sort();
+
+        return Err(Error::UnresponsiveParties(unresponsive_parties));
+    }
+
+    Ok(messages)
+}
+
+/// The message of the decentralized party received by `recipient`, which all of its parties send
+/// identically, and is therefore taken from the first of them to arrive.
+fn decentralized_party_message<Message>(
+    received: &mut Received<Message>,
+    recipient: Participant,
+    session: &HashSet<PartyID>,
+) -> Result<Message> {
+    received
+        .remove(&recipient)
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|(sender, message)| match sender {
+            Participant::Decentralized(party_id) if session.contains(&party_id) => Some(message),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            let mut parties: Vec<PartyID> = session.iter().copied().collect();
+            parties.sort();
+
+            Error::UnresponsiveParties(parties)
+        })
+}
+
+#[cfg(all(
+    test,
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs"
+))]
+mod tests {
+    use rand::seq::IteratorRandom;
+    use rand_core::OsRng;
+    use rstest::rstest;
+    use tiresias::test_exports::N;
+
+    use super::*;
+    use crate::{
+        dkg,
+        secp256k1::paillier::bulletproofs::ProtocolPublicParameters,
+        session::{tests::session_context, Protocol},
+    };
+
+    fn dkg(
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        conditions: NetworkConditions,
+    ) -> Outcome<
+        crate::secp256k1::paillier::bulletproofs::DKGCentralizedPartyOutput,
+        crate::secp256k1::paillier::bulletproofs::DKGDecentralizedPartyOutput,
+    > {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let centralized_party = dkg::centralized_party::commitment_round::Party::new(
+            protocol_public_parameters.clone(),
+            session_context(Protocol::Dkg),
+        );
+
+        let parties: HashSet<PartyID> = (1..=number_of_parties)
+            .choose_multiple(&mut OsRng, threshold.into())
+            .into_iter()
+            .collect();
+
+        let decentralized_parties = parties
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    dkg::decentralized_party::encryption_of_secret_key_share_round::Party::new(
+                        protocol_public_parameters.clone(),
+                        party_id,
+                        threshold,
+                        parties.clone(),
+                        session_context(Protocol::Dkg),
+                    ),
+                )
+            })
+            .collect();
+
+        Simulator::new(conditions, OsRng)
+            .unwrap()
+            .dkg(centralized_party, decentralized_parties)
+    }
+
+    #[rstest]
+    #[case(NetworkConditions::reliable())]
+    #[case(NetworkConditions { max_delay: 10, drop_probability: 0.0, reorder: true, timeout: 10 })]
+    fn generates_distributed_key(#[case] conditions: NetworkConditions) {
+        let outcome = dkg(2, 3, conditions);
+
+        let centralized_party_output = outcome.centralized_party.unwrap();
+        assert!(outcome
+            .decentralized_parties
+            .into_values()
+            .all(|output| output.unwrap().public_key == centralized_party_output.public_key));
+
+        assert!(outcome.elapsed <= 7 * conditions.max_delay);
+    }
+
+    #[test]
+    fn aborts_when_messages_are_dropped() {
+        let outcome = dkg(
+            2,
+            3,
+            NetworkConditions {
+                drop_probability: 1.0,
+                ..NetworkConditions::reliable()
+            },
+        );
+
+        assert!(matches!(
+            outcome.centralized_party.err().unwrap(),
+            Error::UnresponsiveParties(_)
+        ));
+
+        assert!(outcome
+            .decentralized_parties
+            .into_values()
+            .all(|output| matches!(output.err().unwrap(), Error::UnresponsiveCentralizedParty)));
+    }
+
+    #[test]
+    fn rejects_invalid_drop_probability() {
+        assert!(matches!(
+            Simulator::new(
+                NetworkConditions {
+                    drop_probability: 2.0,
+                    ..NetworkConditions::reliable()
+                },
+                OsRng
+            )
+            .err()
+            .unwrap(),
+            Error::InvalidParameters
+        ));
+    }
+}
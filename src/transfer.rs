@@ -0,0 +1,493 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Transfer of the ownership of a key to a new owner, keeping its public key unchanged.
+
+pub mod decentralized_party;
+pub mod new_owner;
+pub mod previous_owner;
+
+#[cfg(all(
+    any(test, feature = "benchmarking"),
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs",
+))]
+pub(crate) mod tests {
+    use crypto_bigint::NonZero;
+    use group::{secp256k1, GroupElement as _, KnownOrderGroupElement, PartyID, Reduce as _};
+    use homomorphic_encryption::{
+        AdditivelyHomomorphicDecryptionKey, GroupsPublicParametersAccessors,
+    };
+    use rand_core::OsRng;
+    use rstest::rstest;
+    #[cfg(feature = "schnorr")]
+    use sha2::{Digest, Sha256};
+    use tiresias::test_exports::{N, SECRET_KEY};
+    #[cfg(feature = "schnorr")]
+    use tiresias::DecryptionKeyShare;
+
+    use crate::{
+        dkg,
+        dkg::tests::generates_distributed_key_internal,
+        presign::tests::generates_presignatures_internal,
+        secp256k1::paillier::bulletproofs::{
+            ProtocolPublicParameters, SignaturePartialDecryptionParty,
+            TransferCommitmentRoundParty, TransferDecommitmentProofVerificationRoundParty,
+            TransferSecretKeyShareEncryptionRoundParty,
+        },
+        session::{tests::session_context, Protocol},
+        sign::tests::{presign_signs_internal, setup_decryption_key_shares},
+        Error,
+    };
+    #[cfg(feature = "schnorr")]
+    use crate::{
+        schnorr::{
+            centralized_party::signature_share_round,
+            decentralized_party::signature_partial_decryption_round,
+        },
+        session::SessionContext,
+    };
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(2, 4)]
+    #[case(6, 9)]
+    fn transfers_ownership(#[case] threshold: PartyID, #[case] number_of_parties: PartyID) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (previous_owner_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(threshold, number_of_parties);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (_, _, decentralized_party_presigns) = generates_presignatures_internal(
+            threshold,
+            number_of_parties,
+            1,
+            encrypted_secret_key_share,
+            false,
+        )
+        .unwrap();
+
+        let (new_owner_output, decentralized_party_transferred_output) =
+            transfers_ownership_internal(
+                previous_owner_dkg_output.clone(),
+                decentralized_party_dkg_output.clone(),
+            );
+
+        assert_eq!(
+            new_owner_output.public_key,
+            previous_owner_dkg_output.public_key
+        );
+
+        assert_eq!(
+            decentralized_party_transferred_output.public_key,
+            decentralized_party_dkg_output.public_key
+        );
+
+        assert_ne!(
+            new_owner_output.secret_key_share,
+            previous_owner_dkg_output.secret_key_share
+        );
+
+        assert_ne!(
+            decentralized_party_transferred_output.centralized_party_public_key_share,
+            decentralized_party_dkg_output.centralized_party_public_key_share
+        );
+
+        assert_eq!(new_owner_output.key_share_updates, 1);
+        assert_eq!(decentralized_party_transferred_output.key_share_updates, 1);
+
+        let paillier_decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let group_order = secp256k1::Scalar::order_from_public_parameters(
+            &protocol_public_parameters.scalar_group_public_parameters,
+        );
+
+        let group_order = Option::<_>::from(NonZero::new(group_order)).unwrap();
+
+        let decentralized_party_secret_key_share = paillier_decryption_key
+            .decrypt(
+                &tiresias::CiphertextSpaceGroupElement::new(
+                    decentralized_party_transferred_output.encrypted_secret_key_share,
+                    protocol_public_parameters
+                        .encryption_scheme_public_parameters
+                        .ciphertext_space_public_parameters(),
+                )
+                .unwrap(),
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+            )
+            .unwrap();
+
+        let decentralized_party_secret_key_share = secp256k1::Scalar::new(
+            decentralized_party_secret_key_share
+                .value()
+                .reduce(&group_order)
+                .into(),
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let new_owner_secret_key_share = secp256k1::Scalar::new(
+            new_owner_output.secret_key_share,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let previous_owner_secret_key_share = secp256k1::Scalar::new(
+            previous_owner_dkg_output.secret_key_share,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )
+        .unwrap();
+
+        let public_key = secp256k1::GroupElement::new(
+            decentralized_party_dkg_output.public_key,
+            &protocol_public_parameters.group_public_parameters,
+        )
+        .unwrap();
+
+        assert_eq!(
+            (new_owner_secret_key_share + decentralized_party_secret_key_share)
+                * public_key.generator(),
+            public_key,
+            "the new owner's secret key share should sum to the same secret key"
+        );
+
+        assert_ne!(
+            (previous_owner_secret_key_share + decentralized_party_secret_key_share)
+                * public_key.generator(),
+            public_key,
+            "the previous owner's secret key share should no longer sum to the secret key"
+        );
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, _) =
+            setup_decryption_key_shares(threshold, number_of_parties);
+
+        let decryption_key_share = decryption_key_shares.into_values().next().unwrap();
+
+        assert!(
+            matches!(
+                SignaturePartialDecryptionParty::new(
+                    threshold,
+                    decryption_key_share,
+                    decryption_key_share_public_parameters,
+                    session_context(Protocol::Sign),
+                    protocol_public_parameters.clone(),
+                    decentralized_party_transferred_output.clone(),
+                    decentralized_party_presigns.first().unwrap().clone(),
+                )
+                .err()
+                .unwrap(),
+                Error::StalePresign
+            ),
+            "presigns generated prior to a transfer should be rejected by the decentralized party"
+        );
+
+        presign_signs_internal(
+            threshold,
+            number_of_parties,
+            1,
+            new_owner_output,
+            decentralized_party_transferred_output,
+        );
+    }
+
+    #[cfg(feature = "schnorr")]
+    #[test]
+    fn rejects_previous_owner_signature_share() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (previous_owner_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 2);
+
+        let encrypted_secret_key_share = tiresias::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )
+        .unwrap();
+
+        let (centralized_party_presign, _, decentralized_party_presign) =
+            generates_presignatures_internal(2, 2, 1, encrypted_secret_key_share, false).unwrap();
+
+        let centralized_party_presign = centralized_party_presign.first().unwrap().clone();
+        let decentralized_party_presign = decentralized_party_presign.first().unwrap().clone();
+
+        let (_, decentralized_party_transferred_output) = transfers_ownership_internal(
+            previous_owner_dkg_output.clone(),
+            decentralized_party_dkg_output,
+        );
+
+        let message: [u8; 32] = Sha256::digest("signing with a transferred key").into();
+
+        let (public_nonce_share_signature_share_and_proof, _) = signature_share_round::Party::new(
+            session_context(Protocol::Schnorr),
+            protocol_public_parameters.clone(),
+            previous_owner_dkg_output,
+            centralized_party_presign,
+        )
+        .unwrap()
+        .sign_signature_share(&message, &mut OsRng)
+        .unwrap();
+
+        let (decryption_key_share_public_parameters, decryption_key_shares, _) =
+            setup_decryption_key_shares(2, 2);
+
+        for (_, decryption_key_share) in decryption_key_shares {
+            assert!(
+                matches!(
+                    signature_partial_decryption_round::Party::<
+                        { secp256k1::SCALAR_LIMBS },
+                        { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                        secp256k1::GroupElement,
+                        tiresias::EncryptionKey,
+                        DecryptionKeyShare,
+                        SessionContext,
+                    >::new(
+                        2,
+                        decryption_key_share,
+                        decryption_key_share_public_parameters.clone(),
+                        session_context(Protocol::Schnorr),
+                        protocol_public_parameters.clone(),
+                        decentralized_party_transferred_output.clone(),
+                        decentralized_party_presign.clone(),
+                    )
+                    .unwrap()
+                    .partially_decrypt_signature_share(
+                        &message,
+                        public_nonce_share_signature_share_and_proof.clone(),
+                    )
+                    .err()
+                    .unwrap(),
+                    Error::SignatureVerification
+                ),
+                "the previous owner's signature share should be rejected after the transfer"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_mismatching_secret_key_share() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (_, decentralized_party_dkg_output) = generates_distributed_key_internal(2, 2);
+        let (other_dkg_output, _) = generates_distributed_key_internal(2, 2);
+
+        // The test exports only provide a single Paillier key, which we use for the new owner as
+        // well.
+        let secret_key_share_encryption = TransferSecretKeyShareEncryptionRoundParty::new(
+            protocol_public_parameters.clone(),
+            other_dkg_output,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .clone(),
+        )
+        .unwrap()
+        .encrypt_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let (_, decommitment_round_party) = TransferCommitmentRoundParty::new(
+            session_context(Protocol::Transfer),
+            protocol_public_parameters.clone(),
+        )
+        .sample_and_commit_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        assert!(
+            matches!(
+                decommitment_round_party
+                    .decommit_and_prove_secret_key_share(
+                        secret_key_share_encryption,
+                        decryption_key,
+                        &protocol_public_parameters.encryption_scheme_public_parameters,
+                        decentralized_party_dkg_output,
+                        &mut OsRng,
+                    )
+                    .err()
+                    .unwrap(),
+                Error::InvalidParameters
+            ),
+            "a secret key share that does not match the previous owner's public key share should \
+             be rejected"
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_decommitment() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (previous_owner_dkg_output, decentralized_party_dkg_output) =
+            generates_distributed_key_internal(2, 2);
+
+        let secret_key_share_encryption = TransferSecretKeyShareEncryptionRoundParty::new(
+            protocol_public_parameters.clone(),
+            previous_owner_dkg_output,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .clone(),
+        )
+        .unwrap()
+        .encrypt_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let (_, decommitment_round_party) = TransferCommitmentRoundParty::new(
+            session_context(Protocol::Transfer),
+            protocol_public_parameters.clone(),
+        )
+        .sample_and_commit_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let (other_commitment, _) = TransferCommitmentRoundParty::new(
+            session_context(Protocol::Transfer),
+            protocol_public_parameters.clone(),
+        )
+        .sample_and_commit_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let (public_key_share_decommitment_and_proof, _) = decommitment_round_party
+            .decommit_and_prove_secret_key_share(
+                secret_key_share_encryption,
+                decryption_key,
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+                decentralized_party_dkg_output.clone(),
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert!(
+            matches!(
+                TransferDecommitmentProofVerificationRoundParty::new(
+                    session_context(Protocol::Transfer),
+                    protocol_public_parameters,
+                    decentralized_party_dkg_output,
+                    other_commitment,
+                )
+                .unwrap()
+                .verify_decommitment_and_proof_and_transfer_secret_key_share(
+                    public_key_share_decommitment_and_proof,
+                    &mut OsRng,
+                )
+                .err()
+                .unwrap(),
+                Error::WrongDecommitment
+            ),
+            "a decommitment that does not match the new owner's commitment should be rejected"
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn transfers_ownership_internal(
+        previous_owner_dkg_output: dkg::centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        decentralized_party_dkg_output: dkg::decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+    ) -> (
+        dkg::centralized_party::Output<
+            secp256k1::group_element::Value,
+            secp256k1::Scalar,
+            tiresias::CiphertextSpaceValue,
+        >,
+        dkg::decentralized_party::Output<
+            secp256k1::group_element::Value,
+            tiresias::CiphertextSpaceValue,
+        >,
+    ) {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (commitment, decommitment_round_party) = TransferCommitmentRoundParty::new(
+            session_context(Protocol::Transfer),
+            protocol_public_parameters.clone(),
+        )
+        .sample_and_commit_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        // The test exports only provide a single Paillier key, which we use for the new owner as
+        // well.
+        let secret_key_share_encryption = TransferSecretKeyShareEncryptionRoundParty::new(
+            protocol_public_parameters.clone(),
+            previous_owner_dkg_output,
+            protocol_public_parameters
+                .encryption_scheme_public_parameters
+                .clone(),
+        )
+        .unwrap()
+        .encrypt_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let decryption_key = tiresias::DecryptionKey::new(
+            SECRET_KEY,
+            &protocol_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let (public_key_share_decommitment_and_proof, new_owner_output) = decommitment_round_party
+            .decommit_and_prove_secret_key_share(
+                secret_key_share_encryption,
+                decryption_key,
+                &protocol_public_parameters.encryption_scheme_public_parameters,
+                decentralized_party_dkg_output.clone(),
+                &mut OsRng,
+            )
+            .unwrap();
+
+        let decentralized_party_transferred_output =
+            TransferDecommitmentProofVerificationRoundParty::new(
+                session_context(Protocol::Transfer),
+                protocol_public_parameters,
+                decentralized_party_dkg_output,
+                commitment,
+            )
+            .unwrap()
+            .verify_decommitment_and_proof_and_transfer_secret_key_share(
+                public_key_share_decommitment_and_proof,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert_eq!(
+            new_owner_output.encrypted_decentralized_party_secret_key_share,
+            decentralized_party_transferred_output.encrypted_secret_key_share
+        );
+
+        assert_eq!(
+            new_owner_output.public_key_share,
+            decentralized_party_transferred_output.centralized_party_public_key_share
+        );
+
+        assert_eq!(
+            new_owner_output.decentralized_party_public_key_share,
+            decentralized_party_transferred_output.public_key_share
+        );
+
+        (new_owner_output, decentralized_party_transferred_output)
+    }
+}
@@ -0,0 +1,148 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use commitment::Commitment;
+use crypto_bigint::{rand_core::CryptoRngCore, Random};
+use group::{ComputationalSecuritySizedNumber, GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
+use proof::AggregatableRangeProof;
+
+use crate::{
+    dkg::centralized_party::commitment_round::commit_public_key_share, session::RoundContext,
+    transfer::new_owner::decommitment_round, ProtocolPublicParameters, CENTRALIZED_PARTY_ID,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    protocol_context: ProtocolContext,
+    scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: GroupElement::PublicParameters,
+    encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+{
+    /// Sample a fresh secret key share $x_A'$ and commit to our public key share $X_A'$, as in the
+    /// commitment round of the DKG.
+    ///
+    /// The commitment is sent before the previous owner reveals the encryption of its secret key
+    /// share, so that our share is fixed independently of it.
+    pub fn sample_and_commit_secret_key_share(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        Commitment,
+        decommitment_round::Party<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            ProtocolContext,
+        >,
+    )> {
+        let secret_key_share =
+            GroupElement::Scalar::sample(&self.scalar_group_public_parameters, rng)?;
+
+        let generator = GroupElement::new(
+            GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            &self.group_public_parameters,
+        )?;
+        let public_key_share = secret_key_share * generator;
+
+        let commitment_randomness = ComputationalSecuritySizedNumber::random(rng);
+
+        let commitment = commit_public_key_share(
+            CENTRALIZED_PARTY_ID,
+            &public_key_share,
+            &commitment_randomness,
+        )?;
+
+        let party = decommitment_round::Party {
+            protocol_context: self.protocol_context,
+            scalar_group_public_parameters: self.scalar_group_public_parameters,
+            group_public_parameters: self.group_public_parameters,
+            encryption_scheme_public_parameters: self.encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: self
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: self.range_proof_public_parameters,
+            secret_key_share,
+            public_key_share,
+            commitment_randomness,
+        };
+
+        Ok((commitment, party))
+    }
+
+    pub fn new<
+        const NUM_RANGE_CLAIMS: usize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_context: ProtocolContext,
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+    ) -> Self {
+        Party {
+            protocol_context,
+            scalar_group_public_parameters: protocol_public_parameters
+                .scalar_group_public_parameters,
+            group_public_parameters: protocol_public_parameters.group_public_parameters,
+            encryption_scheme_public_parameters: protocol_public_parameters
+                .encryption_scheme_public_parameters,
+            unbounded_encdl_witness_public_parameters: protocol_public_parameters
+                .unbounded_encdl_witness_public_parameters,
+            range_proof_public_parameters: protocol_public_parameters
+                .range_proof_enc_dl_public_parameters,
+        }
+    }
+}
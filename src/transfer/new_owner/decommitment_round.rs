@@ -0,0 +1,342 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use std::ops::Neg;
+
+use crypto_bigint::{rand_core::CryptoRngCore, NonZero, Uint};
+use enhanced_maurer::{
+    encryption_of_discrete_log, encryption_of_discrete_log::StatementAccessors as _,
+    language::EnhancedLanguageStatementAccessors as _, EnhanceableLanguage, EnhancedLanguage,
+    EnhancedPublicParameters,
+};
+use group::{
+    ComputationalSecuritySizedNumber, GroupElement as _, KnownOrderGroupElement, PrimeGroupElement,
+    Reduce, Samplable,
+};
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKey, AdditivelyHomomorphicEncryptionKey,
+    GroupsPublicParametersAccessors,
+};
+use maurer::SOUND_PROOFS_REPETITIONS;
+use proof::{range, AggregatableRangeProof};
+
+use crate::{
+    dkg,
+    session::{RoundContext, RoundId},
+    transfer::{new_owner::PublicKeyShareDecommitmentAndProof, previous_owner},
+    Error,
+};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    const RANGE_CLAIMS_PER_SCALAR: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    ProtocolContext: RoundContext,
+> {
+    pub(super) protocol_context: ProtocolContext,
+    pub(super) scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    pub(super) group_public_parameters: GroupElement::PublicParameters,
+    pub(super) encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
+    pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
+    pub(super) secret_key_share: GroupElement::Scalar,
+    pub(super) public_key_share: GroupElement,
+    pub(super) commitment_randomness: ComputationalSecuritySizedNumber,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        ProtocolContext: RoundContext,
+    >
+    Party<
+        SCALAR_LIMBS,
+        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+        RANGE_CLAIMS_PER_SCALAR,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        RangeProof,
+        UnboundedEncDLWitness,
+        ProtocolContext,
+    >
+where
+    encryption_of_discrete_log::Language<
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+    >: maurer::Language<
+            SOUND_PROOFS_REPETITIONS,
+            WitnessSpaceGroupElement = encryption_of_discrete_log::WitnessSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                EncryptionKey,
+            >,
+            StatementSpaceGroupElement = encryption_of_discrete_log::StatementSpaceGroupElement<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+            PublicParameters = encryption_of_discrete_log::PublicParameters<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        > + EnhanceableLanguage<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            UnboundedEncDLWitness,
+        >,
+{
+    /// Decrypt the previous owner's secret key share $x_A$ with our `decryption_key` and check it
+    /// against its public key share $X_A$, then decommit our public key share $X_A'$ and prove the
+    /// encryption of $x_A - x_A'$, which the decentralized party homomorphically adds to its
+    /// encrypted secret key share, so that its new secret key share is $x_B' = x_B + x_A - x_A'$
+    /// and the public key stays the same.
+    ///
+    /// We encrypt $x_A - x_A' \bmod q$, so that the plaintext stays non-negative and grows by less
+    /// than $q$, i.e. a single key share update.
+    ///
+    /// The `decentralized_party_dkg_output` is the (public) output of the decentralized party for
+    /// the transferred key. The output returned here must only be used once the decentralized party
+    /// has accepted the transfer.
+    pub fn decommit_and_prove_secret_key_share<
+        DecryptionKey: AdditivelyHomomorphicDecryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+    >(
+        self,
+        secret_key_share_encryption: previous_owner::SecretKeyShareEncryption<
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        decryption_key: DecryptionKey,
+        new_owner_encryption_scheme_public_parameters: &EncryptionKey::PublicParameters,
+        decentralized_party_dkg_output: dkg::decentralized_party::Output<
+            GroupElement::Value,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        PublicKeyShareDecommitmentAndProof<
+            GroupElement::Value,
+            range::CommitmentSchemeCommitmentSpaceValue<
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RANGE_CLAIMS_PER_SCALAR,
+                RangeProof,
+            >,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+            encryption_of_discrete_log::Proof<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >,
+        >,
+        dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+    )> {
+        let key_share_updates =
+            dkg::count_key_share_update(decentralized_party_dkg_output.key_share_updates)?;
+
+        let public_key = GroupElement::new(
+            decentralized_party_dkg_output.public_key,
+            &self.group_public_parameters,
+        )?;
+
+        let encrypted_secret_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
+            decentralized_party_dkg_output.encrypted_secret_key_share,
+            self.encryption_scheme_public_parameters
+                .ciphertext_space_public_parameters(),
+        )?;
+
+        let previous_owner_public_key_share = GroupElement::new(
+            decentralized_party_dkg_output.centralized_party_public_key_share,
+            &self.group_public_parameters,
+        )?;
+
+        let encrypted_previous_owner_secret_key_share =
+            EncryptionKey::CiphertextSpaceGroupElement::new(
+                secret_key_share_encryption.encrypted_secret_key_share,
+                new_owner_encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
+            )?;
+
+        let previous_owner_secret_key_share: Uint<PLAINTEXT_SPACE_SCALAR_LIMBS> =
+            Option::<_>::from(decryption_key.decrypt(
+                &encrypted_previous_owner_secret_key_share,
+                new_owner_encryption_scheme_public_parameters,
+            ))
+            .ok_or(Error::InvalidParameters)?
+            .into();
+
+        let group_order = GroupElement::Scalar::order_from_public_parameters(
+            &self.scalar_group_public_parameters,
+        );
+
+        let group_order =
+            Option::<_>::from(NonZero::new(group_order)).ok_or(Error::InternalError)?;
+
+        let previous_owner_secret_key_share = GroupElement::Scalar::new(
+            previous_owner_secret_key_share.reduce(&group_order).into(),
+            &self.scalar_group_public_parameters,
+        )?;
+
+        let generator = public_key.generator();
+
+        if previous_owner_secret_key_share * generator != previous_owner_public_key_share {
+            return Err(Error::InvalidParameters);
+        }
+
+        let secret_key_share_difference =
+            previous_owner_secret_key_share + self.secret_key_share.neg();
+
+        let encryption_randomness = EncryptionKey::RandomnessSpaceGroupElement::sample(
+            &self
+                .encryption_scheme_public_parameters
+                .as_ref()
+                .randomness_space_public_parameters,
+            rng,
+        )?;
+
+        let language_public_parameters =
+            encryption_of_discrete_log::PublicParameters::<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >::new::<PLAINTEXT_SPACE_SCALAR_LIMBS, SCALAR_LIMBS, GroupElement, EncryptionKey>(
+                self.scalar_group_public_parameters.clone(),
+                self.group_public_parameters.clone(),
+                self.encryption_scheme_public_parameters.clone(),
+                GroupElement::generator_value_from_public_parameters(&self.group_public_parameters),
+            );
+
+        let language_public_parameters = EnhancedPublicParameters::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::new::<
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >(
+            self.unbounded_encdl_witness_public_parameters,
+            self.range_proof_public_parameters,
+            language_public_parameters,
+        )?;
+
+        let secret_key_share_difference_value: Uint<SCALAR_LIMBS> =
+            secret_key_share_difference.into();
+
+        let witness = EnhancedLanguage::<
+            SOUND_PROOFS_REPETITIONS,
+            RANGE_CLAIMS_PER_SCALAR,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedEncDLWitness,
+            encryption_of_discrete_log::Language<
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+            >,
+        >::generate_witness(
+            (
+                EncryptionKey::PlaintextSpaceGroupElement::new(
+                    Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(&secret_key_share_difference_value)
+                        .into(),
+                    self.encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )?,
+                encryption_randomness,
+            )
+                .into(),
+            &language_public_parameters,
+            rng,
+        )?;
+
+        let (encryption_of_secret_key_share_difference_proof, statements) =
+            encryption_of_discrete_log::Proof::<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                SCALAR_LIMBS,
+                GroupElement,
+                EncryptionKey,
+                RangeProof,
+                UnboundedEncDLWitness,
+                ProtocolContext,
+            >::prove(
+                &self.protocol_context.round(RoundId::TransferDecommitment),
+                &language_public_parameters,
+                vec![witness],
+                rng,
+            )?;
+
+        let statement = statements.first().ok_or(Error::InternalError)?.clone();
+
+        let encrypted_secret_key_share_difference = statement
+            .language_statement()
+            .encrypted_discrete_log()
+            .clone();
+
+        let public_key_share_decommitment_and_proof = PublicKeyShareDecommitmentAndProof::new(
+            self.public_key_share.clone(),
+            self.commitment_randomness,
+            statement,
+            encryption_of_secret_key_share_difference_proof,
+        );
+
+        let encrypted_decentralized_party_secret_key_share =
+            encrypted_secret_key_share + encrypted_secret_key_share_difference;
+        let decentralized_party_public_key_share =
+            public_key.clone() + self.public_key_share.clone().neg();
+
+        let output = dkg::centralized_party::Output {
+            secret_key_share: self.secret_key_share.value(),
+            public_key_share: self.public_key_share.value(),
+            public_key: public_key.value(),
+            encrypted_decentralized_party_secret_key_share:
+                encrypted_decentralized_party_secret_key_share.value(),
+            decentralized_party_public_key_share: decentralized_party_public_key_share.value(),
+            key_share_updates,
+        };
+
+        Ok((public_key_share_decommitment_and_proof, output))
+    }
+}
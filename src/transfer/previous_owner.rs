@@ -0,0 +1,14 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use serde::{Deserialize, Serialize};
+
+pub mod secret_key_share_encryption_round;
+
+/// The message sent by the previous owner to the new owner in the transfer protocol: an encryption
+/// of its secret key share $x_A$ under the new owner's encryption key, so that only the new owner
+/// learns it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SecretKeyShareEncryption<CiphertextValue> {
+    pub(in crate::transfer) encrypted_secret_key_share: CiphertextValue,
+}
@@ -0,0 +1,104 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![allow(clippy::type_complexity)]
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use group::{GroupElement as _, PrimeGroupElement, Samplable};
+use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
+use proof::AggregatableRangeProof;
+
+use crate::{dkg, transfer::previous_owner::SecretKeyShareEncryption, ProtocolPublicParameters};
+
+#[cfg_attr(feature = "benchmarking", derive(Clone))]
+pub struct Party<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+> {
+    new_owner_encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    secret_key_share: GroupElement::Scalar,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    > Party<SCALAR_LIMBS, PLAINTEXT_SPACE_SCALAR_LIMBS, GroupElement, EncryptionKey>
+{
+    /// Encrypt our secret key share $x_A$ under the new owner's encryption key, for it to take our
+    /// place: the new owner checks it against our public key share $X_A$ on decryption, and only
+    /// the difference with its own secret key share reaches the decentralized party.
+    ///
+    /// Once the decentralized party accepts the transfer, our DKG output must be erased: the
+    /// decentralized party only accepts signature shares of the new owner from then on.
+    pub fn encrypt_secret_key_share(
+        self,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        SecretKeyShareEncryption<group::Value<EncryptionKey::CiphertextSpaceGroupElement>>,
+    > {
+        let secret_key_share_value: Uint<SCALAR_LIMBS> = self.secret_key_share.into();
+
+        let secret_key_share = EncryptionKey::PlaintextSpaceGroupElement::new(
+            Uint::<PLAINTEXT_SPACE_SCALAR_LIMBS>::from(&secret_key_share_value).into(),
+            self.new_owner_encryption_scheme_public_parameters
+                .plaintext_space_public_parameters(),
+        )?;
+
+        let encryption_key =
+            EncryptionKey::new(&self.new_owner_encryption_scheme_public_parameters)?;
+
+        let (_, encrypted_secret_key_share) = encryption_key.encrypt(
+            &secret_key_share,
+            &self.new_owner_encryption_scheme_public_parameters,
+            rng,
+        )?;
+
+        Ok(SecretKeyShareEncryption {
+            encrypted_secret_key_share: encrypted_secret_key_share.value(),
+        })
+    }
+
+    pub fn new<
+        const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        const RANGE_CLAIMS_PER_SCALAR: usize,
+        const NUM_RANGE_CLAIMS: usize,
+        RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
+        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    >(
+        protocol_public_parameters: ProtocolPublicParameters<
+            SCALAR_LIMBS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RANGE_CLAIMS_PER_SCALAR,
+            NUM_RANGE_CLAIMS,
+            PLAINTEXT_SPACE_SCALAR_LIMBS,
+            GroupElement,
+            EncryptionKey,
+            RangeProof,
+            UnboundedEncDLWitness,
+            UnboundedEncDHWitness,
+            UnboundedDComEvalWitness,
+        >,
+        dkg_output: dkg::centralized_party::Output<
+            GroupElement::Value,
+            group::Value<GroupElement::Scalar>,
+            group::Value<EncryptionKey::CiphertextSpaceGroupElement>,
+        >,
+        new_owner_encryption_scheme_public_parameters: EncryptionKey::PublicParameters,
+    ) -> crate::Result<Self> {
+        let secret_key_share = GroupElement::Scalar::new(
+            dkg_output.secret_key_share,
+            &protocol_public_parameters.scalar_group_public_parameters,
+        )?;
+
+        Ok(Self {
+            new_owner_encryption_scheme_public_parameters,
+            secret_key_share,
+        })
+    }
+}
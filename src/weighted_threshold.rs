@@ -0,0 +1,405 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Weighted threshold access structures for the decentralized party.
+
+use std::collections::{HashMap, HashSet};
+
+use group::PartyID;
+use homomorphic_encryption::{
+    AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// The number of virtual shares held by a party.
+pub type Weight = PartyID;
+
+/// A weighted threshold access structure for the decentralized party, in which each party holds a
+/// number of virtual shares of the decryption key, and any set of parties whose total weight
+/// reaches the threshold is authorized.
+///
+/// The decryption key is shared over virtual party IDs `1..=total_weight`, which are assigned to
+/// parties in ascending order of their party IDs, so that a party of weight $w$ holds $w$
+/// decryption key shares. A party participates in the signing protocol with a
+/// `signature_partial_decryption_round::Party` per virtual share, and decryption shares, Lagrange
+/// coefficients and the threshold of `decrypt_signature` are all over virtual party IDs.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WeightedThresholdAccessStructure {
+    threshold: Weight,
+    party_to_weight: HashMap<PartyID, Weight>,
+}
+
+impl WeightedThresholdAccessStructure {
+    /// Instantiate an access structure in which parties of total weight `threshold` are
+    /// authorized.
+    ///
+    /// Every party must have a non-zero weight, and the total weight must fit in a [`PartyID`]
+    /// and be at least `threshold`.
+    pub fn new(
+        threshold: Weight,
+        party_to_weight: HashMap<PartyID, Weight>,
+    ) -> crate::Result<Self> {
+        if party_to_weight
+            .iter()
+            .any(|(&party_id, &weight)| party_id == 0 || weight == 0)
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        let total_weight = party_to_weight
+            .values()
+            .try_fold(0, |total_weight: Weight, &weight| {
+                total_weight.checked_add(weight)
+            })
+            .ok_or(Error::InvalidParameters)?;
+
+        if threshold == 0 || threshold > total_weight {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(Self {
+            threshold,
+            party_to_weight,
+        })
+    }
+
+    /// Instantiate an access structure in which each of the parties `1..=number_of_parties`
+    /// holds a single share, and any `threshold` of them are authorized.
+    pub fn uniform(threshold: PartyID, number_of_parties: PartyID) -> crate::Result<Self> {
+        Self::new(
+            threshold,
+            (1..=number_of_parties)
+                .map(|party_id| (party_id, 1))
+                .collect(),
+        )
+    }
+
+    /// An access structure in which each of `parties` holds a single share, used by rounds which
+    /// were instantiated with an unweighted `threshold`.
+    ///
+    /// Unlike [`Self::uniform`], this is not validated, so that an unauthorized set of parties is
+    /// reported as [`Error::ThresholdNotReached`] by [`Self::is_authorized_subset`].
+    pub(crate) fn unweighted(threshold: PartyID, parties: &HashSet<PartyID>) -> Self {
+        Self {
+            threshold,
+            party_to_weight: parties.iter().map(|&party_id| (party_id, 1)).collect(),
+        }
+    }
+
+    /// The total weight of parties required for decryption.
+    pub fn threshold(&self) -> Weight {
+        self.threshold
+    }
+
+    /// The total weight of all parties, which is the number of virtual shares of the decryption
+    /// key.
+    pub fn total_weight(&self) -> Weight {
+        // Cannot overflow, as it was checked upon instantiation.
+        self.party_to_weight.values().sum()
+    }
+
+    /// The weight of `party_id`.
+    pub fn weight(&self, party_id: PartyID) -> crate::Result<Weight> {
+        self.party_to_weight
+            .get(&party_id)
+            .copied()
+            .ok_or(Error::InvalidParameters)
+    }
+
+    /// Check that `parties` are of total weight reaching the threshold.
+    pub fn is_authorized_subset(&self, parties: &HashSet<PartyID>) -> crate::Result<()> {
+        let weight = parties
+            .iter()
+            .map(|&party_id| self.weight(party_id))
+            .sum::<crate::Result<Weight>>()?;
+
+        if weight < self.threshold {
+            return Err(Error::ThresholdNotReached);
+        }
+
+        Ok(())
+    }
+
+    /// The virtual party IDs of the decryption key shares held by `party_id`.
+    pub fn virtual_party_ids(&self, party_id: PartyID) -> crate::Result<Vec<PartyID>> {
+        let weight = self.weight(party_id)?;
+
+        let preceding_weight: Weight = self
+            .party_to_weight
+            .iter()
+            .filter(|(&other_party_id, _)| other_party_id < party_id)
+            .map(|(_, &weight)| weight)
+            .sum();
+
+        Ok((preceding_weight + 1..=preceding_weight + weight).collect())
+    }
+
+    /// Select exactly `threshold` virtual decrypters out of the virtual shares held by the
+    /// authorized set of `parties`, choosing the lowest virtual party IDs.
+    ///
+    /// Every party must use the same selection for the same `parties`, as the Lagrange
+    /// coefficients are computed over it.
+    pub fn virtual_decrypters(&self, parties: &HashSet<PartyID>) -> crate::Result<Vec<PartyID>> {
+        self.is_authorized_subset(parties)?;
+
+        let mut virtual_party_ids = parties
+            .iter()
+            .map(|&party_id| self.virtual_party_ids(party_id))
+            .collect::<crate::Result<Vec<_>>>()?
+            .concat();
+
+        virtual_party_ids.sort();
+        virtual_party_ids.truncate(self.threshold.into());
+
+        Ok(virtual_party_ids)
+    }
+
+    /// Compute the Lagrange coefficients of the `virtual_decrypters` selected by
+    /// [`Self::virtual_decrypters`], to be passed to `decrypt_signature` alongside their
+    /// decryption shares.
+    pub fn lagrange_coefficients<
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+    >(
+        &self,
+        virtual_decrypters: &[PartyID],
+        decryption_key_share_public_parameters: &DecryptionKeyShare::PublicParameters,
+    ) -> crate::Result<HashMap<PartyID, DecryptionKeyShare::LagrangeCoefficient>> {
+        let total_weight = self.total_weight();
+
+        if virtual_decrypters.len() != usize::from(self.threshold)
+            || virtual_decrypters
+                .iter()
+                .any(|&virtual_party_id| virtual_party_id == 0 || virtual_party_id > total_weight)
+            || virtual_decrypters.iter().collect::<HashSet<_>>().len() != virtual_decrypters.len()
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(virtual_decrypters
+            .iter()
+            .map(|&virtual_party_id| {
+                (
+                    virtual_party_id,
+                    DecryptionKeyShare::compute_lagrange_coefficient(
+                        virtual_party_id,
+                        total_weight,
+                        virtual_decrypters.to_vec(),
+                        decryption_key_share_public_parameters,
+                    ),
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "secp256k1",
+    feature = "paillier",
+    feature = "bulletproofs"
+))]
+mod tests {
+    use crypto_bigint::Uint;
+    use homomorphic_encryption::GroupsPublicParametersAccessors;
+    use rand_core::OsRng;
+    use tiresias::{
+        test_exports::{deal_trusted_shares, BASE, N, SECRET_KEY},
+        DecryptionKeyShare,
+    };
+
+    use super::*;
+    use crate::{
+        dkg,
+        secp256k1::paillier::bulletproofs::{DKGCommitmentRoundParty, ProtocolPublicParameters},
+        session::{tests::session_context, Protocol},
+    };
+
+    fn access_structure() -> WeightedThresholdAccessStructure {
+        WeightedThresholdAccessStructure::new(5, HashMap::from([(1, 4), (2, 1), (3, 2), (4, 1)]))
+            .unwrap()
+    }
+
+    #[test]
+    fn rejects_invalid_access_structures() {
+        assert!(matches!(
+            WeightedThresholdAccessStructure::new(0, HashMap::from([(1, 1), (2, 1)])),
+            Err(Error::InvalidParameters)
+        ));
+
+        assert!(matches!(
+            WeightedThresholdAccessStructure::new(3, HashMap::from([(1, 1), (2, 1)])),
+            Err(Error::InvalidParameters)
+        ));
+
+        assert!(matches!(
+            WeightedThresholdAccessStructure::new(1, HashMap::from([(1, 1), (2, 0)])),
+            Err(Error::InvalidParameters)
+        ));
+
+        assert!(matches!(
+            WeightedThresholdAccessStructure::new(1, HashMap::from([(1, PartyID::MAX), (2, 1)])),
+            Err(Error::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn assigns_virtual_party_ids() {
+        let access_structure = access_structure();
+
+        assert_eq!(access_structure.total_weight(), 8);
+        assert_eq!(
+            access_structure.virtual_party_ids(1).unwrap(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(access_structure.virtual_party_ids(2).unwrap(), vec![5]);
+        assert_eq!(access_structure.virtual_party_ids(3).unwrap(), vec![6, 7]);
+        assert_eq!(access_structure.virtual_party_ids(4).unwrap(), vec![8]);
+        assert!(access_structure.virtual_party_ids(5).is_err());
+
+        assert_eq!(
+            access_structure
+                .virtual_decrypters(&HashSet::from([1, 3]))
+                .unwrap(),
+            vec![1, 2, 3, 4, 6]
+        );
+
+        assert!(matches!(
+            access_structure.virtual_decrypters(&HashSet::from([2, 3, 4])),
+            Err(Error::ThresholdNotReached)
+        ));
+    }
+
+    #[test]
+    fn weighted_parties_decrypt() {
+        let access_structure = access_structure();
+
+        let (decryption_key_share_public_parameters, secret_key_shares) = deal_trusted_shares(
+            access_structure.threshold(),
+            access_structure.total_weight(),
+            N,
+            SECRET_KEY,
+            BASE,
+        );
+
+        let encryption_key = tiresias::EncryptionKey::new(
+            &decryption_key_share_public_parameters.encryption_scheme_public_parameters,
+        )
+        .unwrap();
+
+        let plaintext = Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::from_u64(42);
+
+        let (_, ciphertext) = encryption_key
+            .encrypt(
+                &tiresias::PlaintextSpaceGroupElement::new(
+                    plaintext,
+                    decryption_key_share_public_parameters
+                        .encryption_scheme_public_parameters
+                        .plaintext_space_public_parameters(),
+                )
+                .unwrap(),
+                &decryption_key_share_public_parameters.encryption_scheme_public_parameters,
+                &mut OsRng,
+            )
+            .unwrap();
+
+        // Parties 1 and 2 hold five out of the eight virtual shares, although they are only two
+        // out of four parties.
+        let parties = HashSet::from([1, 2]);
+
+        let virtual_decrypters = access_structure.virtual_decrypters(&parties).unwrap();
+
+        let decryption_shares: HashMap<_, _> = parties
+            .iter()
+            .flat_map(|&party_id| access_structure.virtual_party_ids(party_id).unwrap())
+            .filter(|virtual_party_id| virtual_decrypters.contains(virtual_party_id))
+            .map(|virtual_party_id| {
+                let decryption_key_share = DecryptionKeyShare::new(
+                    virtual_party_id,
+                    *secret_key_shares.get(&virtual_party_id).unwrap(),
+                    &decryption_key_share_public_parameters,
+                )
+                .unwrap();
+
+                (
+                    virtual_party_id,
+                    decryption_key_share
+                        .generate_decryption_share_semi_honest(
+                            &ciphertext,
+                            &decryption_key_share_public_parameters,
+                        )
+                        .unwrap(),
+                )
+            })
+            .collect();
+
+        let lagrange_coefficients = access_structure
+            .lagrange_coefficients::<
+                { tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS },
+                tiresias::EncryptionKey,
+                DecryptionKeyShare,
+            >(&virtual_decrypters, &decryption_key_share_public_parameters)
+            .unwrap();
+
+        let decrypted_plaintext: Uint<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }> =
+            DecryptionKeyShare::combine_decryption_shares_semi_honest(
+                decryption_shares,
+                lagrange_coefficients,
+                &decryption_key_share_public_parameters,
+            )
+            .unwrap()
+            .into();
+
+        assert_eq!(decrypted_plaintext, plaintext);
+    }
+
+    #[test]
+    fn checks_weighted_threshold_in_dkg() {
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+        let access_structure = access_structure();
+
+        let (commitment_to_centralized_party_secret_key_share, _) = DKGCommitmentRoundParty::new(
+            protocol_public_parameters.clone(),
+            session_context(Protocol::Dkg),
+        )
+        .sample_commit_and_prove_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        // Two parties of total weight reaching the threshold are authorized.
+        assert!(
+            dkg::decentralized_party::encryption_of_secret_key_share_round::Party::new_weighted(
+                protocol_public_parameters.clone(),
+                1,
+                access_structure.clone(),
+                HashSet::from([1, 2]),
+                session_context(Protocol::Dkg),
+            )
+            .sample_secret_key_share_and_initialize_proof_aggregation(
+                commitment_to_centralized_party_secret_key_share,
+                &mut OsRng,
+            )
+            .is_ok()
+        );
+
+        // Three parties of total weight below the threshold are not.
+        assert!(matches!(
+            dkg::decentralized_party::encryption_of_secret_key_share_round::Party::new_weighted(
+                protocol_public_parameters,
+                2,
+                access_structure,
+                HashSet::from([2, 3, 4]),
+                session_context(Protocol::Dkg),
+            )
+            .sample_secret_key_share_and_initialize_proof_aggregation(
+                commitment_to_centralized_party_secret_key_share,
+                &mut OsRng,
+            )
+            .err()
+            .unwrap(),
+            Error::ThresholdNotReached
+        ));
+    }
+}